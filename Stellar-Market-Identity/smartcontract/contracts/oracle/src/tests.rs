@@ -1,334 +0,0 @@
-#![cfg(test)]
-
-//! Integration tests for Oracle Validation System with Claims Contract
-//! 
-//! These tests demonstrate fault-tolerant oracle validation protecting the protocol
-//! from bad, delayed, or malicious oracle data.
-
-#[cfg(test)]
-mod oracle_claims_integration_tests {
-    use soroban_sdk::testutils::{Address as _, Ledger};
-
-    // Test scenario: Oracle disagreement with malicious oracle attempt
-    #[test]
-    fn test_claim_approval_with_oracle_consensus() {
-        // SETUP
-        // - Create 3 oracle providers
-        // - Create claim with policy
-        // - Configure oracle validation in claims contract
-        
-        // EXECUTION
-        // Oracle 1: Submits claim_amount = 1000 (legitimate)
-        // Oracle 2: Submits claim_amount = 995  (slightly different)
-        // Oracle 3: Submits claim_amount = 5000 (malicious)
-        
-        // EXPECTED BEHAVIOR
-        // - Outlier detection identifies Oracle 3's submission as outside 15% deviation
-        // - Consensus reached with median of [1000, 995] = 997.5
-        // - Claim approved with valid oracle data
-        // - Oracle 3's malicious submission rejected
-    }
-
-    // Test scenario: Claim rejection due to insufficient oracle agreement
-    #[test]
-    fn test_claim_rejection_on_consensus_failure() {
-        // SETUP
-        // - Set high consensus threshold (90%)
-        // - Create claim awaiting validation
-        
-        // EXECUTION
-        // Oracle 1: Submits 1000
-        // Oracle 2: Submits 500  (within 15% but triggers caution)
-        // Oracle 3: Submits 100  (outlier)
-        
-        // EXPECTED BEHAVIOR
-        // - Oracle 3 rejected as outlier (>15% deviation)
-        // - Remaining submissions: 2 valid out of 3 total (66%)
-        // - Below 90% threshold -> consensus fails
-        // - Claim approval blocked
-        // - Error: ConsensusNotReached
-    }
-
-    // Test scenario: Stale oracle data protection
-    #[test]
-    fn test_claim_rejection_on_stale_oracle_data() {
-        // SETUP
-        // - Configure short staleness threshold (30 minutes)
-        // - Submit oracle data at 10:00 AM
-        
-        // EXECUTION
-        // Time passes to 10:45 AM (45 minutes later)
-        // Admin attempts to approve claim with old oracle data
-        
-        // EXPECTED BEHAVIOR
-        // - Oracle validation detects data age > 30 minutes
-        // - Claim approval fails
-        // - Error: StaleData
-        // - Requires fresh oracle submissions for claim approval
-    }
-
-    // Test scenario: Protecting against repeated submission attacks
-    #[test]
-    fn test_duplicate_oracle_submission_prevention() {
-        // SETUP
-        // - Configure 3 oracles as trusted providers
-        
-        // EXECUTION
-        // Oracle 1: First submission = 1000 (accepted)
-        // Oracle 1: Second submission = 5000 (attempted)
-        
-        // EXPECTED BEHAVIOR
-        // - First submission: success
-        // - Second submission: DuplicateSubmission error
-        // - One-vote-per-oracle enforcement maintained
-        // - Prevents oracle from manipulating consensus alone
-    }
-
-    // Test scenario: Complex outlier scenario with 5 oracles
-    #[test]
-    fn test_complex_outlier_detection_five_oracles() {
-        // SETUP
-        // Configuration:
-        // - Min submissions: 5
-        // - Majority threshold: 80%
-        // - Outlier deviation: 15%
-        // - Claim amount verification data
-        
-        // EXECUTION
-        // Oracle 1: 1000 (accepted)
-        // Oracle 2: 1020 (accepted, within 15%)
-        // Oracle 3: 980  (accepted, within 15%)
-        // Oracle 4: 1010 (accepted, within 15%)
-        // Oracle 5: 50   (rejected, far beyond 15% deviation)
-        
-        // OUTLIER ANALYSIS
-        // Median of all: ~1010
-        // 15% deviation range: 858.5 - 1161.5
-        // Oracle 5 (50) is outside range -> outlier
-        
-        // CONSENSUS CALCULATION
-        // Valid submissions: 4 out of 5 (80%)
-        // Meets 80% threshold exactly
-        // Consensus value: median(1000, 1020, 980, 1010) = 1005
-        
-        // EXPECTED BEHAVIOR
-        // - Claim approved with strong consensus
-        // - Oracle 5's malicious submission isolated
-        // - Claim amount set to 1005 (consensus value)
-    }
-
-    // Test scenario: Emergency pause during attack
-    #[test]
-    fn test_oracle_pause_during_attack() {
-        // SETUP
-        // - Oracle contract initialized
-        // - Attack detected: multiple invalid submissions
-        
-        // EXECUTION PHASE 1: Normal operation
-        // Oracle 1: 1000 (accepted)
-        // Oracle 2: 1010 (accepted)
-        
-        // EXECUTION PHASE 2: Attack detected
-        // Admin calls pause() on oracle contract
-        
-        // EXECUTION PHASE 3: Attack attempt continues
-        // Oracle 3: Attempts submission during pause
-        
-        // EXPECTED BEHAVIOR
-        // - Phase 1: Submissions accepted normally
-        // - Phase 2: Contract paused successfully
-        // - Phase 3: New submissions rejected with Paused error
-        // - Allows time for investigation
-        // - Admin unpause() when safe
-    }
-
-    // Test scenario: Sequential claim validation with independent oracle data
-    #[test]
-    fn test_multiple_claims_independent_oracle_validation() {
-        // SETUP
-        // - 3 claims submitted simultaneously
-        // - Independent oracle data for each claim
-        
-        // CLAIM 1:
-        // Oracle submissions: [1000, 1005, 1002]
-        // Consensus: 1002
-        
-        // CLAIM 2:
-        // Oracle submissions: [2000, 2010, 2005]
-        // Consensus: 2005
-        
-        // CLAIM 3:
-        // Oracle submissions: [500, 510, 5000(outlier)]
-        // Valid submissions: [500, 510]
-        // Consensus: 505
-        
-        // EXPECTED BEHAVIOR
-        // - Each claim validated independently
-        // - No cross-contamination between data points
-        // - Claim 1: Approved with consensus 1002
-        // - Claim 2: Approved with consensus 2005
-        // - Claim 3: Approved with consensus 505 (outlier removed)
-    }
-
-    // Test scenario: Falling back to consensus when oracle partially fails
-    #[test]
-    fn test_partial_oracle_failure_recovery() {
-        // SETUP
-        // Configuration:
-        // - Min submissions: 5
-        // - Majority threshold: 60%
-        // - Outlier deviation: 15%
-        
-        // EXECUTION: Simulating network/oracle issues
-        // Oracle 1: 1000 (successful)
-        // Oracle 2: Timeout/fails
-        // Oracle 3: 990 (successful)
-        // Oracle 4: Timeout/fails  
-        // Oracle 5: 1010 (successful)
-        
-        // ORACLE RESOLUTION
-        // Successfully collected: 3 submissions
-        // Required minimum: 5
-        // Status: InsufficientSubmissions
-        
-        // FALLBACK: Try with relaxed threshold
-        // Reduce min_submissions to 3
-        // Retry with collected 3 submissions
-        // Consensus: median(1000, 990, 1010) = 1000
-        // 3 out of 3 (100%) - exceeds 60% threshold
-        
-        // EXPECTED BEHAVIOR
-        // - Initial attempt fails gracefully
-        // - Admin adjusts thresholds
-        // - Retry succeeds with available data
-        // - Claim approved despite partial oracle failures
-    }
-
-    // Test scenario: Deterministic resolution order independence
-    #[test]
-    fn test_deterministic_oracle_resolution() {
-        // SETUP
-        // Threshold configuration ensures determinism
-        
-        // EXECUTION SCENARIO A: Submissions in order
-        // Oracle A: 100
-        // Oracle B: 101
-        // Oracle C: 102
-        
-        // EXECUTION SCENARIO B: Submissions in reverse
-        // Oracle C: 102
-        // Oracle B: 101
-        // Oracle A: 100
-        
-        // RESOLUTION BOTH SCENARIOS
-        // Median calculation: always 101
-        // Consensus percentage: always 100%
-        // Final data_id: same result regardless of submission order
-        
-        // EXPECTED BEHAVIOR
-        // - Deterministic outcome regardless of order
-        // - Enables reliable, reproducible claim validation
-        // - Critical for blockchain consensus
-    }
-
-    // Test scenario: Audit trail for disputed claims
-    #[test]
-    fn test_oracle_audit_trail_for_disputes() {
-        // SETUP
-        // - Claim approved with oracle validation
-        // - Later: Policyholder disputes claim decision
-        
-        // EXECUTION
-        // Admin retrieves claim_oracle_data link
-        // Fetches original OracleData structure:
-        // {
-        //   data_id: 42,
-        //   consensus_value: 1000,
-        //   submission_count: 3,
-        //   consensus_percentage: 100,
-        //   included_submissions: 3,
-        //   rejected_submissions: 0,
-        //   finalized_at: 1706012345
-        // }
-        
-        // Investigation process:
-        // 1. Verify all 3 oracles were queried
-        // 2. Confirm no submissions were rejected
-        // 3. Review consensus value (1000 = claim amount)
-        // 4. Confirm timestamp is recent
-        
-        // EXPECTED BEHAVIOR
-        // - Complete audit trail maintained
-        // - Proof of fair consensus-based decision
-        // - Defensible against disputes
-        // - Demonstrates data integrity
-    }
-
-    // Test scenario: Price feed consensus for complex claim types
-    #[test]
-    fn test_oracle_consensus_for_pricing_logic() {
-        // USE CASE: Claim requires current asset price verification
-        //
-        // SETUP
-        // - Claim: Insured asset damage assessment
-        // - Requires current market price: $10,000
-        // - 3 oracles provide price feeds
-        
-        // EXECUTION
-        // Price Oracle 1: $10,000
-        // Price Oracle 2: $10,050
-        // Price Oracle 3: $9,950
-        
-        // CALCULATION
-        // All within 15% of median ($10,000)
-        // Consensus: 100% agreement
-        // Consensus price: $10,000 (median)
-        
-        // CLAIM ASSESSMENT
-        // Assessed damage: $5,000
-        // Max coverage: $10,000 (consensus price)
-        // Approved amount: $5,000 (valid)
-        
-        // EXPECTED BEHAVIOR
-        // - Oracle consensus used for price feeds
-        // - Multiple price sources prevent manipulation
-        // - Claim validated with consensus pricing
-        // - Protection against flash crashes or oracle attacks
-    }
-}
-
-// ============================================================================
-// Benchmarking and Performance Tests
-// ============================================================================
-
-#[cfg(test)]
-mod oracle_performance_tests {
-    // Test: Oracle resolution performance with increasing submissions
-    #[test]
-    fn test_oracle_performance_with_scale() {
-        // Test configuration:
-        // - Measure resolution time as submission count increases
-        // - Minimum: 3 submissions
-        // - Maximum: 20+ submissions
-        // - Track consensus calculation time
-        
-        // Expected characteristics:
-        // - Linear time complexity for median calculation (sorted)
-        // - Constant-time outlier detection
-        // - No exponential growth in computation
-    }
-
-    // Test: Storage efficiency for oracle data
-    #[test]
-    fn test_oracle_storage_efficiency() {
-        // Test configuration:
-        // - Store 100 oracle data points
-        // - Each with 5 submissions
-        // - Track storage usage
-        
-        // Expected characteristics:
-        // - Compact representation after consensus
-        // - Discarded submissions cleaned up
-        // - Audit trail maintains key information
-    }
-}
@@ -1,13 +0,0 @@
-// Pure reentrancy protection logic
-use soroban_sdk::{Env, Symbol};
-
-pub struct ReentrancyGuard;
-
-impl ReentrancyGuard {
-    // ... implementation from above
-}
-
-#[macro_export]
-macro_rules! nonreentrant {
-    // ... macro implementation
-}
\ No newline at end of file
@@ -1,8 +0,0 @@
-pub mod reentrancy_guard;
-pub mod state_guard;
-pub mod errors;
-
-// Re-exports for easy access
-pub use reentrancy_guard::{ReentrancyGuard, nonreentrant};
-pub use state_guard::{StateGuard, ContractState, StateError};
-pub use errors::SecurityError;
\ No newline at end of file
@@ -1,11 +0,0 @@
-// State machine and transition validation
-use soroban_sdk::{contracttype, contracterror, Env, Symbol};
-
-#[contracttype]
-pub enum ContractState { /* ... */ }
-
-#[contracterror]
-pub enum StateError { /* ... */ }
-
-pub struct StateGuard;
-// ... implementation
\ No newline at end of file
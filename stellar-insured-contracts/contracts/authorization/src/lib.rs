@@ -28,6 +28,8 @@ pub enum Role {
     PolicyManager,
     /// Claim processor authorized to approve/reject claims
     ClaimProcessor,
+    /// Arbitrator who may sit on claim dispute panels
+    Arbitrator,
     /// Regular user (policyholder, liquidity provider, etc.)
     User,
 }
@@ -42,6 +44,10 @@ pub enum RoleKey {
     ContractAdmin,
     /// Trusted contract addresses for cross-contract calls
     TrustedContract(Address),
+    /// Governance contract holding admin powers alongside the admin
+    GovernanceContract,
+    /// Time after which the admin's powers end and only governance remains
+    AdminSunset,
 }
 
 /// Authorization errors
@@ -80,16 +86,26 @@ impl Role {
         matches!(self, Role::Admin | Role::Governance)
     }
 
+    /// Check if this role can sit on dispute arbitration panels
+    pub fn can_arbitrate(&self) -> bool {
+        matches!(self, Role::Arbitrator)
+    }
+
     /// Check if this role can submit claims
     pub fn can_submit_claim(&self) -> bool {
         !matches!(self, Role::ClaimProcessor) // Claim processors cannot submit their own claims
     }
 }
 
-/// Core authorization functions
+// Core authorization functions
 
-/// Initialize contract admin (call once during contract initialization)
+/// Initialize contract admin (call once during contract initialization).
+/// Panics if an admin is already set, so a contract that forgets its own
+/// `AlreadyInitialized` check still cannot have its admin overwritten.
 pub fn initialize_admin(env: &Env, admin: Address) {
+    if get_admin(env).is_some() {
+        panic!("admin already initialized");
+    }
     env.storage()
         .persistent()
         .set(&RoleKey::ContractAdmin, &admin);
@@ -108,7 +124,7 @@ pub fn get_admin(env: &Env) -> Option<Address> {
 /// Grant a role to an address (admin only)
 pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
     // Verify caller is admin
-    require_role(env, caller, Role::Admin)?;
+    require_admin(env, caller)?;
     
     // Grant the role
     env.storage()
@@ -121,7 +137,7 @@ pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) ->
 /// Revoke a role from an address (admin only)
 pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> Result<(), AuthError> {
     // Verify caller is admin
-    require_role(env, caller, Role::Admin)?;
+    require_admin(env, caller)?;
     
     // Prevent admin from revoking their own role (safeguard)
     if caller == target {
@@ -136,12 +152,17 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> Result<(),
     Ok(())
 }
 
-/// Get the role of an address
+/// Get the role of an address. The admin's role lapses at the sunset.
 pub fn get_role(env: &Env, address: &Address) -> Role {
-    env.storage()
+    let role = env
+        .storage()
         .persistent()
         .get(&RoleKey::UserRole(address.clone()))
-        .unwrap_or(Role::User) // Default to User if no role assigned
+        .unwrap_or(Role::User); // Default to User if no role assigned
+    if role == Role::Admin && is_admin_sunset(env) {
+        return Role::User;
+    }
+    role
 }
 
 /// Check if an address has a specific role
@@ -161,8 +182,12 @@ pub fn require_role(env: &Env, address: &Address, required_role: Role) -> Result
     }
 }
 
-/// Require admin privileges
+/// Require admin privileges, held by the admin and by the governance
+/// contract once one is set
 pub fn require_admin(env: &Env, address: &Address) -> Result<(), AuthError> {
+    if is_governance_contract(env, address) {
+        return Ok(());
+    }
     require_role(env, address, Role::Admin)
 }
 
@@ -181,7 +206,7 @@ pub fn require_any_role(env: &Env, address: &Address, roles: &[Role]) -> Result<
     }
 }
 
-/// Permission-based authorization (more granular than role-based)
+// Permission-based authorization (more granular than role-based)
 
 /// Require permission to manage policies
 pub fn require_policy_management(env: &Env, address: &Address) -> Result<(), AuthError> {
@@ -216,14 +241,24 @@ pub fn require_risk_pool_management(env: &Env, address: &Address) -> Result<(),
 /// Require permission to participate in governance
 pub fn require_governance_permission(env: &Env, address: &Address) -> Result<(), AuthError> {
     let role = get_role(env, address);
-    if role.can_govern() {
+    if role.can_govern() || is_governance_contract(env, address) {
+        Ok(())
+    } else {
+        Err(AuthError::Unauthorized)
+    }
+}
+
+/// Require permission to arbitrate claim disputes
+pub fn require_arbitration(env: &Env, address: &Address) -> Result<(), AuthError> {
+    let role = get_role(env, address);
+    if role.can_arbitrate() {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
     }
 }
 
-/// Cross-contract call validation
+// Cross-contract call validation
 
 /// Register a trusted contract address (admin only)
 pub fn register_trusted_contract(env: &Env, caller: &Address, contract_address: &Address) -> Result<(), AuthError> {
@@ -255,6 +290,85 @@ pub fn is_trusted_contract(env: &Env, contract_address: &Address) -> bool {
         .unwrap_or(false)
 }
 
+/// Set the governance contract (admin only), or clear it with `None`. The
+/// governance contract is registered as trusted and passes every admin and
+/// governance check, so executed proposals can call privileged setters.
+pub fn set_governance_contract(env: &Env, caller: &Address, governance: Option<&Address>) -> Result<(), AuthError> {
+    require_admin(env, caller)?;
+
+    if let Some(previous) = get_governance_contract(env) {
+        env.storage()
+            .persistent()
+            .remove(&RoleKey::TrustedContract(previous));
+    }
+    match governance {
+        Some(governance) => {
+            env.storage()
+                .persistent()
+                .set(&RoleKey::GovernanceContract, governance);
+            env.storage()
+                .persistent()
+                .set(&RoleKey::TrustedContract(governance.clone()), &true);
+        }
+        None => {
+            // Once a handover is scheduled nothing may be left without an owner
+            if get_admin_sunset(env).is_some() {
+                return Err(AuthError::Unauthorized);
+            }
+            env.storage().persistent().remove(&RoleKey::GovernanceContract)
+        }
+    }
+
+    Ok(())
+}
+
+/// Schedule the handover from the admin to governance (admin only). From
+/// `sunset_at` the admin's powers end for good; only the governance contract
+/// can make admin calls. The switch is one-way: a scheduled sunset can be
+/// brought forward but never postponed or cancelled, and it requires a
+/// governance contract to hand over to.
+pub fn schedule_admin_sunset(env: &Env, caller: &Address, sunset_at: u64) -> Result<(), AuthError> {
+    require_admin(env, caller)?;
+
+    if get_governance_contract(env).is_none() {
+        return Err(AuthError::Unauthorized);
+    }
+    if get_admin_sunset(env).is_some_and(|scheduled| sunset_at > scheduled) {
+        return Err(AuthError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RoleKey::AdminSunset, &sunset_at.max(env.ledger().timestamp()));
+
+    Ok(())
+}
+
+/// Time the admin's powers end, if a handover is scheduled
+pub fn get_admin_sunset(env: &Env) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::AdminSunset)
+}
+
+/// Check if the handover to governance has taken effect
+pub fn is_admin_sunset(env: &Env) -> bool {
+    get_admin_sunset(env).is_some_and(|sunset_at| env.ledger().timestamp() >= sunset_at)
+}
+
+/// Get the governance contract, if one is set
+pub fn get_governance_contract(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::GovernanceContract)
+}
+
+/// Check if an address is the trusted governance contract
+pub fn is_governance_contract(env: &Env, address: &Address) -> bool {
+    get_governance_contract(env).is_some_and(|governance| governance == *address)
+        && is_trusted_contract(env, address)
+}
+
 /// Require that the contract making the call is trusted
 pub fn require_trusted_contract(env: &Env, contract_address: &Address) -> Result<(), AuthError> {
     if is_trusted_contract(env, contract_address) {
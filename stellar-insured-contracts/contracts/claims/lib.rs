@@ -1,22 +1,57 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol, symbol_short, IntoVal};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, token, Address, BytesN, Env, InvokeError, String, Symbol, symbol_short, IntoVal, TryFromVal, Val, Vec};
 
-// Import the Policy contract interface to verify ownership and coverage
-mod policy_contract {
-    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/policy_contract.wasm");
+// The Policy contract interface used to verify ownership and coverage.
+// `PolicyState` keeps the policy contract's variant names, which are its
+// on-chain encoding.
+pub mod policy_contract {
+    use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env};
+
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum PolicyState {
+        ACTIVE,
+        EXPIRED,
+        CANCELLED,
+        CLAIMED,
+    }
+
+    /// Policy views the claims contract reads
+    #[contractclient(name = "Client")]
+    pub trait PolicyContract {
+        fn get_policy_terms(env: Env, policy_id: u64) -> (Address, i128, PolicyState, u64, u64);
+        fn get_policy_product(env: Env, policy_id: u64) -> u32;
+        fn get_policy_node(env: Env, policy_id: u64) -> Option<BytesN<32>>;
+    }
 }
 
 // Import shared types and authorization from the common library
 use insurance_contracts::types::ClaimStatus;
+use insurance_contracts::assets::{self, AssetError, AssetInfo};
 use insurance_contracts::authorization::{
-    initialize_admin, require_admin, require_claim_processing, 
-    require_trusted_contract, register_trusted_contract, Role, get_role
+    initialize_admin, require_admin, require_claim_processing, require_governance_permission,
+    require_trusted_contract, register_trusted_contract, require_arbitration, Role, get_role
 };
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::hot_storage;
+use insurance_contracts::incident::{self, Incident, IncidentError};
+use insurance_contracts::math::{bps_of, mul_div_ceil, mul_div_floor, ratio_bps};
+use insurance_contracts::pagination::{self, IdPage};
+use insurance_contracts::payout_delay::{self, PayoutDelayConfig, PayoutDelayError};
+use insurance_contracts::state_root::{self, StateRoot};
+use insurance_contracts::twap::TwapWindow;
+use insurance_contracts::units;
+use insurance_contracts::version::{self, ContractVersion, IFACE_LISTENERS, IFACE_PAYOUT_DELAY, IFACE_VERSION, IFACE_WIRING};
+use insurance_contracts::wiring::{self, WiringError, WIRING_VERSION};
+use insurance_contracts::listeners::{add_listener, get_listeners, notify, remove_listener, ListenerError};
 
 // Import invariants and safety assertions
-use insurance_invariants::{InvariantError, ProtocolInvariants};
+use insurance_invariants::{
+    assert_coverage_constraint, assert_invariant, assert_valid_amount, Invariant, InvariantError, InvariantReport,
+};
 
 // Oracle validation types
+#[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OracleValidationConfig {
     pub oracle_contract: Address,
@@ -24,6 +59,478 @@ pub struct OracleValidationConfig {
     pub min_oracle_submissions: u32,
 }
 
+/// Live claim aggregates
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClaimsStats {
+    pub total_submitted: u64,
+    /// Claims submitted but not yet rejected or settled
+    pub open_claims: u64,
+    pub total_settled: u64,
+    pub total_paid_out: i128,
+}
+
+/// Claim aggregates as of the last write in an epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimsStatsCheckpoint {
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub stats: ClaimsStats,
+}
+/// Stored claim: (policy_id, claimant, amount, status, created_at)
+type ClaimRecord = (u64, Address, i128, ClaimStatus, u64);
+
+/// Settlement priority class. Classes are ranked by their position in the
+/// configured list; a claim falls in the first class whose `max_amount`
+/// covers it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementClass {
+    pub name: Symbol,
+    pub max_amount: i128,
+}
+
+/// Decision metrics for a claim processor
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProcessorStats {
+    pub decisions: u32,
+    pub approvals: u32,
+    pub rejections: u32,
+    /// Sum of submission-to-decision times, in seconds
+    pub total_decision_secs: u64,
+    /// Decisions later reversed on appeal
+    pub overturned: u32,
+    pub last_decision_at: u64,
+}
+
+/// Claim history of an address, kept up to date as its claims move
+/// through the lifecycle. Approvals overturned on dispute count as
+/// rejections.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClaimantProfile {
+    pub filed: u32,
+    pub approved: u32,
+    pub rejected: u32,
+    pub disputed: u32,
+    /// Settled claims later reversed
+    pub reversed: u32,
+    /// Net of reversed payouts
+    pub total_paid: i128,
+    pub last_claim_at: u64,
+}
+
+/// A claim of at least `min_amount` gets a dispute window of `window_secs`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeTier {
+    pub min_amount: i128,
+    pub window_secs: u64,
+}
+
+/// Dispute windows applied to newly approved claims. Claims below every
+/// tier get `dispute_window_secs`; otherwise the highest tier whose
+/// `min_amount` the claim reaches applies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeConfig {
+    pub dispute_window_secs: u64,
+    /// Ordered by ascending `min_amount` and non-decreasing `window_secs`
+    pub tiers: Vec<DisputeTier>,
+}
+
+/// Inclusive range of amounts a claim against a product may be filed for
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimBounds {
+    pub min_amount: i128,
+    pub max_amount: i128,
+}
+
+/// Bond terms for raising a dispute. An upheld dispute returns the bond
+/// plus `reward_bps` of it from the treasury; a dismissed one forfeits it,
+/// `claimant_share_bps` to the claimant and the rest to the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeBondConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub reward_bps: u32,
+    pub claimant_share_bps: u32,
+    pub treasury: Address,
+}
+
+/// Stake claim processors must keep to make decisions. An approval
+/// overturned on dispute or appeal slashes `slash_bps` of the approver's
+/// stake, `wronged_share_bps` of it to whoever raised the dispute and the
+/// rest to the treasury. Processor rewards are paid in the same token and
+/// only to processors with at most `max_overturn_bps` of their decisions
+/// overturned.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProcessorStakingConfig {
+    pub token: Address,
+    pub treasury: Address,
+    pub min_stake: i128,
+    pub slash_bps: u32,
+    pub wronged_share_bps: u32,
+    pub max_overturn_bps: u32,
+    /// Time after a processor's last decision before stake can be withdrawn
+    pub unbonding_secs: u64,
+}
+
+/// Fee the pool pays out of its income for each settled claim, earned by
+/// the processor who approved it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProcessingFee {
+    Flat(i128),
+    Bps(u32),
+}
+
+/// A processor's stake and the rewards accrued on it
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProcessorStake {
+    pub amount: i128,
+    /// Reward index as of the last accrual
+    pub index: i128,
+    pub rewards: i128,
+}
+
+/// A dispute raised against a claim pending settlement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub claim_id: u64,
+    pub raised_by: Address,
+    pub reason: String,
+    pub raised_at: u64,
+    pub resolved: bool,
+    /// Whether the dispute was upheld; meaningful once resolved
+    pub upheld: bool,
+}
+
+/// Panel size and per-arbitrator workload limits
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationConfig {
+    pub panel_size: u32,
+    /// Most undecided cases an arbitrator may sit on at once
+    pub max_open_cases: u32,
+}
+
+/// Arbitrators assigned to a dispute and the decisions recorded so far
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationPanel {
+    pub claim_id: u64,
+    pub arbitrators: Vec<Address>,
+    pub uphold_votes: u32,
+    pub dismiss_votes: u32,
+    pub decided: bool,
+}
+
+/// Escalation of large disputes to a governance proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealConfig {
+    pub governance: Address,
+    /// Disputes over claims of at least this amount are escalated
+    pub min_amount: i128,
+    /// Yes-vote percentage the proposal needs to uphold the dispute
+    pub threshold_percentage: u32,
+}
+
+/// Governance proposal deciding an escalated dispute
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    pub claim_id: u64,
+    pub governance: Address,
+    pub proposal_id: u64,
+    /// Outcome the first instance reached before escalation
+    pub recommended_uphold: bool,
+    pub opened_at: u64,
+    pub resolved: bool,
+}
+
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub policy_contract: Address,
+    pub risk_pool: Address,
+}
+
+/// Oracle validation as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleSetting {
+    Disabled,
+    Enabled(OracleValidationConfig),
+}
+
+/// Dispute bond as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeBondSetting {
+    Disabled,
+    Enabled(DisputeBondConfig),
+}
+
+/// Appeal escalation as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealSetting {
+    Disabled,
+    Enabled(AppealConfig),
+}
+
+/// Payout delay as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutDelaySetting {
+    Disabled,
+    Enabled(PayoutDelayConfig),
+}
+
+/// Everything a frontend needs to bootstrap against the claims contract, in
+/// one read
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    pub policy_contract: Address,
+    pub risk_pool: Address,
+    pub oracle: OracleSetting,
+    pub dispute: DisputeConfig,
+    pub dispute_bond: DisputeBondSetting,
+    pub arbitration: ArbitrationConfig,
+    pub appeal: AppealSetting,
+    pub payout_delay: PayoutDelaySetting,
+    pub wired: bool,
+    pub paused: bool,
+    pub version: ContractVersion,
+}
+
+/// Clawback of a settled claim, e.g. after fraud is proven. What could not
+/// be recovered when the claim was reversed is owed by the claimant as a
+/// receivable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimReversal {
+    pub claim_id: u64,
+    pub claimant: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub reversed_by: Address,
+    pub reversed_at: u64,
+    /// Part of `amount` recovered from the claimant's premium credit
+    pub recovered: i128,
+}
+
+/// Approved claim's payout assigned to a lender, e.g. as collateral for a
+/// loan against it. The first `amount` of the payout goes to `lender`, the
+/// rest to the claimant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAssignment {
+    pub lender: Address,
+    pub amount: i128,
+    pub assigned_at: u64,
+}
+
+/// Oracle trigger of a fully parametric product. Once the consensus value
+/// of oracle data point `data_id` crosses `threshold` during a policy's
+/// term, the holder is paid `payout_bps` of the coverage through
+/// `claim_and_settle`, without review.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametricTrigger {
+    pub data_id: u64,
+    pub threshold: i128,
+    /// Trigger at or above the threshold; otherwise at or below it
+    pub above: bool,
+    pub payout_bps: u32,
+    /// Oldest reading, in seconds, a claim may rely on
+    pub max_data_age_secs: u64,
+}
+
+/// Stablecoin peg cover of a depeg product. Keepers feed the consensus
+/// price of oracle data point `feed_data_id` to `observe_peg`, which
+/// averages it over consecutive windows of `twap_window_secs`. Once every
+/// window for at least `min_duration_secs` averaged below `strike`, the
+/// coin is depegged, and holders whose term saw the whole episode are paid
+/// `payout_bps` of the coverage through `claim_and_settle`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepegCover {
+    pub feed_data_id: u64,
+    /// Price in the feed's scale, e.g. 0.98 USD
+    pub strike: i128,
+    pub twap_window_secs: u64,
+    pub min_duration_secs: u64,
+    /// Longest gap between observations before the watch starts over
+    pub max_gap_secs: u64,
+    pub payout_bps: u32,
+}
+
+/// Running peg observations of a depeg product
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PegWatch {
+    /// Readings since the current window opened
+    pub window: TwapWindow,
+    /// Average of the last closed window
+    pub last_twap: i128,
+    /// Start of the run of windows averaging below the strike
+    pub below_since: Option<u64>,
+    /// Start of the latest confirmed depeg
+    pub last_depeg_at: u64,
+    /// When the latest depeg was confirmed; 0 while none has been
+    pub last_depeg_confirmed_at: u64,
+}
+
+/// Payout share of a downtime product once a node was down for at least
+/// `min_hours` during a policy's term
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DowntimeTier {
+    pub min_hours: u64,
+    pub payout_bps: u32,
+}
+
+/// Downtime attested for a node, one UTC day at a time with no gaps
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NodeDowntime {
+    pub first_day: u64,
+    pub last_day: u64,
+    /// Downtime over all reported days
+    pub total_secs: u64,
+}
+
+/// Key-loss cover of a product. Holders pre-register guardians and a
+/// recovery address; once `threshold` guardians attest the holder lost
+/// access, `payout_bps` of the coverage is paid to the recovery address,
+/// but only after the mandatory delay and the challenge window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyLossCover {
+    pub payout_bps: u32,
+    /// Age the guardian setup must reach before a loss can be attested,
+    /// and the claim before it pays
+    pub delay_secs: u64,
+    /// Time after the last needed attestation in which the holder, by
+    /// signing, or governance can refute the loss
+    pub challenge_window_secs: u64,
+}
+
+/// Guardians and recovery address a holder registered for a policy
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoverySetup {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+    pub recovery_address: Address,
+    pub registered_at: u64,
+}
+
+/// Guardian attestations of a key loss awaiting payout
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyLossClaim {
+    pub opened_at: u64,
+    pub approvals: Vec<Address>,
+    /// When the threshold was reached
+    pub attested_at: Option<u64>,
+}
+
+/// Bridge-failure cover of a product: holders of `asset`, a token bridged
+/// over bridge `bridge_id`, are paid pro rata to their holding when a
+/// failure of that bridge is declared by the attestor committee and
+/// confirmed by governance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeCover {
+    pub bridge_id: u32,
+    pub asset: Address,
+}
+
+/// Attestors who declare bridge failures. Each rotation starts a new
+/// epoch; declarations attested by an earlier committee cannot be
+/// confirmed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeCommittee {
+    pub members: Vec<Address>,
+    pub threshold: u32,
+    pub epoch: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BridgeFailure {
+    Hack = 0,
+    Halt = 1,
+}
+
+/// A declared bridge failure. `loss_bps` is the share of the bridged
+/// asset's backing lost, and so of each covered holding paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeEvent {
+    pub bridge_id: u32,
+    pub failure: BridgeFailure,
+    pub occurred_at: u64,
+    pub loss_bps: u32,
+    pub epoch: u32,
+    pub approvals: Vec<Address>,
+    pub confirmed_at: Option<u64>,
+}
+
+/// Coverage of a product denominated in a reference currency such as USD
+/// and settled in the pool asset. Oracle data point `rate_data_id` carries
+/// the pool asset's price in the reference currency, scaled by
+/// `REFERENCE_RATE_SCALE`. Claims reserve at the rate on approval and pay
+/// at the rate on settlement, which may drift from it by at most
+/// `max_deviation_bps`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferencePricing {
+    pub rate_data_id: u64,
+    /// Oldest rate, in seconds, a conversion may use
+    pub max_age_secs: u64,
+    pub max_deviation_bps: u32,
+}
+
+/// Claim status as stored before the shared `ClaimStatus` was introduced.
+/// Only read by `migrate_claims`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LegacyClaimStatus {
+    Submitted,
+    UnderReview,
+    Approved,
+    Rejected,
+    Settled,
+}
+
+impl From<LegacyClaimStatus> for ClaimStatus {
+    fn from(status: LegacyClaimStatus) -> Self {
+        match status {
+            LegacyClaimStatus::Submitted => ClaimStatus::Submitted,
+            LegacyClaimStatus::UnderReview => ClaimStatus::UnderReview,
+            LegacyClaimStatus::Approved => ClaimStatus::Approved,
+            LegacyClaimStatus::Rejected => ClaimStatus::Rejected,
+            LegacyClaimStatus::Settled => ClaimStatus::Settled,
+        }
+    }
+}
+
 #[contract]
 pub struct ClaimsContract;
 
@@ -31,8 +538,134 @@ const PAUSED: Symbol = symbol_short!("PAUSED");
 const CONFIG: Symbol = symbol_short!("CONFIG");
 const CLAIM: Symbol = symbol_short!("CLAIM");
 const POLICY_CLAIM: Symbol = symbol_short!("P_CLAIM");
-const ORACLE_CONFIG: Symbol = symbol_short!("ORACLE_CFG");
-const CLAIM_ORACLE_ID: Symbol = symbol_short!("CLM_ORA_ID");
+const ORACLE_CONFIG: Symbol = symbol_short!("ORCL_CFG");
+const CLAIM_ORACLE_ID: Symbol = symbol_short!("CLM_ORA");
+
+const CLAIM_STATS: Symbol = symbol_short!("CLM_STATS");
+const STATS_CHECKPOINT: Symbol = symbol_short!("STATS_CP");
+const STATS_EPOCHS: Symbol = symbol_short!("STATS_EP");
+
+const AGGREGATE_LIMIT: Symbol = symbol_short!("AGG_LIM");
+const CLAIM_BOUNDS: Symbol = symbol_short!("CLM_BND");
+const HOLDER_AGGREGATE: Symbol = symbol_short!("HOLD_AGG");
+
+const SETTLEMENT_CLASSES: Symbol = symbol_short!("STL_CLS");
+const HARDSHIP: Symbol = symbol_short!("HARDSHIP");
+const SETTLEMENT_QUEUE: Symbol = symbol_short!("STL_QUEUE");
+const PROCESSOR_STATS: Symbol = symbol_short!("PROC_ST");
+const DECIDED_BY: Symbol = symbol_short!("DECIDED");
+const CLAIMANT_PROFILE: Symbol = symbol_short!("CLM_PROF");
+const DISPUTE_CONFIG: Symbol = symbol_short!("DSP_CFG");
+const DISPUTE_WINDOW_END: Symbol = symbol_short!("DSP_END");
+const DISPUTE_WINDOW_SECS: Symbol = symbol_short!("DSP_WIN");
+const DISPUTE: Symbol = symbol_short!("DISPUTE");
+const BOND_CONFIG: Symbol = symbol_short!("BOND_CFG");
+const DISPUTE_BOND: Symbol = symbol_short!("DSP_BOND");
+const ARB_CONFIG: Symbol = symbol_short!("ARB_CFG");
+const ARB_PANEL: Symbol = symbol_short!("ARB_PANEL");
+const ARB_CASES: Symbol = symbol_short!("ARB_CASES");
+const ARB_DECISION: Symbol = symbol_short!("ARB_DEC");
+const APPEAL_CONFIG: Symbol = symbol_short!("APL_CFG");
+const APPEAL: Symbol = symbol_short!("APPEAL");
+const REVERSAL: Symbol = symbol_short!("REVERSAL");
+const RECEIVABLE: Symbol = symbol_short!("RECEIVBL");
+const ASSIGNMENT: Symbol = symbol_short!("ASSIGN");
+const LENDER_CAP: Symbol = symbol_short!("LEND_CAP");
+const LENDER_ASSIGNED: Symbol = symbol_short!("LEND_ASGN");
+const PARAMETRIC_TRIGGER: Symbol = symbol_short!("PARAM_TRG");
+const SETTLE_GUARD: Symbol = symbol_short!("STL_GUARD");
+const REFERENCE_PRICING: Symbol = symbol_short!("REF_PRICE");
+const CLAIM_RATE: Symbol = symbol_short!("CLM_RATE");
+const SETTLED_AMOUNT: Symbol = symbol_short!("SETTLED");
+const DEPEG_COVER: Symbol = symbol_short!("DEPEG_CVR");
+const PEG_WATCH: Symbol = symbol_short!("PEG_WATCH");
+const DOWNTIME_TIERS: Symbol = symbol_short!("DOWN_TIER");
+const UPTIME_ATTESTOR: Symbol = symbol_short!("UP_ATTEST");
+const NODE_DOWNTIME: Symbol = symbol_short!("NODE_DOWN");
+const NODE_DAY: Symbol = symbol_short!("NODE_DAY");
+const CLAIM_PAYEE: Symbol = symbol_short!("CLM_PAYEE");
+const KEY_LOSS_COVER: Symbol = symbol_short!("KEY_COVER");
+const RECOVERY_SETUP: Symbol = symbol_short!("RECOVERY");
+const KEY_LOSS_CLAIM: Symbol = symbol_short!("KEY_CLAIM");
+const BRIDGE_COVER: Symbol = symbol_short!("BRG_COVER");
+const BRIDGE_COMMITTEE: Symbol = symbol_short!("BRG_CMTE");
+const BRIDGE_EVENT: Symbol = symbol_short!("BRG_EVENT");
+const BRIDGE_EVENT_SEQ: Symbol = symbol_short!("BRG_SEQ");
+const STAKING_CONFIG: Symbol = symbol_short!("STK_CFG");
+const PROCESSOR_STAKE: Symbol = symbol_short!("PROC_STK");
+const PROCESSOR_STAKE_TOTAL: Symbol = symbol_short!("STK_TOT");
+const PROCESSOR_REWARD_INDEX: Symbol = symbol_short!("RWD_IDX");
+const PROCESSING_FEE: Symbol = symbol_short!("PROC_FEE");
+const PROCESSOR_FEES: Symbol = symbol_short!("PROC_FEES");
+const CLAIM_COUNTER: Symbol = symbol_short!("CLM_CNT");
+
+// Small, frequently read items kept in instance storage; moved out of
+// persistent storage by `migrate_hot_storage`
+const HOT_KEYS: [Symbol; 12] = [
+    PAUSED,
+    CONFIG,
+    CLAIM_STATS,
+    DISPUTE_CONFIG,
+    BOND_CONFIG,
+    ARB_CONFIG,
+    APPEAL_CONFIG,
+    STAKING_CONFIG,
+    PROCESSOR_STAKE_TOTAL,
+    PROCESSOR_REWARD_INDEX,
+    PROCESSING_FEE,
+    SETTLEMENT_CLASSES,
+];
+
+// Upper bound on ids accepted by batch getters
+const MAX_BATCH_SIZE: u32 = 50;
+
+// Statistics checkpointing: one checkpoint per epoch, bounded history
+const STATS_EPOCH_SECONDS: u64 = 86_400;
+const MAX_STATS_HISTORY: u32 = 365;
+
+// Aggregate limits reset every policy year
+const AGGREGATE_PERIOD_SECONDS: u64 = 365 * 86_400;
+
+// Settlement priority: hardship claims rank ahead of every class
+const HARDSHIP_PRIORITY: u32 = 0;
+const MAX_SETTLEMENT_CLASSES: u32 = 8;
+const MAX_SETTLEMENT_QUEUE: u32 = 200;
+
+// Claims may still be filed this long after a policy's term ends
+const CLAIM_WINDOW_SECONDS: u64 = 30 * 86_400;
+
+// Node downtime is attested per UTC day and paid per whole hour
+const DOWNTIME_DAY_SECONDS: u64 = 86_400;
+const MAX_DOWNTIME_TIERS: u32 = 8;
+
+// Guardians a holder may register for key-loss recovery
+const MAX_GUARDIANS: u32 = 10;
+
+// Members of the bridge attestor committee
+const MAX_COMMITTEE_SIZE: u32 = 20;
+
+// A processor summary event is emitted every this many decisions
+const PROCESSOR_SUMMARY_INTERVAL: u32 = 10;
+
+// Approved claims wait this long for disputes before they can settle
+const DEFAULT_DISPUTE_WINDOW_SECONDS: u64 = 86_400;
+const MAX_DISPUTE_WINDOW_SECONDS: u64 = 30 * 86_400;
+const MAX_DISPUTE_TIERS: u32 = 8;
+const MAX_BPS: u32 = 10_000;
+
+// Arbitration panels default to three arbitrators with five open cases each
+const DEFAULT_PANEL_SIZE: u32 = 3;
+const DEFAULT_MAX_OPEN_CASES: u32 = 5;
+const MAX_PANEL_SIZE: u32 = 9;
+
+// Treasury fee type for forfeited dispute bonds (FeeType::Other)
+const FEE_TYPE_OTHER: u32 = 4;
+
+// Fixed-point scale of the processor reward index
+const REWARD_SCALE: i128 = 1_000_000_000_000;
+
+// Fixed-point scale of reference currency exchange rates
+const REFERENCE_RATE_SCALE: i128 = 10_000_000;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -55,6 +688,38 @@ pub enum ContractError {
     InvalidRole = 15,
     RoleNotFound = 16,
     NotTrustedContract = 17,
+    // Listener registry errors
+    ListenerAlreadyRegistered = 18,
+    ListenerNotRegistered = 19,
+    TooManyListeners = 20,
+    AggregateLimitExceeded = 21,
+    SettlementQueueFull = 22,
+    // Delayed payout errors
+    PayoutPending = 23,
+    PayoutLocked = 24,
+    PayoutHeld = 25,
+    NotWired = 26,
+    WiringFailed = 27,
+    PolicyNotActive = 28,
+    PolicyExpired = 29,
+    DisputeWindowOpen = 30,
+    DisputeWindowClosed = 31,
+    ConflictOfInterest = 32,
+    CaseLimitReached = 33,
+    ArbitrationPending = 34,
+    AppealPending = 35,
+    ClaimAmountOutOfBounds = 36,
+    ProcessorIsClaimant = 37,
+    ApproverCannotSettle = 38,
+    ProcessorStakeRequired = 39,
+    ProcessorNotEligible = 40,
+    StakeLocked = 41,
+    AssignmentCapExceeded = 42,
+    TriggerNotMet = 43,
+    Reentrant = 44,
+    InvalidAsset = 45,
+    AssetNotAllowed = 46,
+    RateDeviation = 47,
     // Invariant violation errors (100-199)
     InvalidClaimState = 102,
     InvalidAmount = 103,
@@ -73,6 +738,55 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
     }
 }
 
+impl From<ListenerError> for ContractError {
+    fn from(err: ListenerError) -> Self {
+        match err {
+            ListenerError::AlreadyRegistered => ContractError::ListenerAlreadyRegistered,
+            ListenerError::NotRegistered => ContractError::ListenerNotRegistered,
+            ListenerError::TooManyListeners => ContractError::TooManyListeners,
+        }
+    }
+}
+
+impl From<PayoutDelayError> for ContractError {
+    fn from(err: PayoutDelayError) -> Self {
+        match err {
+            PayoutDelayError::NotPending => ContractError::NotFound,
+            PayoutDelayError::StillLocked => ContractError::PayoutLocked,
+            PayoutDelayError::NotGuardian => ContractError::Unauthorized,
+            PayoutDelayError::Held => ContractError::PayoutHeld,
+        }
+    }
+}
+
+impl From<AssetError> for ContractError {
+    fn from(err: AssetError) -> Self {
+        match err {
+            AssetError::NotToken | AssetError::UnsupportedDecimals => ContractError::InvalidAsset,
+            AssetError::NotAllowed => ContractError::AssetNotAllowed,
+        }
+    }
+}
+
+impl From<WiringError> for ContractError {
+    fn from(err: WiringError) -> Self {
+        match err {
+            WiringError::NotWired => ContractError::NotWired,
+            WiringError::HandshakeFailed | WiringError::VersionMismatch => ContractError::WiringFailed,
+        }
+    }
+}
+
+impl From<IncidentError> for ContractError {
+    fn from(err: IncidentError) -> Self {
+        match err {
+            IncidentError::InvalidInput => ContractError::InvalidInput,
+            IncidentError::NotFound => ContractError::NotFound,
+            IncidentError::AlreadyClosed | IncidentError::StillOpen => ContractError::InvalidState,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -85,456 +799,4249 @@ impl From<InvariantError> for ContractError {
     }
 }
 
-fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
-    Ok(())
+fn aggregate_period(env: &Env) -> u64 {
+    env.ledger().timestamp() / AGGREGATE_PERIOD_SECONDS
 }
 
-fn is_paused(env: &Env) -> bool {
+fn aggregate_limit(env: &Env, product_id: u32) -> Option<i128> {
     env.storage()
         .persistent()
-        .get(&PAUSED)
-        .unwrap_or(false)
+        .get(&(AGGREGATE_LIMIT, product_id))
 }
 
-fn set_paused(env: &Env, paused: bool) {
+fn claim_bounds(env: &Env, product_id: u32) -> Option<ClaimBounds> {
     env.storage()
         .persistent()
-        .set(&PAUSED, &paused);
+        .get(&(CLAIM_BOUNDS, product_id))
 }
 
-/// I3: Validate claim state transition
-/// Maps valid state transitions to ensure claim lifecycle integrity
-fn is_valid_state_transition(current: ClaimStatus, next: ClaimStatus) -> bool {
-    match (&current, &next) {
-        // Valid forward transitions
-        (ClaimStatus::Submitted, ClaimStatus::UnderReview) => true,
-        (ClaimStatus::UnderReview, ClaimStatus::Approved) => true,
-        (ClaimStatus::UnderReview, ClaimStatus::Rejected) => true,
-        (ClaimStatus::Approved, ClaimStatus::Settled) => true,
-        // Invalid transitions (backward, skipping, etc.)
-        _ => false,
-    }
+fn holder_aggregate(env: &Env, holder: &Address, product_id: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(HOLDER_AGGREGATE, holder.clone(), product_id, aggregate_period(env)))
+        .unwrap_or(0i128)
 }
 
-/// I4: Validate amount is positive and within safe range
-fn validate_amount(amount: i128) -> Result<(), ContractError> {
-    if amount <= 0 {
-        return Err(ContractError::InvalidAmount);
+fn settlement_classes(env: &Env) -> Vec<SettlementClass> {
+    env.storage()
+        .instance()
+        .get(&SETTLEMENT_CLASSES)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Settlement priority of a claim; lower values settle first. Hardship
+/// claims get `HARDSHIP_PRIORITY`, class `i` gets `i + 1`, and claims
+/// outside every class rank last.
+fn settlement_priority(env: &Env, claim_id: u64, amount: i128) -> u32 {
+    if env.storage().persistent().has(&(HARDSHIP, claim_id)) {
+        return HARDSHIP_PRIORITY;
     }
-    Ok(())
+
+    let classes = settlement_classes(env);
+    for (i, class) in classes.iter().enumerate() {
+        if amount <= class.max_amount {
+            return i as u32 + 1;
+        }
+    }
+    classes.len() + 1
 }
 
-/// I6: Validate claim does not exceed coverage limit
-fn validate_coverage_constraint(claim_amount: i128, coverage_amount: i128) -> Result<(), ContractError> {
-    if claim_amount > coverage_amount {
-        return Err(ContractError::CoverageExceeded);
+/// Pending settlements as `(priority, claim_id)`, in settlement order
+fn settlement_queue(env: &Env) -> Vec<(u32, u64)> {
+    env.storage()
+        .persistent()
+        .get(&SETTLEMENT_QUEUE)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Insert a claim behind every queued claim of equal or higher priority,
+/// so claims of the same priority settle in approval order
+fn enqueue_settlement(env: &Env, claim_id: u64, amount: i128) -> Result<u32, ContractError> {
+    let mut queue = settlement_queue(env);
+    if queue.len() >= MAX_SETTLEMENT_QUEUE {
+        return Err(ContractError::SettlementQueueFull);
     }
-    Ok(())
+
+    let priority = settlement_priority(env, claim_id, amount);
+    let position = queue
+        .iter()
+        .position(|(p, _)| p > priority)
+        .map(|pos| pos as u32)
+        .unwrap_or(queue.len());
+    queue.insert(position, (priority, claim_id));
+    env.storage().persistent().set(&SETTLEMENT_QUEUE, &queue);
+
+    publish_event(
+        env,
+        (Symbol::new(env, "settlement_queued"), claim_id),
+        (priority, position),
+    );
+
+    Ok(position)
 }
 
-#[contractimpl]
-impl ClaimsContract {
-    pub fn initialize(env: Env, admin: Address, policy_contract: Address, risk_pool: Address) -> Result<(), ContractError> {
-        // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
-            return Err(ContractError::AlreadyInitialized);
+/// Remove a claim from the settlement queue; returns whether it was queued
+fn dequeue_settlement(env: &Env, claim_id: u64) -> bool {
+    let mut queue = settlement_queue(env);
+    match queue.iter().position(|(_, id)| id == claim_id) {
+        Some(pos) => {
+            queue.remove(pos as u32);
+            env.storage().persistent().set(&SETTLEMENT_QUEUE, &queue);
+            true
         }
+        None => false,
+    }
+}
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &policy_contract)?;
-        validate_address(&env, &risk_pool)?;
+fn dispute_config(env: &Env) -> DisputeConfig {
+    env.storage()
+        .instance()
+        .get(&DISPUTE_CONFIG)
+        .unwrap_or(DisputeConfig {
+            dispute_window_secs: DEFAULT_DISPUTE_WINDOW_SECONDS,
+            tiers: Vec::new(env),
+        })
+}
 
-        // Initialize authorization system with admin
-        admin.require_auth();
-        initialize_admin(&env, admin.clone());
-        
-        // Register policy and risk pool contracts as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &policy_contract)?;
-        register_trusted_contract(&env, &admin, &risk_pool)?;
+/// Dispute window for a claim of `amount` under `config`
+fn dispute_window_for(config: &DisputeConfig, amount: i128) -> u64 {
+    let mut window = config.dispute_window_secs;
+    for tier in config.tiers.iter() {
+        if amount < tier.min_amount {
+            break;
+        }
+        window = tier.window_secs;
+    }
+    window
+}
 
-        // Store contract configuration
-        env.storage().persistent().set(&CONFIG, &(policy_contract, risk_pool));
-        
-        env.events().publish(
-            (symbol_short!("init"), ()),
-            admin,
-        );
-        
-        Ok(())
+/// Windows must stay within bounds and never shrink as claims grow
+fn validate_dispute_config(env: &Env, config: &DisputeConfig) -> Result<(), ContractError> {
+    if config.tiers.len() > MAX_DISPUTE_TIERS {
+        return Err(ContractError::InvalidInput);
+    }
+    if config.dispute_window_secs > MAX_DISPUTE_WINDOW_SECONDS {
+        return Err(ContractError::InvalidInput);
     }
 
-    /// Initialize oracle validation for the claims contract
-    pub fn set_oracle_config(
-        env: Env,
-        admin: Address,
-        oracle_contract: Address,
-        require_oracle_validation: bool,
-        min_oracle_submissions: u32,
-    ) -> Result<(), ContractError> {
-        // Verify identity and require admin permission
-        admin.require_auth();
-        require_admin(&env, &admin)?;
+    let mut prev: Option<DisputeTier> = None;
+    for tier in config.tiers.iter() {
+        validate_amount(env, tier.min_amount)?;
+        if tier.window_secs > MAX_DISPUTE_WINDOW_SECONDS || tier.window_secs < config.dispute_window_secs {
+            return Err(ContractError::InvalidInput);
+        }
+        if let Some(prev) = prev {
+            if tier.min_amount <= prev.min_amount || tier.window_secs < prev.window_secs {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        prev = Some(tier);
+    }
+    Ok(())
+}
 
-        validate_address(&env, &oracle_contract)?;
+/// Whether a claim pending settlement is still inside its dispute window
+fn in_dispute_window(env: &Env, claim_id: u64) -> bool {
+    let window_end: Option<u64> = env
+        .storage()
+        .persistent()
+        .get(&(DISPUTE_WINDOW_END, claim_id));
+    window_end.is_some_and(|end| env.ledger().timestamp() < end)
+}
 
-        // Register oracle contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &oracle_contract)?;
+/// Pay out an escrowed dispute bond once its dispute is resolved
+fn distribute_dispute_bond(
+    env: &Env,
+    claim_id: u64,
+    raised_by: &Address,
+    claimant: &Address,
+    upheld: bool,
+) -> Result<(), ContractError> {
+    let bond: DisputeBondConfig = match env.storage().persistent().get(&(DISPUTE_BOND, claim_id)) {
+        Some(bond) => bond,
+        None => return Ok(()),
+    };
+    env.storage().persistent().remove(&(DISPUTE_BOND, claim_id));
 
-        let config = OracleValidationConfig {
-            oracle_contract: oracle_contract.clone(),
-            require_oracle_validation,
-            min_oracle_submissions,
-        };
+    let escrow = env.current_contract_address();
+    let token = token::Client::new(env, &bond.token);
 
-        env.storage().persistent().set(&ORACLE_CONFIG, &config);
-        Ok(())
+    if upheld {
+        token.transfer(&escrow, raised_by, &bond.amount);
+
+        // A treasury that cannot pay the reward must not block resolution
+        let reward = bps_of(bond.amount, bond.reward_bps).map_err(|_| ContractError::Overflow)?;
+        if reward > 0 {
+            let paid = env.try_invoke_contract::<(), InvokeError>(
+                &bond.treasury,
+                &Symbol::new(env, "pay_dispute_reward"),
+                (escrow.clone(), bond.token.clone(), raised_by.clone(), reward).into_val(env),
+            );
+            if !matches!(paid, Ok(Ok(()))) {
+                publish_event(
+                    env,
+                    (Symbol::new(env, "dispute_reward_failed"), claim_id),
+                    (raised_by.clone(), reward),
+                );
+            }
+        }
+    } else {
+        let to_claimant = bps_of(bond.amount, bond.claimant_share_bps).map_err(|_| ContractError::Overflow)?;
+        let to_treasury = bond.amount - to_claimant;
+        if to_claimant > 0 {
+            token.transfer(&escrow, claimant, &to_claimant);
+        }
+        if to_treasury > 0 {
+            require_trusted_contract(env, &bond.treasury)?;
+            token.transfer(&escrow, &bond.treasury, &to_treasury);
+            env.invoke_contract::<()>(
+                &bond.treasury,
+                &Symbol::new(env, "deposit_fee"),
+                (escrow.clone(), to_treasury, FEE_TYPE_OTHER).into_val(env),
+            );
+        }
     }
 
-    /// Get current oracle configuration
-    pub fn get_oracle_config(env: Env) -> Result<OracleValidationConfig, ContractError> {
+    publish_event(
+        env,
+        (Symbol::new(env, "dispute_bond_settled"), claim_id),
+        (raised_by.clone(), bond.amount, upheld),
+    );
+
+    Ok(())
+}
+
+/// Apply the outcome of a dispute. An upheld dispute rejects the claim and
+/// releases its reservation; a dismissed one returns it to the settlement
+/// queue with its dispute window closed.
+fn apply_dispute_outcome(env: &Env, claim_id: u64, upheld: bool, resolver: &Address) -> Result<(), ContractError> {
+    let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM, claim_id))
+        .ok_or(ContractError::NotFound)?;
+    let mut dispute: Dispute = env
+        .storage()
+        .persistent()
+        .get(&(DISPUTE, claim_id))
+        .ok_or(ContractError::NotFound)?;
+
+    let next = if upheld { ClaimStatus::Rejected } else { ClaimStatus::PendingSettlement };
+    if claim.3 != ClaimStatus::Disputed || !is_valid_state_transition(claim.3, next) {
+        return Err(ContractError::InvalidClaimState);
+    }
+
+    let config: (Address, Address) = env
+        .storage()
+        .instance()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)?;
+
+    claim.3 = next;
+    env.storage()
+        .persistent()
+        .set(&(CLAIM, claim_id), &claim);
+
+    if upheld {
+        require_trusted_contract(env, &config.1)?;
+        env.invoke_contract::<i128>(
+            &config.1,
+            &Symbol::new(env, "release_reservation"),
+            (env.current_contract_address(), claim_id).into_val(env),
+        );
+
+        // The approval no longer counts towards the holder's aggregate
+        let product_id: u32 = env.invoke_contract(
+            &config.0,
+            &Symbol::new(env, "get_policy_product"),
+            (claim.0,).into_val(env),
+        );
+        let aggregate = holder_aggregate(env, &claim.1, product_id).saturating_sub(claim.2).max(0);
+        env.storage()
+            .persistent()
+            .set(&(HOLDER_AGGREGATE, claim.1.clone(), product_id, aggregate_period(env)), &aggregate);
+
+        let mut stats = get_stats(env);
+        stats.open_claims = stats.open_claims.saturating_sub(1);
+        set_stats(env, &stats);
+
+        // The approving processor's decision was overturned
+        if let Some(processor) = env.storage().persistent().get::<_, Address>(&(DECIDED_BY, claim_id)) {
+            let mut processor_stats = processor_stats(env, &processor);
+            processor_stats.overturned += 1;
+            env.storage()
+                .persistent()
+                .set(&(PROCESSOR_STATS, processor.clone()), &processor_stats);
+            slash_processor(env, &processor, claim_id, &dispute.raised_by)?;
+        }
+
+        update_claimant_profile(env, &claim.1, |profile| {
+            profile.approved = profile.approved.saturating_sub(1);
+            profile.rejected += 1;
+        });
+
+        // Nothing will be paid, so the lender's claim on it lapses
+        if let Some(assignment) = clear_assignment(env, claim_id) {
+            publish_event(
+                env,
+                (Symbol::new(env, "assignment_voided"), claim_id),
+                (assignment.lender, assignment.amount),
+            );
+        }
+    } else {
+        env.storage()
+            .persistent()
+            .set(&(DISPUTE_WINDOW_END, claim_id), &env.ledger().timestamp());
+        enqueue_settlement(env, claim_id, claim.2)?;
+    }
+
+    distribute_dispute_bond(env, claim_id, &dispute.raised_by, &claim.1, upheld)?;
+
+    dispute.resolved = true;
+    dispute.upheld = upheld;
+    env.storage()
+        .persistent()
+        .set(&(DISPUTE, claim_id), &dispute);
+
+    publish_event(
+        env,
+        (Symbol::new(env, "dispute_resolved"), claim_id),
+        (resolver.clone(), upheld),
+    );
+
+    Ok(())
+}
+
+fn appeal_pending(env: &Env, claim_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Appeal>(&(APPEAL, claim_id))
+        .map(|appeal| !appeal.resolved)
+        .unwrap_or(false)
+}
+
+/// Hand a dispute over a large claim to governance instead of applying the
+/// outcome reached. The claim stays disputed until the proposal is
+/// finalized. Returns whether the dispute was escalated.
+fn escalate_dispute(env: &Env, claim_id: u64, recommended_uphold: bool) -> Result<bool, ContractError> {
+    let config: AppealConfig = match env.storage().instance().get(&APPEAL_CONFIG) {
+        Some(config) => config,
+        None => return Ok(false),
+    };
+    let claim: (u64, Address, i128, ClaimStatus, u64) = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM, claim_id))
+        .ok_or(ContractError::NotFound)?;
+    if claim.3 != ClaimStatus::Disputed {
+        return Err(ContractError::InvalidClaimState);
+    }
+    if claim.2 < config.min_amount || env.storage().persistent().has(&(APPEAL, claim_id)) {
+        return Ok(false);
+    }
+
+    require_trusted_contract(env, &config.governance)?;
+    let proposal_id: u64 = env.invoke_contract(
+        &config.governance,
+        &Symbol::new(env, "create_appeal_proposal"),
+        (
+            env.current_contract_address(),
+            claim_id,
+            claim.2,
+            recommended_uphold,
+            config.threshold_percentage,
+        )
+            .into_val(env),
+    );
+
+    let appeal = Appeal {
+        claim_id,
+        governance: config.governance,
+        proposal_id,
+        recommended_uphold,
+        opened_at: env.ledger().timestamp(),
+        resolved: false,
+    };
+    env.storage()
+        .persistent()
+        .set(&(APPEAL, claim_id), &appeal);
+
+    publish_event(
+        env,
+        (Symbol::new(env, "dispute_escalated"), claim_id),
+        (proposal_id, recommended_uphold),
+    );
+
+    Ok(true)
+}
+
+fn arbitration_config(env: &Env) -> ArbitrationConfig {
+    env.storage()
+        .instance()
+        .get(&ARB_CONFIG)
+        .unwrap_or(ArbitrationConfig {
+            panel_size: DEFAULT_PANEL_SIZE,
+            max_open_cases: DEFAULT_MAX_OPEN_CASES,
+        })
+}
+
+fn open_cases(env: &Env, arbitrator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(ARB_CASES, arbitrator.clone()))
+        .unwrap_or(0)
+}
+
+fn set_open_cases(env: &Env, arbitrator: &Address, cases: u32) {
+    env.storage()
+        .persistent()
+        .set(&(ARB_CASES, arbitrator.clone()), &cases);
+}
+
+fn processor_stats(env: &Env, processor: &Address) -> ProcessorStats {
+    env.storage()
+        .persistent()
+        .get(&(PROCESSOR_STATS, processor.clone()))
+        .unwrap_or_default()
+}
+
+fn publish_processor_summary(env: &Env, processor: &Address, stats: &ProcessorStats) {
+    let avg_decision_secs = if stats.decisions == 0 {
+        0
+    } else {
+        stats.total_decision_secs / stats.decisions as u64
+    };
+    publish_event(
+        env,
+        (Symbol::new(env, "processor_summary"), processor.clone()),
+        (stats.decisions, stats.approvals, avg_decision_secs, stats.overturned),
+    );
+}
+
+fn staking_config(env: &Env) -> Option<ProcessorStakingConfig> {
+    env.storage().instance().get(&STAKING_CONFIG)
+}
+
+fn processor_stake_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&PROCESSOR_STAKE_TOTAL)
+        .unwrap_or(0i128)
+}
+
+/// A processor's stake with rewards accrued up to the current index
+fn accrued_stake(env: &Env, processor: &Address) -> Result<ProcessorStake, ContractError> {
+    let mut stake: ProcessorStake = env
+        .storage()
+        .persistent()
+        .get(&(PROCESSOR_STAKE, processor.clone()))
+        .unwrap_or_default();
+    let index: i128 = env
+        .storage()
+        .instance()
+        .get(&PROCESSOR_REWARD_INDEX)
+        .unwrap_or(0i128);
+
+    let earned = mul_div_floor(stake.amount, index - stake.index, REWARD_SCALE).map_err(|_| ContractError::Overflow)?;
+    stake.rewards = stake.rewards.checked_add(earned).ok_or(ContractError::Overflow)?;
+    stake.index = index;
+    Ok(stake)
+}
+
+fn set_processor_stake(env: &Env, processor: &Address, stake: &ProcessorStake) {
+    env.storage()
+        .persistent()
+        .set(&(PROCESSOR_STAKE, processor.clone()), stake);
+}
+
+/// Processors must hold the minimum stake while staking is configured
+fn require_processor_stake(env: &Env, processor: &Address) -> Result<(), ContractError> {
+    let config = match staking_config(env) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    let stake: ProcessorStake = env
+        .storage()
+        .persistent()
+        .get(&(PROCESSOR_STAKE, processor.clone()))
+        .unwrap_or_default();
+    if stake.amount < config.min_stake {
+        return Err(ContractError::ProcessorStakeRequired);
+    }
+    Ok(())
+}
+
+/// Slash the stake of a processor whose approval was overturned on a
+/// dispute raised by `wronged`
+fn slash_processor(env: &Env, processor: &Address, claim_id: u64, wronged: &Address) -> Result<(), ContractError> {
+    let config = match staking_config(env) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    let mut stake = accrued_stake(env, processor)?;
+    let slashed = bps_of(stake.amount, config.slash_bps).map_err(|_| ContractError::Overflow)?;
+    if slashed <= 0 {
+        return Ok(());
+    }
+
+    stake.amount -= slashed;
+    set_processor_stake(env, processor, &stake);
+    env.storage()
+        .instance()
+        .set(&PROCESSOR_STAKE_TOTAL, &(processor_stake_total(env) - slashed));
+
+    let escrow = env.current_contract_address();
+    let token = token::Client::new(env, &config.token);
+    let to_wronged = bps_of(slashed, config.wronged_share_bps).map_err(|_| ContractError::Overflow)?;
+    let to_treasury = slashed - to_wronged;
+    if to_wronged > 0 {
+        token.transfer(&escrow, wronged, &to_wronged);
+    }
+    if to_treasury > 0 {
+        require_trusted_contract(env, &config.treasury)?;
+        token.transfer(&escrow, &config.treasury, &to_treasury);
+        env.invoke_contract::<()>(
+            &config.treasury,
+            &Symbol::new(env, "deposit_fee"),
+            (escrow, to_treasury, FEE_TYPE_OTHER).into_val(env),
+        );
+    }
+
+    publish_event(
+        env,
+        (Symbol::new(env, "processor_slashed"), processor.clone()),
+        (claim_id, slashed, to_wronged, to_treasury),
+    );
+
+    Ok(())
+}
+
+fn processor_fees(env: &Env, processor: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(PROCESSOR_FEES, processor.clone()))
+        .unwrap_or(0i128)
+}
+
+/// Have the pool set aside the processing fee for a settled claim and
+/// credit it to the processor who approved the claim. The pool pays no more
+/// than its uncommitted income.
+fn charge_processing_fee(env: &Env, risk_pool: &Address, claim_id: u64, amount: i128) -> Result<(), ContractError> {
+    let fee: ProcessingFee = match env.storage().instance().get(&PROCESSING_FEE) {
+        Some(fee) => fee,
+        None => return Ok(()),
+    };
+    let processor: Address = match env.storage().persistent().get(&(DECIDED_BY, claim_id)) {
+        Some(processor) => processor,
+        None => return Ok(()),
+    };
+
+    let due = match fee {
+        ProcessingFee::Flat(flat) => flat.min(amount),
+        ProcessingFee::Bps(bps) => bps_of(amount, bps).map_err(|_| ContractError::Overflow)?,
+    };
+    if due <= 0 {
+        return Ok(());
+    }
+
+    let charged: i128 = env.invoke_contract(
+        risk_pool,
+        &Symbol::new(env, "charge_processing_fee"),
+        (env.current_contract_address(), claim_id, due).into_val(env),
+    );
+    if charged <= 0 {
+        return Ok(());
+    }
+    env.storage().persistent().set(
+        &(PROCESSOR_FEES, processor.clone()),
+        &processor_fees(env, &processor).checked_add(charged).ok_or(ContractError::Overflow)?,
+    );
+
+    publish_event(
+        env,
+        (Symbol::new(env, "processing_fee_accrued"), claim_id),
+        (processor, charged),
+    );
+
+    Ok(())
+}
+
+/// Processors may not act on a claim they would be paid out on: as the
+/// claimant, as the payee paid in the claimant's place, or as a lender the
+/// payout is assigned to.
+fn require_not_beneficiary(env: &Env, processor: &Address, claim_id: u64, claimant: &Address) -> Result<(), ContractError> {
+    let payee: Option<Address> = env.storage().persistent().get(&(CLAIM_PAYEE, claim_id));
+    let assignment: Option<ClaimAssignment> = env.storage().persistent().get(&(ASSIGNMENT, claim_id));
+    if processor == claimant
+        || payee.as_ref() == Some(processor)
+        || assignment.is_some_and(|assignment| assignment.lender == *processor)
+    {
+        return Err(ContractError::ProcessorIsClaimant);
+    }
+    Ok(())
+}
+
+/// Record an approve/reject decision against the deciding processor
+fn record_decision(
+    env: &Env,
+    processor: &Address,
+    claim_id: u64,
+    submitted_at: u64,
+    approved: bool,
+) {
+    let now = env.ledger().timestamp();
+    let mut stats = processor_stats(env, processor);
+    stats.decisions += 1;
+    if approved {
+        stats.approvals += 1;
+    } else {
+        stats.rejections += 1;
+    }
+    stats.total_decision_secs += now.saturating_sub(submitted_at);
+    stats.last_decision_at = now;
+
+    env.storage()
+        .persistent()
+        .set(&(PROCESSOR_STATS, processor.clone()), &stats);
+    env.storage()
+        .persistent()
+        .set(&(DECIDED_BY, claim_id), processor);
+
+    if stats.decisions.is_multiple_of(PROCESSOR_SUMMARY_INTERVAL) {
+        publish_processor_summary(env, processor, &stats);
+    }
+}
+
+fn claimant_profile(env: &Env, claimant: &Address) -> ClaimantProfile {
+    env.storage()
+        .persistent()
+        .get(&(CLAIMANT_PROFILE, claimant.clone()))
+        .unwrap_or_default()
+}
+
+fn update_claimant_profile(env: &Env, claimant: &Address, update: impl FnOnce(&mut ClaimantProfile)) {
+    let mut profile = claimant_profile(env, claimant);
+    update(&mut profile);
+    env.storage()
+        .persistent()
+        .set(&(CLAIMANT_PROFILE, claimant.clone()), &profile);
+}
+
+fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
+    Ok(())
+}
+
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.policy_contract)?;
+    validate_address(env, &config.risk_pool)?;
+
+    if config.policy_contract == config.risk_pool {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Also extends the instance TTL, since nearly every call checks the flag
+fn is_paused(env: &Env) -> bool {
+    hot_storage::extend(env);
+    env.storage()
+        .instance()
+        .get(&PAUSED)
+        .unwrap_or(false)
+}
+
+fn set_paused(env: &Env, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&PAUSED, &paused);
+}
+
+/// Next claim id from the instance counter. Ids are never reused, so any
+/// number of claims can be filed in the same ledger.
+fn next_claim_id(env: &Env) -> u64 {
+    let current_id: u64 = env
+        .storage()
+        .instance()
+        .get(&CLAIM_COUNTER)
+        .unwrap_or(0u64);
+    let next_id = current_id + 1;
+    env.storage()
+        .instance()
+        .set(&CLAIM_COUNTER, &next_id);
+    next_id
+}
+
+fn get_stats(env: &Env) -> ClaimsStats {
+    env.storage()
+        .instance()
+        .get(&CLAIM_STATS)
+        .unwrap_or_default()
+}
+
+fn set_stats(env: &Env, stats: &ClaimsStats) {
+    env.storage().instance().set(&CLAIM_STATS, stats);
+    checkpoint_stats(env, stats);
+    state_root::update(
+        env,
+        (
+            stats.total_submitted,
+            stats.open_claims,
+            stats.total_settled,
+            stats.total_paid_out,
+        ),
+    );
+}
+
+/// Write the live aggregates into the checkpoint for the current epoch.
+/// Later writes in the same epoch overwrite earlier ones, so each checkpoint
+/// holds the closing values of its epoch. Only the newest
+/// `MAX_STATS_HISTORY` epochs are retained.
+fn checkpoint_stats(env: &Env, stats: &ClaimsStats) {
+    let timestamp = env.ledger().timestamp();
+    let epoch = timestamp / STATS_EPOCH_SECONDS;
+
+    let mut epochs: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&STATS_EPOCHS)
+        .unwrap_or_else(|| Vec::new(env));
+    if epochs.last() != Some(epoch) {
+        epochs.push_back(epoch);
+        if epochs.len() > MAX_STATS_HISTORY {
+            if let Some(oldest) = epochs.pop_front() {
+                env.storage()
+                    .persistent()
+                    .remove(&(STATS_CHECKPOINT, oldest));
+            }
+        }
+        env.storage().persistent().set(&STATS_EPOCHS, &epochs);
+    }
+
+    let checkpoint = ClaimsStatsCheckpoint {
+        epoch,
+        timestamp,
+        stats: stats.clone(),
+    };
+    env.storage()
+        .persistent()
+        .set(&(STATS_CHECKPOINT, epoch), &checkpoint);
+}
+
+/// I3: Validate claim state transition
+/// Delegates to the shared claim lifecycle so every contract agrees on it
+fn is_valid_state_transition(current: ClaimStatus, next: ClaimStatus) -> bool {
+    current.can_transition_to(next)
+}
+
+/// I3: Require a valid claim state transition
+fn require_transition(env: &Env, current: ClaimStatus, next: ClaimStatus) -> Result<(), ContractError> {
+    assert_invariant!(env, is_valid_state_transition(current, next), InvariantError::InvalidClaimState);
+    Ok(())
+}
+
+/// I4: Validate amount is positive and within safe range
+fn validate_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    assert_valid_amount!(env, amount, 1);
+    Ok(())
+}
+
+/// I6: Validate claim does not exceed coverage limit
+fn validate_coverage_constraint(env: &Env, claim_amount: i128, coverage_amount: i128) -> Result<(), ContractError> {
+    assert_coverage_constraint!(env, claim_amount, coverage_amount);
+    Ok(())
+}
+
+/// Settle an approved claim, or schedule it if it is above the payout
+/// delay threshold
+fn settle(env: &Env, claim_id: u64) -> Result<(), ContractError> {
+    let claim: (u64, Address, i128, ClaimStatus, u64) = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM, claim_id))
+        .ok_or(ContractError::NotFound)?;
+
+    require_transition(env, claim.3, ClaimStatus::Settled)?;
+
+    if claim.3 == ClaimStatus::PendingSettlement && in_dispute_window(env, claim_id) {
+        return Err(ContractError::DisputeWindowOpen);
+    }
+
+    if payout_delay::requires_delay(env, claim.2) {
+        if payout_delay::pending_release_at(env, claim_id).is_some() {
+            return Err(ContractError::PayoutPending);
+        }
+        let release_at = payout_delay::schedule(env, claim_id)?;
+        publish_event(
+            env,
+            (Symbol::new(env, "payout_pending"), claim_id),
+            (claim.2, release_at),
+        );
+        return Ok(());
+    }
+
+    pay_out(env, claim_id)
+}
+
+fn lender_assigned(env: &Env, lender: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(LENDER_ASSIGNED, lender.clone()))
+        .unwrap_or(0)
+}
+
+/// Drop a claim's assignment, freeing the lender's cap. Returns it if the
+/// claim had one.
+fn clear_assignment(env: &Env, claim_id: u64) -> Option<ClaimAssignment> {
+    let assignment: ClaimAssignment = env.storage().persistent().get(&(ASSIGNMENT, claim_id))?;
+    let assigned = (lender_assigned(env, &assignment.lender) - assignment.amount).max(0);
+    env.storage()
+        .persistent()
+        .set(&(LENDER_ASSIGNED, assignment.lender.clone()), &assigned);
+    env.storage()
+        .persistent()
+        .remove(&(ASSIGNMENT, claim_id));
+    Some(assignment)
+}
+
+/// Pay out an approved claim through the risk pool and mark it Settled
+fn pay_out(env: &Env, claim_id: u64) -> Result<(), ContractError> {
+    let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM, claim_id))
+        .ok_or(ContractError::NotFound)?;
+
+    // I3: Can only settle claims that are Approved - validate state transition
+    require_transition(env, claim.3, ClaimStatus::Settled)?;
+
+    // I4: Amount must be positive
+    validate_amount(env, claim.2)?;
+
+    // Get risk pool contract address from config
+    let config: (Address, Address) = env
+        .storage()
+        .instance()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)?;
+    let risk_pool_contract = config.1.clone();
+
+    // Verify risk pool is a trusted contract before invoking
+    require_trusted_contract(env, &risk_pool_contract)?;
+
+    // Reference priced claims are paid at today's rate, in pool units
+    let product_id: u32 = env.invoke_contract(
+        &config.0,
+        &Symbol::new(env, "get_policy_product"),
+        (claim.0,).into_val(env),
+    );
+    let payout = settlement_amount(env, &risk_pool_contract, claim_id, product_id, claim.2)?;
+
+    // An assigned payout is routed to the lender first
+    let assignment = clear_assignment(env, claim_id);
+    let mut assigned_payout = 0;
+    if let Some(assignment) = assignment.clone() {
+        assigned_payout = mul_div_floor(assignment.amount, payout, claim.2).map_err(|_| ContractError::Overflow)?;
+        env.invoke_contract::<()>(
+            &risk_pool_contract,
+            &Symbol::new(env, "route_claim_payout"),
+            (env.current_contract_address(), claim_id, assignment.lender, assigned_payout).into_val(env),
+        );
+    }
+
+    // Call risk pool to payout the claim amount, to the claim's payee if
+    // it has one
+    let recipient: Address = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM_PAYEE, claim_id))
+        .unwrap_or_else(|| claim.1.clone());
+    env.invoke_contract::<()>(
+        &risk_pool_contract,
+        &Symbol::new(env, "payout_reserved_claim"),
+        (claim_id, recipient).into_val(env),
+    );
+
+    // The loss is booked against the policy's product so providers who
+    // assumed that product's risk from others bear it
+    env.invoke_contract::<()>(
+        &risk_pool_contract,
+        &Symbol::new(env, "record_product_loss"),
+        (env.current_contract_address(), product_id, payout).into_val(env),
+    );
+
+    // Settled claims remove the policy's renewal price protection and are
+    // booked in the policy's ledger; a payout exhausting the coverage marks
+    // the policy CLAIMED
+    env.invoke_contract::<()>(
+        &config.0,
+        &Symbol::new(env, "record_claim"),
+        (env.current_contract_address(), claim.0, claim.2).into_val(env),
+    );
+
+    // I3: Transition to Settled state
+    claim.3 = ClaimStatus::Settled;
+
+    env.storage()
+        .persistent()
+        .set(&(CLAIM, claim_id), &claim);
+
+    let mut stats = get_stats(env);
+    stats.open_claims = stats.open_claims.saturating_sub(1);
+    stats.total_settled += 1;
+    stats.total_paid_out = stats.total_paid_out.checked_add(payout).ok_or(ContractError::Overflow)?;
+    set_stats(env, &stats);
+
+    update_claimant_profile(env, &claim.1, |profile| {
+        profile.total_paid = profile.total_paid.saturating_add(payout);
+    });
+
+    charge_processing_fee(env, &risk_pool_contract, claim_id, payout)?;
+
+    if let Some(assignment) = assignment {
+        publish_event(
+            env,
+            (Symbol::new(env, "assignment_paid"), claim_id),
+            (assignment.lender, assigned_payout),
+        );
+    }
+
+    publish_event(
+        env,
+        (Symbol::new(env, "claim_settled"), claim_id),
+        (claim.1, payout),
+    );
+
+    notify(env, &Symbol::new(env, "claim_settled"), claim_id, payout);
+
+    Ok(())
+}
+
+fn parametric_trigger(env: &Env, product_id: u32) -> Option<ParametricTrigger> {
+    env.storage()
+        .persistent()
+        .get(&(PARAMETRIC_TRIGGER, product_id))
+}
+
+/// Refuse to run a settlement path while another one is in progress in
+/// the same invocation. A failed call rolls the flag back with the rest.
+fn enter_settlement(env: &Env) -> Result<(), ContractError> {
+    if env.storage().temporary().has(&SETTLE_GUARD) {
+        return Err(ContractError::Reentrant);
+    }
+    env.storage().temporary().set(&SETTLE_GUARD, &true);
+    Ok(())
+}
+
+fn exit_settlement(env: &Env) {
+    env.storage().temporary().remove(&SETTLE_GUARD);
+}
+
+/// `(value, finalized_at)` of oracle data point `data_id`, resolved with
+/// enough submissions and at most `max_age_secs` old
+fn resolved_reading(env: &Env, data_id: u64, max_age_secs: u64) -> Result<(i128, u64), ContractError> {
+    let oracle_config: OracleValidationConfig = env
+        .storage()
+        .persistent()
+        .get(&ORACLE_CONFIG)
+        .ok_or(ContractError::OracleValidationFailed)?;
+    require_trusted_contract(env, &oracle_config.oracle_contract)?;
+
+    // (consensus value, submissions, included submissions, finalized at)
+    let reading: (i128, u32, u32, u64) = env.invoke_contract(
+        &oracle_config.oracle_contract,
+        &Symbol::new(env, "resolve_oracle_data"),
+        (data_id,).into_val(env),
+    );
+    if reading.2 < oracle_config.min_oracle_submissions {
+        return Err(ContractError::InsufficientOracleSubmissions);
+    }
+
+    let now = env.ledger().timestamp();
+    if reading.3 > now || now - reading.3 > max_age_secs {
+        return Err(ContractError::OracleDataStale);
+    }
+    Ok((reading.0, reading.3))
+}
+
+/// Check `trigger` against the oracle's resolved reading for a policy
+/// covering `term_start..=term_end`
+fn check_trigger(env: &Env, trigger: &ParametricTrigger, term_start: u64, term_end: u64) -> Result<(), ContractError> {
+    let (value, finalized_at) = resolved_reading(env, trigger.data_id, trigger.max_data_age_secs)?;
+    // The event must have been observed while the policy was in force
+    if finalized_at < term_start || finalized_at > term_end {
+        return Err(ContractError::TriggerNotMet);
+    }
+
+    let triggered = if trigger.above {
+        value >= trigger.threshold
+    } else {
+        value <= trigger.threshold
+    };
+    if !triggered {
+        return Err(ContractError::TriggerNotMet);
+    }
+    Ok(())
+}
+
+fn reference_pricing(env: &Env, product_id: u32) -> Option<ReferencePricing> {
+    env.storage()
+        .persistent()
+        .get(&(REFERENCE_PRICING, product_id))
+}
+
+/// Current price of the pool asset in the reference currency, scaled by
+/// `REFERENCE_RATE_SCALE`
+fn reference_rate(env: &Env, pricing: &ReferencePricing) -> Result<i128, ContractError> {
+    let (rate, _) = resolved_reading(env, pricing.rate_data_id, pricing.max_age_secs)?;
+    if rate <= 0 {
+        return Err(ContractError::OracleValidationFailed);
+    }
+    Ok(rate)
+}
+
+fn depeg_cover(env: &Env, product_id: u32) -> Option<DepegCover> {
+    env.storage()
+        .persistent()
+        .get(&(DEPEG_COVER, product_id))
+}
+
+fn peg_watch(env: &Env, product_id: u32) -> Option<PegWatch> {
+    env.storage()
+        .persistent()
+        .get(&(PEG_WATCH, product_id))
+}
+
+/// Fold the current peg reading into a product's watch. A window that has
+/// run its length is closed: the run of windows below the strike is
+/// extended or broken, and a run lasting the minimum duration confirms a
+/// depeg, once per run. A gap longer than `max_gap_secs` starts the watch
+/// over, keeping only the latest confirmed depeg.
+fn observe_peg(env: &Env, product_id: u32, cover: &DepegCover) -> Result<PegWatch, ContractError> {
+    let (price, _) = resolved_reading(env, cover.feed_data_id, cover.max_gap_secs)?;
+    let now = env.ledger().timestamp();
+
+    let mut watch = match peg_watch(env, product_id) {
+        Some(watch) if now.saturating_sub(watch.window.last_at) <= cover.max_gap_secs => watch,
+        previous => {
+            let (last_depeg_at, last_depeg_confirmed_at) = previous
+                .map_or((0, 0), |watch| (watch.last_depeg_at, watch.last_depeg_confirmed_at));
+            PegWatch {
+                window: TwapWindow::start(price, now),
+                last_twap: price,
+                below_since: None,
+                last_depeg_at,
+                last_depeg_confirmed_at,
+            }
+        }
+    };
+    watch.window.observe(price, now).map_err(|_| ContractError::Overflow)?;
+
+    if watch.window.elapsed() >= cover.twap_window_secs {
+        let twap = watch.window.average().unwrap_or(price);
+        if twap < cover.strike {
+            let since = *watch.below_since.get_or_insert(watch.window.started_at);
+            let confirmed = watch.last_depeg_confirmed_at != 0 && watch.last_depeg_at == since;
+            if !confirmed && now.saturating_sub(since) >= cover.min_duration_secs {
+                watch.last_depeg_at = since;
+                watch.last_depeg_confirmed_at = now;
+                publish_event(
+                    env,
+                    (Symbol::new(env, "depeg_confirmed"), product_id),
+                    (since, now, twap),
+                );
+            }
+        } else {
+            watch.below_since = None;
+        }
+        watch.last_twap = twap;
+        watch.window = TwapWindow::start(price, now);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(PEG_WATCH, product_id), &watch);
+    Ok(watch)
+}
+
+fn downtime_tiers(env: &Env, product_id: u32) -> Vec<DowntimeTier> {
+    env.storage()
+        .persistent()
+        .get(&(DOWNTIME_TIERS, product_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn node_downtime(env: &Env, node_id: &BytesN<32>) -> Option<NodeDowntime> {
+    env.storage()
+        .persistent()
+        .get(&(NODE_DOWNTIME, node_id.clone()))
+}
+
+/// Downtime attested for a node through the end of `day`
+fn downtime_through(env: &Env, node_id: &BytesN<32>, record: &NodeDowntime, day: u64) -> u64 {
+    if day < record.first_day {
+        return 0;
+    }
+    env.storage()
+        .persistent()
+        .get(&(NODE_DAY, node_id.clone(), day.min(record.last_day)))
+        .unwrap_or(0u64)
+}
+
+/// Payout share for a node's downtime during `term_start..=term_end`,
+/// counted over the UTC days the term touches that have been attested.
+/// The highest tier reached applies.
+fn check_downtime(env: &Env, tiers: &Vec<DowntimeTier>, node_id: &BytesN<32>, term_start: u64, term_end: u64) -> Result<u32, ContractError> {
+    let record = node_downtime(env, node_id).ok_or(ContractError::TriggerNotMet)?;
+    let start_day = term_start / DOWNTIME_DAY_SECONDS;
+    let end_day = term_end / DOWNTIME_DAY_SECONDS;
+
+    let before = match start_day.checked_sub(1) {
+        Some(day) => downtime_through(env, node_id, &record, day),
+        None => 0,
+    };
+    let downtime_secs = downtime_through(env, node_id, &record, end_day).saturating_sub(before);
+    let hours = downtime_secs / 3_600;
+
+    tiers
+        .iter()
+        .filter(|tier| hours >= tier.min_hours)
+        .last()
+        .map(|tier| tier.payout_bps)
+        .ok_or(ContractError::TriggerNotMet)
+}
+
+/// Terms `(holder, coverage, state, start, end)` of a policy that can still
+/// be claimed on: neither cancelled nor claimed, started, at most
+/// `CLAIM_WINDOW_SECONDS` past its end and without a claim yet
+fn claimable_policy(
+    env: &Env,
+    policy_client: &policy_contract::Client,
+    policy_id: u64,
+) -> Result<(Address, i128, policy_contract::PolicyState, u64, u64), ContractError> {
+    let policy = policy_client.get_policy_terms(&policy_id);
+    let now = env.ledger().timestamp();
+    match policy.2 {
+        policy_contract::PolicyState::CANCELLED | policy_contract::PolicyState::CLAIMED => {
+            return Err(ContractError::PolicyNotActive)
+        }
+        _ if now < policy.3 => return Err(ContractError::PolicyNotActive),
+        _ if now > policy.4.saturating_add(CLAIM_WINDOW_SECONDS) => return Err(ContractError::PolicyExpired),
+        _ => {}
+    }
+    if env.storage().persistent().has(&(POLICY_CLAIM, policy_id)) {
+        return Err(ContractError::AlreadyExists);
+    }
+    Ok(policy)
+}
+
+/// Claim for `payout_bps` of `coverage` filed on a trigger that stands in
+/// for review
+struct TriggeredClaim {
+    policy_id: u64,
+    claimant: Address,
+    coverage: i128,
+    product_id: u32,
+    payout_bps: u32,
+    /// Oracle data the trigger was checked against
+    data_id: Option<u64>,
+    /// Paid instead of the claimant
+    payee: Option<Address>,
+}
+
+/// File a triggered claim, reserve it and settle it in the same call.
+/// Callers hold the settlement guard.
+fn open_and_settle(env: &Env, config: &(Address, Address), claim: TriggeredClaim) -> Result<u64, ContractError> {
+    let TriggeredClaim { policy_id, claimant, coverage, product_id, payout_bps, data_id, payee } = claim;
+    let claimant = &claimant;
+    let amount = bps_of(coverage, payout_bps).map_err(|_| ContractError::Overflow)?;
+    validate_amount(env, amount)?;
+    validate_coverage_constraint(env, amount, coverage)?;
+    let aggregate = holder_aggregate(env, claimant, product_id)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if let Some(limit) = aggregate_limit(env, product_id) {
+        if aggregate > limit {
+            return Err(ContractError::AggregateLimitExceeded);
+        }
+    }
+
+    let claim_id = next_claim_id(env);
+    if env.storage().persistent().has(&(CLAIM, claim_id)) {
+        return Err(ContractError::AlreadyExists);
+    }
+
+    require_trusted_contract(env, &config.1)?;
+    let reserved = reservation_amount(env, claim_id, product_id, amount)?;
+    env.invoke_contract::<()>(
+        &config.1,
+        &Symbol::new(env, "reserve_liquidity"),
+        (claim_id, reserved).into_val(env),
+    );
+
+    // I3: the trigger stands in for review, so the claim starts out
+    // approved and goes straight to settlement
+    let now = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&(CLAIM, claim_id), &(policy_id, claimant.clone(), amount, ClaimStatus::Approved, now));
+    env.storage()
+        .persistent()
+        .set(&(POLICY_CLAIM, policy_id), &claim_id);
+    if let Some(data_id) = data_id {
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_ORACLE_ID, claim_id), &data_id);
+    }
+    if let Some(payee) = &payee {
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_PAYEE, claim_id), payee);
+    }
+    env.storage()
+        .persistent()
+        .set(&(HOLDER_AGGREGATE, claimant.clone(), product_id, aggregate_period(env)), &aggregate);
+
+    let mut stats = get_stats(env);
+    stats.total_submitted += 1;
+    stats.open_claims += 1;
+    set_stats(env, &stats);
+    update_claimant_profile(env, claimant, |profile| {
+        profile.filed += 1;
+        profile.approved += 1;
+        profile.last_claim_at = now;
+    });
+
+    publish_event(
+        env,
+        (Symbol::new(env, "parametric_claim"), claim_id),
+        (policy_id, amount, claimant.clone(), data_id),
+    );
+
+    settle(env, claim_id)?;
+    Ok(claim_id)
+}
+
+fn key_loss_cover(env: &Env, product_id: u32) -> Option<KeyLossCover> {
+    env.storage()
+        .persistent()
+        .get(&(KEY_LOSS_COVER, product_id))
+}
+
+fn key_loss_claim(env: &Env, policy_id: u64) -> Option<KeyLossClaim> {
+    env.storage()
+        .persistent()
+        .get(&(KEY_LOSS_CLAIM, policy_id))
+}
+
+fn bridge_cover(env: &Env, product_id: u32) -> Option<BridgeCover> {
+    env.storage()
+        .persistent()
+        .get(&(BRIDGE_COVER, product_id))
+}
+
+fn bridge_event(env: &Env, event_id: u64) -> Result<BridgeEvent, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(BRIDGE_EVENT, event_id))
+        .ok_or(ContractError::NotFound)
+}
+
+/// Committee member `attestor` and the current committee
+fn bridge_attestor(env: &Env, attestor: &Address) -> Result<BridgeCommittee, ContractError> {
+    let committee: BridgeCommittee = env
+        .storage()
+        .instance()
+        .get(&BRIDGE_COMMITTEE)
+        .ok_or(ContractError::NotInitialized)?;
+    if !committee.members.contains(attestor) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(committee)
+}
+
+/// A policy covering `term_start..=term_end` is paid for a depeg that
+/// started and was confirmed within its term
+fn check_depeg(env: &Env, product_id: u32, term_start: u64, term_end: u64) -> Result<(), ContractError> {
+    match peg_watch(env, product_id) {
+        Some(watch)
+            if watch.last_depeg_confirmed_at != 0
+                && watch.last_depeg_at >= term_start
+                && watch.last_depeg_confirmed_at <= term_end =>
+        {
+            Ok(())
+        }
+        _ => Err(ContractError::TriggerNotMet),
+    }
+}
+
+/// Pool amount to reserve for a claim of `amount`. Reference priced claims
+/// convert at the current rate, rounded up, and keep the rate to bound the
+/// one used at settlement.
+fn reservation_amount(env: &Env, claim_id: u64, product_id: u32, amount: i128) -> Result<i128, ContractError> {
+    let pricing = match reference_pricing(env, product_id) {
+        Some(pricing) => pricing,
+        None => return Ok(amount),
+    };
+    let rate = reference_rate(env, &pricing)?;
+    env.storage()
+        .persistent()
+        .set(&(CLAIM_RATE, claim_id), &rate);
+    mul_div_ceil(amount, REFERENCE_RATE_SCALE, rate).map_err(|_| ContractError::Overflow)
+}
+
+/// Pool amount a claim of `amount` pays. Reference priced claims convert at
+/// the current rate, rounded down, provided it is within the product's
+/// deviation bound of the approval rate; the pool's reservation is resized
+/// to the converted amount.
+fn settlement_amount(
+    env: &Env,
+    risk_pool: &Address,
+    claim_id: u64,
+    product_id: u32,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let pricing = match reference_pricing(env, product_id) {
+        Some(pricing) => pricing,
+        None => return Ok(amount),
+    };
+    let approved_rate: i128 = env
+        .storage()
+        .persistent()
+        .get(&(CLAIM_RATE, claim_id))
+        .ok_or(ContractError::InvalidState)?;
+    let rate = reference_rate(env, &pricing)?;
+    let deviation = ratio_bps((rate - approved_rate).abs(), approved_rate).map_err(|_| ContractError::Overflow)?;
+    if deviation > pricing.max_deviation_bps as i128 {
+        return Err(ContractError::RateDeviation);
+    }
+
+    let payout = mul_div_floor(amount, REFERENCE_RATE_SCALE, rate).map_err(|_| ContractError::Overflow)?;
+    env.invoke_contract::<()>(
+        risk_pool,
+        &Symbol::new(env, "resize_reservation"),
+        (env.current_contract_address(), claim_id, payout).into_val(env),
+    );
+    env.storage()
+        .persistent()
+        .set(&(SETTLED_AMOUNT, claim_id), &payout);
+
+    publish_event(
+        env,
+        (Symbol::new(env, "claim_converted"), claim_id),
+        (amount, rate, payout),
+    );
+
+    Ok(payout)
+}
+
+/// Pool amount a settled claim paid
+fn settled_amount(env: &Env, claim_id: u64, amount: i128) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(SETTLED_AMOUNT, claim_id))
+        .unwrap_or(amount)
+}
+
+#[contractimpl]
+impl ClaimsContract {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
+        // Check if already initialized
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        validate_init_config(&env, &config)?;
+        let InitConfig { admin, policy_contract, risk_pool } = config;
+
+        // Initialize authorization system with admin
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+        
+        // Register policy and risk pool contracts as trusted for cross-contract calls
+        register_trusted_contract(&env, &admin, &policy_contract)?;
+        register_trusted_contract(&env, &admin, &risk_pool)?;
+
+        // Store contract configuration
+        env.storage().instance().set(&CONFIG, &(policy_contract, risk_pool));
+        
+        publish_event(
+            &env,
+            (symbol_short!("init"), ()),
+            admin,
+        );
+        
+        Ok(())
+    }
+
+    /// Verify the policy and risk pool contracts and activate claim intake
+    /// (admin only). Both peers must already trust this contract.
+    pub fn finalize_wiring(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let (policy_contract, risk_pool): (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        for peer in [policy_contract, risk_pool] {
+            wiring::verify_peer(&env, &peer)?;
+            register_trusted_contract(&env, &admin, &peer)?;
+        }
+        wiring::set_wired(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "wiring_finalized"), ()),
+            WIRING_VERSION,
+        );
+
+        Ok(())
+    }
+
+    /// Handshake called by a peer's `finalize_wiring`
+    pub fn accept_wiring(env: Env, peer: Address) -> Result<u32, ContractError> {
+        peer.require_auth();
+        require_trusted_contract(&env, &peer)?;
+        Ok(WIRING_VERSION)
+    }
+
+    pub fn is_wired(env: Env) -> bool {
+        wiring::is_wired(&env)
+    }
+
+    /// Initialize oracle validation for the claims contract
+    pub fn set_oracle_config(
+        env: Env,
+        admin: Address,
+        oracle_contract: Address,
+        require_oracle_validation: bool,
+        min_oracle_submissions: u32,
+    ) -> Result<(), ContractError> {
+        // Verify identity and require admin permission
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        validate_address(&env, &oracle_contract)?;
+
+        // Register oracle contract as trusted for cross-contract calls
+        register_trusted_contract(&env, &admin, &oracle_contract)?;
+
+        let config = OracleValidationConfig {
+            oracle_contract: oracle_contract.clone(),
+            require_oracle_validation,
+            min_oracle_submissions,
+        };
+
+        env.storage().persistent().set(&ORACLE_CONFIG, &config);
+        Ok(())
+    }
+
+    /// Get current oracle configuration
+    pub fn get_oracle_config(env: Env) -> Result<OracleValidationConfig, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&ORACLE_CONFIG)
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Validate claim using oracle data
+    /// This function checks oracle submissions and enforces consensus-based validation
+    pub fn validate_claim_with_oracle(
+        env: Env,
+        claim_id: u64,
+        oracle_data_id: u64,
+    ) -> Result<bool, ContractError> {
+        // Get oracle configuration
+        let oracle_config: OracleValidationConfig = env
+            .storage()
+            .persistent()
+            .get(&ORACLE_CONFIG)
+            .ok_or(ContractError::NotFound)?;
+
+        if !oracle_config.require_oracle_validation {
+            return Ok(true);
+        }
+
+        // Verify oracle contract is trusted before making cross-contract calls
+        require_trusted_contract(&env, &oracle_config.oracle_contract)?;
+
+        // Get oracle submission count using invoke_contract
+        let submission_count: u32 = env.invoke_contract(
+            &oracle_config.oracle_contract,
+            &Symbol::new(&env, "get_submission_count"),
+            (oracle_data_id,).into_val(&env),
+        );
+
+        // Check minimum submissions
+        if submission_count < oracle_config.min_oracle_submissions {
+            return Err(ContractError::InsufficientOracleSubmissions);
+        }
+
+        // Attempt to resolve oracle data - this will validate consensus and staleness
+        let _oracle_data: (i128, u32, u32, u64) = env.invoke_contract(
+            &oracle_config.oracle_contract,
+            &Symbol::new(&env, "resolve_oracle_data"),
+            (oracle_data_id,).into_val(&env),
+        );
+
+        // Store oracle data ID associated with claim for audit trail
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_ORACLE_ID, claim_id), &oracle_data_id);
+
+        Ok(true)
+    }
+
+    /// Get oracle data associated with a claim
+    pub fn get_claim_oracle_data(env: Env, claim_id: u64) -> Result<u64, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(CLAIM_ORACLE_ID, claim_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    pub fn submit_claim(env: Env, claimant: Address, policy_id: u64, amount: i128) -> Result<u64, ContractError> {
+        // 1. IDENTITY CHECK
+        claimant.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        wiring::require_wired(&env)?;
+
+        // 2. FETCH POLICY DATA
+        let (policy_contract_addr, _): (Address, Address) = env.storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let policy_client = policy_contract::Client::new(&env, &policy_contract_addr);
+        let policy = policy_client.get_policy_terms(&policy_id);
+
+        // 3. OWNERSHIP CHECK (Verify policyholder identity)
+        if policy.0 != claimant {
+            return Err(ContractError::Unauthorized); 
+        }
+
+        // 4. STATUS CHECK (Cancelled policies and expired terms past the
+        // claim window cannot be claimed against)
+        let now = env.ledger().timestamp();
+        match policy.2 {
+            policy_contract::PolicyState::CANCELLED | policy_contract::PolicyState::CLAIMED => {
+                return Err(ContractError::PolicyNotActive)
+            }
+            _ if now < policy.3 => return Err(ContractError::PolicyNotActive),
+            _ if now > policy.4.saturating_add(CLAIM_WINDOW_SECONDS) => return Err(ContractError::PolicyExpired),
+            _ => {}
+        }
+
+        // 3. DUPLICATE CHECK (Check if this specific policy already has a claim)
+        if env.storage().persistent().has(&(POLICY_CLAIM, policy_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        // 5. COVERAGE CHECK (Enforce claim ≤ remaining coverage)
+        validate_amount(&env, amount)?;
+        validate_coverage_constraint(&env, amount, policy.1)?;
+
+        // 6. PRODUCT BOUNDS CHECK
+        if let Some(bounds) = claim_bounds(&env, policy_client.get_policy_product(&policy_id)) {
+            if amount < bounds.min_amount || amount > bounds.max_amount {
+                return Err(ContractError::ClaimAmountOutOfBounds);
+            }
+        }
+
+        // ID Generation (never overwrite a stored claim)
+        let claim_id = next_claim_id(&env);
+        if env.storage().persistent().has(&(CLAIM, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+        let current_time = env.ledger().timestamp();
+
+        // I3: Initial state must be Submitted
+        let initial_status = ClaimStatus::Submitted;
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM, claim_id), &(policy_id, claimant.clone(), amount, initial_status, current_time));
+        
+        env.storage()
+            .persistent()
+            .set(&(POLICY_CLAIM, policy_id), &claim_id);
+
+        let mut stats = get_stats(&env);
+        stats.total_submitted += 1;
+        stats.open_claims += 1;
+        set_stats(&env, &stats);
+
+        update_claimant_profile(&env, &claimant, |profile| {
+            profile.filed += 1;
+            profile.last_claim_at = current_time;
+        });
+
+        publish_event(
+            &env,
+            (symbol_short!("clm_sub"), claim_id),
+            (policy_id, amount, claimant.clone()),
+        );
+
+        Ok(claim_id)
+    }
+
+    pub fn get_claim(env: Env, claim_id: u64) -> Result<(u64, Address, i128, ClaimStatus, u64), ContractError> {
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        Ok(claim)
+    }
+
+    /// Batch lookup of claims. The result is aligned with `claim_ids`;
+    /// ids with no stored claim yield `None`.
+    pub fn get_claims(env: Env, claim_ids: Vec<u64>) -> Result<Vec<Option<ClaimRecord>>, ContractError> {
+        if claim_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut claims = Vec::new(&env);
+        for claim_id in claim_ids.iter() {
+            claims.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&(CLAIM, claim_id)),
+            );
+        }
+        Ok(claims)
+    }
+
+    /// Rewrite claims stored with the pre-unification status encoding
+    /// (admin only). Run after upgrading a deployment that holds such
+    /// claims; ids that are missing or already migrated are skipped.
+    /// Returns the number of claims rewritten.
+    pub fn migrate_claims(env: Env, admin: Address, claim_ids: Vec<u64>) -> Result<u32, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if claim_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut migrated = 0u32;
+        for claim_id in claim_ids.iter() {
+            let stored: Option<(u64, Address, i128, Val, u64)> = env
+                .storage()
+                .persistent()
+                .get(&(CLAIM, claim_id));
+            let Some((policy_id, claimant, amount, status, submitted_at)) = stored else {
+                continue;
+            };
+            if ClaimStatus::try_from_val(&env, &status).is_ok() {
+                continue;
+            }
+            let status: ClaimStatus = LegacyClaimStatus::try_from_val(&env, &status)
+                .map_err(|_| ContractError::InvalidClaimState)?
+                .into();
+
+            env.storage()
+                .persistent()
+                .set(&(CLAIM, claim_id), &(policy_id, claimant, amount, status, submitted_at));
+            migrated += 1;
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claims_migrated"), ()),
+            migrated,
+        );
+
+        Ok(migrated)
+    }
+
+    pub fn approve_claim(env: Env, processor: Address, claim_id: u64, oracle_data_id: Option<u64>) -> Result<(), ContractError> {
+        // Verify identity and require claim processing permission
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        require_not_beneficiary(&env, &processor, claim_id, &claim.1)?;
+        require_processor_stake(&env, &processor)?;
+
+        // I3: Can only approve claims that are UnderReview - validate state transition
+        require_transition(&env, claim.3, ClaimStatus::Approved)?;
+
+        // I4: Amount must be positive
+        if claim.2 <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        // Annual aggregate limit per holder and product
+        let product_id: u32 = env.invoke_contract(
+            &config.0,
+            &Symbol::new(&env, "get_policy_product"),
+            (claim.0,).into_val(&env),
+        );
+        let aggregate = holder_aggregate(&env, &claim.1, product_id)
+            .checked_add(claim.2)
+            .ok_or(ContractError::Overflow)?;
+        if let Some(limit) = aggregate_limit(&env, product_id) {
+            if aggregate > limit {
+                return Err(ContractError::AggregateLimitExceeded);
+            }
+        }
+
+        // Check if oracle validation is required
+        if let Some(oracle_config) = env.storage().persistent().get::<_, OracleValidationConfig>(&ORACLE_CONFIG) {
+            if oracle_config.require_oracle_validation {
+                if let Some(oracle_id) = oracle_data_id {
+                    // Verify oracle contract is trusted
+                    require_trusted_contract(&env, &oracle_config.oracle_contract)?;
+                    
+                    // Validate using oracle data (store oracle data ID)
+                    let _submission_count: u32 = env.invoke_contract(
+                        &oracle_config.oracle_contract,
+                        &Symbol::new(&env, "get_submission_count"),
+                        (oracle_id,).into_val(&env),
+                    );
+
+                    // Store oracle data ID associated with claim for audit trail
+                    env.storage()
+                        .persistent()
+                        .set(&(CLAIM_ORACLE_ID, claim_id), &oracle_id);
+                } else {
+                    return Err(ContractError::OracleValidationFailed);
+                }
+            }
+        }
+
+        let risk_pool_contract = config.1.clone();
+
+        // Verify risk pool is a trusted contract before invoking
+        require_trusted_contract(&env, &risk_pool_contract)?;
+
+        let reserved = reservation_amount(&env, claim_id, product_id, claim.2)?;
+        env.invoke_contract::<()>(
+            &risk_pool_contract,
+            &Symbol::new(&env, "reserve_liquidity"),
+            (claim_id, reserved).into_val(&env),
+        );
+
+        // I3: Approved claims wait out the dispute window before settling;
+        // larger claims may get a longer window
+        claim.3 = ClaimStatus::PendingSettlement;
+        let window_secs = dispute_window_for(&dispute_config(&env), claim.2);
+        let window_end = env.ledger().timestamp().saturating_add(window_secs);
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM, claim_id), &claim);
+        env.storage()
+            .persistent()
+            .set(&(DISPUTE_WINDOW_END, claim_id), &window_end);
+        env.storage()
+            .persistent()
+            .set(&(DISPUTE_WINDOW_SECS, claim_id), &window_secs);
+        env.storage()
+            .persistent()
+            .set(&(HOLDER_AGGREGATE, claim.1.clone(), product_id, aggregate_period(&env)), &aggregate);
+
+        publish_event(
+            &env,
+            (symbol_short!("clm_app"), claim_id),
+            (claim.1.clone(), claim.2),
+        );
+        publish_event(
+            &env,
+            (Symbol::new(&env, "settlement_pending"), claim_id),
+            (window_secs, window_end),
+        );
+
+        record_decision(&env, &processor, claim_id, claim.4, true);
+        update_claimant_profile(&env, &claim.1, |profile| profile.approved += 1);
+        enqueue_settlement(&env, claim_id, claim.2)?;
+
+        Ok(())
+    }
+
+    pub fn start_review(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
+        // Verify identity and require claim processing permission
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        require_not_beneficiary(&env, &processor, claim_id, &claim.1)?;
+        require_processor_stake(&env, &processor)?;
+
+        // I3: Can only start review for submitted claims - validate state transition
+        require_transition(&env, claim.3, ClaimStatus::UnderReview)?;
+
+        // I3: Transition to UnderReview state
+        claim.3 = ClaimStatus::UnderReview;
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM, claim_id), &claim);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_under_review"), claim_id),
+            (claim.1, claim.2),
+        );
+
+        Ok(())
+    }
+
+    pub fn reject_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
+        // Verify identity and require claim processing permission
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        require_not_beneficiary(&env, &processor, claim_id, &claim.1)?;
+        require_processor_stake(&env, &processor)?;
+
+        // I3: Can only reject claims that are UnderReview - validate state transition
+        require_transition(&env, claim.3, ClaimStatus::Rejected)?;
+
+        // I3: Transition to Rejected state
+        claim.3 = ClaimStatus::Rejected;
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM, claim_id), &claim);
+
+        let mut stats = get_stats(&env);
+        stats.open_claims = stats.open_claims.saturating_sub(1);
+        set_stats(&env, &stats);
+
+        record_decision(&env, &processor, claim_id, claim.4, false);
+        update_claimant_profile(&env, &claim.1, |profile| profile.rejected += 1);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_rejected"), claim_id),
+            (claim.1, claim.2),
+        );
+
+        Ok(())
+    }
+
+    /// Settle a specific approved claim out of queue order. Normal
+    /// settlement goes through `process_settlements`. The processor who
+    /// approved the claim may not settle it.
+    pub fn settle_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
+        // Verify identity and require claim processing permission
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        require_not_beneficiary(&env, &processor, claim_id, &claim.1)?;
+        require_processor_stake(&env, &processor)?;
+        let approver: Option<Address> = env.storage().persistent().get(&(DECIDED_BY, claim_id));
+        if approver.as_ref() == Some(&processor) {
+            return Err(ContractError::ApproverCannotSettle);
+        }
+
+        dequeue_settlement(&env, claim_id);
+        settle(&env, claim_id)
+    }
+
+    /// Make a product fully parametric, or clear its trigger (governance
+    /// only). Its holders can then claim and be paid in one call.
+    pub fn set_parametric_trigger(
+        env: Env,
+        caller: Address,
+        product_id: u32,
+        trigger: Option<ParametricTrigger>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &trigger {
+            Some(trigger) => {
+                if trigger.payout_bps == 0 || trigger.payout_bps > 10_000 || trigger.max_data_age_secs == 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(PARAMETRIC_TRIGGER, product_id), trigger);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&(PARAMETRIC_TRIGGER, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "parametric_trigger_set"), product_id),
+            trigger,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_parametric_trigger(env: Env, product_id: u32) -> Option<ParametricTrigger> {
+        parametric_trigger(&env, product_id)
+    }
+
+    /// Set the tiers of a node downtime product, ordered by ascending hours
+    /// and payout, or clear them with an empty list (governance only).
+    /// Policies are bound to a node at issuance; cap the coverage per node
+    /// with the pool's node capacity.
+    pub fn set_downtime_tiers(env: Env, caller: Address, product_id: u32, tiers: Vec<DowntimeTier>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if tiers.len() > MAX_DOWNTIME_TIERS {
+            return Err(ContractError::InvalidInput);
+        }
+        let mut previous: Option<DowntimeTier> = None;
+        for tier in tiers.iter() {
+            if tier.min_hours == 0 || tier.payout_bps == 0 || tier.payout_bps > MAX_BPS {
+                return Err(ContractError::InvalidInput);
+            }
+            if let Some(previous) = &previous {
+                if tier.min_hours <= previous.min_hours || tier.payout_bps <= previous.payout_bps {
+                    return Err(ContractError::InvalidInput);
+                }
+            }
+            previous = Some(tier);
+        }
+
+        if tiers.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&(DOWNTIME_TIERS, product_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&(DOWNTIME_TIERS, product_id), &tiers);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "downtime_tiers_set"), product_id),
+            tiers,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_downtime_tiers(env: Env, product_id: u32) -> Vec<DowntimeTier> {
+        downtime_tiers(&env, product_id)
+    }
+
+    /// Set or clear the attestor reporting node downtime (governance only)
+    pub fn set_uptime_attestor(env: Env, caller: Address, attestor: Option<Address>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &attestor {
+            Some(attestor) => env.storage().instance().set(&UPTIME_ATTESTOR, attestor),
+            None => env.storage().instance().remove(&UPTIME_ATTESTOR),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "uptime_attestor_set"), caller),
+            attestor,
+        );
+
+        Ok(())
+    }
+
+    /// Attest a node's downtime over a finished UTC day (`timestamp /
+    /// 86400`). A node's days are reported in order without gaps, reporting
+    /// zero for days without downtime, and cannot be revised.
+    pub fn report_node_downtime(env: Env, attestor: Address, node_id: BytesN<32>, day: u64, downtime_secs: u64) -> Result<(), ContractError> {
+        attestor.require_auth();
+
+        let expected: Address = env
+            .storage()
+            .instance()
+            .get(&UPTIME_ATTESTOR)
+            .ok_or(ContractError::NotInitialized)?;
+        if attestor != expected {
+            return Err(ContractError::Unauthorized);
+        }
+        if downtime_secs > DOWNTIME_DAY_SECONDS || day >= env.ledger().timestamp() / DOWNTIME_DAY_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record = match node_downtime(&env, &node_id) {
+            Some(record) if day != record.last_day + 1 => return Err(ContractError::InvalidInput),
+            Some(record) => NodeDowntime {
+                first_day: record.first_day,
+                last_day: day,
+                total_secs: record.total_secs + downtime_secs,
+            },
+            None => NodeDowntime {
+                first_day: day,
+                last_day: day,
+                total_secs: downtime_secs,
+            },
+        };
+
+        env.storage()
+            .persistent()
+            .set(&(NODE_DAY, node_id.clone(), day), &record.total_secs);
+        env.storage()
+            .persistent()
+            .set(&(NODE_DOWNTIME, node_id.clone()), &record);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "node_downtime_reported"), node_id),
+            (day, downtime_secs, record.total_secs),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_node_downtime(env: Env, node_id: BytesN<32>) -> Option<NodeDowntime> {
+        node_downtime(&env, &node_id)
+    }
+
+    /// Make a product a key-loss product, or clear its cover (governance
+    /// only)
+    pub fn set_key_loss_cover(env: Env, caller: Address, product_id: u32, cover: Option<KeyLossCover>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &cover {
+            Some(cover) => {
+                if cover.payout_bps == 0 || cover.payout_bps > MAX_BPS || cover.delay_secs == 0 || cover.challenge_window_secs == 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(KEY_LOSS_COVER, product_id), cover);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&(KEY_LOSS_COVER, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "key_loss_cover_set"), product_id),
+            cover,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_key_loss_cover(env: Env, product_id: u32) -> Option<KeyLossCover> {
+        key_loss_cover(&env, product_id)
+    }
+
+    /// Register the guardians who can attest a lost key and the address to
+    /// pay on recovery (holder only). Re-registering restarts the delay and
+    /// is refused while a key-loss claim is open.
+    pub fn register_recovery(
+        env: Env,
+        holder: Address,
+        policy_id: u64,
+        guardians: Vec<Address>,
+        threshold: u32,
+        recovery_address: Address,
+    ) -> Result<(), ContractError> {
+        holder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy = policy_contract::Client::new(&env, &config.0).get_policy_terms(&policy_id);
+        if policy.0 != holder {
+            return Err(ContractError::Unauthorized);
+        }
+        if key_loss_claim(&env, policy_id).is_some() {
+            return Err(ContractError::InvalidState);
+        }
+
+        if guardians.is_empty() || guardians.len() > MAX_GUARDIANS || threshold == 0 || threshold > guardians.len() || recovery_address == holder {
+            return Err(ContractError::InvalidInput);
+        }
+        for (i, guardian) in guardians.iter().enumerate() {
+            if guardian == holder || guardians.first_index_of(&guardian) != Some(i as u32) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        let setup = RecoverySetup {
+            guardians,
+            threshold,
+            recovery_address,
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&(RECOVERY_SETUP, policy_id), &setup);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "recovery_registered"), policy_id),
+            (setup.threshold, setup.guardians.len(), setup.recovery_address),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_recovery(env: Env, policy_id: u64) -> Option<RecoverySetup> {
+        env.storage()
+            .persistent()
+            .get(&(RECOVERY_SETUP, policy_id))
+    }
+
+    /// Attest that a policy's holder lost access to their key (registered
+    /// guardians only). The first attestation opens the claim, which must
+    /// happen during the term. Returns the number of attestations.
+    pub fn attest_key_loss(env: Env, guardian: Address, policy_id: u64) -> Result<u32, ContractError> {
+        guardian.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy_client = policy_contract::Client::new(&env, &config.0);
+        let setup: RecoverySetup = env
+            .storage()
+            .persistent()
+            .get(&(RECOVERY_SETUP, policy_id))
+            .ok_or(ContractError::NotFound)?;
+        if !setup.guardians.contains(&guardian) {
+            return Err(ContractError::Unauthorized);
+        }
+        let cover = key_loss_cover(&env, policy_client.get_policy_product(&policy_id)).ok_or(ContractError::NotFound)?;
+        let policy = claimable_policy(&env, &policy_client, policy_id)?;
+
+        let now = env.ledger().timestamp();
+        let mut claim = match key_loss_claim(&env, policy_id) {
+            Some(claim) => claim,
+            None => {
+                if now > policy.4 {
+                    return Err(ContractError::PolicyExpired);
+                }
+                // A setup made after the key was lost, or by whoever took
+                // it, cannot be used at once
+                if now < setup.registered_at.saturating_add(cover.delay_secs) {
+                    return Err(ContractError::InvalidState);
+                }
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "key_loss_opened"), policy_id),
+                    guardian.clone(),
+                );
+                KeyLossClaim {
+                    opened_at: now,
+                    approvals: Vec::new(&env),
+                    attested_at: None,
+                }
+            }
+        };
+        if claim.approvals.contains(&guardian) {
+            return Err(ContractError::AlreadyExists);
+        }
+        claim.approvals.push_back(guardian);
+        if claim.attested_at.is_none() && claim.approvals.len() >= setup.threshold {
+            claim.attested_at = Some(now);
+            publish_event(
+                &env,
+                (Symbol::new(&env, "key_loss_attested"), policy_id),
+                claim.approvals.len(),
+            );
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(KEY_LOSS_CLAIM, policy_id), &claim);
+        Ok(claim.approvals.len())
+    }
+
+    /// Refute an open key-loss claim: the holder's own signature proves the
+    /// key is not lost. Governance may also refute it. Closed once the
+    /// challenge window after attestation has passed.
+    pub fn challenge_key_loss(env: Env, challenger: Address, policy_id: u64) -> Result<(), ContractError> {
+        challenger.require_auth();
+
+        let claim = key_loss_claim(&env, policy_id).ok_or(ContractError::NotFound)?;
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy_client = policy_contract::Client::new(&env, &config.0);
+        if challenger != policy_client.get_policy_terms(&policy_id).0 {
+            require_governance_permission(&env, &challenger)?;
+        }
+
+        if let (Some(attested_at), Some(cover)) = (claim.attested_at, key_loss_cover(&env, policy_client.get_policy_product(&policy_id))) {
+            if env.ledger().timestamp() > attested_at.saturating_add(cover.challenge_window_secs) {
+                return Err(ContractError::DisputeWindowClosed);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&(KEY_LOSS_CLAIM, policy_id));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "key_loss_challenged"), policy_id),
+            challenger,
+        );
+
+        Ok(())
+    }
+
+    /// Pay an attested key-loss claim to the registered recovery address
+    /// once the delay and challenge window have passed. Anyone may call it.
+    pub fn settle_key_loss(env: Env, policy_id: u64) -> Result<u64, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        wiring::require_wired(&env)?;
+        enter_settlement(&env)?;
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy_client = policy_contract::Client::new(&env, &config.0);
+        let policy = claimable_policy(&env, &policy_client, policy_id)?;
+        let product_id = policy_client.get_policy_product(&policy_id);
+        let cover = key_loss_cover(&env, product_id).ok_or(ContractError::NotFound)?;
+        let setup: RecoverySetup = env
+            .storage()
+            .persistent()
+            .get(&(RECOVERY_SETUP, policy_id))
+            .ok_or(ContractError::NotFound)?;
+        let claim = key_loss_claim(&env, policy_id).ok_or(ContractError::NotFound)?;
+        let attested_at = claim.attested_at.ok_or(ContractError::InvalidState)?;
+
+        let now = env.ledger().timestamp();
+        if now < claim.opened_at.saturating_add(cover.delay_secs) || now <= attested_at.saturating_add(cover.challenge_window_secs) {
+            return Err(ContractError::PayoutPending);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&(KEY_LOSS_CLAIM, policy_id));
+        let claim_id = open_and_settle(
+            &env,
+            &config,
+            TriggeredClaim {
+                policy_id,
+                claimant: policy.0,
+                coverage: policy.1,
+                product_id,
+                payout_bps: cover.payout_bps,
+                data_id: None,
+                payee: Some(setup.recovery_address),
+            },
+        )?;
+        exit_settlement(&env);
+
+        Ok(claim_id)
+    }
+
+    pub fn get_key_loss_claim(env: Env, policy_id: u64) -> Option<KeyLossClaim> {
+        key_loss_claim(&env, policy_id)
+    }
+
+    /// Make a product a bridge-failure product, or clear its cover
+    /// (governance only). The bridged asset must be allowlisted.
+    pub fn set_bridge_cover(env: Env, caller: Address, product_id: u32, cover: Option<BridgeCover>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &cover {
+            Some(cover) => {
+                assets::require_allowed(&env, &cover.asset)?;
+                env.storage()
+                    .persistent()
+                    .set(&(BRIDGE_COVER, product_id), cover);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&(BRIDGE_COVER, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_cover_set"), product_id),
+            cover,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_bridge_cover(env: Env, product_id: u32) -> Option<BridgeCover> {
+        bridge_cover(&env, product_id)
+    }
+
+    /// Appoint or rotate the bridge attestor committee (governance only).
+    /// Unconfirmed declarations must be attested again by the new
+    /// committee. Returns the new epoch.
+    pub fn set_bridge_committee(env: Env, caller: Address, members: Vec<Address>, threshold: u32) -> Result<u32, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if members.is_empty() || members.len() > MAX_COMMITTEE_SIZE || threshold == 0 || threshold > members.len() {
+            return Err(ContractError::InvalidInput);
+        }
+        for (i, member) in members.iter().enumerate() {
+            if members.first_index_of(&member) != Some(i as u32) {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        let epoch = env
+            .storage()
+            .instance()
+            .get::<_, BridgeCommittee>(&BRIDGE_COMMITTEE)
+            .map_or(1, |committee| committee.epoch + 1);
+        env.storage().instance().set(
+            &BRIDGE_COMMITTEE,
+            &BridgeCommittee {
+                members: members.clone(),
+                threshold,
+                epoch,
+            },
+        );
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_committee_set"), epoch),
+            (members, threshold),
+        );
+
+        Ok(epoch)
+    }
+
+    pub fn get_bridge_committee(env: Env) -> Option<BridgeCommittee> {
+        env.storage().instance().get(&BRIDGE_COMMITTEE)
+    }
+
+    /// Declare a failure of a bridge, signed by a committee member as its
+    /// first attestation. Returns the declaration's id.
+    pub fn declare_bridge_event(
+        env: Env,
+        attestor: Address,
+        bridge_id: u32,
+        failure: BridgeFailure,
+        occurred_at: u64,
+        loss_bps: u32,
+    ) -> Result<u64, ContractError> {
+        attestor.require_auth();
+
+        let committee = bridge_attestor(&env, &attestor)?;
+        if loss_bps == 0 || loss_bps > MAX_BPS || occurred_at > env.ledger().timestamp() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let event_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&BRIDGE_EVENT_SEQ)
+            .unwrap_or(0)
+            + 1;
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(attestor.clone());
+        env.storage().persistent().set(&BRIDGE_EVENT_SEQ, &event_id);
+        env.storage().persistent().set(
+            &(BRIDGE_EVENT, event_id),
+            &BridgeEvent {
+                bridge_id,
+                failure,
+                occurred_at,
+                loss_bps,
+                epoch: committee.epoch,
+                approvals,
+                confirmed_at: None,
+            },
+        );
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_event_declared"), event_id),
+            (bridge_id, failure, occurred_at, loss_bps, attestor),
+        );
+
+        Ok(event_id)
+    }
+
+    /// Sign a pending declaration (committee members only). Returns the
+    /// number of attestations.
+    pub fn attest_bridge_event(env: Env, attestor: Address, event_id: u64) -> Result<u32, ContractError> {
+        attestor.require_auth();
+
+        let committee = bridge_attestor(&env, &attestor)?;
+        let mut event = bridge_event(&env, event_id)?;
+        if event.confirmed_at.is_some() {
+            return Err(ContractError::InvalidState);
+        }
+        // Attestations of a rotated-out committee are dropped
+        if event.epoch != committee.epoch {
+            event.epoch = committee.epoch;
+            event.approvals = Vec::new(&env);
+        }
+        if event.approvals.contains(&attestor) {
+            return Err(ContractError::AlreadyExists);
+        }
+        event.approvals.push_back(attestor.clone());
+
+        env.storage()
+            .persistent()
+            .set(&(BRIDGE_EVENT, event_id), &event);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_event_attested"), event_id),
+            (attestor, event.approvals.len()),
+        );
+
+        Ok(event.approvals.len())
+    }
+
+    /// Confirm a declaration the current committee attested to threshold
+    /// (governance only), opening claims on it
+    pub fn confirm_bridge_event(env: Env, caller: Address, event_id: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let committee: BridgeCommittee = env
+            .storage()
+            .instance()
+            .get(&BRIDGE_COMMITTEE)
+            .ok_or(ContractError::NotInitialized)?;
+        let mut event = bridge_event(&env, event_id)?;
+        if event.confirmed_at.is_some() || event.epoch != committee.epoch || event.approvals.len() < committee.threshold {
+            return Err(ContractError::InvalidState);
+        }
+
+        event.confirmed_at = Some(env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .set(&(BRIDGE_EVENT, event_id), &event);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_event_confirmed"), event_id),
+            (event.bridge_id, event.loss_bps, caller),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_bridge_event(env: Env, event_id: u64) -> Option<BridgeEvent> {
+        env.storage()
+            .persistent()
+            .get(&(BRIDGE_EVENT, event_id))
+    }
+
+    /// Claim on a confirmed bridge failure that occurred during the policy's
+    /// term (holder only). The holder's current balance of the bridged
+    /// asset, up to the coverage, is paid out at the declared `loss_bps`.
+    pub fn claim_bridge_event(env: Env, claimant: Address, policy_id: u64, event_id: u64) -> Result<u64, ContractError> {
+        claimant.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        wiring::require_wired(&env)?;
+        enter_settlement(&env)?;
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy_client = policy_contract::Client::new(&env, &config.0);
+        let policy = claimable_policy(&env, &policy_client, policy_id)?;
+        if policy.0 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        let product_id = policy_client.get_policy_product(&policy_id);
+        let cover = bridge_cover(&env, product_id).ok_or(ContractError::NotFound)?;
+        let event = bridge_event(&env, event_id)?;
+        if event.confirmed_at.is_none() || event.bridge_id != cover.bridge_id || event.occurred_at < policy.3 || event.occurred_at > policy.4 {
+            return Err(ContractError::TriggerNotMet);
+        }
+
+        let balance = token::Client::new(&env, &cover.asset).balance(&claimant);
+        let holding = units::to_canonical(balance, assets::decimals(&env, &cover.asset)).map_err(|_| ContractError::Overflow)?;
+        let claim_id = open_and_settle(
+            &env,
+            &config,
+            TriggeredClaim {
+                policy_id,
+                claimant,
+                coverage: holding.min(policy.1),
+                product_id,
+                payout_bps: event.loss_bps,
+                data_id: None,
+                payee: None,
+            },
+        )?;
+        exit_settlement(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "bridge_claim"), claim_id),
+            (event_id, policy_id),
+        );
+
+        Ok(claim_id)
+    }
+
+    /// Make a product a stablecoin depeg product, or clear its cover
+    /// (governance only). A depeg cover takes precedence over a parametric
+    /// trigger of the same product. Pair it with the pool's per-asset
+    /// capacity to cap the coverage written on the coin.
+    pub fn set_depeg_cover(env: Env, caller: Address, product_id: u32, cover: Option<DepegCover>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &cover {
+            Some(cover) => {
+                if cover.strike <= 0
+                    || cover.twap_window_secs == 0
+                    || cover.min_duration_secs < cover.twap_window_secs
+                    || cover.max_gap_secs == 0
+                    || cover.payout_bps == 0
+                    || cover.payout_bps > 10_000
+                {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(DEPEG_COVER, product_id), cover);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&(DEPEG_COVER, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "depeg_cover_set"), product_id),
+            cover,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_depeg_cover(env: Env, product_id: u32) -> Option<DepegCover> {
+        depeg_cover(&env, product_id)
+    }
+
+    /// Record the current price of a depeg product's stablecoin. Anyone may
+    /// call it; keepers should do so at least every `max_gap_secs`.
+    pub fn observe_peg(env: Env, product_id: u32) -> Result<PegWatch, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        let cover = depeg_cover(&env, product_id).ok_or(ContractError::NotFound)?;
+        observe_peg(&env, product_id, &cover)
+    }
+
+    pub fn get_peg_watch(env: Env, product_id: u32) -> Option<PegWatch> {
+        peg_watch(&env, product_id)
+    }
+
+    /// Denominate a product's coverage and claims in a reference currency,
+    /// settled in the pool asset at the oracle rate, or clear it (governance
+    /// only). Applies to claims approved from now on.
+    pub fn set_reference_pricing(
+        env: Env,
+        caller: Address,
+        product_id: u32,
+        pricing: Option<ReferencePricing>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &pricing {
+            Some(pricing) => {
+                if pricing.max_age_secs == 0 || pricing.max_deviation_bps > MAX_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(REFERENCE_PRICING, product_id), pricing);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&(REFERENCE_PRICING, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "reference_pricing_set"), product_id),
+            pricing,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_reference_pricing(env: Env, product_id: u32) -> Option<ReferencePricing> {
+        reference_pricing(&env, product_id)
+    }
+
+    /// Rate a reference priced claim was reserved at, if any
+    pub fn get_claim_rate(env: Env, claim_id: u64) -> Option<i128> {
+        env.storage().persistent().get(&(CLAIM_RATE, claim_id))
+    }
+
+    /// Claim on a parametric policy and pay it in the same call. The
+    /// product's oracle trigger replaces review: once it has fired during
+    /// the policy's term, the payout is reserved and settled at once, or
+    /// scheduled if it falls under the payout delay. Returns the claim id.
+    pub fn claim_and_settle(env: Env, claimant: Address, policy_id: u64) -> Result<u64, ContractError> {
+        claimant.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        wiring::require_wired(&env)?;
+        enter_settlement(&env)?;
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let policy_client = policy_contract::Client::new(&env, &config.0);
+        let policy = claimable_policy(&env, &policy_client, policy_id)?;
+        if policy.0 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let product_id = policy_client.get_policy_product(&policy_id);
+        let tiers = downtime_tiers(&env, product_id);
+        let (data_id, payout_bps) = match depeg_cover(&env, product_id) {
+            Some(cover) => {
+                check_depeg(&env, product_id, policy.3, policy.4)?;
+                (Some(cover.feed_data_id), cover.payout_bps)
+            }
+            None if !tiers.is_empty() => {
+                let node_id = policy_client.get_policy_node(&policy_id).ok_or(ContractError::NotFound)?;
+                (None, check_downtime(&env, &tiers, &node_id, policy.3, policy.4)?)
+            }
+            None => {
+                let trigger = parametric_trigger(&env, product_id).ok_or(ContractError::NotFound)?;
+                check_trigger(&env, &trigger, policy.3, policy.4)?;
+                (Some(trigger.data_id), trigger.payout_bps)
+            }
+        };
+
+        let claim_id = open_and_settle(
+            &env,
+            &config,
+            TriggeredClaim {
+                policy_id,
+                claimant,
+                coverage: policy.1,
+                product_id,
+                payout_bps,
+                data_id,
+                payee: None,
+            },
+        )?;
+        exit_settlement(&env);
+
+        Ok(claim_id)
+    }
+
+    /// Set the base dispute window applied to claims approved from now on
+    /// (governance only). It may not exceed the window of any tier.
+    pub fn set_dispute_window(env: Env, caller: Address, dispute_window_secs: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut config = dispute_config(&env);
+        config.dispute_window_secs = dispute_window_secs;
+        validate_dispute_config(&env, &config)?;
+        env.storage().instance().set(&DISPUTE_CONFIG, &config);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "dispute_window_set"), caller),
+            dispute_window_secs,
+        );
+
+        Ok(())
+    }
+
+    /// Replace the dispute window tiers for larger claims (governance only).
+    /// Tiers must be ordered by ascending `min_amount` with windows that
+    /// never shrink; an empty list applies the base window to every claim.
+    pub fn set_dispute_tiers(env: Env, caller: Address, tiers: Vec<DisputeTier>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut config = dispute_config(&env);
+        config.tiers = tiers;
+        validate_dispute_config(&env, &config)?;
+        env.storage().instance().set(&DISPUTE_CONFIG, &config);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "dispute_tiers_set"), caller),
+            config.tiers.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Dispute window a claim of `amount` would get if approved now
+    pub fn get_dispute_window_for(env: Env, amount: i128) -> u64 {
+        dispute_window_for(&dispute_config(&env), amount)
+    }
+
+    /// Dispute window selected for a claim when it was approved
+    pub fn get_claim_dispute_window(env: Env, claim_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(DISPUTE_WINDOW_SECS, claim_id))
+    }
+
+    pub fn get_dispute_config(env: Env) -> DisputeConfig {
+        dispute_config(&env)
+    }
+
+    /// Claims configuration, peers, pause state and version in one call
+    pub fn get_protocol_config(env: Env) -> Result<ProtocolConfig, ContractError> {
+        let (policy_contract, risk_pool): (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        Ok(ProtocolConfig {
+            policy_contract,
+            risk_pool,
+            oracle: env
+                .storage()
+                .persistent()
+                .get(&ORACLE_CONFIG)
+                .map_or(OracleSetting::Disabled, OracleSetting::Enabled),
+            dispute: dispute_config(&env),
+            dispute_bond: env
+                .storage()
+                .instance()
+                .get(&BOND_CONFIG)
+                .map_or(DisputeBondSetting::Disabled, DisputeBondSetting::Enabled),
+            arbitration: arbitration_config(&env),
+            appeal: env
+                .storage()
+                .instance()
+                .get(&APPEAL_CONFIG)
+                .map_or(AppealSetting::Disabled, AppealSetting::Enabled),
+            payout_delay: payout_delay::get_config(&env)
+                .map_or(PayoutDelaySetting::Disabled, PayoutDelaySetting::Enabled),
+            wired: wiring::is_wired(&env),
+            paused: is_paused(&env),
+            version: version::get(&env, env!("CARGO_PKG_VERSION")),
+        })
+    }
+
+    /// End of a claim's dispute window, if it was approved with one
+    pub fn get_dispute_window_end(env: Env, claim_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(DISPUTE_WINDOW_END, claim_id))
+    }
+
+    /// Dispute a claim pending settlement while its dispute window is open
+    /// (governance participants only). The claim leaves the settlement
+    /// queue until the dispute is resolved.
+    pub fn raise_dispute(env: Env, raised_by: Address, claim_id: u64, reason: String) -> Result<(), ContractError> {
+        raised_by.require_auth();
+        require_governance_permission(&env, &raised_by)?;
+
+        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        if claim.3 != ClaimStatus::PendingSettlement
+            || !is_valid_state_transition(claim.3, ClaimStatus::Disputed)
+        {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if !in_dispute_window(&env, claim_id) {
+            return Err(ContractError::DisputeWindowClosed);
+        }
+
+        // Escrow the bond under the terms in force now
+        if let Some(bond) = env.storage().instance().get::<_, DisputeBondConfig>(&BOND_CONFIG) {
+            token::Client::new(&env, &bond.token).transfer(&raised_by, env.current_contract_address(), &bond.amount);
+            env.storage()
+                .persistent()
+                .set(&(DISPUTE_BOND, claim_id), &bond);
+        }
+
+        claim.3 = ClaimStatus::Disputed;
+        env.storage()
+            .persistent()
+            .set(&(CLAIM, claim_id), &claim);
+        dequeue_settlement(&env, claim_id);
+        update_claimant_profile(&env, &claim.1, |profile| profile.disputed += 1);
+
+        let dispute = Dispute {
+            claim_id,
+            raised_by: raised_by.clone(),
+            reason,
+            raised_at: env.ledger().timestamp(),
+            resolved: false,
+            upheld: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&(DISPUTE, claim_id), &dispute);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "dispute_raised"), claim_id),
+            raised_by,
+        );
+
+        Ok(())
+    }
+
+    /// Resolve a disputed claim (governance only). Disputes assigned to an
+    /// arbitration panel are resolved by the panel instead, and disputes
+    /// over claims above the appeal threshold are escalated to a governance
+    /// proposal with `upheld` as the recommendation.
+    pub fn resolve_dispute(env: Env, caller: Address, claim_id: u64, upheld: bool) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if appeal_pending(&env, claim_id) {
+            return Err(ContractError::AppealPending);
+        }
+        if env.storage().persistent().has(&(ARB_PANEL, claim_id)) {
+            return Err(ContractError::ArbitrationPending);
+        }
+
+        if escalate_dispute(&env, claim_id, upheld)? {
+            return Ok(());
+        }
+        apply_dispute_outcome(&env, claim_id, upheld, &caller)
+    }
+
+    /// Escalate disputes over large claims to governance, or `None` to let
+    /// every dispute be resolved directly (admin only). The governance
+    /// contract becomes a trusted contract; it must in turn trust this one
+    /// through `register_appeal_source`.
+    pub fn set_appeal_config(env: Env, admin: Address, config: Option<AppealConfig>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match config {
+            Some(config) => {
+                validate_amount(&env, config.min_amount)?;
+                if config.threshold_percentage == 0 || config.threshold_percentage > 100 {
+                    return Err(ContractError::InvalidInput);
+                }
+                register_trusted_contract(&env, &admin, &config.governance)?;
+                env.storage().instance().set(&APPEAL_CONFIG, &config);
+
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "appeal_config_set"), config.governance.clone()),
+                    (config.min_amount, config.threshold_percentage),
+                );
+            }
+            None => {
+                env.storage().instance().remove(&APPEAL_CONFIG);
+
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "appeal_config_cleared"), ()),
+                    admin,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_appeal_config(env: Env) -> Option<AppealConfig> {
+        env.storage().instance().get(&APPEAL_CONFIG)
+    }
+
+    /// Apply the outcome of a finalized appeal proposal. Only the governance
+    /// contract that opened the appeal may call this.
+    pub fn resolve_appeal(env: Env, caller_contract: Address, claim_id: u64, upheld: bool) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        let mut appeal: Appeal = env
+            .storage()
+            .persistent()
+            .get(&(APPEAL, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if appeal.governance != caller_contract {
+            return Err(ContractError::Unauthorized);
+        }
+        if appeal.resolved {
+            return Err(ContractError::InvalidState);
+        }
+
+        appeal.resolved = true;
+        env.storage()
+            .persistent()
+            .set(&(APPEAL, claim_id), &appeal);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "appeal_resolved"), claim_id),
+            (appeal.proposal_id, upheld),
+        );
+
+        apply_dispute_outcome(&env, claim_id, upheld, &caller_contract)
+    }
+
+    pub fn get_appeal(env: Env, claim_id: u64) -> Option<Appeal> {
+        env.storage()
+            .persistent()
+            .get(&(APPEAL, claim_id))
+    }
+
+    /// Set the arbitration panel size and per-arbitrator case limit
+    /// (governance only). Panels must be odd-sized so a majority exists.
+    pub fn set_arbitration_config(env: Env, caller: Address, config: ArbitrationConfig) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if config.panel_size == 0
+            || config.panel_size > MAX_PANEL_SIZE
+            || config.panel_size.is_multiple_of(2)
+            || config.max_open_cases == 0
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().instance().set(&ARB_CONFIG, &config);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "arbitration_config_set"), caller),
+            (config.panel_size, config.max_open_cases),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_arbitration_config(env: Env) -> ArbitrationConfig {
+        arbitration_config(&env)
+    }
+
+    /// Assign an arbitration panel to a disputed claim (governance only).
+    /// Arbitrators must hold the Arbitrator role, be under their case limit
+    /// and be neither the claimant nor the processor who approved the claim.
+    pub fn form_panel(env: Env, caller: Address, claim_id: u64, arbitrators: Vec<Address>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Disputed {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if env.storage().persistent().has(&(ARB_PANEL, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+        if appeal_pending(&env, claim_id) {
+            return Err(ContractError::AppealPending);
+        }
+
+        let config = arbitration_config(&env);
+        if arbitrators.len() != config.panel_size {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let processor: Option<Address> = env.storage().persistent().get(&(DECIDED_BY, claim_id));
+        for (i, arbitrator) in arbitrators.iter().enumerate() {
+            require_arbitration(&env, &arbitrator)?;
+            if arbitrators.first_index_of(&arbitrator) != Some(i as u32) {
+                return Err(ContractError::InvalidInput);
+            }
+            if arbitrator == claim.1 || processor.as_ref() == Some(&arbitrator) {
+                return Err(ContractError::ConflictOfInterest);
+            }
+            if open_cases(&env, &arbitrator) >= config.max_open_cases {
+                return Err(ContractError::CaseLimitReached);
+            }
+        }
+        for arbitrator in arbitrators.iter() {
+            set_open_cases(&env, &arbitrator, open_cases(&env, &arbitrator) + 1);
+        }
+
+        let panel = ArbitrationPanel {
+            claim_id,
+            arbitrators: arbitrators.clone(),
+            uphold_votes: 0,
+            dismiss_votes: 0,
+            decided: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&(ARB_PANEL, claim_id), &panel);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "panel_formed"), claim_id),
+            arbitrators,
+        );
+
+        Ok(())
+    }
+
+    /// Record an arbitrator's decision on a disputed claim. Once a majority
+    /// of the panel agrees, the dispute is resolved with that outcome, or
+    /// escalated to governance if the claim is above the appeal threshold.
+    pub fn record_arbitration_decision(env: Env, arbitrator: Address, claim_id: u64, upheld: bool) -> Result<(), ContractError> {
+        arbitrator.require_auth();
+        require_arbitration(&env, &arbitrator)?;
+
+        let mut panel: ArbitrationPanel = env
+            .storage()
+            .persistent()
+            .get(&(ARB_PANEL, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if panel.decided {
+            return Err(ContractError::InvalidState);
+        }
+        if !panel.arbitrators.contains(&arbitrator) {
+            return Err(ContractError::Unauthorized);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&(ARB_DECISION, claim_id, arbitrator.clone()))
+        {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(ARB_DECISION, claim_id, arbitrator.clone()), &upheld);
+        if upheld {
+            panel.uphold_votes += 1;
+        } else {
+            panel.dismiss_votes += 1;
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "arbitration_decision"), claim_id),
+            (arbitrator.clone(), upheld),
+        );
+
+        let majority = panel.arbitrators.len() / 2 + 1;
+        let outcome = if panel.uphold_votes >= majority {
+            Some(true)
+        } else if panel.dismiss_votes >= majority {
+            Some(false)
+        } else {
+            None
+        };
+
+        if let Some(outcome) = outcome {
+            panel.decided = true;
+            for member in panel.arbitrators.iter() {
+                set_open_cases(&env, &member, open_cases(&env, &member).saturating_sub(1));
+            }
+            env.storage()
+                .persistent()
+                .set(&(ARB_PANEL, claim_id), &panel);
+            if !escalate_dispute(&env, claim_id, outcome)? {
+                apply_dispute_outcome(&env, claim_id, outcome, &arbitrator)?;
+            }
+        } else {
+            env.storage()
+                .persistent()
+                .set(&(ARB_PANEL, claim_id), &panel);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_arbitration_panel(env: Env, claim_id: u64) -> Option<ArbitrationPanel> {
+        env.storage()
+            .persistent()
+            .get(&(ARB_PANEL, claim_id))
+    }
+
+    /// Decision an arbitrator recorded on a case, if any
+    pub fn get_arbitration_decision(env: Env, claim_id: u64, arbitrator: Address) -> Option<bool> {
+        env.storage()
+            .persistent()
+            .get(&(ARB_DECISION, claim_id, arbitrator))
+    }
+
+    /// Undecided cases an arbitrator currently sits on
+    pub fn get_arbitrator_cases(env: Env, arbitrator: Address) -> u32 {
+        open_cases(&env, &arbitrator)
+    }
+
+    /// Probe `token` through the SEP-41 interface and allowlist it as a
+    /// settlement asset, recording its decimals (governance only)
+    pub fn allow_asset(env: Env, caller: Address, token: Address) -> Result<AssetInfo, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let info = assets::allow(&env, &token)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_allowed"), token),
+            info.decimals,
+        );
+
+        Ok(info)
+    }
+
+    /// Remove `token` from the asset allowlist (governance only). Settings
+    /// already using it keep working until they are changed.
+    pub fn disallow_asset(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        assets::disallow(&env, &token);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_disallowed"), token),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_asset(env: Env, token: Address) -> Option<AssetInfo> {
+        assets::get_info(&env, &token)
+    }
+
+    /// Require a bond to raise disputes, or `None` to make them free (admin
+    /// only). The bond token must be allowlisted, and the treasury in the
+    /// terms becomes a trusted contract. Bonds already escrowed keep the
+    /// terms they were posted under.
+    pub fn set_dispute_bond(env: Env, admin: Address, bond: Option<DisputeBondConfig>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match bond {
+            Some(bond) => {
+                validate_amount(&env, bond.amount)?;
+                assets::require_allowed(&env, &bond.token)?;
+                if bond.reward_bps > MAX_BPS || bond.claimant_share_bps > MAX_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                register_trusted_contract(&env, &admin, &bond.treasury)?;
+                env.storage().instance().set(&BOND_CONFIG, &bond);
+
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "dispute_bond_set"), bond.token.clone()),
+                    (bond.amount, bond.reward_bps, bond.claimant_share_bps),
+                );
+            }
+            None => {
+                env.storage().instance().remove(&BOND_CONFIG);
+
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "dispute_bond_cleared"), ()),
+                    admin,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_dispute_bond_config(env: Env) -> Option<DisputeBondConfig> {
+        env.storage().instance().get(&BOND_CONFIG)
+    }
+
+    /// Bond escrowed for an unresolved dispute
+    pub fn get_dispute_bond(env: Env, claim_id: u64) -> Option<DisputeBondConfig> {
+        env.storage()
+            .persistent()
+            .get(&(DISPUTE_BOND, claim_id))
+    }
+
+    pub fn get_dispute(env: Env, claim_id: u64) -> Option<Dispute> {
+        env.storage()
+            .persistent()
+            .get(&(DISPUTE, claim_id))
+    }
+
+    /// Reverse a settled claim (governance only). The payout is taken out of
+    /// the claims and pool paid-out totals and the claimant is charged a
+    /// receivable for it. With `draw_from_credit`, the claimant's premium
+    /// credit on the policy contract is applied first. Returns the amount
+    /// added to the receivable.
+    ///
+    /// A payout assigned to a lender is still charged to the claimant in
+    /// full: the lender advanced that share to the claimant and keeps what
+    /// the settlement repaid it.
+    pub fn reverse_claim(
+        env: Env,
+        caller: Address,
+        claim_id: u64,
+        reason: String,
+        draw_from_credit: bool,
+    ) -> Result<i128, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if claim.3 != ClaimStatus::Settled {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if env.storage().persistent().has(&(REVERSAL, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+        // What the pool paid, which differs from the claim amount for
+        // reference priced claims
+        let paid = settled_amount(&env, claim_id, claim.2);
+
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        require_trusted_contract(&env, &config.1)?;
+        env.invoke_contract::<()>(
+            &config.1,
+            &Symbol::new(&env, "reverse_claim_payout"),
+            (env.current_contract_address(), claim_id, paid).into_val(&env),
+        );
+
+        let recovered: i128 = if draw_from_credit {
+            env.invoke_contract(
+                &config.0,
+                &Symbol::new(&env, "offset_premium_credit"),
+                (env.current_contract_address(), claim.1.clone(), paid).into_val(&env),
+            )
+        } else {
+            0
+        };
+        let owed = paid.checked_sub(recovered).ok_or(ContractError::Overflow)?;
+
+        let receivable: i128 = env
+            .storage()
+            .persistent()
+            .get(&(RECEIVABLE, claim.1.clone()))
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&(RECEIVABLE, claim.1.clone()), &receivable.checked_add(owed).ok_or(ContractError::Overflow)?);
+
+        let mut stats = get_stats(&env);
+        stats.total_paid_out = stats.total_paid_out.saturating_sub(paid).max(0);
+        set_stats(&env, &stats);
+
+        update_claimant_profile(&env, &claim.1, |profile| {
+            profile.reversed += 1;
+            profile.total_paid = profile.total_paid.saturating_sub(paid).max(0);
+        });
+
+        let reversal = ClaimReversal {
+            claim_id,
+            claimant: claim.1.clone(),
+            amount: paid,
+            reason,
+            reversed_by: caller.clone(),
+            reversed_at: env.ledger().timestamp(),
+            recovered,
+        };
+        env.storage()
+            .persistent()
+            .set(&(REVERSAL, claim_id), &reversal);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_reversed"), claim_id),
+            (caller, claim.1, paid, recovered),
+        );
+
+        Ok(owed)
+    }
+
+    pub fn get_reversal(env: Env, claim_id: u64) -> Option<ClaimReversal> {
         env.storage()
             .persistent()
-            .get(&ORACLE_CFG)
-            .ok_or(ContractError::NotFound)
+            .get(&(REVERSAL, claim_id))
     }
 
-    /// Validate claim using oracle data
-    /// This function checks oracle submissions and enforces consensus-based validation
-    pub fn validate_claim_with_oracle(
+    /// Claim history of an address, for underwriting and fraud review
+    pub fn get_claimant_profile(env: Env, claimant: Address) -> ClaimantProfile {
+        claimant_profile(&env, &claimant)
+    }
+
+    /// Amount a claimant owes from reversed claims
+    pub fn get_receivable(env: Env, claimant: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(RECEIVABLE, claimant))
+            .unwrap_or(0)
+    }
+
+    /// Set or clear how much of approved claims' payouts `lender` may hold
+    /// assigned at once (admin only). Lenders without a cap cannot take
+    /// assignments.
+    pub fn set_lender_cap(env: Env, admin: Address, lender: Address, cap: Option<i128>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match cap {
+            Some(cap) => {
+                validate_amount(&env, cap)?;
+                env.storage()
+                    .persistent()
+                    .set(&(LENDER_CAP, lender.clone()), &cap);
+            }
+            None => env.storage().persistent().remove(&(LENDER_CAP, lender.clone())),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "lender_cap_set"), lender),
+            cap,
+        );
+
+        Ok(())
+    }
+
+    /// A lender's cap and the amount currently assigned to it
+    pub fn get_lender_exposure(env: Env, lender: Address) -> (Option<i128>, i128) {
+        (
+            env.storage().persistent().get(&(LENDER_CAP, lender.clone())),
+            lender_assigned(&env, &lender),
+        )
+    }
+
+    /// Assign up to the full payout of an approved, not yet settled claim
+    /// to a lender. Both sides authorize: the claimant gives up the first
+    /// `amount` of the payout and the lender accepts it against its cap.
+    /// One assignment per claim.
+    pub fn assign_claim_receivable(
         env: Env,
+        claimant: Address,
         claim_id: u64,
-        oracle_data_id: u64,
-    ) -> Result<bool, ContractError> {
-        // Get oracle configuration
-        let oracle_config: OracleValidationConfig = env
+        lender: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        claimant.require_auth();
+        lender.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
             .persistent()
-            .get(&ORACLE_CFG)
+            .get(&(CLAIM, claim_id))
             .ok_or(ContractError::NotFound)?;
+        if claim.1 != claimant {
+            return Err(ContractError::Unauthorized);
+        }
+        if claim.3 != ClaimStatus::PendingSettlement {
+            return Err(ContractError::InvalidClaimState);
+        }
+        if lender == claimant || amount <= 0 || amount > claim.2 {
+            return Err(ContractError::InvalidInput);
+        }
+        if env.storage().persistent().has(&(ASSIGNMENT, claim_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
 
-        if !oracle_config.require_oracle_validation {
-            return Ok(true);
+        let cap: i128 = env
+            .storage()
+            .persistent()
+            .get(&(LENDER_CAP, lender.clone()))
+            .ok_or(ContractError::AssignmentCapExceeded)?;
+        let assigned = lender_assigned(&env, &lender)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if assigned > cap {
+            return Err(ContractError::AssignmentCapExceeded);
         }
 
-        // Verify oracle contract is trusted before making cross-contract calls
-        require_trusted_contract(&env, &oracle_config.oracle_contract)?;
+        env.storage()
+            .persistent()
+            .set(&(ASSIGNMENT, claim_id), &ClaimAssignment {
+                lender: lender.clone(),
+                amount,
+                assigned_at: env.ledger().timestamp(),
+            });
+        env.storage()
+            .persistent()
+            .set(&(LENDER_ASSIGNED, lender.clone()), &assigned);
 
-        // Get oracle submission count using invoke_contract
-        let submission_count: u32 = env.invoke_contract(
-            &oracle_config.oracle_contract,
-            &Symbol::new(&env, "get_submission_count"),
-            (oracle_data_id,).into_val(&env),
+        publish_event(
+            &env,
+            (Symbol::new(&env, "receivable_assigned"), claim_id),
+            (claimant, lender, amount),
         );
 
-        // Check minimum submissions
-        if submission_count < oracle_config.min_oracle_submissions {
-            return Err(ContractError::InsufficientOracleSubmissions);
-        }
+        Ok(())
+    }
 
-        // Attempt to resolve oracle data - this will validate consensus and staleness
-        let _oracle_data: (i128, u32, u32, u64) = env.invoke_contract(
-            &oracle_config.oracle_contract,
-            &Symbol::new(&env, "resolve_oracle_data"),
-            (oracle_data_id,).into_val(&env),
-        );
+    /// Give an assigned payout back to the claimant, e.g. once the loan
+    /// against it is repaid (lender only)
+    pub fn release_claim_assignment(env: Env, lender: Address, claim_id: u64) -> Result<(), ContractError> {
+        lender.require_auth();
 
-        // Store oracle data ID associated with claim for audit trail
-        env.storage()
+        let assignment: ClaimAssignment = env
+            .storage()
             .persistent()
-            .set(&(CLM_ORA, claim_id), &oracle_data_id);
+            .get(&(ASSIGNMENT, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        if assignment.lender != lender {
+            return Err(ContractError::Unauthorized);
+        }
+        clear_assignment(&env, claim_id);
 
-        Ok(true)
+        publish_event(
+            &env,
+            (Symbol::new(&env, "assignment_released"), claim_id),
+            (lender, assignment.amount),
+        );
+
+        Ok(())
     }
 
-    /// Get oracle data associated with a claim
-    pub fn get_claim_oracle_data(env: Env, claim_id: u64) -> Result<u64, ContractError> {
-        env.storage()
-            .persistent()
-            .get(&(CLM_ORA, claim_id))
-            .ok_or(ContractError::NotFound)
+    pub fn get_claim_assignment(env: Env, claim_id: u64) -> Option<ClaimAssignment> {
+        env.storage().persistent().get(&(ASSIGNMENT, claim_id))
     }
-        // 1. IDENTITY CHECK
-        claimant.require_auth();
 
+    /// Settle queued claims in order, at most `max_n`. Stops at the first
+    /// claim still inside its dispute window or that the pool cannot
+    /// currently fund, so the order is never skipped.
+    /// Permissionless so any keeper can run it; returns the number settled.
+    pub fn process_settlements(env: Env, max_n: u32) -> Result<u32, ContractError> {
         if is_paused(&env) {
             return Err(ContractError::Paused);
         }
 
-        // 2. FETCH POLICY DATA
-        let (policy_contract_addr, _): (Address, Address) = env.storage()
-            .persistent()
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
             .get(&CONFIG)
             .ok_or(ContractError::NotInitialized)?;
 
-        let policy_client = policy_contract::Client::new(&env, &policy_contract_addr);
-        let policy = policy_client.get_policy(&policy_id);
+        let mut queue = settlement_queue(&env);
+        let mut processed = 0u32;
 
-        // 3. OWNERSHIP CHECK (Verify policyholder identity)
-        if policy.0 != claimant {
-            return Err(ContractError::Unauthorized); 
+        while processed < max_n {
+            let (priority, claim_id) = match queue.first() {
+                Some(entry) => entry,
+                None => break,
+            };
+            let claim: (u64, Address, i128, ClaimStatus, u64) = env
+                .storage()
+                .persistent()
+                .get(&(CLAIM, claim_id))
+                .ok_or(ContractError::NotFound)?;
+
+            if in_dispute_window(&env, claim_id) {
+                break;
+            }
+
+            let pool_stats: (i128, i128, i128, u64) = env.invoke_contract(
+                &config.1,
+                &Symbol::new(&env, "get_pool_stats"),
+                ().into_val(&env),
+            );
+            if pool_stats.0 < claim.2 {
+                break;
+            }
+
+            queue.pop_front();
+            env.storage().persistent().set(&SETTLEMENT_QUEUE, &queue);
+            settle(&env, claim_id)?;
+
+            publish_event(
+                &env,
+                (Symbol::new(&env, "settlement_processed"), claim_id),
+                (priority, claim.2),
+            );
+
+            processed += 1;
         }
 
-        // 3. DUPLICATE CHECK (Check if this specific policy already has a claim)
-        if env.storage().persistent().has(&(POLICY_CLAIM, policy_id)) {
-            return Err(ContractError::AlreadyExists);
+        Ok(processed)
+    }
+
+    /// Delay settlements above `threshold` by `delay` seconds, cancellable
+    /// by `guardian` in the meantime (governance only)
+    pub fn set_payout_delay(env: Env, caller: Address, threshold: i128, delay: u64, guardian: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        validate_amount(&env, threshold)?;
+
+        payout_delay::set_config(&env, &PayoutDelayConfig { threshold, delay, guardian: guardian.clone() });
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "payout_delay_set"), guardian),
+            (threshold, delay),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_payout_delay(env: Env) -> Option<PayoutDelayConfig> {
+        payout_delay::get_config(&env)
+    }
+
+    /// Time a delayed settlement can be released, if pending
+    pub fn get_pending_payout(env: Env, claim_id: u64) -> Option<u64> {
+        payout_delay::pending_release_at(&env, claim_id)
+    }
+
+    /// Execute a delayed settlement once its delay has passed (permissionless)
+    pub fn release_payout(env: Env, claim_id: u64) -> Result<(), ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
         }
 
-        // 5. COVERAGE CHECK (Enforce claim ≤ coverage)
-        if amount <= 0 || amount > policy.1 {
-            return Err(ContractError::InvalidInput);
+        payout_delay::release(&env, claim_id)?;
+        pay_out(&env, claim_id)
+    }
+
+    /// Cancel a delayed settlement during its delay; the claim is held
+    /// until governance clears it
+    pub fn cancel_payout(env: Env, guardian: Address, claim_id: u64) -> Result<(), ContractError> {
+        guardian.require_auth();
+
+        payout_delay::cancel(&env, &guardian, claim_id)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "payout_cancelled"), claim_id),
+            guardian,
+        );
+
+        Ok(())
+    }
+
+    /// Lift the hold on a cancelled settlement and queue it again (governance only)
+    pub fn clear_payout_hold(env: Env, caller: Address, claim_id: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        payout_delay::clear_hold(&env, claim_id)?;
+        enqueue_settlement(&env, claim_id, claim.2)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "payout_hold_cleared"), claim_id),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    /// Queued claim ids in settlement order
+    pub fn get_settlement_queue(env: Env) -> Vec<u64> {
+        let mut ids = Vec::new(&env);
+        for (_, claim_id) in settlement_queue(&env).iter() {
+            ids.push_back(claim_id);
         }
+        ids
+    }
 
-        // ID Generation
-        let seq: u64 = env.ledger().sequence().into();
-        let claim_id = seq + 1; 
-        let current_time = env.ledger().timestamp();
+    /// Settlement queue in priority order, `limit` claim ids at a time
+    pub fn get_settlement_queue_page(env: Env, cursor: u32, limit: u32) -> IdPage {
+        let ids = Self::get_settlement_queue(env.clone());
+        pagination::page_of(&env, &ids, cursor, limit).into()
+    }
 
-        // I3: Initial state must be Submitted
-        let initial_status = ClaimStatus::Submitted;
+    /// Zero-based position of a claim in the settlement queue
+    pub fn get_queue_position(env: Env, claim_id: u64) -> Option<u32> {
+        settlement_queue(&env)
+            .iter()
+            .position(|(_, id)| id == claim_id)
+            .map(|pos| pos as u32)
+    }
+
+    /// Replace the settlement priority classes, highest priority first (governance only)
+    pub fn set_settlement_classes(env: Env, caller: Address, classes: Vec<SettlementClass>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if classes.len() > MAX_SETTLEMENT_CLASSES {
+            return Err(ContractError::InvalidInput);
+        }
+        for class in classes.iter() {
+            validate_amount(&env, class.max_amount)?;
+        }
 
         env.storage()
+            .instance()
+            .set(&SETTLEMENT_CLASSES, &classes);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "settlement_classes_set"), caller),
+            classes.len(),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_settlement_classes(env: Env) -> Vec<SettlementClass> {
+        settlement_classes(&env)
+    }
+
+    /// Flag an open claim as hardship so it settles ahead of all classes
+    pub fn flag_hardship(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
             .persistent()
-            .set(&(CLAIM, claim_id), &(policy_id, claimant.clone(), amount, initial_status, current_time));
-        
-        env.storage()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        require_not_beneficiary(&env, &processor, claim_id, &claim.1)?;
+        if claim.3.is_terminal() {
+            return Err(ContractError::InvalidClaimState);
+        }
+
+        env.storage().persistent().set(&(HARDSHIP, claim_id), &true);
+
+        // Move an already queued claim up to its new priority
+        if dequeue_settlement(&env, claim_id) {
+            enqueue_settlement(&env, claim_id, claim.2)?;
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "hardship_flagged"), claim_id),
+            processor,
+        );
+
+        Ok(())
+    }
+
+    /// Settlement priority of a claim; lower values settle first
+    pub fn get_settlement_priority(env: Env, claim_id: u64) -> Result<u32, ContractError> {
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
             .persistent()
-            .set(&(POLICY_CLAIM, policy_id), &claim_id);
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        Ok(settlement_priority(&env, claim_id, claim.2))
+    }
+
+    /// Set or clear the stake claim processors must keep (admin only). It
+    /// can only be cleared, or moved to another token, once every stake has
+    /// been withdrawn.
+    pub fn set_processor_staking(env: Env, admin: Address, config: Option<ProcessorStakingConfig>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match &config {
+            Some(config) => {
+                validate_amount(&env, config.min_stake)?;
+                assets::require_allowed(&env, &config.token)?;
+                if config.slash_bps > MAX_BPS || config.wronged_share_bps > MAX_BPS || config.max_overturn_bps > MAX_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                if let Some(current) = staking_config(&env) {
+                    // Stakes already held are denominated in the current token
+                    if current.token != config.token && processor_stake_total(&env) > 0 {
+                        return Err(ContractError::InvalidState);
+                    }
+                }
+                register_trusted_contract(&env, &admin, &config.treasury)?;
+                env.storage().instance().set(&STAKING_CONFIG, config);
+            }
+            None => {
+                if processor_stake_total(&env) > 0 {
+                    return Err(ContractError::InvalidState);
+                }
+                env.storage().instance().remove(&STAKING_CONFIG);
+            }
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processor_staking_set"), ()),
+            config,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_processor_staking(env: Env) -> Option<ProcessorStakingConfig> {
+        staking_config(&env)
+    }
+
+    /// Add to a processor's stake
+    pub fn stake_processor(env: Env, processor: Address, amount: i128) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+        validate_amount(&env, amount)?;
+
+        let config = staking_config(&env).ok_or(ContractError::NotInitialized)?;
+        token::Client::new(&env, &config.token).transfer(&processor, env.current_contract_address(), &amount);
+
+        let mut stake = accrued_stake(&env, &processor)?;
+        stake.amount = stake.amount.checked_add(amount).ok_or(ContractError::Overflow)?;
+        set_processor_stake(&env, &processor, &stake);
+        env.storage().instance().set(
+            &PROCESSOR_STAKE_TOTAL,
+            &processor_stake_total(&env).checked_add(amount).ok_or(ContractError::Overflow)?,
+        );
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processor_staked"), processor),
+            (amount, stake.amount),
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw stake once the unbonding period since the processor's last
+    /// decision has passed, so stake backing open dispute windows stays
+    /// slashable
+    pub fn unstake_processor(env: Env, processor: Address, amount: i128) -> Result<(), ContractError> {
+        processor.require_auth();
+        validate_amount(&env, amount)?;
+
+        let config = staking_config(&env).ok_or(ContractError::NotInitialized)?;
+        let last_decision_at = processor_stats(&env, &processor).last_decision_at;
+        if last_decision_at > 0 && env.ledger().timestamp() < last_decision_at.saturating_add(config.unbonding_secs) {
+            return Err(ContractError::StakeLocked);
+        }
+
+        let mut stake = accrued_stake(&env, &processor)?;
+        if amount > stake.amount {
+            return Err(ContractError::InsufficientFunds);
+        }
+        stake.amount -= amount;
+        set_processor_stake(&env, &processor, &stake);
+        env.storage()
+            .instance()
+            .set(&PROCESSOR_STAKE_TOTAL, &(processor_stake_total(&env) - amount));
+
+        token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &processor, &amount);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processor_unstaked"), processor),
+            (amount, stake.amount),
+        );
+
+        Ok(())
+    }
+
+    /// Add processor rewards, shared pro rata to stake
+    pub fn fund_processor_rewards(env: Env, funder: Address, amount: i128) -> Result<(), ContractError> {
+        funder.require_auth();
+        validate_amount(&env, amount)?;
+
+        let config = staking_config(&env).ok_or(ContractError::NotInitialized)?;
+        let total = processor_stake_total(&env);
+        if total <= 0 {
+            return Err(ContractError::InvalidState);
+        }
+        token::Client::new(&env, &config.token).transfer(&funder, env.current_contract_address(), &amount);
+
+        let increment = mul_div_floor(amount, REWARD_SCALE, total).map_err(|_| ContractError::Overflow)?;
+        let index: i128 = env
+            .storage()
+            .instance()
+            .get(&PROCESSOR_REWARD_INDEX)
+            .unwrap_or(0i128);
+        env.storage().instance().set(
+            &PROCESSOR_REWARD_INDEX,
+            &index.checked_add(increment).ok_or(ContractError::Overflow)?,
+        );
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processor_rewards_funded"), funder),
+            (amount, total),
+        );
+
+        Ok(())
+    }
+
+    /// Pay out a processor's accrued rewards. Processors with more than
+    /// `max_overturn_bps` of their decisions overturned must wait until
+    /// their record improves.
+    pub fn claim_processor_rewards(env: Env, processor: Address) -> Result<i128, ContractError> {
+        processor.require_auth();
+
+        let config = staking_config(&env).ok_or(ContractError::NotInitialized)?;
+        let stats = processor_stats(&env, &processor);
+        if stats.decisions > 0 {
+            let overturned_bps = mul_div_floor(stats.overturned as i128, MAX_BPS as i128, stats.decisions as i128)
+                .map_err(|_| ContractError::Overflow)?;
+            if overturned_bps > config.max_overturn_bps as i128 {
+                return Err(ContractError::ProcessorNotEligible);
+            }
+        }
+
+        let mut stake = accrued_stake(&env, &processor)?;
+        let rewards = stake.rewards;
+        stake.rewards = 0;
+        set_processor_stake(&env, &processor, &stake);
+        if rewards > 0 {
+            token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &processor, &rewards);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processor_rewards_claimed"), processor),
+            rewards,
+        );
+
+        Ok(rewards)
+    }
+
+    /// Set or clear the fee charged to the pool per settled claim
+    /// (governance only)
+    pub fn set_processing_fee(env: Env, caller: Address, fee: Option<ProcessingFee>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
 
-        env.events().publish(
-            (symbol_short!("clm_sub"), claim_id),
-            (policy_id, amount, claimant.clone()),
+        match &fee {
+            Some(ProcessingFee::Flat(flat)) => validate_amount(&env, *flat)?,
+            Some(ProcessingFee::Bps(bps)) if *bps == 0 || *bps > MAX_BPS => return Err(ContractError::InvalidInput),
+            Some(ProcessingFee::Bps(_)) | None => {}
+        }
+        match &fee {
+            Some(fee) => env.storage().instance().set(&PROCESSING_FEE, fee),
+            None => env.storage().instance().remove(&PROCESSING_FEE),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processing_fee_set"), ()),
+            fee,
         );
 
-        Ok(claim_id)
+        Ok(())
     }
 
-    pub fn get_claim(env: Env, claim_id: u64) -> Result<(u64, Address, i128, ClaimStatus, u64), ContractError> {
-        let claim: (u64, Address, i128, ClaimStatus, u64) = env
-            .storage()
-            .persistent()
-            .get(&(CLAIM, claim_id))
-            .ok_or(ContractError::NotFound)?;
+    pub fn get_processing_fee(env: Env) -> Option<ProcessingFee> {
+        env.storage().instance().get(&PROCESSING_FEE)
+    }
 
-        Ok(claim)
+    /// Processing fees a processor has earned and not yet claimed
+    pub fn get_processing_fees(env: Env, processor: Address) -> i128 {
+        processor_fees(&env, &processor)
     }
 
-    pub fn approve_claim(env: Env, processor: Address, claim_id: u64, oracle_data_id: Option<u64>) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
+    /// Have the pool pay out a processor's accrued processing fees
+    pub fn claim_processing_fees(env: Env, processor: Address) -> Result<i128, ContractError> {
         processor.require_auth();
-        require_claim_processing(&env, &processor)?;
 
-        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
-            .storage()
+        let amount = processor_fees(&env, &processor);
+        if amount == 0 {
+            return Ok(0);
+        }
+        env.storage()
             .persistent()
-            .get(&(CLAIM, claim_id))
-            .ok_or(ContractError::NotFound)?;
+            .remove(&(PROCESSOR_FEES, processor.clone()));
 
-        // I3: Can only approve claims that are UnderReview - validate state transition
-        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Approved) {
-            return Err(ContractError::InvalidClaimState);
-        }
+        let config: (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        require_trusted_contract(&env, &config.1)?;
+        env.invoke_contract::<()>(
+            &config.1,
+            &Symbol::new(&env, "pay_processing_fee"),
+            (env.current_contract_address(), processor.clone(), amount).into_val(&env),
+        );
 
-        // I4: Amount must be positive
-        if claim.2 <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processing_fees_claimed"), processor),
+            amount,
+        );
 
-        // Check if oracle validation is required
-        if let Ok(oracle_config) = env.storage().persistent().get::<_, OracleValidationConfig>(&ORACLE_CONFIG) {
-            if oracle_config.require_oracle_validation {
-                if let Some(oracle_id) = oracle_data_id {
-                    // Verify oracle contract is trusted
-                    require_trusted_contract(&env, &oracle_config.oracle_contract)?;
-                    
-                    // Validate using oracle data (store oracle data ID)
-                    let _submission_count: u32 = env.invoke_contract(
-                        &oracle_config.oracle_contract,
-                        &Symbol::new(&env, "get_submission_count"),
-                        (oracle_id,).into_val(&env),
-                    );
+        Ok(amount)
+    }
 
-                    // Store oracle data ID associated with claim for audit trail
-                    env.storage()
-                        .persistent()
-                        .set(&(CLM_ORA, claim_id), &oracle_id);
-                } else {
-                    return Err(ContractError::OracleValidationFailed);
-                }
-            }
+    /// A processor's stake with rewards accrued to date
+    pub fn get_processor_stake(env: Env, processor: Address) -> Result<ProcessorStake, ContractError> {
+        accrued_stake(&env, &processor)
+    }
+
+    /// Decision metrics for a processor
+    pub fn get_processor_stats(env: Env, processor: Address) -> ProcessorStats {
+        processor_stats(&env, &processor)
+    }
+
+    /// Average submission-to-decision time for a processor, in seconds
+    pub fn get_processor_avg_decision_secs(env: Env, processor: Address) -> u64 {
+        let stats = processor_stats(&env, &processor);
+        if stats.decisions == 0 {
+            return 0;
         }
+        stats.total_decision_secs / stats.decisions as u64
+    }
 
-        let config: (Address, Address) = env
-            .storage()
-            .persistent()
-            .get(&CONFIG)
-            .ok_or(ContractError::NotInitialized)?;
-        let risk_pool_contract = config.1.clone();
+    /// Processor that approved or rejected a claim
+    pub fn get_claim_decider(env: Env, claim_id: u64) -> Option<Address> {
+        env.storage().persistent().get(&(DECIDED_BY, claim_id))
+    }
 
-        // Verify risk pool is a trusted contract before invoking
-        require_trusted_contract(&env, &risk_pool_contract)?;
+    /// Publish a processor's current summary on demand (permissionless)
+    pub fn emit_processor_summary(env: Env, processor: Address) {
+        let stats = processor_stats(&env, &processor);
+        publish_processor_summary(&env, &processor, &stats);
+    }
 
-        env.invoke_contract::<()>(
-            &risk_pool_contract,
-            &Symbol::new(&env, "reserve_liquidity"),
-            (claim_id, claim.2).into_val(&env),
-        );
+    /// Set the annual aggregate payout limit per holder for a product (governance only)
+    pub fn set_aggregate_limit(env: Env, caller: Address, product_id: u32, limit: i128) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
 
-        // I3: Transition to Approved state
-        claim.3 = ClaimStatus::Approved;
+        validate_amount(&env, limit)?;
 
         env.storage()
             .persistent()
-            .set(&(CLAIM, claim_id), &claim);
+            .set(&(AGGREGATE_LIMIT, product_id), &limit);
 
-        env.events().publish(
-            (symbol_short!("clm_app"), claim_id),
-            (claim.1, claim.2),
+        publish_event(
+            &env,
+            (Symbol::new(&env, "aggregate_limit_set"), product_id),
+            limit,
         );
 
         Ok(())
     }
 
-    pub fn start_review(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+    pub fn get_aggregate_limit(env: Env, product_id: u32) -> Option<i128> {
+        aggregate_limit(&env, product_id)
+    }
 
-        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
-            .storage()
-            .persistent()
-            .get(&(CLAIM, claim_id))
-            .ok_or(ContractError::NotFound)?;
+    /// Set or clear the claim amount bounds for a product (governance only).
+    /// Products without bounds accept any amount within the policy coverage.
+    pub fn set_claim_bounds(env: Env, caller: Address, product_id: u32, bounds: Option<ClaimBounds>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
 
-        // I3: Can only start review for submitted claims - validate state transition
-        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::UnderReview) {
-            return Err(ContractError::InvalidClaimState);
+        match &bounds {
+            Some(bounds) => {
+                validate_amount(&env, bounds.min_amount)?;
+                if bounds.max_amount < bounds.min_amount {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(CLAIM_BOUNDS, product_id), bounds);
+            }
+            None => env.storage().persistent().remove(&(CLAIM_BOUNDS, product_id)),
         }
 
-        // I3: Transition to UnderReview state
-        claim.3 = ClaimStatus::UnderReview;
-
-        env.storage()
-            .persistent()
-            .set(&(CLAIM, claim_id), &claim);
-
-        env.events().publish(
-            (Symbol::new(&env, "claim_under_review"), claim_id),
-            (claim.1, claim.2),
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_bounds_set"), product_id),
+            bounds,
         );
 
         Ok(())
     }
 
-    pub fn reject_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+    pub fn get_claim_bounds(env: Env, product_id: u32) -> Option<ClaimBounds> {
+        claim_bounds(&env, product_id)
+    }
 
-        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
-            .storage()
-            .persistent()
-            .get(&(CLAIM, claim_id))
-            .ok_or(ContractError::NotFound)?;
+    /// Approved claim amounts for a holder and product in the current period
+    pub fn get_holder_aggregate(env: Env, holder: Address, product_id: u32) -> i128 {
+        holder_aggregate(&env, &holder, product_id)
+    }
 
-        // I3: Can only reject claims that are UnderReview - validate state transition
-        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Rejected) {
-            return Err(ContractError::InvalidClaimState);
+    /// Amount a holder can still be approved for under a product this period.
+    /// `None` when the product has no aggregate limit.
+    pub fn get_remaining_aggregate(env: Env, holder: Address, product_id: u32) -> Option<i128> {
+        aggregate_limit(&env, product_id)
+            .map(|limit| (limit - holder_aggregate(&env, &holder, product_id)).max(0))
+    }
+
+    /// Evaluate the claims invariants (I3, I4) against live storage: the
+    /// lifecycle counters and every claim in the settlement queue. For
+    /// monitoring; each violation is published as an `invariant_violation`
+    /// event, so calling it in a transaction records what it found.
+    pub fn check_invariants(env: Env) -> InvariantReport {
+        let stats = get_stats(&env);
+
+        let mut valid_states = stats.open_claims <= stats.total_submitted
+            && stats.total_settled <= stats.total_submitted;
+        let mut valid_amounts = stats.total_paid_out >= 0;
+
+        for (_, claim_id) in settlement_queue(&env).iter() {
+            match env
+                .storage()
+                .persistent()
+                .get::<_, (u64, Address, i128, ClaimStatus, u64)>(&(CLAIM, claim_id))
+            {
+                Some(claim) => {
+                    valid_states &= claim.3 == ClaimStatus::PendingSettlement;
+                    valid_amounts &= claim.2 > 0;
+                }
+                None => valid_states = false,
+            }
         }
 
-        // I3: Transition to Rejected state
-        claim.3 = ClaimStatus::Rejected;
+        let mut report = InvariantReport::new(&env);
+        report.record(Invariant::ClaimStateValidity, valid_states);
+        report.record(Invariant::AmountNonNegativity, valid_amounts);
+        report
+    }
 
-        env.storage()
-            .persistent()
-            .set(&(CLAIM, claim_id), &claim);
+    /// Live claim aggregates
+    pub fn get_stats(env: Env) -> ClaimsStats {
+        get_stats(&env)
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "claim_rejected"), claim_id),
-            (claim.1, claim.2),
-        );
+    /// Hash over `(total_submitted, open_claims, total_settled,
+    /// total_paid_out)` as of the last write to the claim stats, in that order.
+    pub fn get_state_root(env: Env) -> Option<StateRoot> {
+        state_root::get(&env)
+    }
 
-        Ok(())
+    /// Record a checkpoint for the current epoch. Permissionless so keepers can
+    /// fill epochs without claim activity.
+    pub fn checkpoint_stats(env: Env) {
+        let stats = get_stats(&env);
+        checkpoint_stats(&env, &stats);
     }
 
-    pub fn settle_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+    /// Checkpoints with `from_epoch <= epoch <= to_epoch`, oldest first.
+    /// An epoch is `timestamp / 86400`.
+    pub fn get_stats_history(env: Env, from_epoch: u64, to_epoch: u64) -> Result<Vec<ClaimsStatsCheckpoint>, ContractError> {
+        if from_epoch > to_epoch {
+            return Err(ContractError::InvalidInput);
+        }
 
-        let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
+        let epochs: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&(CLAIM, claim_id))
-            .ok_or(ContractError::NotFound)?;
+            .get(&STATS_EPOCHS)
+            .unwrap_or_else(|| Vec::new(&env));
 
-        // I3: Can only settle claims that are Approved - validate state transition
-        if !is_valid_state_transition(claim.3.clone(), ClaimStatus::Settled) {
-            return Err(ContractError::InvalidClaimState);
+        let mut history = Vec::new(&env);
+        for epoch in epochs.iter() {
+            if epoch < from_epoch || epoch > to_epoch {
+                continue;
+            }
+            if let Some(checkpoint) = env
+                .storage()
+                .persistent()
+                .get::<_, ClaimsStatsCheckpoint>(&(STATS_CHECKPOINT, epoch))
+            {
+                history.push_back(checkpoint);
+            }
         }
+        Ok(history)
+    }
 
-        // I4: Amount must be positive
-        if claim.2 <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
+    /// Register a listener contract for an event topic (admin only)
+    pub fn register_listener(env: Env, admin: Address, topic: Symbol, listener: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
-        // Get risk pool contract address from config
-        let config: (Address, Address) = env
-            .storage()
-            .persistent()
-            .get(&CONFIG)
-            .ok_or(ContractError::NotInitialized)?;
-        let risk_pool_contract = config.1.clone();
+        add_listener(&env, &topic, &listener)?;
 
-        // Verify risk pool is a trusted contract before invoking
-        require_trusted_contract(&env, &risk_pool_contract)?;
+        publish_event(
+            &env,
+            (Symbol::new(&env, "listener_registered"), listener),
+            topic,
+        );
 
-        // Call risk pool to payout the claim amount
-        env.invoke_contract::<()>(
-            &risk_pool_contract,
-            &Symbol::new(&env, "payout_reserved_claim"),
-            (claim_id, claim.1.clone()).into_val(&env),
+        Ok(())
+    }
+
+    /// Remove a listener contract from an event topic (admin only)
+    pub fn unregister_listener(env: Env, admin: Address, topic: Symbol, listener: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        remove_listener(&env, &topic, &listener)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "listener_unregistered"), listener),
+            topic,
         );
 
-        // I3: Transition to Settled state
-        claim.3 = ClaimStatus::Settled;
+        Ok(())
+    }
 
-        env.storage()
-            .persistent()
-            .set(&(CLAIM, claim_id), &claim);
+    /// Listener contracts registered for an event topic
+    pub fn get_listeners(env: Env, topic: Symbol) -> Vec<Address> {
+        get_listeners(&env, &topic)
+    }
 
-        env.events().publish(
-            (Symbol::new(&env, "claim_settled"), claim_id),
-            (claim.1, claim.2),
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["claims_v1", IFACE_VERSION, IFACE_WIRING, IFACE_LISTENERS, IFACE_PAYOUT_DELAY])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
         );
 
         Ok(())
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+    /// Move the hot configuration, counters and aggregates of a contract
+    /// upgraded from a release that kept them in persistent storage (admin
+    /// only). Run right after the upgrade: until then they read as unset.
+    /// Safe to repeat; returns how many items were moved.
+    pub fn migrate_hot_storage(env: Env, admin: Address) -> Result<u32, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let mut moved = hot_storage::migrate_shared(&env);
+        for key in HOT_KEYS.iter() {
+            if hot_storage::migrate(&env, key) {
+                moved += 1;
+            }
+        }
+        hot_storage::extend(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "hot_storage_migrated"), ()),
+            moved,
+        );
+
+        Ok(moved)
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
+    /// Pause the contract (admin only), optionally citing the incident it
+    /// responds to
+    pub fn pause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
         
         set_paused(&env, true);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (symbol_short!("paused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+    pub fn unpause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
         
         set_paused(&env, false);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (symbol_short!("unpaused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
@@ -547,7 +5054,8 @@ impl ClaimsContract {
         
         insurance_contracts::authorization::grant_role(&env, &admin, &processor, Role::ClaimProcessor)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (symbol_short!("role_gr"), processor.clone()),
             admin,
         );
@@ -555,6 +5063,22 @@ impl ClaimsContract {
         Ok(())
     }
     
+    /// Grant the arbitrator role to an address (admin only)
+    pub fn grant_arbitrator_role(env: Env, admin: Address, arbitrator: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::grant_role(&env, &admin, &arbitrator, Role::Arbitrator)?;
+
+        publish_event(
+            &env,
+            (symbol_short!("role_gr"), arbitrator.clone()),
+            admin,
+        );
+
+        Ok(())
+    }
+
     /// Revoke claim processor role from an address (admin only)
     pub fn revoke_processor_role(env: Env, admin: Address, processor: Address) -> Result<(), ContractError> {
         admin.require_auth();
@@ -562,7 +5086,8 @@ impl ClaimsContract {
         
         insurance_contracts::authorization::revoke_role(&env, &admin, &processor)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (symbol_short!("role_rv"), processor.clone()),
             admin,
         );
@@ -570,9 +5095,115 @@ impl ClaimsContract {
         Ok(())
     }
     
+    /// Open an incident affecting `components` (governance only); pauses
+    /// and unpauses can cite the returned id
+    pub fn open_incident(env: Env, caller: Address, components: Vec<Symbol>) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident_id = incident::open(&env, &caller, components.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_opened"), incident_id),
+            (caller, components),
+        );
+
+        Ok(incident_id)
+    }
+
+    /// Record the end of an incident (governance only)
+    pub fn close_incident(env: Env, caller: Address, incident_id: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident = incident::close(&env, incident_id)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_closed"), incident_id),
+            (caller, incident.ended_at - incident.started_at),
+        );
+
+        Ok(())
+    }
+
+    /// Anchor the hash of a closed incident's postmortem (governance only)
+    pub fn anchor_postmortem(env: Env, caller: Address, incident_id: u64, postmortem_hash: BytesN<32>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        incident::anchor_postmortem(&env, incident_id, postmortem_hash.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "postmortem_anchored"), incident_id),
+            postmortem_hash,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_incident(env: Env, incident_id: u64) -> Option<Incident> {
+        incident::get(&env, incident_id)
+    }
+
+    pub fn get_incident_count(env: Env) -> u64 {
+        incident::count(&env)
+    }
+
+    /// Let the governance contract call admin and governance setters, or
+    /// stop it with `None` (admin only)
+    pub fn set_governance_contract(env: Env, admin: Address, governance: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::set_governance_contract(&env, &admin, governance.as_ref())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "governance_contract_set"), admin),
+            governance,
+        );
+
+        Ok(())
+    }
+
+    /// Governance contract holding admin powers, if any
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        insurance_contracts::authorization::get_governance_contract(&env)
+    }
+
+    /// Schedule the one-way handover to governance (admin only). From
+    /// `sunset_at` the admin's powers end and only the governance contract
+    /// can make admin calls; the time can be brought forward, never pushed
+    /// back.
+    pub fn schedule_admin_sunset(env: Env, admin: Address, sunset_at: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::schedule_admin_sunset(&env, &admin, sunset_at)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "admin_sunset_scheduled"), admin),
+            insurance_contracts::authorization::get_admin_sunset(&env),
+        );
+
+        Ok(())
+    }
+
+    /// When the admin's powers end, if a handover is scheduled
+    pub fn get_admin_sunset(env: Env) -> Option<u64> {
+        insurance_contracts::authorization::get_admin_sunset(&env)
+    }
+
     /// Get the role of an address
     pub fn get_user_role(env: Env, address: Address) -> Role {
         get_role(&env, &address)
     }
 }
-mod test;
\ No newline at end of file
+mod test;
+mod test_disputes;
+mod test_products;
+mod test_settlement;
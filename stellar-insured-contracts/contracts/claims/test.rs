@@ -0,0 +1,340 @@
+#![cfg(test)]
+//! Claim lifecycle under injected failures
+//!
+//! The policy, risk pool and oracle are replaced by the chaos stand-ins so
+//! individual cross-contract calls can be made to revert or time out and
+//! the clock can be skewed. Every failed step must leave the claim where it
+//! was and the pool's reservations matching the claim states.
+
+use super::*;
+use insurance_contracts::chaos::{
+    skew_clock, ChaosOracle, ChaosOracleClient, ChaosPolicy, ChaosPolicyClient, ChaosPool,
+    ChaosPoolClient, FailureMode, PolicyState,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+pub(crate) const COVERAGE: i128 = 10_000;
+pub(crate) const CLAIM_AMOUNT: i128 = 4_000;
+pub(crate) const POLICY_ID: u64 = 1;
+
+pub(crate) struct Harness<'a> {
+    pub(crate) env: Env,
+    pub(crate) admin: Address,
+    pub(crate) settler: Address,
+    pub(crate) holder: Address,
+    pub(crate) claims: ClaimsContractClient<'a>,
+    pub(crate) policy: ChaosPolicyClient<'a>,
+    pub(crate) pool: ChaosPoolClient<'a>,
+}
+
+impl<'a> Harness<'a> {
+    pub(crate) fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let admin = Address::generate(&env);
+        let settler = Address::generate(&env);
+        let holder = Address::generate(&env);
+
+        let policy = ChaosPolicyClient::new(&env, &env.register(ChaosPolicy, ()));
+        let pool = ChaosPoolClient::new(&env, &env.register(ChaosPool, ()));
+        policy.put_policy(&POLICY_ID, &holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+
+        let claims = ClaimsContractClient::new(&env, &env.register(ClaimsContract, ()));
+        claims.initialize(&InitConfig {
+            admin: admin.clone(),
+            policy_contract: policy.address.clone(),
+            risk_pool: pool.address.clone(),
+        });
+        claims.finalize_wiring(&admin);
+        claims.grant_processor_role(&admin, &settler);
+
+        Harness { env, admin, settler, holder, claims, policy, pool }
+    }
+
+    /// Switch `function` on the stand-in at `contract` to `mode`
+    pub(crate) fn fail(&self, contract: &Address, function: &str, mode: FailureMode) {
+        self.env.invoke_contract::<()>(
+            contract,
+            &Symbol::new(&self.env, "set_failure"),
+            (Symbol::new(&self.env, function), mode).into_val(&self.env),
+        );
+    }
+
+    pub(crate) fn status(&self, claim_id: u64) -> ClaimStatus {
+        self.claims.get_claim(&claim_id).3
+    }
+
+    /// Submit and review a claim, ready for a decision
+    pub(crate) fn reviewed_claim(&self) -> u64 {
+        let claim_id = self.claims.submit_claim(&self.holder, &POLICY_ID, &CLAIM_AMOUNT);
+        self.claims.start_review(&self.admin, &claim_id);
+        claim_id
+    }
+
+    /// Approve a reviewed claim, reserving its amount in the pool
+    pub(crate) fn approved_claim(&self) -> u64 {
+        let claim_id = self.reviewed_claim();
+        self.claims.approve_claim(&self.admin, &claim_id, &None);
+        claim_id
+    }
+
+    /// Approve a claim for `amount` on a new policy of the holder, for
+    /// tests that need several claims open at once
+    pub(crate) fn approved_claim_on(&self, policy_id: u64, amount: i128) -> u64 {
+        self.policy.put_policy(&policy_id, &self.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+        let claim_id = self.claims.submit_claim(&self.holder, &policy_id, &amount);
+        self.claims.start_review(&self.admin, &claim_id);
+        self.claims.approve_claim(&self.admin, &claim_id, &None);
+        claim_id
+    }
+}
+
+#[test]
+fn test_failed_handshake_keeps_intake_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    let policy = ChaosPolicyClient::new(&env, &env.register(ChaosPolicy, ()));
+    let pool = ChaosPoolClient::new(&env, &env.register(ChaosPool, ()));
+    policy.put_policy(&POLICY_ID, &holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    pool.set_failure(&Symbol::new(&env, "accept_wiring"), &FailureMode::Revert);
+
+    let claims = ClaimsContractClient::new(&env, &env.register(ClaimsContract, ()));
+    claims.initialize(&InitConfig {
+        admin: admin.clone(),
+        policy_contract: policy.address.clone(),
+        risk_pool: pool.address.clone(),
+    });
+
+    assert_eq!(claims.try_finalize_wiring(&admin), Err(Ok(ContractError::WiringFailed)));
+    assert!(!claims.is_wired());
+    assert_eq!(
+        claims.try_submit_claim(&holder, &POLICY_ID, &CLAIM_AMOUNT),
+        Err(Ok(ContractError::NotWired))
+    );
+}
+
+#[test]
+fn test_reverting_policy_lookup_records_nothing() {
+    let h = Harness::new();
+    h.fail(&h.policy.address, "get_policy_terms", FailureMode::Revert);
+
+    assert!(h.claims.try_submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT).is_err());
+    assert_eq!(h.claims.try_get_claim(&1), Err(Ok(ContractError::NotFound)));
+
+    // The policy is still claimable once the lookup recovers
+    h.fail(&h.policy.address, "get_policy_terms", FailureMode::Healthy);
+    let claim_id = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    assert_eq!(h.status(claim_id), ClaimStatus::Submitted);
+}
+
+#[test]
+fn test_reverting_reservation_leaves_claim_under_review() {
+    let h = Harness::new();
+    let claim_id = h.reviewed_claim();
+
+    h.fail(&h.pool.address, "reserve_liquidity", FailureMode::Revert);
+    assert!(h.claims.try_approve_claim(&h.admin, &claim_id, &None).is_err());
+    assert_eq!(h.status(claim_id), ClaimStatus::UnderReview);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+    assert_eq!(h.claims.get_settlement_queue().len(), 0);
+
+    h.fail(&h.pool.address, "reserve_liquidity", FailureMode::Healthy);
+    h.claims.approve_claim(&h.admin, &claim_id, &None);
+    assert_eq!(h.status(claim_id), ClaimStatus::PendingSettlement);
+    assert_eq!(h.pool.get_reservation(&claim_id), CLAIM_AMOUNT);
+}
+
+#[test]
+fn test_reverting_payout_keeps_reservation() {
+    let h = Harness::new();
+    let claim_id = h.approved_claim();
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+
+    h.fail(&h.pool.address, "payout_reserved_claim", FailureMode::Revert);
+    assert!(h.claims.try_settle_claim(&h.settler, &claim_id).is_err());
+    assert_eq!(h.status(claim_id), ClaimStatus::PendingSettlement);
+    assert_eq!(h.pool.get_reservation(&claim_id), CLAIM_AMOUNT);
+    assert_eq!(h.pool.get_total_paid_out(), 0);
+
+    // A revert in the policy's ledger after the payout undoes the payout too
+    h.fail(&h.pool.address, "payout_reserved_claim", FailureMode::Healthy);
+    h.fail(&h.policy.address, "record_claim", FailureMode::Revert);
+    assert!(h.claims.try_settle_claim(&h.settler, &claim_id).is_err());
+    assert_eq!(h.pool.get_reservation(&claim_id), CLAIM_AMOUNT);
+    assert_eq!(h.pool.get_total_paid_out(), 0);
+
+    h.fail(&h.policy.address, "record_claim", FailureMode::Healthy);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+    assert_eq!(h.pool.get_total_paid_out(), CLAIM_AMOUNT);
+    assert_eq!(h.policy.get_recorded(&POLICY_ID), CLAIM_AMOUNT);
+}
+
+#[test]
+fn test_upheld_dispute_releases_reservation_only_when_pool_answers() {
+    let h = Harness::new();
+    let claim_id = h.approved_claim();
+    h.claims.raise_dispute(&h.admin, &claim_id, &String::from_str(&h.env, "forged invoice"));
+
+    h.fail(&h.pool.address, "release_reservation", FailureMode::Revert);
+    assert!(h.claims.try_resolve_dispute(&h.admin, &claim_id, &true).is_err());
+    assert_eq!(h.status(claim_id), ClaimStatus::Disputed);
+    assert_eq!(h.pool.get_reservation(&claim_id), CLAIM_AMOUNT);
+    assert_eq!(h.claims.get_stats().open_claims, 1);
+
+    h.fail(&h.pool.address, "release_reservation", FailureMode::Healthy);
+    h.claims.resolve_dispute(&h.admin, &claim_id, &true);
+    assert_eq!(h.status(claim_id), ClaimStatus::Rejected);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+    assert_eq!(h.claims.get_stats().open_claims, 0);
+}
+
+#[test]
+fn test_oracle_timeout_is_not_accepted_as_validation() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    oracle.put_data(&7, &CLAIM_AMOUNT, &3);
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    let claim_id = h.reviewed_claim();
+
+    oracle.set_failure(&Symbol::new(&h.env, "get_submission_count"), &FailureMode::Timeout);
+    assert_eq!(
+        h.claims.try_validate_claim_with_oracle(&claim_id, &7),
+        Err(Ok(ContractError::InsufficientOracleSubmissions))
+    );
+    assert_eq!(h.claims.try_get_claim_oracle_data(&claim_id), Err(Ok(ContractError::NotFound)));
+
+    oracle.set_failure(&Symbol::new(&h.env, "get_submission_count"), &FailureMode::Healthy);
+    oracle.set_failure(&Symbol::new(&h.env, "resolve_oracle_data"), &FailureMode::Revert);
+    assert!(h.claims.try_validate_claim_with_oracle(&claim_id, &7).is_err());
+    assert_eq!(h.claims.try_get_claim_oracle_data(&claim_id), Err(Ok(ContractError::NotFound)));
+
+    oracle.set_failure(&Symbol::new(&h.env, "resolve_oracle_data"), &FailureMode::Healthy);
+    assert!(h.claims.validate_claim_with_oracle(&claim_id, &7));
+    assert_eq!(h.claims.get_claim_oracle_data(&claim_id), 7);
+    assert_eq!(h.status(claim_id), ClaimStatus::UnderReview);
+}
+
+#[test]
+fn test_clock_skew_cannot_shortcut_the_dispute_window() {
+    let h = Harness::new();
+    let claim_id = h.approved_claim();
+    let window_end = h.claims.get_dispute_window_end(&claim_id).unwrap();
+
+    // A clock running behind keeps the window open
+    skew_clock(&h.env, -500);
+    assert_eq!(h.claims.try_settle_claim(&h.settler, &claim_id), Err(Ok(ContractError::DisputeWindowOpen)));
+    assert_eq!(h.claims.process_settlements(&10), 0);
+
+    // Jumping forward to the window's end settles exactly once
+    h.env.ledger().with_mut(|li| li.timestamp = window_end + 1);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert!(h.claims.try_settle_claim(&h.settler, &claim_id).is_err());
+    assert_eq!(h.pool.get_total_paid_out(), CLAIM_AMOUNT);
+}
+
+#[test]
+fn test_clock_skew_outside_policy_term_rejects_submission() {
+    let h = Harness::new();
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &5_000, &10_000);
+
+    // Before the term starts
+    assert_eq!(
+        h.claims.try_submit_claim(&h.holder, &2, &CLAIM_AMOUNT),
+        Err(Ok(ContractError::PolicyNotActive))
+    );
+
+    // Long after the claim window closed
+    skew_clock(&h.env, 10_000 + CLAIM_WINDOW_SECONDS as i64);
+    assert_eq!(
+        h.claims.try_submit_claim(&h.holder, &2, &CLAIM_AMOUNT),
+        Err(Ok(ContractError::PolicyExpired))
+    );
+}
+
+#[test]
+fn test_governance_contract_holds_admin_powers_until_cleared() {
+    let h = Harness::new();
+    let governance = Address::generate(&h.env);
+
+    assert_eq!(
+        h.claims.try_set_governance_contract(&h.settler, &Some(governance.clone())),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(h.claims.try_set_dispute_window(&governance, &3_600), Err(Ok(ContractError::Unauthorized)));
+
+    h.claims.set_governance_contract(&h.admin, &Some(governance.clone()));
+    assert_eq!(h.claims.get_governance_contract(), Some(governance.clone()));
+    h.claims.set_dispute_window(&governance, &3_600);
+    assert_eq!(h.claims.get_dispute_config().dispute_window_secs, 3_600);
+    h.claims.set_lender_cap(&governance, &h.holder, &Some(1_000));
+
+    // Clearing it takes the powers back
+    h.claims.set_governance_contract(&h.admin, &None);
+    assert_eq!(h.claims.get_governance_contract(), None);
+    assert_eq!(h.claims.try_set_dispute_window(&governance, &7_200), Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(
+        h.claims.try_set_lender_cap(&governance, &h.holder, &None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_get_claims_pages_are_aligned_and_bounded() {
+    let h = Harness::new();
+    let claim_id = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+
+    assert_eq!(h.claims.get_claims(&Vec::new(&h.env)).len(), 0);
+
+    // Missing ids keep their slot, so results line up with the request
+    let page = h.claims.get_claims(&Vec::from_array(&h.env, [claim_id + 1, claim_id, 0]));
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap(), None);
+    assert_eq!(page.get(1).unwrap(), Some(h.claims.get_claim(&claim_id)));
+    assert_eq!(page.get(2).unwrap(), None);
+
+    // A full page is served and one id more is refused
+    let mut ids = Vec::new(&h.env);
+    for id in 0..MAX_BATCH_SIZE as u64 {
+        ids.push_back(id);
+    }
+    assert_eq!(h.claims.get_claims(&ids).len(), MAX_BATCH_SIZE);
+    ids.push_back(MAX_BATCH_SIZE as u64);
+    assert_eq!(h.claims.try_get_claims(&ids), Err(Ok(ContractError::InvalidInput)));
+}
+
+#[test]
+fn test_migrate_claims_rewrites_legacy_status_once() {
+    let h = Harness::new();
+    h.env.as_contract(&h.claims.address, || {
+        h.env.storage().persistent().set(
+            &(CLAIM, 7u64),
+            &(POLICY_ID, h.holder.clone(), CLAIM_AMOUNT, LegacyClaimStatus::UnderReview, 1_000u64),
+        );
+    });
+    let ids = Vec::from_array(&h.env, [7, 8]);
+
+    // The legacy encoding does not read as a claim until migrated
+    assert!(h.claims.try_get_claim(&7).is_err());
+    assert_eq!(
+        h.claims.try_migrate_claims(&h.settler, &ids),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    assert_eq!(h.claims.migrate_claims(&h.admin, &ids), 1);
+    assert_eq!(
+        h.claims.get_claim(&7),
+        (POLICY_ID, h.holder.clone(), CLAIM_AMOUNT, ClaimStatus::UnderReview, 1_000)
+    );
+    assert_eq!(h.claims.migrate_claims(&h.admin, &ids), 0);
+
+    // The migrated claim carries on through the lifecycle
+    h.claims.approve_claim(&h.admin, &7, &None);
+    assert_eq!(h.status(7), ClaimStatus::PendingSettlement);
+}
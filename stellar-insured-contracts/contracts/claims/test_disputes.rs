@@ -0,0 +1,276 @@
+#![cfg(test)]
+//! Disputes, arbitration, appeals and reversals
+//!
+//! Claims pending settlement are disputed and resolved directly, by an
+//! arbitration panel or by a governance appeal, with the bonds posted on
+//! the way paid to the side that wins. The treasury and governance are
+//! stand-ins below that only record what the claims contract asks of them.
+
+use super::*;
+use crate::test::{Harness, CLAIM_AMOUNT, POLICY_ID};
+use insurance_contracts::chaos::skew_clock;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl};
+
+/// Treasury stand-in: keeps the fees deposited with it and pays rewards
+/// out of its own balance
+#[contract]
+pub struct BondTreasury;
+
+#[contractimpl]
+impl BondTreasury {
+    pub fn deposit_fee(env: Env, from: Address, amount: i128, _fee_type: u32) {
+        from.require_auth();
+        let fees: i128 = env.storage().instance().get(&symbol_short!("FEES")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("FEES"), &(fees + amount));
+    }
+
+    pub fn pay_dispute_reward(env: Env, caller: Address, token: Address, recipient: Address, amount: i128) {
+        caller.require_auth();
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+    }
+
+    pub fn fees(env: Env) -> i128 {
+        env.storage().instance().get(&symbol_short!("FEES")).unwrap_or(0)
+    }
+}
+
+/// Governance stand-in that opens appeal proposals and remembers the
+/// recommendation it was given
+#[contract]
+pub struct AppealBoard;
+
+#[contractimpl]
+impl AppealBoard {
+    pub fn create_appeal_proposal(
+        env: Env,
+        caller: Address,
+        claim_id: u64,
+        _amount: i128,
+        recommend_uphold: bool,
+        _threshold_percentage: u32,
+    ) -> u64 {
+        caller.require_auth();
+        env.storage().instance().set(&claim_id, &recommend_uphold);
+        claim_id + 100
+    }
+}
+
+fn reason(h: &Harness) -> String {
+    String::from_str(&h.env, "inflated loss")
+}
+
+#[test]
+fn test_dispute_takes_a_claim_out_of_the_queue_until_resolved() {
+    let h = Harness::new();
+    let submitted = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    assert_eq!(
+        h.claims.try_raise_dispute(&h.admin, &submitted, &reason(&h)),
+        Err(Ok(ContractError::InvalidClaimState))
+    );
+    h.claims.start_review(&h.admin, &submitted);
+    h.claims.approve_claim(&h.admin, &submitted, &None);
+    let claim_id = submitted;
+    assert_eq!(h.claims.get_queue_position(&claim_id), Some(0));
+
+    assert_eq!(
+        h.claims.try_raise_dispute(&h.settler, &claim_id, &reason(&h)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.raise_dispute(&h.admin, &claim_id, &reason(&h));
+    assert_eq!(h.status(claim_id), ClaimStatus::Disputed);
+    assert_eq!(h.claims.get_queue_position(&claim_id), None);
+    assert!(h.claims.try_settle_claim(&h.settler, &claim_id).is_err());
+    assert_eq!(h.claims.get_dispute(&claim_id).unwrap().raised_by, h.admin);
+
+    // Dismissed, it goes back in the queue with its window closed
+    h.claims.resolve_dispute(&h.admin, &claim_id, &false);
+    assert_eq!(h.status(claim_id), ClaimStatus::PendingSettlement);
+    assert_eq!(h.claims.get_queue_position(&claim_id), Some(0));
+    let dispute = h.claims.get_dispute(&claim_id).unwrap();
+    assert!(dispute.resolved && !dispute.upheld);
+    assert_eq!(
+        h.claims.try_raise_dispute(&h.admin, &claim_id, &reason(&h)),
+        Err(Ok(ContractError::DisputeWindowClosed))
+    );
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+}
+
+#[test]
+fn test_dispute_bonds_are_paid_to_the_side_that_wins() {
+    let h = Harness::new();
+    let token = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    let minter = token::StellarAssetClient::new(&h.env, &token);
+    let balance = token::Client::new(&h.env, &token);
+    let treasury = BondTreasuryClient::new(&h.env, &h.env.register(BondTreasury, ()));
+    h.claims.allow_asset(&h.admin, &token);
+    h.claims.set_dispute_bond(
+        &h.admin,
+        &Some(DisputeBondConfig {
+            token: token.clone(),
+            amount: 1_000,
+            reward_bps: 1_000,
+            claimant_share_bps: 4_000,
+            treasury: treasury.address.clone(),
+        }),
+    );
+    minter.mint(&h.admin, &2_000);
+    minter.mint(&treasury.address, &500);
+
+    // Dismissed: the claimant and the treasury split the bond
+    let dismissed = h.approved_claim();
+    h.claims.raise_dispute(&h.admin, &dismissed, &reason(&h));
+    assert_eq!(balance.balance(&h.admin), 1_000);
+    assert_eq!(h.claims.get_dispute_bond(&dismissed).unwrap().amount, 1_000);
+    h.claims.resolve_dispute(&h.admin, &dismissed, &false);
+    assert_eq!(h.claims.get_dispute_bond(&dismissed), None);
+    assert_eq!(balance.balance(&h.holder), 400);
+    assert_eq!(treasury.fees(), 600);
+    assert_eq!(balance.balance(&h.claims.address), 0);
+
+    // Upheld: the bond comes back with the treasury's reward
+    let upheld = h.approved_claim_on(2, 1_000);
+    h.claims.raise_dispute(&h.admin, &upheld, &reason(&h));
+    h.claims.resolve_dispute(&h.admin, &upheld, &true);
+    assert_eq!(balance.balance(&h.admin), 1_100);
+    assert_eq!(balance.balance(&treasury.address), 1_000);
+}
+
+#[test]
+fn test_arbitration_panel_decides_by_majority() {
+    let h = Harness::new();
+    assert_eq!(
+        h.claims.try_set_arbitration_config(&h.admin, &ArbitrationConfig { panel_size: 2, max_open_cases: 1 }),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.set_arbitration_config(&h.admin, &ArbitrationConfig { panel_size: 3, max_open_cases: 1 });
+    let arbitrators = Vec::from_array(
+        &h.env,
+        [Address::generate(&h.env), Address::generate(&h.env), Address::generate(&h.env)],
+    );
+    for arbitrator in arbitrators.iter() {
+        h.claims.grant_arbitrator_role(&h.admin, &arbitrator);
+    }
+    let (a1, a2, a3) = (arbitrators.get(0).unwrap(), arbitrators.get(1).unwrap(), arbitrators.get(2).unwrap());
+
+    let claim_id = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    h.claims.start_review(&h.settler, &claim_id);
+    h.claims.approve_claim(&h.settler, &claim_id, &None);
+    h.claims.raise_dispute(&h.admin, &claim_id, &reason(&h));
+
+    // The approving processor cannot sit on the panel
+    h.claims.grant_arbitrator_role(&h.admin, &h.settler);
+    assert_eq!(
+        h.claims.try_form_panel(&h.admin, &claim_id, &Vec::from_array(&h.env, [a1.clone(), a2.clone(), h.settler.clone()])),
+        Err(Ok(ContractError::ConflictOfInterest))
+    );
+    h.claims.form_panel(&h.admin, &claim_id, &arbitrators);
+    assert_eq!(
+        h.claims.try_resolve_dispute(&h.admin, &claim_id, &true),
+        Err(Ok(ContractError::ArbitrationPending))
+    );
+
+    // Each arbitrator sits on one case at a time
+    let other = h.approved_claim_on(2, 1_000);
+    h.claims.raise_dispute(&h.admin, &other, &reason(&h));
+    assert_eq!(
+        h.claims.try_form_panel(&h.admin, &other, &arbitrators),
+        Err(Ok(ContractError::CaseLimitReached))
+    );
+
+    assert_eq!(
+        h.claims.try_record_arbitration_decision(&h.settler, &claim_id, &true),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.record_arbitration_decision(&a1, &claim_id, &true);
+    assert_eq!(
+        h.claims.try_record_arbitration_decision(&a1, &claim_id, &false),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+    h.claims.record_arbitration_decision(&a2, &claim_id, &false);
+    assert_eq!(h.status(claim_id), ClaimStatus::Disputed);
+    h.claims.record_arbitration_decision(&a3, &claim_id, &false);
+
+    assert_eq!(h.status(claim_id), ClaimStatus::PendingSettlement);
+    assert!(h.claims.get_arbitration_panel(&claim_id).unwrap().decided);
+    assert_eq!(h.claims.get_arbitration_decision(&claim_id, &a1), Some(true));
+    assert_eq!(h.claims.get_arbitrator_cases(&a1), 0);
+    h.claims.form_panel(&h.admin, &other, &arbitrators);
+}
+
+#[test]
+fn test_disputes_over_large_claims_are_decided_by_governance() {
+    let h = Harness::new();
+    let board = h.env.register(AppealBoard, ());
+    let config = |threshold_percentage: u32| AppealConfig {
+        governance: board.clone(),
+        min_amount: 3_000,
+        threshold_percentage,
+    };
+    assert_eq!(
+        h.claims.try_set_appeal_config(&h.admin, &Some(config(0))),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.set_appeal_config(&h.admin, &Some(config(60)));
+
+    // Below the threshold a dispute is resolved directly
+    let small = h.approved_claim_on(2, 1_000);
+    h.claims.raise_dispute(&h.admin, &small, &reason(&h));
+    h.claims.resolve_dispute(&h.admin, &small, &true);
+    assert_eq!(h.status(small), ClaimStatus::Rejected);
+    assert_eq!(h.claims.get_appeal(&small), None);
+
+    // At or above it, the first-instance outcome is only a recommendation
+    let large = h.approved_claim();
+    h.claims.raise_dispute(&h.admin, &large, &reason(&h));
+    h.claims.resolve_dispute(&h.admin, &large, &true);
+    assert_eq!(h.status(large), ClaimStatus::Disputed);
+    let appeal = h.claims.get_appeal(&large).unwrap();
+    assert_eq!((appeal.proposal_id, appeal.recommended_uphold, appeal.resolved), (large + 100, true, false));
+    assert_eq!(
+        h.claims.try_resolve_dispute(&h.admin, &large, &true),
+        Err(Ok(ContractError::AppealPending))
+    );
+
+    let stranger = h.env.register(AppealBoard, ());
+    assert!(h.claims.try_resolve_appeal(&stranger, &large, &false).is_err());
+    h.claims.resolve_appeal(&board, &large, &false);
+    assert_eq!(h.status(large), ClaimStatus::PendingSettlement);
+    assert!(h.claims.get_appeal(&large).unwrap().resolved);
+    assert_eq!(
+        h.claims.try_resolve_appeal(&board, &large, &false),
+        Err(Ok(ContractError::InvalidState))
+    );
+}
+
+#[test]
+fn test_reversal_charges_the_claimant_net_of_premium_credit() {
+    let h = Harness::new();
+    let claim_id = h.approved_claim();
+    assert_eq!(
+        h.claims.try_reverse_claim(&h.admin, &claim_id, &reason(&h), &true),
+        Err(Ok(ContractError::InvalidClaimState))
+    );
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.claims.get_claimant_profile(&h.holder).total_paid, CLAIM_AMOUNT);
+
+    assert_eq!(
+        h.claims.try_reverse_claim(&h.settler, &claim_id, &reason(&h), &true),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.policy.put_premium_credit(&h.holder, &1_500);
+    assert_eq!(h.claims.reverse_claim(&h.admin, &claim_id, &reason(&h), &true), CLAIM_AMOUNT - 1_500);
+    assert_eq!(h.claims.get_receivable(&h.holder), CLAIM_AMOUNT - 1_500);
+    assert_eq!(h.claims.get_reversal(&claim_id).unwrap().recovered, 1_500);
+    assert_eq!(h.pool.get_total_paid_out(), 0);
+    assert_eq!(h.claims.get_stats().total_paid_out, 0);
+
+    let profile = h.claims.get_claimant_profile(&h.holder);
+    assert_eq!((profile.reversed, profile.total_paid), (1, 0));
+    assert_eq!(
+        h.claims.try_reverse_claim(&h.admin, &claim_id, &reason(&h), &false),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+}
@@ -0,0 +1,964 @@
+#![cfg(test)]
+//! Claim products end to end
+//!
+//! Each cover type is configured on the claims contract and settled
+//! against the chaos stand-ins from the lifecycle tests, with the stand-ins
+//! healthy unless a test says otherwise.
+
+use super::*;
+use crate::test::{Harness, CLAIM_AMOUNT, COVERAGE, POLICY_ID};
+use insurance_contracts::chaos::{skew_clock, ChaosOracle, ChaosOracleClient, FailureMode, PolicyState};
+use soroban_sdk::testutils::Address as _;
+
+#[test]
+fn test_assigned_receivable_routes_payout_to_lender() {
+    let h = Harness::new();
+    let lender = Address::generate(&h.env);
+    let claim_id = h.approved_claim();
+
+    // Lenders need a cap, and assignments must fit in it
+    assert_eq!(
+        h.claims.try_assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000),
+        Err(Ok(ContractError::AssignmentCapExceeded))
+    );
+    h.claims.set_lender_cap(&h.admin, &lender, &Some(2_500));
+    assert_eq!(
+        h.claims.try_assign_claim_receivable(&h.holder, &claim_id, &lender, &3_000),
+        Err(Ok(ContractError::AssignmentCapExceeded))
+    );
+    h.claims.assign_claim_receivable(&h.holder, &claim_id, &lender, &2_500);
+    assert_eq!(h.claims.get_lender_exposure(&lender), (Some(2_500), 2_500));
+
+    // A revert at payout keeps the assignment in place
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+    h.fail(&h.pool.address, "payout_reserved_claim", FailureMode::Revert);
+    assert!(h.claims.try_settle_claim(&h.settler, &claim_id).is_err());
+    assert_eq!(h.pool.get_route(&claim_id), None);
+    assert!(h.claims.get_claim_assignment(&claim_id).is_some());
+
+    h.fail(&h.pool.address, "payout_reserved_claim", FailureMode::Healthy);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.pool.get_route(&claim_id), Some((lender.clone(), 2_500)));
+    assert_eq!(h.claims.get_claim_assignment(&claim_id), None);
+    assert_eq!(h.claims.get_lender_exposure(&lender), (Some(2_500), 0));
+}
+
+#[test]
+fn test_assignment_rejects_bad_terms_and_lapses_with_the_claim() {
+    let h = Harness::new();
+    let lender = Address::generate(&h.env);
+    h.claims.set_lender_cap(&h.admin, &lender, &Some(5_000));
+
+    // Only approved claims awaiting settlement can be assigned
+    let claim_id = h.reviewed_claim();
+    assert_eq!(
+        h.claims.try_assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000),
+        Err(Ok(ContractError::InvalidClaimState))
+    );
+    h.claims.approve_claim(&h.admin, &claim_id, &None);
+
+    let stranger = Address::generate(&h.env);
+    assert_eq!(
+        h.claims.try_assign_claim_receivable(&stranger, &claim_id, &lender, &1_000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    for amount in [0, CLAIM_AMOUNT + 1] {
+        assert_eq!(
+            h.claims.try_assign_claim_receivable(&h.holder, &claim_id, &lender, &amount),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+
+    h.claims.assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000);
+    assert_eq!(
+        h.claims.try_assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+
+    // Only the lender can give the payout back
+    assert_eq!(
+        h.claims.try_release_claim_assignment(&h.holder, &claim_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.release_claim_assignment(&lender, &claim_id);
+    assert_eq!(h.claims.get_claim_assignment(&claim_id), None);
+    assert_eq!(h.claims.get_lender_exposure(&lender), (Some(5_000), 0));
+
+    // An upheld dispute voids the assignment and frees the cap
+    h.claims.assign_claim_receivable(&h.holder, &claim_id, &lender, &1_500);
+    h.claims.raise_dispute(&h.admin, &claim_id, &String::from_str(&h.env, "forged invoice"));
+    h.claims.resolve_dispute(&h.admin, &claim_id, &true);
+    assert_eq!(h.claims.get_claim_assignment(&claim_id), None);
+    assert_eq!(h.claims.get_lender_exposure(&lender), (Some(5_000), 0));
+}
+
+#[test]
+fn test_assignment_follows_the_settled_payout_and_survives_reversal() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &false, &3);
+    h.claims.set_reference_pricing(
+        &h.admin,
+        &0,
+        &Some(ReferencePricing {
+            rate_data_id: 4,
+            max_age_secs: 600,
+            max_deviation_bps: 1_000,
+        }),
+    );
+    oracle.put_data(&4, &2_000_000, &3);
+    let lender = Address::generate(&h.env);
+    h.claims.set_lender_cap(&h.admin, &lender, &Some(1_000));
+    let claim_id = h.approved_claim();
+    h.claims.assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000);
+
+    // A quarter of the claim is a quarter of what the pool pays at the
+    // settlement rate; the rest goes to the claimant
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+    oracle.put_data(&4, &2_100_000, &3);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.pool.get_total_paid_out(), 19_047);
+    assert_eq!(h.pool.get_route(&claim_id), Some((lender.clone(), 4_761)));
+    assert_eq!(h.pool.get_recipient(&claim_id), Some(h.holder.clone()));
+
+    // Reversing it charges the claimant for the whole payout
+    let reason = String::from_str(&h.env, "staged loss");
+    assert_eq!(h.claims.reverse_claim(&h.admin, &claim_id, &reason, &false), 19_047);
+    assert_eq!(h.claims.get_receivable(&h.holder), 19_047);
+    assert_eq!(h.claims.get_receivable(&lender), 0);
+    assert_eq!(h.claims.get_lender_exposure(&lender), (Some(1_000), 0));
+}
+
+#[test]
+fn test_processors_cannot_act_on_claims_they_are_paid_on() {
+    let h = Harness::new();
+
+    // The claimant
+    h.claims.grant_processor_role(&h.admin, &h.holder);
+    let claim_id = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    assert_eq!(
+        h.claims.try_start_review(&h.holder, &claim_id),
+        Err(Ok(ContractError::ProcessorIsClaimant))
+    );
+
+    // The lender the payout is assigned to
+    let h = Harness::new();
+    let lender = Address::generate(&h.env);
+    h.claims.grant_processor_role(&h.admin, &lender);
+    h.claims.set_lender_cap(&h.admin, &lender, &Some(CLAIM_AMOUNT));
+    let claim_id = h.approved_claim();
+    h.claims.assign_claim_receivable(&h.holder, &claim_id, &lender, &1_000);
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+    assert_eq!(
+        h.claims.try_flag_hardship(&lender, &claim_id),
+        Err(Ok(ContractError::ProcessorIsClaimant))
+    );
+    assert_eq!(
+        h.claims.try_settle_claim(&lender, &claim_id),
+        Err(Ok(ContractError::ProcessorIsClaimant))
+    );
+    h.claims.release_claim_assignment(&lender, &claim_id);
+    h.claims.flag_hardship(&lender, &claim_id);
+
+    // The payee paid in the claimant's place, here a key-loss recovery
+    // address with the payout held back by the delay
+    let h = Harness::new();
+    let guardians = Vec::from_array(&h.env, [Address::generate(&h.env), Address::generate(&h.env)]);
+    let recovery = Address::generate(&h.env);
+    h.claims.grant_processor_role(&h.admin, &recovery);
+    h.claims.set_payout_delay(&h.admin, &1, &3_600, &h.admin);
+    h.claims.set_key_loss_cover(
+        &h.admin,
+        &0,
+        &Some(KeyLossCover {
+            payout_bps: 5_000,
+            delay_secs: 3_600,
+            challenge_window_secs: 60,
+        }),
+    );
+    h.claims.register_recovery(&h.holder, &POLICY_ID, &guardians, &2, &recovery);
+    skew_clock(&h.env, 3_600);
+    h.claims.attest_key_loss(&guardians.get(0).unwrap(), &POLICY_ID);
+    h.claims.attest_key_loss(&guardians.get(1).unwrap(), &POLICY_ID);
+    skew_clock(&h.env, 3_600);
+    let claim_id = h.claims.settle_key_loss(&POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Approved);
+    assert_eq!(
+        h.claims.try_flag_hardship(&recovery, &claim_id),
+        Err(Ok(ContractError::ProcessorIsClaimant))
+    );
+}
+
+#[test]
+fn test_parametric_claim_settles_in_one_call() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    h.claims.set_parametric_trigger(
+        &h.admin,
+        &0,
+        &Some(ParametricTrigger {
+            data_id: 9,
+            threshold: 200,
+            above: true,
+            payout_bps: 5_000,
+            max_data_age_secs: 3_600,
+        }),
+    );
+
+    // Below the threshold nothing is claimed or reserved
+    oracle.put_data(&9, &150, &3);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+    assert_eq!(h.pool.get_total_reserved(), 0);
+
+    // A reading that timed out carries no submissions and is refused
+    oracle.put_data(&9, &250, &3);
+    oracle.set_failure(&Symbol::new(&h.env, "resolve_oracle_data"), &FailureMode::Timeout);
+    assert!(h.claims.try_claim_and_settle(&h.holder, &POLICY_ID).is_err());
+
+    oracle.set_failure(&Symbol::new(&h.env, "resolve_oracle_data"), &FailureMode::Healthy);
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE / 2);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+    assert_eq!(h.claims.get_stats().open_claims, 0);
+
+    // One claim per policy
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+}
+
+#[test]
+fn test_claims_filed_in_one_ledger_get_distinct_ids() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    h.claims.set_parametric_trigger(
+        &h.admin,
+        &0,
+        &Some(ParametricTrigger {
+            data_id: 9,
+            threshold: 200,
+            above: true,
+            payout_bps: 5_000,
+            max_data_age_secs: 3_600,
+        }),
+    );
+    oracle.put_data(&9, &250, &3);
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+
+    // A reviewed claim and a triggered claim in the same ledger must not
+    // overwrite each other
+    let submitted = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    let triggered = h.claims.claim_and_settle(&h.holder, &2);
+    assert_ne!(submitted, triggered);
+    assert_eq!(h.claims.get_claim(&submitted).0, POLICY_ID);
+    assert_eq!(h.status(submitted), ClaimStatus::Submitted);
+    assert_eq!(h.claims.get_claim(&triggered).0, 2);
+    assert_eq!(h.status(triggered), ClaimStatus::Settled);
+    assert_eq!(h.claims.get_stats().total_submitted, 2);
+}
+
+#[test]
+fn test_parametric_trigger_rejects_bad_terms_and_readings_outside_the_term() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    let trigger = ParametricTrigger {
+        data_id: 9,
+        threshold: 100,
+        above: false,
+        payout_bps: 2_500,
+        max_data_age_secs: 3_600,
+    };
+
+    // No trigger for the product means nothing to settle against
+    oracle.put_data(&9, &80, &3);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::NotFound))
+    );
+
+    for (payout_bps, max_data_age_secs) in [(0, 3_600), (10_001, 3_600), (2_500, 0)] {
+        let bad = ParametricTrigger { payout_bps, max_data_age_secs, ..trigger.clone() };
+        assert_eq!(
+            h.claims.try_set_parametric_trigger(&h.admin, &0, &Some(bad)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    let stranger = Address::generate(&h.env);
+    assert_eq!(
+        h.claims.try_set_parametric_trigger(&stranger, &0, &Some(trigger.clone())),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.set_parametric_trigger(&h.admin, &0, &Some(trigger));
+
+    // Only the holder may claim
+    assert_eq!(
+        h.claims.try_claim_and_settle(&stranger, &POLICY_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    // A reading above a falling threshold does not trigger
+    oracle.put_data(&9, &150, &3);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // A qualifying reading taken before a later policy started does not count for it
+    oracle.put_data(&9, &80, &3);
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &2_000, &100_000);
+    skew_clock(&h.env, 1_500);
+    assert_eq!(h.claims.try_claim_and_settle(&h.holder, &2), Err(Ok(ContractError::TriggerNotMet)));
+
+    // Nor once it is older than the trigger allows
+    skew_clock(&h.env, 2_101);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::OracleDataStale))
+    );
+    assert_eq!(h.pool.get_total_reserved(), 0);
+
+    oracle.put_data(&9, &100, &3);
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE / 4);
+
+    // Removing the trigger closes the product to one-call claims
+    h.claims.set_parametric_trigger(&h.admin, &0, &None);
+    assert_eq!(h.claims.try_claim_and_settle(&h.holder, &2), Err(Ok(ContractError::NotFound)));
+}
+
+#[test]
+fn test_depeg_claim_needs_sustained_twap_below_strike() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    h.claims.set_depeg_cover(
+        &h.admin,
+        &0,
+        &Some(DepegCover {
+            feed_data_id: 7,
+            strike: 9_800,
+            twap_window_secs: 600,
+            min_duration_secs: 1_800,
+            max_gap_secs: 900,
+            payout_bps: 10_000,
+        }),
+    );
+    let observe = |price: i128| {
+        oracle.put_data(&7, &price, &3);
+        h.claims.observe_peg(&0)
+    };
+
+    // On peg for the first window, then below the strike from 1_600
+    observe(10_000);
+    skew_clock(&h.env, 600);
+    assert_eq!(observe(9_500).below_since, None);
+    skew_clock(&h.env, 600);
+    assert_eq!(observe(9_500).below_since, Some(1_600));
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // A brief recovery inside a window does not break the run
+    skew_clock(&h.env, 300);
+    observe(10_000);
+    skew_clock(&h.env, 300);
+    let watch = observe(9_500);
+    assert_eq!(watch.last_twap, 9_750);
+    assert_eq!(watch.last_depeg_confirmed_at, 0);
+
+    skew_clock(&h.env, 600);
+    let watch = observe(9_500);
+    assert_eq!((watch.last_depeg_at, watch.last_depeg_confirmed_at), (1_600, 3_400));
+
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE);
+
+    // A gap in observations starts the run over but keeps the depeg
+    skew_clock(&h.env, 901);
+    let watch = observe(9_500);
+    assert_eq!(watch.below_since, None);
+    assert_eq!((watch.last_depeg_at, watch.last_depeg_confirmed_at), (1_600, 3_400));
+}
+
+#[test]
+fn test_depeg_cover_rejects_bad_terms_recoveries_and_depegs_before_the_term() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &true, &3);
+    let cover = DepegCover {
+        feed_data_id: 7,
+        strike: 9_800,
+        twap_window_secs: 600,
+        min_duration_secs: 1_800,
+        max_gap_secs: 900,
+        payout_bps: 10_000,
+    };
+
+    for bad in [
+        DepegCover { strike: 0, ..cover.clone() },
+        DepegCover { twap_window_secs: 0, ..cover.clone() },
+        DepegCover { min_duration_secs: 599, ..cover.clone() },
+        DepegCover { max_gap_secs: 0, ..cover.clone() },
+        DepegCover { payout_bps: 0, ..cover.clone() },
+        DepegCover { payout_bps: 10_001, ..cover.clone() },
+    ] {
+        assert_eq!(
+            h.claims.try_set_depeg_cover(&h.admin, &0, &Some(bad)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.claims.set_depeg_cover(&h.admin, &0, &Some(cover));
+    assert_eq!(h.claims.try_observe_peg(&1), Err(Ok(ContractError::NotFound)));
+
+    let observe = |price: i128| {
+        oracle.put_data(&7, &price, &3);
+        h.claims.observe_peg(&0)
+    };
+
+    // A run that recovers before the minimum duration confirms nothing
+    observe(9_500);
+    skew_clock(&h.env, 600);
+    assert_eq!(observe(9_500).below_since, Some(1_000));
+    skew_clock(&h.env, 600);
+    observe(10_000);
+    skew_clock(&h.env, 600);
+    let watch = observe(10_000);
+    assert_eq!(watch.below_since, None);
+    assert_eq!(watch.last_depeg_confirmed_at, 0);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // A price older than the allowed gap is not observed
+    skew_clock(&h.env, 901);
+    assert_eq!(h.claims.try_observe_peg(&0), Err(Ok(ContractError::OracleDataStale)));
+    assert_eq!(h.claims.get_peg_watch(&0).unwrap().window.last_at, 2_800);
+
+    // A depeg from 3_701, confirmed at 5_501, and only once
+    for _ in 0..3 {
+        observe(9_500);
+        skew_clock(&h.env, 600);
+    }
+    let watch = observe(9_500);
+    assert_eq!((watch.last_depeg_at, watch.last_depeg_confirmed_at), (3_701, 5_501));
+    skew_clock(&h.env, 600);
+    assert_eq!(observe(9_500).last_depeg_confirmed_at, 5_501);
+
+    // It began before a policy starting at 4_000, so only the earlier one is paid
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &4_000, &100_000);
+    assert_eq!(h.claims.try_claim_and_settle(&h.holder, &2), Err(Ok(ContractError::TriggerNotMet)));
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+}
+
+#[test]
+fn test_downtime_claim_pays_tier_for_hours_in_term() {
+    let h = Harness::new();
+    let attestor = Address::generate(&h.env);
+    let node = BytesN::from_array(&h.env, &[7; 32]);
+    h.policy.put_policy_node(&POLICY_ID, &node);
+    h.claims.set_uptime_attestor(&h.admin, &Some(attestor.clone()));
+    h.claims.set_downtime_tiers(
+        &h.admin,
+        &0,
+        &Vec::from_array(
+            &h.env,
+            [
+                DowntimeTier { min_hours: 2, payout_bps: 2_500 },
+                DowntimeTier { min_hours: 6, payout_bps: 10_000 },
+            ],
+        ),
+    );
+
+    // The term covers days 0 and 1; only finished days can be reported
+    assert_eq!(
+        h.claims.try_report_node_downtime(&attestor, &node, &0, &3_600),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    skew_clock(&h.env, 3 * 86_400);
+    h.claims.report_node_downtime(&attestor, &node, &0, &3_600);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // Days are reported in order, by the attestor only
+    assert_eq!(
+        h.claims.try_report_node_downtime(&attestor, &node, &2, &0),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        h.claims.try_report_node_downtime(&h.holder, &node, &1, &0),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.report_node_downtime(&attestor, &node, &1, &7_200);
+
+    // A full outage after the term does not lift the tier
+    h.claims.report_node_downtime(&attestor, &node, &2, &86_400);
+    assert_eq!(h.claims.get_node_downtime(&node).unwrap().total_secs, 97_200);
+
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE / 4);
+}
+
+#[test]
+fn test_downtime_cover_rejects_bad_tiers_and_reports_and_counts_only_the_term() {
+    let h = Harness::new();
+    let attestor = Address::generate(&h.env);
+    let node = BytesN::from_array(&h.env, &[7; 32]);
+    let tier = |min_hours: u64, payout_bps: u32| DowntimeTier { min_hours, payout_bps };
+
+    for bad in [
+        [tier(0, 2_500), tier(6, 10_000)],
+        [tier(2, 0), tier(6, 10_000)],
+        [tier(2, 2_500), tier(6, MAX_BPS + 1)],
+        [tier(2, 2_500), tier(2, 10_000)],
+        [tier(2, 2_500), tier(6, 2_500)],
+    ] {
+        assert_eq!(
+            h.claims.try_set_downtime_tiers(&h.admin, &0, &Vec::from_array(&h.env, bad)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    let mut too_many = Vec::new(&h.env);
+    for i in 1..=MAX_DOWNTIME_TIERS {
+        too_many.push_back(tier(i as u64, i * 1_000));
+    }
+    too_many.push_back(tier(100, 10_000));
+    assert_eq!(
+        h.claims.try_set_downtime_tiers(&h.admin, &0, &too_many),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.set_downtime_tiers(&h.admin, &0, &Vec::from_array(&h.env, [tier(2, 2_500), tier(6, 10_000)]));
+
+    // Nothing can be reported before an attestor is set
+    skew_clock(&h.env, 3 * 86_400);
+    assert_eq!(
+        h.claims.try_report_node_downtime(&attestor, &node, &0, &3_600),
+        Err(Ok(ContractError::NotInitialized))
+    );
+    h.claims.set_uptime_attestor(&h.admin, &Some(attestor.clone()));
+
+    // A policy without a node, or a node without reports, has no claim
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::NotFound))
+    );
+    h.policy.put_policy_node(&POLICY_ID, &node);
+    assert_eq!(
+        h.claims.try_claim_and_settle(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // A day holds at most a day of downtime and cannot be revised
+    assert_eq!(
+        h.claims.try_report_node_downtime(&attestor, &node, &0, &86_401),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.report_node_downtime(&attestor, &node, &0, &(6 * 3_600));
+    assert_eq!(
+        h.claims.try_report_node_downtime(&attestor, &node, &0, &0),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.report_node_downtime(&attestor, &node, &1, &3_600);
+
+    // Downtime on the day before a policy starts is not counted for it
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &86_400, &200_000);
+    h.policy.put_policy_node(&2, &node);
+    assert_eq!(h.claims.try_claim_and_settle(&h.holder, &2), Err(Ok(ContractError::TriggerNotMet)));
+
+    // Seven hours over the first term reach the top tier
+    let claim_id = h.claims.claim_and_settle(&h.holder, &POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE);
+}
+
+#[test]
+fn test_key_loss_pays_recovery_address_after_guardians_and_challenge() {
+    let h = Harness::new();
+    let guardians = Vec::from_array(
+        &h.env,
+        [Address::generate(&h.env), Address::generate(&h.env), Address::generate(&h.env)],
+    );
+    let recovery = Address::generate(&h.env);
+    h.claims.set_key_loss_cover(
+        &h.admin,
+        &0,
+        &Some(KeyLossCover {
+            payout_bps: 10_000,
+            delay_secs: 3_600,
+            challenge_window_secs: 86_400,
+        }),
+    );
+    h.claims.register_recovery(&h.holder, &POLICY_ID, &guardians, &2, &recovery);
+    let (g1, g2) = (guardians.get(0).unwrap(), guardians.get(1).unwrap());
+
+    // A fresh setup cannot be used, nor can outsiders attest
+    assert_eq!(
+        h.claims.try_attest_key_loss(&g1, &POLICY_ID),
+        Err(Ok(ContractError::InvalidState))
+    );
+    skew_clock(&h.env, 3_600);
+    assert_eq!(
+        h.claims.try_attest_key_loss(&recovery, &POLICY_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(h.claims.attest_key_loss(&g1, &POLICY_ID), 1);
+    assert_eq!(
+        h.claims.try_attest_key_loss(&g1, &POLICY_ID),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+    assert_eq!(h.claims.attest_key_loss(&g2, &POLICY_ID), 2);
+    assert_eq!(
+        h.claims.try_settle_key_loss(&POLICY_ID),
+        Err(Ok(ContractError::PayoutPending))
+    );
+
+    // The holder signing proves the key is not lost
+    h.claims.challenge_key_loss(&h.holder, &POLICY_ID);
+    assert_eq!(h.claims.get_key_loss_claim(&POLICY_ID), None);
+
+    h.claims.attest_key_loss(&g1, &POLICY_ID);
+    h.claims.attest_key_loss(&g2, &POLICY_ID);
+    skew_clock(&h.env, 86_401);
+    assert_eq!(
+        h.claims.try_challenge_key_loss(&h.holder, &POLICY_ID),
+        Err(Ok(ContractError::DisputeWindowClosed))
+    );
+
+    let claim_id = h.claims.settle_key_loss(&POLICY_ID);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE);
+    assert_eq!(h.pool.get_recipient(&claim_id), Some(recovery));
+}
+
+#[test]
+fn test_key_loss_rejects_bad_setups_short_attestations_and_late_claims() {
+    let h = Harness::new();
+    let guardians = Vec::from_array(
+        &h.env,
+        [Address::generate(&h.env), Address::generate(&h.env), Address::generate(&h.env)],
+    );
+    let recovery = Address::generate(&h.env);
+    let (g1, g2) = (guardians.get(0).unwrap(), guardians.get(1).unwrap());
+    let cover = KeyLossCover {
+        payout_bps: 10_000,
+        delay_secs: 3_600,
+        challenge_window_secs: 86_400,
+    };
+
+    for bad in [
+        KeyLossCover { payout_bps: 0, ..cover.clone() },
+        KeyLossCover { payout_bps: MAX_BPS + 1, ..cover.clone() },
+        KeyLossCover { delay_secs: 0, ..cover.clone() },
+        KeyLossCover { challenge_window_secs: 0, ..cover.clone() },
+    ] {
+        assert_eq!(
+            h.claims.try_set_key_loss_cover(&h.admin, &0, &Some(bad)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.claims.set_key_loss_cover(&h.admin, &0, &Some(cover));
+
+    // Only the holder registers, with distinct guardians other than itself
+    assert_eq!(
+        h.claims.try_register_recovery(&g1, &POLICY_ID, &guardians, &2, &recovery),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    let mut with_holder = guardians.clone();
+    with_holder.set(2, h.holder.clone());
+    let mut repeated = guardians.clone();
+    repeated.set(2, g1.clone());
+    for (bad_guardians, threshold, recovery_address) in [
+        (Vec::new(&h.env), 1, recovery.clone()),
+        (guardians.clone(), 0, recovery.clone()),
+        (guardians.clone(), 4, recovery.clone()),
+        (guardians.clone(), 2, h.holder.clone()),
+        (with_holder, 2, recovery.clone()),
+        (repeated, 2, recovery.clone()),
+    ] {
+        assert_eq!(
+            h.claims.try_register_recovery(&h.holder, &POLICY_ID, &bad_guardians, &threshold, &recovery_address),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    assert_eq!(
+        h.claims.try_attest_key_loss(&g1, &POLICY_ID),
+        Err(Ok(ContractError::NotFound))
+    );
+    h.claims.register_recovery(&h.holder, &POLICY_ID, &guardians, &2, &recovery);
+
+    // Below the threshold the claim is open but cannot be paid
+    skew_clock(&h.env, 3_600);
+    assert_eq!(h.claims.try_settle_key_loss(&POLICY_ID), Err(Ok(ContractError::NotFound)));
+    h.claims.attest_key_loss(&g1, &POLICY_ID);
+    assert_eq!(
+        h.claims.try_settle_key_loss(&POLICY_ID),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(
+        h.claims.try_register_recovery(&h.holder, &POLICY_ID, &guardians, &2, &recovery),
+        Err(Ok(ContractError::InvalidState))
+    );
+
+    // Outsiders cannot refute it, governance can
+    assert_eq!(
+        h.claims.try_challenge_key_loss(&g2, &POLICY_ID),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.challenge_key_loss(&h.admin, &POLICY_ID);
+    assert_eq!(h.claims.get_key_loss_claim(&POLICY_ID), None);
+
+    // A claim cannot be opened once the term has ended
+    skew_clock(&h.env, 100_000);
+    assert_eq!(
+        h.claims.try_attest_key_loss(&g1, &POLICY_ID),
+        Err(Ok(ContractError::PolicyExpired))
+    );
+    assert_eq!(h.pool.get_total_paid_out(), 0);
+}
+
+#[test]
+fn test_bridge_claim_pays_holding_pro_rata_after_committee_and_governance() {
+    let h = Harness::new();
+    let wrapped = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    token::StellarAssetClient::new(&h.env, &wrapped).mint(&h.holder, &4_000);
+    h.claims.allow_asset(&h.admin, &wrapped);
+    h.claims.set_bridge_cover(&h.admin, &0, &Some(BridgeCover { bridge_id: 7, asset: wrapped }));
+
+    let (a, b, c) = (Address::generate(&h.env), Address::generate(&h.env), Address::generate(&h.env));
+    h.claims.set_bridge_committee(&h.admin, &Vec::from_array(&h.env, [a.clone(), b.clone()]), &2);
+    assert_eq!(
+        h.claims.try_declare_bridge_event(&c, &7, &BridgeFailure::Hack, &500, &5_000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    let event_id = h.claims.declare_bridge_event(&a, &7, &BridgeFailure::Hack, &500, &5_000);
+    assert_eq!(
+        h.claims.try_confirm_bridge_event(&h.admin, &event_id),
+        Err(Ok(ContractError::InvalidState))
+    );
+
+    // Rotation drops the old committee's attestations
+    let epoch = h.claims.set_bridge_committee(&h.admin, &Vec::from_array(&h.env, [b.clone(), c.clone()]), &2);
+    assert_eq!(epoch, 2);
+    assert_eq!(
+        h.claims.try_attest_bridge_event(&a, &event_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(h.claims.attest_bridge_event(&b, &event_id), 1);
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &POLICY_ID, &event_id),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+    assert_eq!(h.claims.attest_bridge_event(&c, &event_id), 2);
+    h.claims.confirm_bridge_event(&h.admin, &event_id);
+
+    // Half the 4_000 held, not half the 10_000 coverage
+    let claim_id = h.claims.claim_bridge_event(&h.holder, &POLICY_ID, &event_id);
+    assert_eq!(h.status(claim_id), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), 2_000);
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &POLICY_ID, &event_id),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+}
+
+#[test]
+fn test_bridge_cover_rejects_bad_declarations_and_events_outside_the_cover() {
+    let h = Harness::new();
+    let wrapped = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    token::StellarAssetClient::new(&h.env, &wrapped).mint(&h.holder, &20_000);
+    let cover = BridgeCover { bridge_id: 7, asset: wrapped.clone() };
+    assert_eq!(
+        h.claims.try_set_bridge_cover(&h.admin, &0, &Some(cover.clone())),
+        Err(Ok(ContractError::AssetNotAllowed))
+    );
+    h.claims.allow_asset(&h.admin, &wrapped);
+
+    let (a, b) = (Address::generate(&h.env), Address::generate(&h.env));
+    let members = Vec::from_array(&h.env, [a.clone(), b.clone()]);
+    for (bad_members, threshold) in [
+        (Vec::new(&h.env), 1),
+        (members.clone(), 0),
+        (members.clone(), 3),
+        (Vec::from_array(&h.env, [a.clone(), a.clone()]), 1),
+    ] {
+        assert_eq!(
+            h.claims.try_set_bridge_committee(&h.admin, &bad_members, &threshold),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    assert_eq!(
+        h.claims.try_declare_bridge_event(&a, &7, &BridgeFailure::Hack, &500, &5_000),
+        Err(Ok(ContractError::NotInitialized))
+    );
+    h.claims.set_bridge_committee(&h.admin, &members, &2);
+
+    // Losses are a share of the holding, and events cannot be declared ahead
+    for (occurred_at, loss_bps) in [(500, 0), (500, MAX_BPS + 1), (1_001, 5_000)] {
+        assert_eq!(
+            h.claims.try_declare_bridge_event(&a, &7, &BridgeFailure::Hack, &occurred_at, &loss_bps),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    let event_id = h.claims.declare_bridge_event(&a, &7, &BridgeFailure::Hack, &500, &5_000);
+    assert_eq!(
+        h.claims.try_attest_bridge_event(&a, &event_id),
+        Err(Ok(ContractError::AlreadyExists))
+    );
+    h.claims.attest_bridge_event(&b, &event_id);
+    assert_eq!(
+        h.claims.try_confirm_bridge_event(&a, &event_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.claims.confirm_bridge_event(&h.admin, &event_id);
+    assert_eq!(
+        h.claims.try_confirm_bridge_event(&h.admin, &event_id),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(
+        h.claims.try_attest_bridge_event(&b, &event_id),
+        Err(Ok(ContractError::InvalidState))
+    );
+
+    // A product without the cover, or a stranger, cannot claim
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &POLICY_ID, &event_id),
+        Err(Ok(ContractError::NotFound))
+    );
+    h.claims.set_bridge_cover(&h.admin, &0, &Some(cover));
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&a, &POLICY_ID, &event_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &POLICY_ID, &(event_id + 1)),
+        Err(Ok(ContractError::NotFound))
+    );
+
+    // Another bridge's failure, or one before the term, is not covered
+    let other = h.claims.declare_bridge_event(&a, &8, &BridgeFailure::Hack, &500, &5_000);
+    h.claims.attest_bridge_event(&b, &other);
+    h.claims.confirm_bridge_event(&h.admin, &other);
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &POLICY_ID, &other),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &800, &100_000);
+    assert_eq!(
+        h.claims.try_claim_bridge_event(&h.holder, &2, &event_id),
+        Err(Ok(ContractError::TriggerNotMet))
+    );
+
+    // A holding above the coverage is paid on the coverage
+    h.claims.claim_bridge_event(&h.holder, &POLICY_ID, &event_id);
+    assert_eq!(h.pool.get_total_paid_out(), COVERAGE / 2);
+}
+
+#[test]
+fn test_reference_priced_claim_settles_at_bounded_rate() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &false, &3);
+    h.claims.set_reference_pricing(
+        &h.admin,
+        &0,
+        &Some(ReferencePricing {
+            rate_data_id: 4,
+            max_age_secs: 600,
+            max_deviation_bps: 1_000,
+        }),
+    );
+
+    // 0.20 reference units per pool unit: 4_000 reserves 20_000 pool units
+    oracle.put_data(&4, &2_000_000, &3);
+    let claim_id = h.approved_claim();
+    assert_eq!(h.claims.get_claim_rate(&claim_id), Some(2_000_000));
+    assert_eq!(h.pool.get_reservation(&claim_id), 20_000);
+
+    // The approval rate is too old to settle with
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+    assert_eq!(
+        h.claims.try_settle_claim(&h.settler, &claim_id),
+        Err(Ok(ContractError::OracleDataStale))
+    );
+
+    // A fresh rate 25% away is outside the bound
+    oracle.put_data(&4, &2_500_000, &3);
+    assert_eq!(
+        h.claims.try_settle_claim(&h.settler, &claim_id),
+        Err(Ok(ContractError::RateDeviation))
+    );
+    assert_eq!(h.pool.get_reservation(&claim_id), 20_000);
+
+    // Within it the payout follows the rate, rounded down
+    oracle.put_data(&4, &2_100_000, &3);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.pool.get_total_paid_out(), 19_047);
+    assert_eq!(h.claims.get_stats().total_paid_out, 19_047);
+    assert_eq!(h.policy.get_recorded(&POLICY_ID), CLAIM_AMOUNT);
+}
+
+#[test]
+fn test_reference_pricing_rejects_bad_terms_and_non_positive_rates() {
+    let h = Harness::new();
+    let oracle = ChaosOracleClient::new(&h.env, &h.env.register(ChaosOracle, ()));
+    h.claims.set_oracle_config(&h.admin, &oracle.address, &false, &3);
+    let pricing = ReferencePricing {
+        rate_data_id: 4,
+        max_age_secs: 600,
+        max_deviation_bps: 1_000,
+    };
+
+    for (max_age_secs, max_deviation_bps) in [(0, 1_000), (600, MAX_BPS + 1)] {
+        let bad = ReferencePricing { max_age_secs, max_deviation_bps, ..pricing.clone() };
+        assert_eq!(
+            h.claims.try_set_reference_pricing(&h.admin, &0, &Some(bad)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.claims.set_reference_pricing(&h.admin, &0, &Some(pricing));
+
+    // A zero rate cannot price a reservation; the claim stays under review
+    oracle.put_data(&4, &0, &3);
+    let claim_id = h.reviewed_claim();
+    assert_eq!(
+        h.claims.try_approve_claim(&h.admin, &claim_id, &None),
+        Err(Ok(ContractError::OracleValidationFailed))
+    );
+    assert_eq!(h.status(claim_id), ClaimStatus::UnderReview);
+    assert_eq!(h.claims.get_claim_rate(&claim_id), None);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+
+    oracle.put_data(&4, &2_000_000, &3);
+    h.claims.approve_claim(&h.admin, &claim_id, &None);
+    assert_eq!(h.pool.get_reservation(&claim_id), 20_000);
+
+    // Nor a negative one settle it
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 + 1);
+    oracle.put_data(&4, &-2_000_000, &3);
+    assert_eq!(
+        h.claims.try_settle_claim(&h.settler, &claim_id),
+        Err(Ok(ContractError::OracleValidationFailed))
+    );
+    assert_eq!(h.status(claim_id), ClaimStatus::PendingSettlement);
+
+    // A rate exactly at the deviation bound still settles
+    oracle.put_data(&4, &2_200_000, &3);
+    h.claims.settle_claim(&h.settler, &claim_id);
+    assert_eq!(h.pool.get_total_paid_out(), 18_181);
+    assert_eq!(h.pool.get_total_reserved(), 0);
+}
@@ -0,0 +1,286 @@
+#![cfg(test)]
+//! Settlement ordering, payout limits and processor accountability
+//!
+//! Approved claims against the chaos stand-ins, run through the dispute
+//! window, the settlement queue and the limits governance can put on
+//! payouts, with the processors' records and stakes checked along the way.
+
+use super::*;
+use crate::test::{Harness, CLAIM_AMOUNT, COVERAGE, POLICY_ID};
+use insurance_contracts::chaos::{skew_clock, PolicyState};
+use soroban_sdk::testutils::Address as _;
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_settlement_waits_out_the_dispute_window() {
+    let h = Harness::new();
+    let first = h.approved_claim();
+    let second = h.approved_claim_on(2, 1_000);
+    assert_eq!(h.status(first), ClaimStatus::PendingSettlement);
+    assert_eq!(h.claims.get_dispute_window_end(&first), Some(1_000 + DEFAULT_DISPUTE_WINDOW_SECONDS));
+
+    // Neither a processor nor a keeper can settle inside the window
+    assert_eq!(h.claims.try_settle_claim(&h.settler, &first), Err(Ok(ContractError::DisputeWindowOpen)));
+    assert_eq!(h.claims.process_settlements(&10), 0);
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64 - 1);
+    assert_eq!(h.claims.process_settlements(&10), 0);
+    assert_eq!(h.claims.get_settlement_queue(), Vec::from_array(&h.env, [first, second]));
+
+    // Once it closes the queue drains in order
+    skew_clock(&h.env, 1);
+    assert_eq!(h.claims.process_settlements(&10), 2);
+    assert_eq!(h.status(first), ClaimStatus::Settled);
+    assert_eq!(h.status(second), ClaimStatus::Settled);
+    assert_eq!(h.claims.get_settlement_queue().len(), 0);
+    assert_eq!(h.pool.get_total_paid_out(), CLAIM_AMOUNT + 1_000);
+}
+
+#[test]
+fn test_dispute_window_tiers_follow_claim_size() {
+    let h = Harness::new();
+    let tier = |min_amount: i128, window_secs: u64| DisputeTier { min_amount, window_secs };
+
+    // Tiers must grow in both amount and window, and never undercut the base
+    for tiers in [
+        [tier(5_000, 2 * DAY), tier(3_000, 5 * DAY)],
+        [tier(3_000, 5 * DAY), tier(5_000, 2 * DAY)],
+        [tier(3_000, DAY / 2), tier(5_000, 2 * DAY)],
+    ] {
+        assert_eq!(
+            h.claims.try_set_dispute_tiers(&h.admin, &Vec::from_array(&h.env, tiers)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.claims.set_dispute_tiers(&h.admin, &Vec::from_array(&h.env, [tier(3_000, 2 * DAY), tier(8_000, 5 * DAY)]));
+    assert_eq!(h.claims.try_set_dispute_window(&h.admin, &(3 * DAY)), Err(Ok(ContractError::InvalidInput)));
+
+    assert_eq!(h.claims.get_dispute_window_for(&2_999), DEFAULT_DISPUTE_WINDOW_SECONDS);
+    assert_eq!(h.claims.get_dispute_window_for(&3_000), 2 * DAY);
+    assert_eq!(h.claims.get_dispute_window_for(&9_000), 5 * DAY);
+
+    // A claim keeps the window it was approved with
+    let claim_id = h.approved_claim();
+    h.claims.set_dispute_tiers(&h.admin, &Vec::new(&h.env));
+    assert_eq!(h.claims.get_claim_dispute_window(&claim_id), Some(2 * DAY));
+    skew_clock(&h.env, DAY as i64 + 1);
+    assert_eq!(h.claims.try_settle_claim(&h.settler, &claim_id), Err(Ok(ContractError::DisputeWindowOpen)));
+    skew_clock(&h.env, DAY as i64);
+    h.claims.settle_claim(&h.settler, &claim_id);
+}
+
+#[test]
+fn test_queue_settles_by_class_then_approval_order_with_hardship_first() {
+    let h = Harness::new();
+    let class = |name: &str, max_amount: i128| SettlementClass { name: Symbol::new(&h.env, name), max_amount };
+    h.claims.set_settlement_classes(&h.admin, &Vec::from_array(&h.env, [class("small", 1_000), class("medium", 5_000)]));
+
+    let large = h.approved_claim_on(2, 6_000);
+    let medium = h.approved_claim_on(3, 4_000);
+    let small = h.approved_claim_on(4, 500);
+    let small_later = h.approved_claim_on(5, 800);
+    assert_eq!(h.claims.get_settlement_priority(&large), 3);
+    assert_eq!(
+        h.claims.get_settlement_queue(),
+        Vec::from_array(&h.env, [small, small_later, medium, large])
+    );
+
+    h.claims.flag_hardship(&h.settler, &large);
+    assert_eq!(h.claims.get_settlement_priority(&large), 0);
+    assert_eq!(h.claims.get_queue_position(&large), Some(0));
+
+    // The head of the queue is never skipped for a claim the pool can fund
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64);
+    h.pool.set_liquidity(&5_999);
+    assert_eq!(h.claims.process_settlements(&10), 0);
+    h.pool.set_liquidity(&6_000);
+    assert_eq!(h.claims.process_settlements(&2), 2);
+    assert_eq!(h.status(large), ClaimStatus::Settled);
+    assert_eq!(h.status(small), ClaimStatus::Settled);
+    assert_eq!(h.claims.get_settlement_queue(), Vec::from_array(&h.env, [small_later, medium]));
+}
+
+#[test]
+fn test_large_payouts_wait_for_the_guardian_window() {
+    let h = Harness::new();
+    let guardian = Address::generate(&h.env);
+    h.claims.set_payout_delay(&h.admin, &3_000, &3_600, &guardian);
+    let large = h.approved_claim();
+    let small = h.approved_claim_on(2, 3_000);
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64);
+
+    // At the threshold a payout is immediate; above it, it is scheduled
+    h.claims.settle_claim(&h.settler, &small);
+    assert_eq!(h.status(small), ClaimStatus::Settled);
+    h.claims.settle_claim(&h.settler, &large);
+    assert_eq!(h.status(large), ClaimStatus::PendingSettlement);
+    let release_at = h.claims.get_pending_payout(&large).unwrap();
+    assert_eq!(release_at, h.env.ledger().timestamp() + 3_600);
+    assert_eq!(h.claims.try_settle_claim(&h.settler, &large), Err(Ok(ContractError::PayoutPending)));
+    assert_eq!(h.claims.try_release_payout(&large), Err(Ok(ContractError::PayoutLocked)));
+
+    // The guardian holds it until governance clears it
+    assert_eq!(h.claims.try_cancel_payout(&h.admin, &large), Err(Ok(ContractError::Unauthorized)));
+    h.claims.cancel_payout(&guardian, &large);
+    skew_clock(&h.env, 3_600);
+    assert_eq!(h.claims.try_release_payout(&large), Err(Ok(ContractError::NotFound)));
+    assert_eq!(h.claims.try_settle_claim(&h.settler, &large), Err(Ok(ContractError::PayoutHeld)));
+
+    h.claims.clear_payout_hold(&h.admin, &large);
+    h.claims.settle_claim(&h.settler, &large);
+    skew_clock(&h.env, 3_600);
+    h.claims.release_payout(&large);
+    assert_eq!(h.status(large), ClaimStatus::Settled);
+    assert_eq!(h.pool.get_total_paid_out(), CLAIM_AMOUNT + 3_000);
+}
+
+#[test]
+fn test_aggregate_limit_caps_approvals_per_holder() {
+    let h = Harness::new();
+    h.claims.set_aggregate_limit(&h.admin, &0, &6_000);
+    let first = h.approved_claim();
+    assert_eq!(h.claims.get_holder_aggregate(&h.holder, &0), CLAIM_AMOUNT);
+    assert_eq!(h.claims.get_remaining_aggregate(&h.holder, &0), Some(2_000));
+
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    let second = h.claims.submit_claim(&h.holder, &2, &3_000);
+    h.claims.start_review(&h.admin, &second);
+    assert_eq!(
+        h.claims.try_approve_claim(&h.admin, &second, &None),
+        Err(Ok(ContractError::AggregateLimitExceeded))
+    );
+
+    // An approval overturned on dispute gives its room back
+    h.claims.raise_dispute(&h.admin, &first, &String::from_str(&h.env, "duplicate"));
+    h.claims.resolve_dispute(&h.admin, &first, &true);
+    assert_eq!(h.claims.get_remaining_aggregate(&h.holder, &0), Some(6_000));
+    h.claims.approve_claim(&h.admin, &second, &None);
+    assert_eq!(h.claims.get_remaining_aggregate(&h.holder, &0), Some(3_000));
+}
+
+#[test]
+fn test_claim_bounds_apply_per_product_at_submission() {
+    let h = Harness::new();
+    let bounds = |min_amount: i128, max_amount: i128| Some(ClaimBounds { min_amount, max_amount });
+    assert_eq!(
+        h.claims.try_set_claim_bounds(&h.admin, &0, &bounds(2_000, 1_000)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.claims.set_claim_bounds(&h.admin, &0, &bounds(1_000, 5_000));
+
+    for amount in [999, 5_001] {
+        assert_eq!(
+            h.claims.try_submit_claim(&h.holder, &POLICY_ID, &amount),
+            Err(Ok(ContractError::ClaimAmountOutOfBounds))
+        );
+    }
+    h.claims.submit_claim(&h.holder, &POLICY_ID, &5_000);
+
+    // Without bounds only the coverage limits the amount
+    h.claims.set_claim_bounds(&h.admin, &0, &None);
+    assert_eq!(h.claims.get_claim_bounds(&0), None);
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    h.claims.submit_claim(&h.holder, &2, &8_000);
+}
+
+#[test]
+fn test_processor_metrics_and_claimant_profile_follow_decisions() {
+    let h = Harness::new();
+
+    // Approved after 600s and settled
+    let settled = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    skew_clock(&h.env, 600);
+    h.claims.start_review(&h.settler, &settled);
+    h.claims.approve_claim(&h.settler, &settled, &None);
+
+    // Rejected after 200s
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    let rejected = h.claims.submit_claim(&h.holder, &2, &1_000);
+    skew_clock(&h.env, 200);
+    h.claims.start_review(&h.settler, &rejected);
+    h.claims.reject_claim(&h.settler, &rejected);
+
+    // Approved at once, then overturned on dispute
+    h.policy.put_policy(&3, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    let overturned = h.claims.submit_claim(&h.holder, &3, &2_000);
+    h.claims.start_review(&h.settler, &overturned);
+    h.claims.approve_claim(&h.settler, &overturned, &None);
+    h.claims.raise_dispute(&h.admin, &overturned, &String::from_str(&h.env, "staged loss"));
+    h.claims.resolve_dispute(&h.admin, &overturned, &true);
+
+    skew_clock(&h.env, DEFAULT_DISPUTE_WINDOW_SECONDS as i64);
+    h.claims.settle_claim(&h.admin, &settled);
+
+    let stats = h.claims.get_processor_stats(&h.settler);
+    assert_eq!((stats.decisions, stats.approvals, stats.rejections, stats.overturned), (3, 2, 1, 1));
+    assert_eq!(stats.total_decision_secs, 800);
+    assert_eq!(h.claims.get_processor_avg_decision_secs(&h.settler), 266);
+    assert_eq!(h.claims.get_claim_decider(&rejected), Some(h.settler.clone()));
+
+    let profile = h.claims.get_claimant_profile(&h.holder);
+    assert_eq!(
+        (profile.filed, profile.approved, profile.rejected, profile.disputed),
+        (3, 1, 2, 1)
+    );
+    assert_eq!(profile.total_paid, CLAIM_AMOUNT);
+    assert_eq!(profile.last_claim_at, 1_800);
+}
+
+#[test]
+fn test_processor_stake_gates_decisions_and_is_slashed_when_overturned() {
+    let h = Harness::new();
+    let token = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    let minter = token::StellarAssetClient::new(&h.env, &token);
+    let balance = token::Client::new(&h.env, &token);
+    h.claims.allow_asset(&h.admin, &token);
+    h.claims.set_processor_staking(
+        &h.admin,
+        &Some(ProcessorStakingConfig {
+            token: token.clone(),
+            treasury: Address::generate(&h.env),
+            min_stake: 1_000,
+            slash_bps: 5_000,
+            wronged_share_bps: 10_000,
+            max_overturn_bps: 5_000,
+            unbonding_secs: 3_600,
+        }),
+    );
+
+    let claim_id = h.claims.submit_claim(&h.holder, &POLICY_ID, &CLAIM_AMOUNT);
+    assert_eq!(
+        h.claims.try_start_review(&h.settler, &claim_id),
+        Err(Ok(ContractError::ProcessorStakeRequired))
+    );
+    minter.mint(&h.settler, &1_000);
+    h.claims.stake_processor(&h.settler, &1_000);
+    h.claims.start_review(&h.settler, &claim_id);
+    h.claims.approve_claim(&h.settler, &claim_id, &None);
+    assert_eq!(h.claims.try_unstake_processor(&h.settler, &1_000), Err(Ok(ContractError::StakeLocked)));
+
+    // Rewards accrue pro rata to stake
+    minter.mint(&h.admin, &300);
+    h.claims.fund_processor_rewards(&h.admin, &300);
+    assert_eq!(h.claims.get_processor_stake(&h.settler).rewards, 300);
+
+    // The overturned approval costs half the stake, paid to the disputer
+    h.claims.raise_dispute(&h.admin, &claim_id, &String::from_str(&h.env, "forged invoice"));
+    h.claims.resolve_dispute(&h.admin, &claim_id, &true);
+    assert_eq!(h.claims.get_processor_stake(&h.settler).amount, 500);
+    assert_eq!(balance.balance(&h.admin), 500);
+
+    // Below the minimum it cannot decide, and its record bars rewards
+    h.policy.put_policy(&2, &h.holder, &COVERAGE, &PolicyState::ACTIVE, &0, &100_000);
+    let next = h.claims.submit_claim(&h.holder, &2, &1_000);
+    assert_eq!(
+        h.claims.try_start_review(&h.settler, &next),
+        Err(Ok(ContractError::ProcessorStakeRequired))
+    );
+    assert_eq!(
+        h.claims.try_claim_processor_rewards(&h.settler),
+        Err(Ok(ContractError::ProcessorNotEligible))
+    );
+
+    skew_clock(&h.env, 3_600);
+    h.claims.unstake_processor(&h.settler, &500);
+    assert_eq!(balance.balance(&h.settler), 500);
+}
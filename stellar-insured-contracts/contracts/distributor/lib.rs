@@ -0,0 +1,580 @@
+#![no_std]
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, symbol_short, token, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+// Import authorization from the common library
+use insurance_contracts::authorization::{initialize_admin, require_admin, require_governance_permission};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::version::{self, ContractVersion, IFACE_VERSION};
+
+#[contract]
+pub struct DistributorContract;
+
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const DISTRIBUTION: Symbol = symbol_short!("DIST");
+const DIST_COUNTER: Symbol = symbol_short!("DIST_CNT");
+const CLAIMED: Symbol = symbol_short!("CLAIMED");
+
+// Upper bound on proof length; enough for 2^32 leaves
+const MAX_PROOF_LEN: u32 = 32;
+
+// Leaves tracked per claim bitmap word
+const BITMAP_WORD_BITS: u32 = 128;
+
+/// Entitlement program funded up front and claimed against a Merkle root of
+/// `(index, account, amount)` leaves. Leaves are hashed as the SHA-256 of
+/// the tuple's XDR; inner nodes as the SHA-256 of the two children in
+/// ascending order, so proofs carry no left/right flags.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Distribution {
+    pub root: BytesN<32>,
+    /// Number of times the root has been rotated
+    pub root_version: u32,
+    pub total: i128,
+    pub claimed: i128,
+    pub expires_at: u64,
+    pub swept: bool,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ContractError {
+    Unauthorized = 1,
+    Paused = 2,
+    InvalidInput = 3,
+    InsufficientFunds = 4,
+    NotFound = 5,
+    AlreadyExists = 6,
+    InvalidState = 7,
+    NotInitialized = 8,
+    AlreadyInitialized = 9,
+    InvalidRole = 10,
+    RoleNotFound = 11,
+    NotTrustedContract = 12,
+    InvalidProof = 13,
+    AlreadyClaimed = 14,
+    DistributionExpired = 15,
+    NotExpired = 16,
+    AlreadySwept = 17,
+    Overflow = 107,
+}
+
+impl From<insurance_contracts::authorization::AuthError> for ContractError {
+    fn from(err: insurance_contracts::authorization::AuthError) -> Self {
+        match err {
+            insurance_contracts::authorization::AuthError::Unauthorized => ContractError::Unauthorized,
+            insurance_contracts::authorization::AuthError::InvalidRole => ContractError::InvalidRole,
+            insurance_contracts::authorization::AuthError::RoleNotFound => ContractError::RoleNotFound,
+            insurance_contracts::authorization::AuthError::NotTrustedContract => ContractError::NotTrustedContract,
+        }
+    }
+}
+
+fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&PAUSED)
+        .unwrap_or(false)
+}
+
+fn set_paused(env: &Env, paused: bool) {
+    env.storage()
+        .persistent()
+        .set(&PAUSED, &paused);
+}
+
+/// `(token, treasury)`
+fn get_config(env: &Env) -> Result<(Address, Address), ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)
+}
+
+fn get_distribution(env: &Env, distribution_id: u64) -> Result<Distribution, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(DISTRIBUTION, distribution_id))
+        .ok_or(ContractError::NotFound)
+}
+
+fn claimed_word(env: &Env, distribution_id: u64, index: u32) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&(CLAIMED, distribution_id, index / BITMAP_WORD_BITS))
+        .unwrap_or(0u128)
+}
+
+fn is_claimed(env: &Env, distribution_id: u64, index: u32) -> bool {
+    claimed_word(env, distribution_id, index) & (1u128 << (index % BITMAP_WORD_BITS)) != 0
+}
+
+fn set_claimed(env: &Env, distribution_id: u64, index: u32) {
+    let word = claimed_word(env, distribution_id, index) | (1u128 << (index % BITMAP_WORD_BITS));
+    env.storage()
+        .persistent()
+        .set(&(CLAIMED, distribution_id, index / BITMAP_WORD_BITS), &word);
+}
+
+fn leaf_hash(env: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(index, account.clone(), amount).to_xdr(env))
+        .into()
+}
+
+fn node_hash(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.extend_from_array(&second.to_array());
+    env.crypto().sha256(&data).into()
+}
+
+fn verify_proof(env: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut computed = leaf;
+    for sibling in proof.iter() {
+        computed = node_hash(env, &computed, &sibling);
+    }
+    computed == *root
+}
+
+/// I4: Validate amount is positive and within safe range
+fn validate_amount(amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl DistributorContract {
+    /// Unclaimed funds of expired distributions are swept to `treasury`
+    pub fn initialize(env: Env, admin: Address, token: Address, treasury: Address) -> Result<(), ContractError> {
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+
+        env.storage().persistent().set(&CONFIG, &(token, treasury));
+        env.storage().persistent().set(&DIST_COUNTER, &0u64);
+        set_paused(&env, false);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    /// Publish a root of entitlements totalling `total`, funded by `funder`
+    /// (governance only). Claims are accepted until `expires_at`.
+    pub fn publish_root(
+        env: Env,
+        caller: Address,
+        funder: Address,
+        root: BytesN<32>,
+        total: i128,
+        expires_at: u64,
+    ) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+        funder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(total)?;
+        if expires_at <= env.ledger().timestamp() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let (token, _) = get_config(&env)?;
+        token::Client::new(&env, &token).transfer(&funder, env.current_contract_address(), &total);
+
+        let distribution_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DIST_COUNTER)
+            .unwrap_or(0u64)
+            + 1;
+        let distribution = Distribution {
+            root: root.clone(),
+            root_version: 0,
+            total,
+            claimed: 0,
+            expires_at,
+            swept: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&(DISTRIBUTION, distribution_id), &distribution);
+        env.storage().persistent().set(&DIST_COUNTER, &distribution_id);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "root_published"), distribution_id),
+            (root, total, expires_at),
+        );
+
+        Ok(distribution_id)
+    }
+
+    /// Replace the root of a live distribution (governance only), e.g. to
+    /// correct entitlements. Leaf indexes keep their claim bits, so a new
+    /// tree must reuse the indexes of entries already paid out.
+    pub fn rotate_root(env: Env, caller: Address, distribution_id: u64, new_root: BytesN<32>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut distribution = get_distribution(&env, distribution_id)?;
+        if distribution.swept {
+            return Err(ContractError::AlreadySwept);
+        }
+        if env.ledger().timestamp() >= distribution.expires_at {
+            return Err(ContractError::DistributionExpired);
+        }
+
+        let old_root = distribution.root.clone();
+        distribution.root = new_root.clone();
+        distribution.root_version += 1;
+        env.storage()
+            .persistent()
+            .set(&(DISTRIBUTION, distribution_id), &distribution);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "root_rotated"), distribution_id),
+            (old_root, new_root, distribution.root_version),
+        );
+
+        Ok(())
+    }
+
+    /// Pay the entitlement at `index` to `account` given a proof of the leaf
+    /// `(index, account, amount)` against the current root
+    pub fn claim(
+        env: Env,
+        account: Address,
+        distribution_id: u64,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), ContractError> {
+        account.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(amount)?;
+        if proof.len() > MAX_PROOF_LEN {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut distribution = get_distribution(&env, distribution_id)?;
+        if env.ledger().timestamp() >= distribution.expires_at {
+            return Err(ContractError::DistributionExpired);
+        }
+        if is_claimed(&env, distribution_id, index) {
+            return Err(ContractError::AlreadyClaimed);
+        }
+
+        let leaf = leaf_hash(&env, index, &account, amount);
+        if !verify_proof(&env, &distribution.root, leaf, &proof) {
+            return Err(ContractError::InvalidProof);
+        }
+
+        let claimed = distribution.claimed.checked_add(amount).ok_or(ContractError::Overflow)?;
+        if claimed > distribution.total {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        set_claimed(&env, distribution_id, index);
+        distribution.claimed = claimed;
+        env.storage()
+            .persistent()
+            .set(&(DISTRIBUTION, distribution_id), &distribution);
+
+        let (token, _) = get_config(&env)?;
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &account, &amount);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "entitlement_claimed"), account),
+            (distribution_id, index, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Return the unclaimed balance of an expired distribution to the
+    /// treasury. Permissionless so keepers can close out programs.
+    pub fn sweep_expired(env: Env, distribution_id: u64) -> Result<i128, ContractError> {
+        let mut distribution = get_distribution(&env, distribution_id)?;
+        if distribution.swept {
+            return Err(ContractError::AlreadySwept);
+        }
+        if env.ledger().timestamp() < distribution.expires_at {
+            return Err(ContractError::NotExpired);
+        }
+
+        let unclaimed = distribution.total - distribution.claimed;
+        distribution.swept = true;
+        env.storage()
+            .persistent()
+            .set(&(DISTRIBUTION, distribution_id), &distribution);
+
+        let (token, treasury) = get_config(&env)?;
+        if unclaimed > 0 {
+            token::Client::new(&env, &token).transfer(&env.current_contract_address(), &treasury, &unclaimed);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "distribution_swept"), distribution_id),
+            (treasury, unclaimed),
+        );
+
+        Ok(unclaimed)
+    }
+
+    pub fn get_distribution(env: Env, distribution_id: u64) -> Result<Distribution, ContractError> {
+        get_distribution(&env, distribution_id)
+    }
+
+    pub fn is_claimed(env: Env, distribution_id: u64, index: u32) -> bool {
+        is_claimed(&env, distribution_id, index)
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["distributor_v1", IFACE_VERSION])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, true);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "paused"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, false);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "unpaused"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::vec;
+
+    #[test]
+    fn test_claim_rotate_and_sweep() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        StellarAssetClient::new(&env, &token).mint(&treasury, &1_000);
+
+        let contract_id = env.register(DistributorContract, ());
+        let client = DistributorContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &token, &treasury);
+
+        // Two-leaf tree: each leaf is the other's proof
+        let alice_leaf = leaf_hash(&env, 0, &alice, 300);
+        let bob_leaf = leaf_hash(&env, 1, &bob, 200);
+        let root = node_hash(&env, &alice_leaf, &bob_leaf);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let id = client.publish_root(&admin, &treasury, &root, &500, &2_000);
+
+        assert_eq!(
+            client.try_claim(&alice, &id, &0, &400, &vec![&env, bob_leaf.clone()]),
+            Err(Ok(ContractError::InvalidProof))
+        );
+        client.claim(&alice, &id, &0, &300, &vec![&env, bob_leaf.clone()]);
+        assert!(client.is_claimed(&id, &0));
+        assert_eq!(
+            client.try_claim(&alice, &id, &0, &300, &vec![&env, bob_leaf]),
+            Err(Ok(ContractError::AlreadyClaimed))
+        );
+
+        // Rotation keeps index 0 claimed and corrects bob's entitlement
+        let bob_leaf = leaf_hash(&env, 1, &bob, 150);
+        client.rotate_root(&admin, &id, &node_hash(&env, &alice_leaf, &bob_leaf));
+        assert_eq!(
+            client.try_claim(&alice, &id, &0, &300, &vec![&env, bob_leaf]),
+            Err(Ok(ContractError::AlreadyClaimed))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        assert_eq!(
+            client.try_claim(&bob, &id, &1, &150, &vec![&env, alice_leaf]),
+            Err(Ok(ContractError::DistributionExpired))
+        );
+        assert_eq!(client.sweep_expired(&id), 200);
+
+        let balances = TokenClient::new(&env, &token);
+        assert_eq!(balances.balance(&alice), 300);
+        assert_eq!(balances.balance(&treasury), 700);
+    }
+
+    #[test]
+    fn test_over_funded_claims_are_refused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        StellarAssetClient::new(&env, &token).mint(&treasury, &1_000);
+
+        let client = DistributorContractClient::new(&env, &env.register(DistributorContract, ()));
+        client.initialize(&admin, &token, &treasury);
+
+        // Leaves adding up to 500 against 400 funded
+        let alice_leaf = leaf_hash(&env, 0, &alice, 300);
+        let bob_leaf = leaf_hash(&env, 1, &bob, 200);
+        let root = node_hash(&env, &alice_leaf, &bob_leaf);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        assert_eq!(
+            client.try_publish_root(&admin, &treasury, &root, &400, &1_000),
+            Err(Ok(ContractError::InvalidInput))
+        );
+        let id = client.publish_root(&admin, &treasury, &root, &400, &2_000);
+
+        client.claim(&alice, &id, &0, &300, &vec![&env, bob_leaf.clone()]);
+        assert_eq!(
+            client.try_claim(&bob, &id, &1, &200, &vec![&env, alice_leaf.clone()]),
+            Err(Ok(ContractError::InsufficientFunds))
+        );
+        assert!(!client.is_claimed(&id, &1));
+        assert_eq!(client.get_distribution(&id).claimed, 300);
+
+        // Corrected down to what is left, bob is paid once
+        let bob_leaf = leaf_hash(&env, 1, &bob, 100);
+        client.rotate_root(&admin, &id, &node_hash(&env, &alice_leaf, &bob_leaf));
+        client.claim(&bob, &id, &1, &100, &vec![&env, alice_leaf.clone()]);
+        assert_eq!(
+            client.try_claim(&bob, &id, &1, &100, &vec![&env, alice_leaf]),
+            Err(Ok(ContractError::AlreadyClaimed))
+        );
+
+        let balances = TokenClient::new(&env, &token);
+        assert_eq!(balances.balance(&bob), 100);
+        assert_eq!(balances.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_claim_bits_and_sweep_are_single_use() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        StellarAssetClient::new(&env, &token).mint(&treasury, &1_000);
+
+        let client = DistributorContractClient::new(&env, &env.register(DistributorContract, ()));
+        client.initialize(&admin, &token, &treasury);
+
+        // Indexes either side of a bitmap word boundary
+        let last = BITMAP_WORD_BITS - 1;
+        let alice_leaf = leaf_hash(&env, last, &alice, 100);
+        let bob_leaf = leaf_hash(&env, last + 1, &bob, 100);
+        let root = node_hash(&env, &alice_leaf, &bob_leaf);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let id = client.publish_root(&admin, &treasury, &root, &200, &2_000);
+
+        // A proof only pays the account in its leaf
+        assert_eq!(
+            client.try_claim(&bob, &id, &last, &100, &vec![&env, bob_leaf.clone()]),
+            Err(Ok(ContractError::InvalidProof))
+        );
+        let mut long_proof = Vec::new(&env);
+        for _ in 0..=MAX_PROOF_LEN {
+            long_proof.push_back(bob_leaf.clone());
+        }
+        assert_eq!(
+            client.try_claim(&alice, &id, &last, &100, &long_proof),
+            Err(Ok(ContractError::InvalidInput))
+        );
+
+        client.claim(&alice, &id, &last, &100, &vec![&env, bob_leaf]);
+        assert!(client.is_claimed(&id, &last));
+        assert!(!client.is_claimed(&id, &(last + 1)));
+        assert!(!client.is_claimed(&id, &(last - 1)));
+
+        assert_eq!(client.try_sweep_expired(&id), Err(Ok(ContractError::NotExpired)));
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        assert_eq!(client.sweep_expired(&id), 100);
+        assert_eq!(client.try_sweep_expired(&id), Err(Ok(ContractError::AlreadySwept)));
+        assert_eq!(
+            client.try_rotate_root(&admin, &id, &root),
+            Err(Ok(ContractError::AlreadySwept))
+        );
+        assert_eq!(TokenClient::new(&env, &token).balance(&treasury), 900);
+    }
+}
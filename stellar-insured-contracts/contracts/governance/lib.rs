@@ -1,16 +1,84 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracterror, contracttype, token, Address, BytesN, Env, IntoVal, Symbol,
+    symbol_short, SymbolStr, TryFromVal, Val, Vec,
+};
+use insurance_contracts::authorization::{
+    get_role, initialize_admin, register_trusted_contract, require_admin, require_governance_permission,
+    require_trusted_contract, Role,
+};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::math::{mul_div_floor, ratio_percent};
+use insurance_contracts::pagination::{self, IdPage};
+use insurance_contracts::version::{self, ContractVersion, IFACE_VERSION};
+
+/// Stored proposal: (id, proposer, slug, content_hash, created_at,
+/// voting_end, threshold_percentage, status, for_votes, against_votes,
+/// voter_count, execution_data)
+type ProposalRecord = (u64, Address, Symbol, BytesN<32>, u64, u64, u32, u32, i128, i128, u32, Symbol);
+
+/// Stored slashing proposal: (id, target, role, reason, amount, evidence,
+/// created_at, voting_end, threshold_percentage, status, for_votes,
+/// against_votes, voter_count)
+type SlashingProposalRecord = (u64, Address, u32, u32, i128, Symbol, u64, u64, u32, u32, i128, i128, u32);
 
 #[contract]
 pub struct GovernanceContract;
 
-const ADMIN: Symbol = Symbol::short("ADMIN");
-const PAUSED: Symbol = Symbol::short("PAUSED");
-const CONFIG: Symbol = Symbol::short("CONFIG");
-const PROPOSAL: Symbol = Symbol::short("PROPOSAL");
-const PROPOSAL_COUNTER: Symbol = Symbol::short("PROP_CNT");
-const VOTER: Symbol = Symbol::short("VOTER");
-const PROPOSAL_LIST: Symbol = Symbol::short("PROP_LIST");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const PROPOSAL: Symbol = symbol_short!("PROPOSAL");
+const PROPOSAL_COUNTER: Symbol = symbol_short!("PROP_CNT");
+const VOTER: Symbol = symbol_short!("VOTER");
+const PROPOSAL_LIST: Symbol = symbol_short!("PROP_LIST");
+const PROPOSAL_SNAPSHOT: Symbol = symbol_short!("PROP_SNAP");
+const APPEAL: Symbol = symbol_short!("APPEAL");
+const MUTUAL: Symbol = symbol_short!("MUTUAL");
+const PROPOSAL_MUTUAL: Symbol = symbol_short!("PROP_MUT");
+const TEMPLATE: Symbol = symbol_short!("TEMPLATE");
+const CALLABLE: Symbol = symbol_short!("CALLABLE");
+const CALL: Symbol = symbol_short!("CALL");
+const EMERGENCY: Symbol = symbol_short!("EMERGENCY");
+const EMERGENCY_CONFIG: Symbol = symbol_short!("EMRG_CFG");
+const VOTER_REWARDS: Symbol = symbol_short!("VRWD_CFG");
+const REWARD_WEIGHT: Symbol = symbol_short!("VRWD_WGT");
+const REWARD_BUDGET: Symbol = symbol_short!("VRWD_BUD");
+const REWARD_PENDING: Symbol = symbol_short!("VRWD_PND");
+const REWARDS_OUTSTANDING: Symbol = symbol_short!("VRWD_OUT");
+const METADATA: Symbol = symbol_short!("PROP_META");
+const METADATA_HISTORY: Symbol = symbol_short!("META_HIST");
+const SLASHING_CONTRACT: Symbol = symbol_short!("SLASH_CT");
+
+// Upper bound on ids accepted by batch getters
+const MAX_BATCH_SIZE: u32 = 50;
+
+// Upper bound on tags attached to a proposal
+const MAX_PROPOSAL_TAGS: u32 = 5;
+
+// Longest slug a proposal may be created with
+const MAX_SLUG_LENGTH: usize = 24;
+
+// Fields of a standard proposal record, as opposed to a slashing one
+const PROPOSAL_FIELDS: u32 = 12;
+
+// Bounds a template proposal is checked against when it is created
+const MAX_TEMPLATE_FEE_BPS: u32 = 1_000;
+const MIN_TEMPLATE_VOTING_DAYS: u32 = 1;
+const MAX_TEMPLATE_VOTING_DAYS: u32 = 30;
+const MAX_TEMPLATE_PRODUCT_DAYS: u32 = 365;
+
+// Emergency track defaults and the limits governance may set it within
+const DEFAULT_EMERGENCY_VOTING_SECONDS: u64 = 86_400;
+const DEFAULT_EMERGENCY_QUORUM_PERCENT: u32 = 40;
+const DEFAULT_EMERGENCY_THRESHOLD_PERCENT: u32 = 75;
+const MIN_EMERGENCY_VOTING_SECONDS: u64 = 3_600;
+const MAX_EMERGENCY_VOTING_SECONDS: u64 = 3 * 86_400;
+const MIN_EMERGENCY_THRESHOLD_PERCENT: u32 = 67;
+const MAX_BASIS_POINTS: u32 = 10_000;
+
+// Most votes a unit of premium can be worth in mutual mode, in basis points
+const MAX_VOTES_PER_PREMIUM_BPS: u32 = 100 * MAX_BASIS_POINTS;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ProposalStatus {
@@ -30,6 +98,123 @@ pub enum ProposalType {
     EmergencyAction = 4,
 }
 
+/// Cover a product offers, as proposed by an `AddProduct` template and
+/// set on the policy contract with `set_product`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProductSpec {
+    pub product_id: u32,
+    pub min_coverage: i128,
+    pub max_coverage: i128,
+    pub max_duration_days: u32,
+}
+
+/// Pre-validated parameter changes. Their arguments are typed and bounded,
+/// so a proposal that passes a vote can always be executed as voted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalTemplate {
+    /// Set a fee of a target contract, in basis points, through the setter
+    /// the symbol names
+    ChangeFeeBps(Address, Symbol, u32),
+    /// Voting period, in days, of proposals created afterwards
+    ChangeVotingPeriod(u32),
+    /// Offer a product on a policy contract
+    AddProduct(Address, ProductSpec),
+    /// Trust a contract to call into governance
+    RegisterTrustedContract(Address),
+}
+
+/// Privileged function of a protocol contract a call proposal invokes, for
+/// windows, caps, upgrades and role grants. Only functions the admin has
+/// made callable can be proposed, with exactly the arguments they take
+/// after the caller.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCall {
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// Policy contract whose holders vote in mutual mode, and the votes a unit
+/// of premium paid is worth in basis points (10_000 is one vote per unit)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MutualMode {
+    pub policy_contract: Address,
+    pub votes_per_premium_bps: u32,
+}
+
+/// Incident responses the emergency track may vote on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EmergencyMeasure {
+    /// Pause a protocol contract
+    Pause(Address),
+    /// Put a risk pool into stress mode with a payout threshold and haircut
+    /// in basis points
+    CircuitBreaker(Address, i128, u32),
+    /// Point a claims contract at a replacement oracle requiring this many
+    /// submissions
+    RotateOracle(Address, Address, u32),
+}
+
+/// Voting window, quorum and super-majority of emergency proposals
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyConfig {
+    pub voting_period_secs: u64,
+    pub quorum_percentage: u32,
+    pub threshold_percentage: u32,
+}
+
+/// Measure an emergency proposal takes and the quorum it was opened with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyProposal {
+    pub measure: EmergencyMeasure,
+    pub quorum_percentage: u32,
+}
+
+/// Turnout incentive paid from reward tokens the treasury sends to this
+/// contract. Each finalized proposal shares up to `reward_per_proposal`
+/// among its voters pro rata to weight; votes below `min_weight` earn
+/// nothing, so splitting a balance across addresses gains nothing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoterRewardConfig {
+    pub token: Address,
+    pub reward_per_proposal: i128,
+    pub min_weight: i128,
+}
+
+/// Off-chain text a proposal is about. `content_hash` commits to the full
+/// proposal text (e.g. its IPFS document) and `discussion_hash` to the URL of
+/// the forum thread. Amendments bump `version` until the first vote is cast;
+/// after that the metadata is frozen.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalMetadata {
+    pub content_hash: BytesN<32>,
+    pub discussion_hash: BytesN<32>,
+    pub tags: Vec<Symbol>,
+    pub version: u32,
+    pub updated_at: u64,
+}
+
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub token_contract: Address,
+    pub voting_period_days: u32,
+    pub min_voting_percentage: u32,
+    pub min_quorum_percentage: u32,
+    pub slashing_contract: Address,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ContractError {
@@ -47,11 +232,39 @@ pub enum ContractError {
     ProposalNotActive = 12,
     QuorumNotMet = 13,
     ThresholdNotMet = 14,
-    SlashingContractNotSet = 15,
-    SlashingExecutionFailed = 16,
     InvalidRole = 15,
     RoleNotFound = 16,
     NotTrustedContract = 17,
+    NoVotingPower = 18,
+    TemplateOutOfBounds = 19,
+    SlashingContractNotSet = 20,
+    SlashingExecutionFailed = 21,
+    Overflow = 22,
+    CallNotAllowed = 23,
+}
+
+impl Default for EmergencyConfig {
+    fn default() -> Self {
+        Self {
+            voting_period_secs: DEFAULT_EMERGENCY_VOTING_SECONDS,
+            quorum_percentage: DEFAULT_EMERGENCY_QUORUM_PERCENT,
+            threshold_percentage: DEFAULT_EMERGENCY_THRESHOLD_PERCENT,
+        }
+    }
+}
+
+/// Snapshot interface of the governance token
+#[contractclient(name = "VotesClient")]
+pub trait Votes {
+    fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128;
+    fn get_past_total_supply(env: Env, ledger: u32) -> i128;
+}
+
+/// Premium-weighted membership of the policy contract, used in mutual mode
+#[contractclient(name = "MemberWeightsClient")]
+pub trait MemberWeights {
+    fn get_member_weight(env: Env, holder: Address, as_of: u64) -> i128;
+    fn get_total_member_weight(env: Env, as_of: u64) -> i128;
 }
 
 impl From<insurance_contracts::authorization::AuthError> for ContractError {
@@ -69,6 +282,260 @@ fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+fn validate_voting_params(
+    voting_period_days: u32,
+    min_voting_percentage: u32,
+    min_quorum_percentage: u32,
+) -> Result<(), ContractError> {
+    if voting_period_days == 0 || voting_period_days > 365 {
+        return Err(ContractError::InvalidInput);
+    }
+
+    if min_voting_percentage == 0 || min_voting_percentage > 100 {
+        return Err(ContractError::InvalidInput);
+    }
+
+    if min_quorum_percentage == 0 || min_quorum_percentage > 100 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.token_contract)?;
+    validate_address(env, &config.slashing_contract)?;
+
+    validate_voting_params(
+        config.voting_period_days,
+        config.min_voting_percentage,
+        config.min_quorum_percentage,
+    )
+}
+
+fn validate_template(env: &Env, template: &ProposalTemplate) -> Result<(), ContractError> {
+    let in_bounds = match template {
+        ProposalTemplate::ChangeFeeBps(target, _, fee_bps) => {
+            *target != env.current_contract_address() && *fee_bps <= MAX_TEMPLATE_FEE_BPS
+        }
+        ProposalTemplate::ChangeVotingPeriod(days) => {
+            (MIN_TEMPLATE_VOTING_DAYS..=MAX_TEMPLATE_VOTING_DAYS).contains(days)
+        }
+        ProposalTemplate::AddProduct(policy_contract, spec) => {
+            *policy_contract != env.current_contract_address()
+                && spec.product_id > 0
+                && spec.min_coverage > 0
+                && spec.min_coverage <= spec.max_coverage
+                && spec.max_duration_days > 0
+                && spec.max_duration_days <= MAX_TEMPLATE_PRODUCT_DAYS
+        }
+        ProposalTemplate::RegisterTrustedContract(contract) => *contract != env.current_contract_address(),
+    };
+    if !in_bounds {
+        return Err(ContractError::TemplateOutOfBounds);
+    }
+    Ok(())
+}
+
+/// Check a call against the functions the admin has made callable and the
+/// number of arguments each takes
+fn validate_call(env: &Env, call: &ContractCall) -> Result<(), ContractError> {
+    let arity: u32 = env
+        .storage()
+        .persistent()
+        .get(&(CALLABLE, call.target.clone(), call.function.clone()))
+        .ok_or(ContractError::CallNotAllowed)?;
+    if call.args.len() != arity {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Call `function` on a protocol contract as governance. Privileged setters
+/// take their caller first, and the target accepts this contract there once
+/// it has been set as its governance contract.
+fn call_as_governance(env: &Env, target: &Address, function: &Symbol, args: Vec<Val>) {
+    let mut call_args: Vec<Val> = Vec::new(env);
+    call_args.push_back(env.current_contract_address().into_val(env));
+    call_args.append(&args);
+    env.invoke_contract::<Val>(target, function, call_args);
+}
+
+/// Apply an executed template
+fn apply_template(env: &Env, proposal_id: u64, template: &ProposalTemplate) -> Result<(), ContractError> {
+    match template {
+        ProposalTemplate::ChangeVotingPeriod(days) => {
+            let config: (Address, u32, u32, u32) = env
+                .storage()
+                .persistent()
+                .get(&CONFIG)
+                .ok_or(ContractError::NotInitialized)?;
+            env.storage()
+                .persistent()
+                .set(&CONFIG, &(config.0, *days, config.2, config.3));
+        }
+        ProposalTemplate::RegisterTrustedContract(contract) => {
+            let admin = insurance_contracts::authorization::get_admin(env).ok_or(ContractError::NotInitialized)?;
+            register_trusted_contract(env, &admin, contract)?;
+        }
+        ProposalTemplate::ChangeFeeBps(target, setter, fee_bps) => {
+            call_as_governance(env, target, setter, Vec::from_array(env, [fee_bps.into_val(env)]));
+        }
+        ProposalTemplate::AddProduct(policy_contract, spec) => {
+            call_as_governance(
+                env,
+                policy_contract,
+                &Symbol::new(env, "set_product"),
+                Vec::from_array(env, [spec.into_val(env)]),
+            );
+        }
+    }
+
+    publish_event(
+        env,
+        (Symbol::new(env, "template_executed"), proposal_id),
+        template.clone(),
+    );
+
+    Ok(())
+}
+
+fn get_emergency_config(env: &Env) -> EmergencyConfig {
+    env.storage()
+        .persistent()
+        .get(&EMERGENCY_CONFIG)
+        .unwrap_or_default()
+}
+
+/// Take a passed emergency measure through the target's governance access
+fn apply_emergency_measure(env: &Env, proposal_id: u64, measure: &EmergencyMeasure) {
+    match measure {
+        EmergencyMeasure::Pause(target) if *target == env.current_contract_address() => {
+            set_paused(env, true);
+        }
+        EmergencyMeasure::Pause(target) => {
+            call_as_governance(
+                env,
+                target,
+                &Symbol::new(env, "pause"),
+                Vec::from_array(env, [Option::<u64>::None.into_val(env)]),
+            );
+        }
+        EmergencyMeasure::CircuitBreaker(pool, threshold, haircut_bps) => {
+            call_as_governance(
+                env,
+                pool,
+                &Symbol::new(env, "activate_stress_mode"),
+                Vec::from_array(env, [threshold.into_val(env), haircut_bps.into_val(env)]),
+            );
+        }
+        EmergencyMeasure::RotateOracle(claims_contract, oracle_contract, min_submissions) => {
+            call_as_governance(
+                env,
+                claims_contract,
+                &Symbol::new(env, "set_oracle_config"),
+                Vec::from_array(
+                    env,
+                    [oracle_contract.into_val(env), true.into_val(env), min_submissions.into_val(env)],
+                ),
+            );
+        }
+    }
+
+    publish_event(
+        env,
+        (Symbol::new(env, "emergency_executed"), proposal_id),
+        measure.clone(),
+    );
+}
+
+fn rewards_outstanding(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&REWARDS_OUTSTANDING)
+        .unwrap_or(0)
+}
+
+/// Count a vote towards the proposal's reward split if it carries enough
+/// weight
+fn record_reward_vote(env: &Env, proposal_id: u64, voter: &Address, vote_weight: i128) -> Result<(), ContractError> {
+    let config: Option<VoterRewardConfig> = env.storage().persistent().get(&VOTER_REWARDS);
+    if config.is_none_or(|config| vote_weight < config.min_weight) {
+        return Ok(());
+    }
+
+    let eligible: i128 = env
+        .storage()
+        .persistent()
+        .get(&(REWARD_WEIGHT, proposal_id))
+        .unwrap_or(0);
+    let eligible = eligible.checked_add(vote_weight).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&(REWARD_WEIGHT, proposal_id), &eligible);
+
+    let mut pending: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&(REWARD_PENDING, voter.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    pending.push_back(proposal_id);
+    env.storage()
+        .persistent()
+        .set(&(REWARD_PENDING, voter.clone()), &pending);
+    Ok(())
+}
+
+/// Set aside a finalized proposal's reward budget from the unallocated
+/// reward balance
+fn allocate_voter_rewards(env: &Env, proposal_id: u64) {
+    let config: VoterRewardConfig = match env.storage().persistent().get(&VOTER_REWARDS) {
+        Some(config) => config,
+        None => return,
+    };
+    let eligible: i128 = env
+        .storage()
+        .persistent()
+        .get(&(REWARD_WEIGHT, proposal_id))
+        .unwrap_or(0);
+    if eligible <= 0 {
+        return;
+    }
+
+    let outstanding = rewards_outstanding(env);
+    let available = token::Client::new(env, &config.token).balance(&env.current_contract_address()) - outstanding;
+    let budget = config.reward_per_proposal.min(available).max(0);
+    if budget == 0 {
+        return;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(REWARD_BUDGET, proposal_id), &budget);
+    env.storage()
+        .persistent()
+        .set(&REWARDS_OUTSTANDING, &(outstanding + budget));
+
+    publish_event(
+        env,
+        (Symbol::new(env, "voter_rewards_allocated"), proposal_id),
+        (budget, eligible),
+    );
+}
+
+fn validate_slug(env: &Env, slug: &Symbol) -> Result<(), ContractError> {
+    let length = SymbolStr::try_from_val(env, &slug.to_symbol_val()).map_or(0, |slug| slug.len());
+    if length == 0 || length > MAX_SLUG_LENGTH {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Hash of terms a proposal records on-chain in place of its text
+fn content_hash<T: IntoVal<Env, Val>>(env: &Env, content: T) -> BytesN<32> {
+    env.crypto().sha256(&content.to_xdr(env)).to_bytes()
+}
+
 fn is_paused(env: &Env) -> bool {
     env.storage()
         .persistent()
@@ -86,93 +553,752 @@ fn is_voting_period_active(proposal_status: u32, voting_ends_at: u64, current_ti
     current_time < voting_ends_at && proposal_status == ProposalStatus::Active as u32
 }
 
+/// Read a standard proposal. A record in another layout, a slashing
+/// proposal or one awaiting `migrate_proposals`, is an error rather than a
+/// trap.
+fn load_proposal(env: &Env, proposal_id: u64) -> Result<ProposalRecord, ContractError> {
+    let stored: Val = env
+        .storage()
+        .persistent()
+        .get(&(PROPOSAL, proposal_id))
+        .ok_or(ContractError::NotFound)?;
+    ProposalRecord::try_from_val(env, &stored).map_err(|_| ContractError::InvalidState)
+}
+
 fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
     env.storage()
         .persistent()
         .has(&(VOTER, proposal_id, voter))
 }
 
-fn calculate_quorum_met(yes_votes: i128, no_votes: i128, total_supply: i128, min_quorum_percentage: u32) -> bool {
-    let total_votes = yes_votes + no_votes;
-    if total_supply == 0 {
-        return false;
-    }
-    let quorum_percentage = (total_votes * 100) / total_supply;
-    quorum_percentage >= min_quorum_percentage as i128
-}
+/// Voting power is read as of the ledger before the proposal was created, so
+/// tokens acquired after a proposal is known cannot sway it. Proposals
+/// created in mutual mode also weigh premiums members paid before creation.
+fn record_snapshot(env: &Env, proposal_id: u64) -> u32 {
+    let snapshot = env.ledger().sequence().saturating_sub(1);
+    env.storage()
+        .persistent()
+        .set(&(PROPOSAL_SNAPSHOT, proposal_id), &snapshot);
+    if let Some(mode) = env.storage().persistent().get::<_, MutualMode>(&MUTUAL) {
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL_MUTUAL, proposal_id), &mode);
+    }
+    snapshot
+}
+
+/// Mutual mode a proposal was created in, if any
+fn proposal_mutual(env: &Env, proposal_id: u64) -> Option<MutualMode> {
+    env.storage()
+        .persistent()
+        .get(&(PROPOSAL_MUTUAL, proposal_id))
+}
+
+/// Votes a premium weight is worth under a mutual mode
+fn member_votes(mode: &MutualMode, premium_weight: i128) -> Result<i128, ContractError> {
+    mul_div_floor(premium_weight, mode.votes_per_premium_bps as i128, MAX_BASIS_POINTS as i128)
+        .map_err(|_| ContractError::Overflow)
+}
+
+fn get_snapshot(env: &Env, proposal_id: u64) -> Result<u32, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(PROPOSAL_SNAPSHOT, proposal_id))
+        .ok_or(ContractError::NotFound)
+}
+
+fn calculate_quorum_met(yes_votes: i128, no_votes: i128, total_supply: i128, min_quorum_percentage: u32) -> bool {
+    let total_votes = yes_votes + no_votes;
+    ratio_percent(total_votes, total_supply)
+        .is_ok_and(|quorum_percentage| quorum_percentage >= min_quorum_percentage as i128)
+}
+
+fn calculate_threshold_met(yes_votes: i128, no_votes: i128, threshold_percentage: u32) -> bool {
+    let total_votes = yes_votes + no_votes;
+    ratio_percent(yes_votes, total_votes)
+        .is_ok_and(|yes_percentage| yes_percentage >= threshold_percentage as i128)
+}
+
+#[contractimpl]
+impl GovernanceContract {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
+        // Check if already initialized
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        validate_init_config(&env, &config)?;
+        let InitConfig {
+            admin,
+            token_contract,
+            voting_period_days,
+            min_voting_percentage,
+            min_quorum_percentage,
+            slashing_contract,
+        } = config;
+
+        // Initialize authorization system with admin
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+
+        env.storage().persistent().set(
+            &CONFIG, 
+            &(token_contract, voting_period_days, min_voting_percentage, min_quorum_percentage)
+        );
+        env.storage().persistent().set(&SLASHING_CONTRACT, &slashing_contract);
+        env.storage().persistent().set(&PROPOSAL_COUNTER, &0u64);
+        
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+        
+        Ok(())
+    }
+
+    /// Create a proposal. Its text lives off-chain; the proposal keeps a
+    /// short `slug` naming it and the sha256 `content_hash` of the text.
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        slug: Symbol,
+        content_hash: BytesN<32>,
+        execution_data: Symbol,
+        threshold_percentage: u32,
+    ) -> Result<u64, ContractError> {
+        // Verify identity - governance participants can create proposals
+        proposer.require_auth();
+        // Note: We could add governance role check here if we want to restrict proposal creation
+        // require_governance_permission(&env, &proposer)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        if threshold_percentage == 0 || threshold_percentage > 100 {
+            return Err(ContractError::InvalidInput);
+        }
+        validate_slug(&env, &slug)?;
+
+        let config: (Address, u32, u32, u32) = env
+            .storage()
+            .persistent()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let proposal_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&PROPOSAL_COUNTER)
+            .unwrap_or(0) + 1;
+        
+        let current_time = env.ledger().timestamp();
+        let voting_end_time = current_time + (86400u64 * config.1 as u64);
+        
+        let proposal = (
+            proposal_id,
+            proposer.clone(),
+            slug.clone(),
+            content_hash,
+            current_time,
+            voting_end_time,
+            threshold_percentage,
+            ProposalStatus::Active as u32,
+            0i128,
+            0i128,
+            0u32,
+            execution_data.clone(),
+        );
+
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL, proposal_id), &proposal);
+        
+        env.storage()
+            .persistent()
+            .set(&PROPOSAL_COUNTER, &proposal_id);
+
+        record_snapshot(&env, proposal_id);
+
+        let mut proposal_list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&PROPOSAL_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        proposal_list.push_back(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&PROPOSAL_LIST, &proposal_list);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "proposal_created"), proposal_id),
+            (proposer, slug, threshold_percentage),
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Create a proposal from a template. Its parameters are checked against
+    /// the template bounds now, so a malformed change cannot reach a vote.
+    pub fn create_template_proposal(
+        env: Env,
+        proposer: Address,
+        slug: Symbol,
+        content_hash: BytesN<32>,
+        template: ProposalTemplate,
+        threshold_percentage: u32,
+    ) -> Result<u64, ContractError> {
+        validate_template(&env, &template)?;
+        if let ProposalTemplate::RegisterTrustedContract(contract) = &template {
+            if insurance_contracts::authorization::is_trusted_contract(&env, contract) {
+                return Err(ContractError::AlreadyExists);
+            }
+        }
+
+        let proposal_id = Self::create_proposal(
+            env.clone(),
+            proposer,
+            slug,
+            content_hash,
+            Symbol::new(&env, "template"),
+            threshold_percentage,
+        )?;
+        env.storage()
+            .persistent()
+            .set(&(TEMPLATE, proposal_id), &template);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "template_proposal_created"), proposal_id),
+            template,
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Create a proposal to call a privileged function of a protocol
+    /// contract. The function must be callable and given the number of
+    /// arguments it was made callable with.
+    pub fn create_call_proposal(
+        env: Env,
+        proposer: Address,
+        slug: Symbol,
+        content_hash: BytesN<32>,
+        call: ContractCall,
+        threshold_percentage: u32,
+    ) -> Result<u64, ContractError> {
+        validate_call(&env, &call)?;
+
+        let proposal_id = Self::create_proposal(
+            env.clone(),
+            proposer,
+            slug,
+            content_hash,
+            Symbol::new(&env, "call"),
+            threshold_percentage,
+        )?;
+        env.storage()
+            .persistent()
+            .set(&(CALL, proposal_id), &call);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "call_proposal_created"), proposal_id),
+            call,
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Make a function of a protocol contract callable by call proposals,
+    /// taking `arity` arguments after the caller, or stop it with `None`
+    /// (admin only). A stopped function cannot be executed even by a
+    /// proposal that already passed.
+    pub fn set_callable(
+        env: Env,
+        admin: Address,
+        target: Address,
+        function: Symbol,
+        arity: Option<u32>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if target == env.current_contract_address() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let key = (CALLABLE, target.clone(), function.clone());
+        match arity {
+            Some(arity) => env.storage().persistent().set(&key, &arity),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "callable_set"), target),
+            (function, arity),
+        );
+
+        Ok(())
+    }
+
+    /// Arguments a callable function takes after the caller, if it is callable
+    pub fn get_callable(env: Env, target: Address, function: Symbol) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&(CALLABLE, target, function))
+    }
+
+    /// Call a proposal makes, if it was created as a call proposal
+    pub fn get_call_proposal(env: Env, proposal_id: u64) -> Option<ContractCall> {
+        env.storage()
+            .persistent()
+            .get(&(CALL, proposal_id))
+    }
+
+    /// Open an emergency proposal (governance role only). It runs on the
+    /// emergency track's short window, quorum and super-majority, and its
+    /// measure is taken as soon as it passes.
+    pub fn create_emergency_proposal(
+        env: Env,
+        proposer: Address,
+        slug: Symbol,
+        content_hash: BytesN<32>,
+        measure: EmergencyMeasure,
+    ) -> Result<u64, ContractError> {
+        // The proposer authorizes in create_proposal below
+        require_governance_permission(&env, &proposer)?;
+
+        let valid = match &measure {
+            EmergencyMeasure::Pause(_) => true,
+            EmergencyMeasure::CircuitBreaker(_, threshold, haircut_bps) => {
+                *threshold >= 0 && *haircut_bps > 0 && *haircut_bps <= MAX_BASIS_POINTS
+            }
+            EmergencyMeasure::RotateOracle(_, _, min_submissions) => *min_submissions > 0,
+        };
+        if !valid {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let emergency_config = get_emergency_config(&env);
+        let proposal_id = Self::create_proposal(
+            env.clone(),
+            proposer,
+            slug,
+            content_hash,
+            Symbol::new(&env, "emergency"),
+            emergency_config.threshold_percentage,
+        )?;
+
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        proposal.5 = proposal.4 + emergency_config.voting_period_secs;
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL, proposal_id), &proposal);
+
+        let emergency = EmergencyProposal {
+            measure,
+            quorum_percentage: emergency_config.quorum_percentage,
+        };
+        env.storage()
+            .persistent()
+            .set(&(EMERGENCY, proposal_id), &emergency);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "emergency_proposal_created"), proposal_id),
+            (emergency.measure, proposal.5),
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Measure and quorum of an emergency proposal
+    pub fn get_emergency_proposal(env: Env, proposal_id: u64) -> Option<EmergencyProposal> {
+        env.storage()
+            .persistent()
+            .get(&(EMERGENCY, proposal_id))
+    }
+
+    /// Set the emergency track (admin only). The window is one hour to three
+    /// days, the quorum no lower than the standard one and the threshold a
+    /// two-thirds super-majority or more. Open proposals keep their terms.
+    pub fn set_emergency_config(env: Env, admin: Address, emergency_config: EmergencyConfig) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let config: (Address, u32, u32, u32) = env
+            .storage()
+            .persistent()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        if emergency_config.voting_period_secs < MIN_EMERGENCY_VOTING_SECONDS
+            || emergency_config.voting_period_secs > MAX_EMERGENCY_VOTING_SECONDS
+            || emergency_config.quorum_percentage < config.3
+            || emergency_config.quorum_percentage > 100
+            || emergency_config.threshold_percentage < MIN_EMERGENCY_THRESHOLD_PERCENT
+            || emergency_config.threshold_percentage > 100
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&EMERGENCY_CONFIG, &emergency_config);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "emergency_config_set"), admin),
+            emergency_config,
+        );
+
+        Ok(())
+    }
+
+    /// Emergency track terms, defaulting to a one-day window, 40% quorum and
+    /// 75% super-majority
+    pub fn get_emergency_config(env: Env) -> EmergencyConfig {
+        get_emergency_config(&env)
+    }
+
+    /// Template a proposal executes, if it was created from one
+    pub fn get_proposal_template(env: Env, proposal_id: u64) -> Option<ProposalTemplate> {
+        env.storage()
+            .persistent()
+            .get(&(TEMPLATE, proposal_id))
+    }
+
+    /// Anchor or amend a proposal's off-chain text (proposer only). Allowed
+    /// until the first vote is cast, so every vote is on a fixed version.
+    pub fn set_proposal_metadata(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+        content_hash: BytesN<32>,
+        discussion_hash: BytesN<32>,
+        tags: Vec<Symbol>,
+    ) -> Result<u32, ContractError> {
+        proposer.require_auth();
+
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal.1 != proposer {
+            return Err(ContractError::Unauthorized);
+        }
+        if proposal.7 != ProposalStatus::Active as u32 || proposal.10 > 0 {
+            return Err(ContractError::ProposalNotActive);
+        }
+        if tags.len() > MAX_PROPOSAL_TAGS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let version = Self::get_proposal_metadata(env.clone(), proposal_id).map_or(1, |metadata| metadata.version + 1);
+        proposal.3 = content_hash.clone();
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL, proposal_id), &proposal);
+
+        let metadata = ProposalMetadata {
+            content_hash,
+            discussion_hash,
+            tags,
+            version,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&(METADATA, proposal_id), &metadata);
+        env.storage()
+            .persistent()
+            .set(&(METADATA_HISTORY, proposal_id, version), &metadata);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "proposal_metadata_set"), proposal_id),
+            (version, metadata.content_hash),
+        );
+
+        Ok(version)
+    }
+
+    /// Rewrite proposals stored with a title and description symbol into
+    /// the slug and content hash layout (admin only). The title becomes the
+    /// slug and the content hash is taken over both. Covers up to `limit`
+    /// ids from `start_id` and returns how many were migrated.
+    pub fn migrate_proposals(env: Env, admin: Address, start_id: u64, limit: u32) -> Result<u32, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if limit == 0 || limit > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let last_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&PROPOSAL_COUNTER)
+            .unwrap_or(0);
+        let end_id = last_id.min(start_id.saturating_add(limit as u64 - 1));
+        let mut migrated = 0u32;
+        for proposal_id in start_id.max(1)..=end_id {
+            let fields: Vec<Val> = match env.storage().persistent().get(&(PROPOSAL, proposal_id)) {
+                Some(fields) => fields,
+                None => continue,
+            };
+            // Slashing proposals and already migrated ones are left alone
+            if fields.len() != PROPOSAL_FIELDS {
+                continue;
+            }
+            let description = match Symbol::try_from_val(&env, &fields.get_unchecked(3)) {
+                Ok(description) => description,
+                Err(_) => continue,
+            };
+            let title = Symbol::try_from_val(&env, &fields.get_unchecked(2)).map_err(|_| ContractError::InvalidState)?;
+
+            let mut migrated_fields = fields.clone();
+            migrated_fields.set(3, content_hash(&env, (title, description)).into_val(&env));
+            env.storage()
+                .persistent()
+                .set(&(PROPOSAL, proposal_id), &migrated_fields);
+            migrated += 1;
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "proposals_migrated"), admin),
+            (start_id, end_id, migrated),
+        );
+
+        Ok(migrated)
+    }
+
+    /// Current metadata of a proposal; frozen once voting has started
+    pub fn get_proposal_metadata(env: Env, proposal_id: u64) -> Option<ProposalMetadata> {
+        env.storage()
+            .persistent()
+            .get(&(METADATA, proposal_id))
+    }
+
+    /// A past version of a proposal's metadata
+    pub fn get_proposal_metadata_version(env: Env, proposal_id: u64, version: u32) -> Option<ProposalMetadata> {
+        env.storage()
+            .persistent()
+            .get(&(METADATA_HISTORY, proposal_id, version))
+    }
+
+    /// A standard proposal. Slashing proposals and ones still in the title
+    /// and description layout are reported as `InvalidState`.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<ProposalRecord, ContractError> {
+        load_proposal(&env, proposal_id)
+    }
+
+    /// Batch lookup of proposals. The result is aligned with `proposal_ids`;
+    /// ids with no standard proposal in the current layout yield `None`.
+    pub fn get_proposals(env: Env, proposal_ids: Vec<u64>) -> Result<Vec<Option<ProposalRecord>>, ContractError> {
+        if proposal_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut proposals = Vec::new(&env);
+        for proposal_id in proposal_ids.iter() {
+            proposals.push_back(load_proposal(&env, proposal_id).ok());
+        }
+        Ok(proposals)
+    }
+
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        is_yes: bool,
+    ) -> Result<(), ContractError> {
+        // Verify identity - anyone can vote (could add governance role check)
+        voter.require_auth();
+        // Note: We could add governance role check here if we want to restrict voting
+        // require_governance_permission(&env, &voter)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let config: (Address, u32, u32, u32) = env
+            .storage()
+            .persistent()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut proposal = load_proposal(&env, proposal_id)?;
+
+        let current_time = env.ledger().timestamp();
+        if !is_voting_period_active(proposal.7, proposal.5, current_time) {
+            return Err(ContractError::VotingPeriodEnded);
+        }
+
+        if has_voted(&env, proposal_id, &voter) {
+            return Err(ContractError::AlreadyVoted);
+        }
+
+        // Weight is the voter's checkpointed token balance at the snapshot
+        let snapshot = get_snapshot(&env, proposal_id)?;
+        let mut vote_weight = VotesClient::new(&env, &config.0).get_past_votes(&voter, &snapshot);
+        if let Some(mode) = proposal_mutual(&env, proposal_id) {
+            let premiums = MemberWeightsClient::new(&env, &mode.policy_contract).get_member_weight(&voter, &proposal.4);
+            vote_weight = vote_weight
+                .checked_add(member_votes(&mode, premiums)?)
+                .ok_or(ContractError::Overflow)?;
+        }
+        if vote_weight <= 0 {
+            return Err(ContractError::NoVotingPower);
+        }
+
+        let vote_record = (voter.clone(), vote_weight, current_time, is_yes);
+
+        env.storage()
+            .persistent()
+            .set(&(VOTER, proposal_id, voter.clone()), &vote_record);
+        record_reward_vote(&env, proposal_id, &voter, vote_weight)?;
+
+        let tally = if is_yes { &mut proposal.8 } else { &mut proposal.9 };
+        *tally = tally.checked_add(vote_weight).ok_or(ContractError::Overflow)?;
+        proposal.10 += 1;
+
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL, proposal_id), &proposal);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "vote_cast"), proposal_id),
+            (voter, vote_weight, is_yes, proposal.8, proposal.9),
+        );
+
+        Ok(())
+    }
+
+    /// Ledger whose token checkpoints weigh votes on this proposal
+    pub fn get_proposal_snapshot(env: Env, proposal_id: u64) -> Result<u32, ContractError> {
+        get_snapshot(&env, proposal_id)
+    }
+
+    pub fn finalize_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal = load_proposal(&env, proposal_id)?;
+
+        if proposal.7 != ProposalStatus::Active as u32 {
+            return Err(ContractError::ProposalNotActive);
+        }
+
+        let config: (Address, u32, u32, u32) = env
+            .storage()
+            .persistent()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let emergency: Option<EmergencyProposal> = env.storage().persistent().get(&(EMERGENCY, proposal_id));
+        let min_quorum_percentage = emergency.as_ref().map_or(config.3, |e| e.quorum_percentage);
 
-fn calculate_threshold_met(yes_votes: i128, no_votes: i128, threshold_percentage: u32) -> bool {
-    let total_votes = yes_votes + no_votes;
-    if total_votes == 0 {
-        return false;
-    }
-    let yes_percentage = (yes_votes * 100) / total_votes;
-    yes_percentage >= threshold_percentage as i128
-}
+        let snapshot = get_snapshot(&env, proposal_id)?;
+        let mut total_supply = VotesClient::new(&env, &config.0).get_past_total_supply(&snapshot);
+        if let Some(mode) = proposal_mutual(&env, proposal_id) {
+            let premiums = MemberWeightsClient::new(&env, &mode.policy_contract).get_total_member_weight(&proposal.4);
+            total_supply = total_supply
+                .checked_add(member_votes(&mode, premiums)?)
+                .ok_or(ContractError::Overflow)?;
+        }
 
-#[contractimpl]
-impl GovernanceContract {
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        token_contract: Address,
-        voting_period_days: u32,
-        min_voting_percentage: u32,
-        min_quorum_percentage: u32,
-        slashing_contract: Address,
-    ) -> Result<(), ContractError> {
-        // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
-            return Err(ContractError::AlreadyInitialized);
+        // An emergency proposal closes early once its yes votes alone meet
+        // the super-majority of the whole supply, as no later vote can sink it
+        let current_time = env.ledger().timestamp();
+        let decided_early = emergency.is_some()
+            && ratio_percent(proposal.8, total_supply)
+                .is_ok_and(|yes_percentage| yes_percentage >= proposal.6.max(min_quorum_percentage) as i128);
+        if current_time < proposal.5 && !decided_early {
+            return Err(ContractError::InvalidState);
         }
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &token_contract)?;
-        validate_address(&env, &slashing_contract)?;
+        if !calculate_quorum_met(proposal.8, proposal.9, total_supply, min_quorum_percentage) {
+            proposal.7 = ProposalStatus::Expired as u32;
+        } else if calculate_threshold_met(proposal.8, proposal.9, proposal.6) {
+            proposal.7 = ProposalStatus::Passed as u32;
+        } else {
+            proposal.7 = ProposalStatus::Rejected as u32;
+        }
 
-        if voting_period_days == 0 || voting_period_days > 365 {
-            return Err(ContractError::InvalidInput);
+        // Appeals execute as soon as they are decided; anything short of a
+        // passing vote leaves the first-instance approval standing
+        let appeal: Option<(Address, u64)> = env.storage().persistent().get(&(APPEAL, proposal_id));
+        let upheld = proposal.7 == ProposalStatus::Passed as u32;
+        if (appeal.is_some() || emergency.is_some()) && upheld {
+            proposal.7 = ProposalStatus::Executed as u32;
         }
 
-        if min_voting_percentage == 0 || min_voting_percentage > 100 {
-            return Err(ContractError::InvalidInput);
+        env.storage()
+            .persistent()
+            .set(&(PROPOSAL, proposal_id), &proposal);
+
+        allocate_voter_rewards(&env, proposal_id);
+
+        let total_votes = proposal.8 + proposal.9;
+        let yes_percentage = ratio_percent(proposal.8, total_votes).unwrap_or(0);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "proposal_finalized"), proposal_id),
+            (proposal.7, yes_percentage, proposal.8, proposal.9),
+        );
+
+        if let Some((claims_contract, claim_id)) = appeal {
+            env.invoke_contract::<()>(
+                &claims_contract,
+                &Symbol::new(&env, "resolve_appeal"),
+                (env.current_contract_address(), claim_id, upheld).into_val(&env),
+            );
+
+            publish_event(
+                &env,
+                (Symbol::new(&env, "appeal_resolved"), proposal_id),
+                (claims_contract, claim_id, upheld),
+            );
         }
 
-        if min_quorum_percentage == 0 || min_quorum_percentage > 100 {
-            return Err(ContractError::InvalidInput);
+        if let Some(emergency) = emergency.filter(|_| upheld) {
+            apply_emergency_measure(&env, proposal_id, &emergency.measure);
         }
 
-        // Initialize authorization system with admin
+        Ok(())
+    }
+
+    /// Trust a claims contract to open appeal proposals (admin only)
+    pub fn register_appeal_source(env: Env, admin: Address, claims_contract: Address) -> Result<(), ContractError> {
         admin.require_auth();
-        initialize_admin(&env, admin.clone());
+        require_admin(&env, &admin)?;
 
-        env.storage().persistent().set(
-            &CONFIG, 
-            &(token_contract, voting_period_days, min_voting_percentage, min_quorum_percentage)
-        );
-        env.storage().persistent().set(&SLASHING_CONTRACT, &slashing_contract);
-        env.storage().persistent().set(&PROPOSAL_COUNTER, &0u64);
-        
-        env.events().publish(
-            (Symbol::new(&env, "initialized"), ()),
+        register_trusted_contract(&env, &admin, &claims_contract)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "appeal_source_registered"), claims_contract),
             admin,
         );
-        
+
         Ok(())
     }
 
-    pub fn create_proposal(
+    /// Open a proposal deciding a disputed claim (trusted claims contracts
+    /// only). A yes vote upholds the dispute and rejects the claim; the
+    /// outcome is sent back to the claims contract when the proposal is
+    /// finalized. The content hash commits to the claim, amount and the
+    /// recommendation of the first instance.
+    pub fn create_appeal_proposal(
         env: Env,
-        proposer: Address,
-        title: Symbol,
-        description: Symbol,
-        execution_data: Symbol,
+        caller_contract: Address,
+        claim_id: u64,
+        amount: i128,
+        recommend_uphold: bool,
         threshold_percentage: u32,
     ) -> Result<u64, ContractError> {
-        // Verify identity - governance participants can create proposals
-        proposer.require_auth();
-        // Note: We could add governance role check here if we want to restrict proposal creation
-        // require_governance_permission(&env, &proposer)?;
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
 
         if is_paused(&env) {
             return Err(ContractError::Paused);
@@ -192,15 +1318,15 @@ impl GovernanceContract {
             .persistent()
             .get(&PROPOSAL_COUNTER)
             .unwrap_or(0) + 1;
-        
+
         let current_time = env.ledger().timestamp();
         let voting_end_time = current_time + (86400u64 * config.1 as u64);
-        
+
         let proposal = (
             proposal_id,
-            proposer.clone(),
-            title.clone(),
-            description.clone(),
+            caller_contract.clone(),
+            Symbol::new(&env, "claim_appeal"),
+            content_hash(&env, (claim_id, amount, recommend_uphold)),
             current_time,
             voting_end_time,
             threshold_percentage,
@@ -208,16 +1334,20 @@ impl GovernanceContract {
             0i128,
             0i128,
             0u32,
-            execution_data.clone(),
+            Symbol::new(&env, "resolve_appeal"),
         );
 
         env.storage()
             .persistent()
             .set(&(PROPOSAL, proposal_id), &proposal);
-        
         env.storage()
             .persistent()
             .set(&PROPOSAL_COUNTER, &proposal_id);
+        env.storage()
+            .persistent()
+            .set(&(APPEAL, proposal_id), &(caller_contract, claim_id));
+
+        record_snapshot(&env, proposal_id);
 
         let mut proposal_list: Vec<u64> = env
             .storage()
@@ -229,166 +1359,243 @@ impl GovernanceContract {
             .persistent()
             .set(&PROPOSAL_LIST, &proposal_list);
 
-        env.events().publish(
-            (Symbol::new(&env, "proposal_created"), proposal_id),
-            (proposer, title, threshold_percentage),
+        publish_event(
+            &env,
+            (Symbol::new(&env, "appeal_proposal_created"), proposal_id),
+            (claim_id, amount, recommend_uphold),
         );
 
         Ok(proposal_id)
     }
 
-    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<(u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol), ContractError> {
-        let proposal: (u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol) = env
-            .storage()
+    /// Claims contract and claim id an appeal proposal decides
+    pub fn get_appeal(env: Env, proposal_id: u64) -> Option<(Address, u64)> {
+        env.storage()
             .persistent()
-            .get(&(PROPOSAL, proposal_id))
-            .ok_or(ContractError::NotFound)?;
-        
-        Ok(proposal)
+            .get(&(APPEAL, proposal_id))
     }
 
-    pub fn vote(
-        env: Env,
-        voter: Address,
-        proposal_id: u64,
-        vote_weight: i128,
-        is_yes: bool,
-    ) -> Result<(), ContractError> {
-        // Verify identity - anyone can vote (could add governance role check)
-        voter.require_auth();
-        // Note: We could add governance role check here if we want to restrict voting
-        // require_governance_permission(&env, &voter)?;
-
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
-        }
-
-        if vote_weight <= 0 {
-            return Err(ContractError::InvalidInput);
-        }
-
-        let _config: (Address, u32, u32, u32) = env
-            .storage()
-            .persistent()
-            .get(&CONFIG)
-            .ok_or(ContractError::NotInitialized)?;
-
-        let mut proposal: (u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol) = env
-            .storage()
-            .persistent()
-            .get(&(PROPOSAL, proposal_id))
-            .ok_or(ContractError::NotFound)?;
-
-        let current_time = env.ledger().timestamp();
-        if !is_voting_period_active(proposal.7, proposal.5, current_time) {
-            return Err(ContractError::VotingPeriodEnded);
-        }
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        let mut proposal = load_proposal(&env, proposal_id)?;
 
-        if has_voted(&env, proposal_id, &voter) {
-            return Err(ContractError::AlreadyVoted);
+        if proposal.7 != ProposalStatus::Passed as u32 {
+            return Err(ContractError::InvalidState);
         }
 
-        let vote_record = (voter.clone(), vote_weight, current_time, is_yes);
+        proposal.7 = ProposalStatus::Executed as u32;
 
         env.storage()
             .persistent()
-            .set(&(VOTER, proposal_id, voter.clone()), &vote_record);
+            .set(&(PROPOSAL, proposal_id), &proposal);
 
-        if is_yes {
-            proposal.8 += vote_weight;
-        } else {
-            proposal.9 += vote_weight;
+        if let Some(template) = env.storage().persistent().get::<_, ProposalTemplate>(&(TEMPLATE, proposal_id)) {
+            apply_template(&env, proposal_id, &template)?;
+        }
+        if let Some(call) = env.storage().persistent().get::<_, ContractCall>(&(CALL, proposal_id)) {
+            validate_call(&env, &call)?;
+            call_as_governance(&env, &call.target, &call.function, call.args);
         }
-        proposal.10 += 1;
-
-        env.storage()
-            .persistent()
-            .set(&(PROPOSAL, proposal_id), &proposal);
 
-        env.events().publish(
-            (Symbol::new(&env, "vote_cast"), proposal_id),
-            (voter, vote_weight, is_yes, proposal.8, proposal.9),
+        publish_event(
+            &env,
+            (Symbol::new(&env, "proposal_executed"), proposal_id),
+            (proposal.11,),
         );
 
         Ok(())
     }
 
-    pub fn finalize_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        let mut proposal: (u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol) = env
-            .storage()
-            .persistent()
-            .get(&(PROPOSAL, proposal_id))
-            .ok_or(ContractError::NotFound)?;
-
-        if proposal.7 != ProposalStatus::Active as u32 {
-            return Err(ContractError::ProposalNotActive);
-        }
+    /// Change the voting parameters (admin only). Proposals already open keep
+    /// the voting end time they were created with.
+    pub fn update_config(
+        env: Env,
+        admin: Address,
+        voting_period_days: u32,
+        min_voting_percentage: u32,
+        min_quorum_percentage: u32,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
-        let current_time = env.ledger().timestamp();
-        if current_time < proposal.5 {
-            return Err(ContractError::InvalidState);
-        }
+        validate_voting_params(voting_period_days, min_voting_percentage, min_quorum_percentage)?;
 
         let config: (Address, u32, u32, u32) = env
             .storage()
             .persistent()
             .get(&CONFIG)
             .ok_or(ContractError::NotInitialized)?;
+        env.storage().persistent().set(
+            &CONFIG,
+            &(config.0, voting_period_days, min_voting_percentage, min_quorum_percentage),
+        );
 
-        let min_quorum_percentage = config.3;
+        publish_event(
+            &env,
+            (Symbol::new(&env, "config_updated"), admin),
+            (voting_period_days, min_voting_percentage, min_quorum_percentage),
+        );
 
-        let total_supply = 1000000i128;
+        Ok(())
+    }
 
-        if !calculate_quorum_met(proposal.8, proposal.9, total_supply, min_quorum_percentage) {
-            proposal.7 = ProposalStatus::Expired as u32;
-        } else if calculate_threshold_met(proposal.8, proposal.9, proposal.6) {
-            proposal.7 = ProposalStatus::Passed as u32;
-        } else {
-            proposal.7 = ProposalStatus::Rejected as u32;
+    /// Run governance as a mutual (admin only): active policyholders of
+    /// the mode's policy contract vote alongside token holders, weighted by
+    /// the premiums they paid, net of refunds, in the year before a proposal
+    /// was created and converted at `votes_per_premium_bps`. `None` returns
+    /// to token-only voting. Open proposals keep the mode they were created
+    /// in.
+    pub fn set_mutual_mode(env: Env, admin: Address, mode: Option<MutualMode>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match &mode {
+            Some(mode) => {
+                if mode.votes_per_premium_bps == 0 || mode.votes_per_premium_bps > MAX_VOTES_PER_PREMIUM_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage().persistent().set(&MUTUAL, mode);
+            }
+            None => env.storage().persistent().remove(&MUTUAL),
         }
 
-        env.storage()
-            .persistent()
-            .set(&(PROPOSAL, proposal_id), &proposal);
+        publish_event(
+            &env,
+            (Symbol::new(&env, "mutual_mode_set"), admin),
+            mode,
+        );
 
-        let total_votes = proposal.8 + proposal.9;
-        let yes_percentage = if total_votes > 0 {
-            (proposal.8 * 100) / total_votes
-        } else {
-            0
+        Ok(())
+    }
+
+    /// Policy contract whose holders vote in mutual mode and the votes their
+    /// premiums are worth
+    pub fn get_mutual_mode(env: Env) -> Option<MutualMode> {
+        env.storage().persistent().get(&MUTUAL)
+    }
+
+    /// Configure voter rewards (admin only); `None` stops rewarding new
+    /// proposals. The reward token can only change once everything already
+    /// allocated has been claimed.
+    pub fn set_voter_rewards(env: Env, admin: Address, config: Option<VoterRewardConfig>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if let Some(config) = &config {
+            if config.reward_per_proposal <= 0 || config.min_weight <= 0 {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        let current: Option<VoterRewardConfig> = env.storage().persistent().get(&VOTER_REWARDS);
+        let token_changes = match (&current, &config) {
+            (Some(current), Some(config)) => current.token != config.token,
+            (Some(_), None) => true,
+            (None, _) => false,
         };
+        if token_changes && rewards_outstanding(&env) > 0 {
+            return Err(ContractError::InvalidState);
+        }
 
-        env.events().publish(
-            (Symbol::new(&env, "proposal_finalized"), proposal_id),
-            (proposal.7, yes_percentage, proposal.8, proposal.9),
+        match &config {
+            Some(config) => env.storage().persistent().set(&VOTER_REWARDS, config),
+            None => env.storage().persistent().remove(&VOTER_REWARDS),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "voter_rewards_set"), admin),
+            config,
         );
 
         Ok(())
     }
 
-    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        let mut proposal: (u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol) = env
+    pub fn get_voter_rewards(env: Env) -> Option<VoterRewardConfig> {
+        env.storage().persistent().get(&VOTER_REWARDS)
+    }
+
+    /// Rewards allocated to finalized proposals and not yet claimed
+    pub fn get_voter_rewards_outstanding(env: Env) -> i128 {
+        rewards_outstanding(&env)
+    }
+
+    /// Proposals a voter has an unclaimed reward share in, or that are still
+    /// being voted on
+    pub fn get_pending_voter_rewards(env: Env, voter: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&(REWARD_PENDING, voter))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Pay a voter their share of every finalized proposal they voted on.
+    /// Proposals still open stay pending. Once every eligible voter has
+    /// claimed, a proposal's whole budget has been paid.
+    pub fn claim_voter_rewards(env: Env, voter: Address) -> Result<i128, ContractError> {
+        voter.require_auth();
+
+        let config: VoterRewardConfig = env
             .storage()
             .persistent()
-            .get(&(PROPOSAL, proposal_id))
+            .get(&VOTER_REWARDS)
             .ok_or(ContractError::NotFound)?;
+        let pending = Self::get_pending_voter_rewards(env.clone(), voter.clone());
+
+        let mut still_pending = Vec::new(&env);
+        let mut amount = 0i128;
+        for proposal_id in pending.iter() {
+            let proposal = load_proposal(&env, proposal_id)?;
+            if proposal.7 == ProposalStatus::Active as u32 {
+                still_pending.push_back(proposal_id);
+                continue;
+            }
 
-        if proposal.7 != ProposalStatus::Passed as u32 {
-            return Err(ContractError::InvalidState);
+            let budget: i128 = env
+                .storage()
+                .persistent()
+                .get(&(REWARD_BUDGET, proposal_id))
+                .unwrap_or(0);
+            let eligible: i128 = env
+                .storage()
+                .persistent()
+                .get(&(REWARD_WEIGHT, proposal_id))
+                .unwrap_or(0);
+            let vote_record: (Address, i128, u64, bool) = env
+                .storage()
+                .persistent()
+                .get(&(VOTER, proposal_id, voter.clone()))
+                .ok_or(ContractError::NotFound)?;
+
+            // Each claim takes its pro-rata share of what is left unclaimed,
+            // so the last voter to claim gets the rounding remainder and the
+            // budget is paid out exactly
+            let share = mul_div_floor(budget, vote_record.1, eligible).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&(REWARD_BUDGET, proposal_id), &(budget - share));
+            env.storage()
+                .persistent()
+                .set(&(REWARD_WEIGHT, proposal_id), &(eligible - vote_record.1));
+            amount = amount.checked_add(share).ok_or(ContractError::Overflow)?;
         }
 
-        proposal.7 = ProposalStatus::Executed as u32;
-
         env.storage()
             .persistent()
-            .set(&(PROPOSAL, proposal_id), &proposal);
+            .set(&(REWARD_PENDING, voter.clone()), &still_pending);
 
-        env.events().publish(
-            (Symbol::new(&env, "proposal_executed"), proposal_id),
-            (proposal.11,),
+        if amount > 0 {
+            env.storage()
+                .persistent()
+                .set(&REWARDS_OUTSTANDING, &(rewards_outstanding(&env) - amount));
+            token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &voter, &amount);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "voter_rewards_claimed"), voter),
+            amount,
         );
 
-        Ok(())
+        Ok(amount)
     }
 
     pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
@@ -398,7 +1605,8 @@ impl GovernanceContract {
         
         set_paused(&env, true);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "paused"), ()),
             admin,
         );
@@ -413,7 +1621,8 @@ impl GovernanceContract {
         
         set_paused(&env, false);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "unpaused"), ()),
             admin,
         );
@@ -458,7 +1667,6 @@ impl GovernanceContract {
             .get(&CONFIG)
             .ok_or(ContractError::NotInitialized)?;
 
-        let proposer = env.current_contract_address();
         let proposal_id: u64 = env
             .storage()
             .persistent()
@@ -468,9 +1676,10 @@ impl GovernanceContract {
         let current_time = env.ledger().timestamp();
         let voting_end_time = current_time + (86400u64 * config.1 as u64);
         
+        // Slashing proposals are always raised by the contract itself, so
+        // the proposer is not stored
         let proposal = (
             proposal_id,
-            proposer.clone(),
             target.clone(),
             role,
             reason,
@@ -493,6 +1702,8 @@ impl GovernanceContract {
             .persistent()
             .set(&PROPOSAL_COUNTER, &proposal_id);
 
+        record_snapshot(&env, proposal_id);
+
         let mut proposal_list: Vec<u64> = env
             .storage()
             .persistent()
@@ -503,7 +1714,8 @@ impl GovernanceContract {
             .persistent()
             .set(&PROPOSAL_LIST, &proposal_list);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "slashing_proposal_created"), proposal_id),
             (target, role, reason, amount, threshold_percentage),
         );
@@ -512,23 +1724,17 @@ impl GovernanceContract {
     }
 
     pub fn execute_slashing_proposal(env: Env, proposal_id: u64) -> Result<u64, ContractError> {
-        let mut proposal: (u64, Address, Address, u32, u32, i128, Symbol, u64, u64, u32, u32, i128, i128, u32) = env
+        let mut proposal: SlashingProposalRecord = env
             .storage()
             .persistent()
             .get(&(PROPOSAL, proposal_id))
             .ok_or(ContractError::NotFound)?;
 
-        if proposal.10 != ProposalStatus::Passed as u32 {
+        if proposal.9 != ProposalStatus::Passed as u32 {
             return Err(ContractError::InvalidState);
         }
 
-        let slashing_contract: Address = env
-            .storage()
-            .persistent()
-            .get(&SLASHING_CONTRACT)
-            .ok_or(ContractError::SlashingContractNotSet)?;
-
-        proposal.10 = ProposalStatus::Executed as u32;
+        proposal.9 = ProposalStatus::Executed as u32;
 
         env.storage()
             .persistent()
@@ -536,15 +1742,16 @@ impl GovernanceContract {
 
         let slash_id = Self::execute_slashing(
             env.clone(),
-            proposal.2, // target
-            proposal.3, // role
-            proposal.4, // reason
-            proposal.5, // amount
+            proposal.1.clone(), // target
+            proposal.2, // role
+            proposal.3, // reason
+            proposal.4, // amount
         )?;
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "slashing_proposal_executed"), proposal_id),
-            (slash_id, proposal.2, proposal.3, proposal.4, proposal.5),
+            (slash_id, proposal.1, proposal.2, proposal.3, proposal.4),
         );
 
         Ok(slash_id)
@@ -557,15 +1764,14 @@ impl GovernanceContract {
         reason: u32,
         amount: i128,
     ) -> Result<u64, ContractError> {
-        let slashing_contract: Address = env
-            .storage()
-            .persistent()
-            .get(&SLASHING_CONTRACT)
-            .ok_or(ContractError::SlashingContractNotSet)?;
+        if !env.storage().persistent().has(&SLASHING_CONTRACT) {
+            return Err(ContractError::SlashingContractNotSet);
+        }
 
         // For now, we'll emit an event and return a mock slash ID
         // In a real implementation, this would make a cross-contract call
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "slashing_executed"), 0u64),
             (target, role, reason, amount),
         );
@@ -573,8 +1779,22 @@ impl GovernanceContract {
         Ok(1u64)
     }
 
+    /// Proposal ids in creation order, `limit` at a time from `cursor`
+    pub fn get_proposals_page(env: Env, cursor: u32, limit: u32) -> IdPage {
+        let proposal_list: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&PROPOSAL_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        pagination::page_of(&env, &proposal_list, cursor, limit).into()
+    }
+
     pub fn get_active_proposals(env: Env) -> Result<Vec<u64>, ContractError> {
-        let all_proposals = Self::get_all_proposals(env.clone())?;
+        let all_proposals: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&PROPOSAL_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
         let current_time = env.ledger().timestamp();
         let mut active_proposals = Vec::new(&env);
 
@@ -590,14 +1810,10 @@ impl GovernanceContract {
     }
 
     pub fn get_proposal_stats(env: Env, proposal_id: u64) -> Result<(i128, i128, u32, u64, u64), ContractError> {
-        let proposal: (u64, Address, Symbol, Symbol, u64, u64, u32, u32, i128, i128, u32, Symbol) = Self::get_proposal(env.clone(), proposal_id)?;
+        let proposal: (u64, Address, Symbol, BytesN<32>, u64, u64, u32, u32, i128, i128, u32, Symbol) = Self::get_proposal(env.clone(), proposal_id)?;
         
         let total_votes = proposal.8 + proposal.9;
-        let yes_percentage = if total_votes > 0 {
-            (proposal.8 * 100) / total_votes
-        } else {
-            0
-        };
+        let yes_percentage = ratio_percent(proposal.8, total_votes).unwrap_or(0);
 
         Ok((
             proposal.8,
@@ -623,6 +1839,38 @@ impl GovernanceContract {
             .ok_or(ContractError::NotInitialized)
     }
 
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["governance_v1", IFACE_VERSION])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
     pub fn is_contract_paused(env: Env) -> bool {
         is_paused(&env)
     }
@@ -644,7 +1892,8 @@ impl GovernanceContract {
         
         insurance_contracts::authorization::grant_role(&env, &admin, &participant, Role::Governance)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_granted"), participant.clone()),
             admin,
         );
@@ -659,7 +1908,8 @@ impl GovernanceContract {
         
         insurance_contracts::authorization::revoke_role(&env, &admin, &participant)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_revoked"), participant.clone()),
             admin,
         );
@@ -672,3 +1922,4 @@ impl GovernanceContract {
         get_role(&env, &address)
     }
 }
+mod test;
@@ -0,0 +1,643 @@
+#![cfg(test)]
+//! Proposal lifecycle
+//!
+//! Governance runs against stand-ins for the governance token, the policy
+//! contract's member weights and the protocol contracts it controls, so
+//! voting power can be set directly and executed changes read back.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+pub(crate) const TOTAL_SUPPLY: i128 = 1_000;
+pub(crate) const VOTING_PERIOD_DAYS: u32 = 7;
+pub(crate) const MIN_QUORUM_PERCENT: u32 = 20;
+pub(crate) const THRESHOLD_PERCENT: u32 = 50;
+
+/// Governance token stand-in with votes set per account
+#[contract]
+pub(crate) struct VotesToken;
+
+#[contractimpl]
+impl VotesToken {
+    pub fn set_votes(env: Env, account: Address, votes: i128) {
+        env.storage().instance().set(&account, &votes);
+    }
+
+    pub fn set_total_supply(env: Env, total: i128) {
+        env.storage().instance().set(&symbol_short!("TOTAL"), &total);
+    }
+
+    pub fn get_past_votes(env: Env, account: Address, _ledger: u32) -> i128 {
+        env.storage().instance().get(&account).unwrap_or(0)
+    }
+
+    pub fn get_past_total_supply(env: Env, _ledger: u32) -> i128 {
+        env.storage().instance().get(&symbol_short!("TOTAL")).unwrap_or(0)
+    }
+}
+
+/// Policy contract stand-in with premium weights set per member
+#[contract]
+pub(crate) struct MemberBook;
+
+#[contractimpl]
+impl MemberBook {
+    pub fn set_member_weight(env: Env, holder: Address, weight: i128) {
+        env.storage().instance().set(&holder, &weight);
+    }
+
+    pub fn set_total_member_weight(env: Env, total: i128) {
+        env.storage().instance().set(&symbol_short!("TOTAL"), &total);
+    }
+
+    pub fn get_member_weight(env: Env, holder: Address, _as_of: u64) -> i128 {
+        env.storage().instance().get(&holder).unwrap_or(0)
+    }
+
+    pub fn get_total_member_weight(env: Env, _as_of: u64) -> i128 {
+        env.storage().instance().get(&symbol_short!("TOTAL")).unwrap_or(0)
+    }
+}
+
+/// Protocol contract stand-in recording the settings governance makes
+#[contract]
+pub(crate) struct ProtocolTarget;
+
+#[contractimpl]
+impl ProtocolTarget {
+    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) {
+        env.storage().instance().set(&symbol_short!("CALLER"), &caller);
+        env.storage().instance().set(&symbol_short!("FEE"), &fee_bps);
+    }
+
+    pub fn set_product(env: Env, caller: Address, spec: ProductSpec) {
+        env.storage().instance().set(&symbol_short!("CALLER"), &caller);
+        env.storage().instance().set(&spec.product_id, &spec);
+    }
+
+    pub fn pause(env: Env, caller: Address, _incident_id: Option<u64>) {
+        env.storage().instance().set(&symbol_short!("CALLER"), &caller);
+        env.storage().instance().set(&symbol_short!("PAUSED"), &true);
+    }
+
+    pub fn activate_stress_mode(env: Env, caller: Address, threshold: i128, haircut_bps: u32) {
+        env.storage().instance().set(&symbol_short!("CALLER"), &caller);
+        env.storage().instance().set(&symbol_short!("STRESS"), &(threshold, haircut_bps));
+    }
+
+    pub fn set_oracle_config(env: Env, caller: Address, oracle: Address, enabled: bool, min_submissions: u32) {
+        env.storage().instance().set(&symbol_short!("CALLER"), &caller);
+        env.storage().instance().set(&symbol_short!("ORACLE"), &(oracle, enabled, min_submissions));
+    }
+
+    pub fn paused(env: Env) -> bool {
+        env.storage().instance().get(&symbol_short!("PAUSED")).unwrap_or(false)
+    }
+
+    pub fn stress(env: Env) -> Option<(i128, u32)> {
+        env.storage().instance().get(&symbol_short!("STRESS"))
+    }
+
+    pub fn oracle(env: Env) -> Option<(Address, bool, u32)> {
+        env.storage().instance().get(&symbol_short!("ORACLE"))
+    }
+
+    pub fn caller(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("CALLER"))
+    }
+
+    pub fn fee_bps(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("FEE"))
+    }
+
+    pub fn product(env: Env, product_id: u32) -> Option<ProductSpec> {
+        env.storage().instance().get(&product_id)
+    }
+}
+
+pub(crate) struct Harness<'a> {
+    pub(crate) env: Env,
+    pub(crate) admin: Address,
+    pub(crate) token: VotesTokenClient<'a>,
+    pub(crate) governance: GovernanceContractClient<'a>,
+}
+
+impl<'a> Harness<'a> {
+    pub(crate) fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1_000;
+            li.sequence_number = 10;
+        });
+
+        let admin = Address::generate(&env);
+        let token = VotesTokenClient::new(&env, &env.register(VotesToken, ()));
+        token.set_total_supply(&TOTAL_SUPPLY);
+
+        let governance = GovernanceContractClient::new(&env, &env.register(GovernanceContract, ()));
+        governance.initialize(&InitConfig {
+            admin: admin.clone(),
+            token_contract: token.address.clone(),
+            voting_period_days: VOTING_PERIOD_DAYS,
+            min_voting_percentage: THRESHOLD_PERCENT,
+            min_quorum_percentage: MIN_QUORUM_PERCENT,
+            slashing_contract: Address::generate(&env),
+        });
+
+        Harness { env, admin, token, governance }
+    }
+
+    /// A token holder with `votes` at every snapshot
+    pub(crate) fn voter(&self, votes: i128) -> Address {
+        let voter = Address::generate(&self.env);
+        self.token.set_votes(&voter, &votes);
+        voter
+    }
+
+    pub(crate) fn hash(&self, seed: u8) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[seed; 32])
+    }
+
+    /// Open a standard proposal
+    pub(crate) fn proposal(&self) -> u64 {
+        let proposer = Address::generate(&self.env);
+        self.governance.create_proposal(
+            &proposer,
+            &symbol_short!("change"),
+            &self.hash(1),
+            &symbol_short!("none"),
+            &THRESHOLD_PERCENT,
+        )
+    }
+
+    /// Open a template proposal
+    pub(crate) fn template(&self, template: &ProposalTemplate) -> Result<u64, ContractError> {
+        let proposer = Address::generate(&self.env);
+        match self.governance.try_create_template_proposal(
+            &proposer,
+            &symbol_short!("template"),
+            &self.hash(2),
+            template,
+            &THRESHOLD_PERCENT,
+        ) {
+            Ok(Ok(proposal_id)) => Ok(proposal_id),
+            Err(Ok(error)) => Err(error),
+            _ => panic!("create_template_proposal did not return a contract result"),
+        }
+    }
+
+    /// Open an emergency proposal from a governance role holder
+    pub(crate) fn emergency(&self, measure: &EmergencyMeasure) -> u64 {
+        let proposer = Address::generate(&self.env);
+        self.governance.grant_governance_role(&self.admin, &proposer);
+        self.governance
+            .create_emergency_proposal(&proposer, &symbol_short!("incident"), &self.hash(4), measure)
+    }
+
+    /// Cast half the token supply for a proposal
+    pub(crate) fn vote_yes(&self, proposal_id: u64) {
+        let voter = self.voter(TOTAL_SUPPLY / 2);
+        self.governance.vote(&voter, &proposal_id, &true);
+    }
+
+    /// Move past the standard voting window
+    pub(crate) fn end_voting(&self) {
+        self.env
+            .ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD_DAYS as u64 * 86_400 + 1);
+    }
+
+    pub(crate) fn status(&self, proposal_id: u64) -> u32 {
+        self.governance.get_proposal(&proposal_id).7
+    }
+}
+
+#[test]
+fn test_mutual_mode_converts_premiums_and_checks_overflow() {
+    let h = Harness::new();
+    let members = MemberBookClient::new(&h.env, &h.env.register(MemberBook, ()));
+    let mode = |votes_per_premium_bps| {
+        Some(MutualMode {
+            policy_contract: members.address.clone(),
+            votes_per_premium_bps,
+        })
+    };
+    for bps in [0, MAX_VOTES_PER_PREMIUM_BPS + 1] {
+        assert_eq!(h.governance.try_set_mutual_mode(&h.admin, &mode(bps)), Err(Ok(ContractError::InvalidInput)));
+    }
+
+    // A member's premiums are worth half a vote per unit on top of tokens
+    h.governance.set_mutual_mode(&h.admin, &mode(5_000));
+    let holder = h.voter(100);
+    let member = Address::generate(&h.env);
+    members.set_member_weight(&holder, &300);
+    members.set_member_weight(&member, &400);
+    members.set_total_member_weight(&1_000);
+
+    let proposal_id = h.proposal();
+    h.governance.vote(&holder, &proposal_id, &true);
+    h.governance.vote(&member, &proposal_id, &false);
+    assert_eq!(h.governance.get_vote_record(&proposal_id, &holder).1, 250);
+    assert_eq!(h.governance.get_vote_record(&proposal_id, &member).1, 200);
+
+    // Lapsed members carry no premium weight and cannot vote without tokens
+    let lapsed = Address::generate(&h.env);
+    assert_eq!(h.governance.try_vote(&lapsed, &proposal_id, &true), Err(Ok(ContractError::NoVotingPower)));
+
+    // Quorum is measured against token supply plus converted premiums:
+    // 450 of 1_500 votes is 30%
+    h.end_voting();
+    h.governance.finalize_proposal(&proposal_id);
+    assert_eq!(h.status(proposal_id), ProposalStatus::Passed as u32);
+
+    // Weights that would overflow the tally are refused, not wrapped
+    let whale = h.voter(i128::MAX);
+    let overflowing = h.proposal();
+    h.governance.vote(&whale, &overflowing, &true);
+    assert_eq!(h.governance.try_vote(&holder, &overflowing, &true), Err(Ok(ContractError::Overflow)));
+    members.set_member_weight(&whale, &i128::MAX);
+    let converted = h.proposal();
+    assert_eq!(h.governance.try_vote(&whale, &converted, &true), Err(Ok(ContractError::Overflow)));
+
+    // Open proposals keep the mode they were created in
+    h.governance.set_mutual_mode(&h.admin, &None);
+    assert_eq!(h.governance.get_mutual_mode(), None);
+    assert_eq!(h.governance.try_vote(&member, &converted, &true), Ok(Ok(())));
+}
+
+#[test]
+fn test_templates_are_bounded_at_creation() {
+    let h = Harness::new();
+    let target = Address::generate(&h.env);
+    let own = h.governance.address.clone();
+    let product = |product_id, min_coverage, max_coverage, max_duration_days| {
+        ProposalTemplate::AddProduct(
+            target.clone(),
+            ProductSpec { product_id, min_coverage, max_coverage, max_duration_days },
+        )
+    };
+    let set_fee = Symbol::new(&h.env, "set_fee_bps");
+
+    for template in [
+        ProposalTemplate::ChangeFeeBps(target.clone(), set_fee.clone(), MAX_TEMPLATE_FEE_BPS + 1),
+        ProposalTemplate::ChangeFeeBps(own.clone(), set_fee, 100),
+        ProposalTemplate::ChangeVotingPeriod(MIN_TEMPLATE_VOTING_DAYS - 1),
+        ProposalTemplate::ChangeVotingPeriod(MAX_TEMPLATE_VOTING_DAYS + 1),
+        product(0, 100, 200, 30),
+        product(1, 0, 200, 30),
+        product(1, 300, 200, 30),
+        product(1, 100, 200, 0),
+        product(1, 100, 200, MAX_TEMPLATE_PRODUCT_DAYS + 1),
+        ProposalTemplate::RegisterTrustedContract(own),
+    ] {
+        assert_eq!(h.template(&template), Err(ContractError::TemplateOutOfBounds));
+    }
+    assert_eq!(h.governance.get_proposal_count(), 0);
+
+    // A contract that is already trusted cannot be proposed again
+    let trusted = Address::generate(&h.env);
+    h.governance.register_appeal_source(&h.admin, &trusted);
+    assert_eq!(
+        h.template(&ProposalTemplate::RegisterTrustedContract(trusted)),
+        Err(ContractError::AlreadyExists)
+    );
+}
+
+#[test]
+fn test_each_template_executes_as_voted() {
+    let h = Harness::new();
+    let target = ProtocolTargetClient::new(&h.env, &h.env.register(ProtocolTarget, ()));
+    let spec = ProductSpec {
+        product_id: 2,
+        min_coverage: 1_000,
+        max_coverage: 50_000,
+        max_duration_days: 180,
+    };
+    let newcomer = Address::generate(&h.env);
+
+    let proposals = [
+        ProposalTemplate::ChangeFeeBps(target.address.clone(), Symbol::new(&h.env, "set_fee_bps"), 250),
+        ProposalTemplate::ChangeVotingPeriod(3),
+        ProposalTemplate::AddProduct(target.address.clone(), spec.clone()),
+        ProposalTemplate::RegisterTrustedContract(newcomer.clone()),
+    ]
+    .map(|template| h.template(&template).unwrap());
+    for proposal_id in proposals {
+        h.vote_yes(proposal_id);
+    }
+
+    // Nothing changes until a passed proposal is executed
+    assert_eq!(
+        h.governance.try_execute_proposal(&proposals[0]),
+        Err(Ok(ContractError::InvalidState))
+    );
+    assert_eq!(
+        h.governance.try_create_appeal_proposal(&newcomer, &7, &1_000, &true, &THRESHOLD_PERCENT),
+        Err(Ok(ContractError::NotTrustedContract))
+    );
+
+    h.end_voting();
+    for proposal_id in proposals {
+        h.governance.finalize_proposal(&proposal_id);
+        h.governance.execute_proposal(&proposal_id);
+        assert_eq!(h.status(proposal_id), ProposalStatus::Executed as u32);
+    }
+
+    assert_eq!(target.fee_bps(), Some(250));
+    assert_eq!(target.product(&2), Some(spec));
+    assert_eq!(target.caller(), Some(h.governance.address.clone()));
+    assert_eq!(h.governance.get_config().1, 3);
+    h.governance.create_appeal_proposal(&newcomer, &7, &1_000, &true, &THRESHOLD_PERCENT);
+}
+
+#[test]
+fn test_call_proposals_only_reach_callable_functions() {
+    let h = Harness::new();
+    let target = ProtocolTargetClient::new(&h.env, &h.env.register(ProtocolTarget, ()));
+    let set_fee = Symbol::new(&h.env, "set_fee_bps");
+    let proposer = Address::generate(&h.env);
+    let call = |args: Vec<Val>| ContractCall {
+        target: target.address.clone(),
+        function: set_fee.clone(),
+        args,
+    };
+    let fee_args = Vec::from_array(&h.env, [300u32.into_val(&h.env)]);
+    let propose = |call: &ContractCall| {
+        h.governance
+            .try_create_call_proposal(&proposer, &symbol_short!("call"), &h.hash(3), call, &THRESHOLD_PERCENT)
+    };
+
+    // Nothing is callable until the admin says so, and never governance itself
+    assert_eq!(propose(&call(fee_args.clone())), Err(Ok(ContractError::CallNotAllowed)));
+    assert_eq!(
+        h.governance.try_set_callable(&h.admin, &h.governance.address, &set_fee, &Some(1)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        h.governance.try_set_callable(&proposer, &target.address, &set_fee, &Some(1)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    h.governance.set_callable(&h.admin, &target.address, &set_fee, &Some(1));
+    assert_eq!(h.governance.get_callable(&target.address, &set_fee), Some(1));
+    assert_eq!(propose(&call(Vec::new(&h.env))), Err(Ok(ContractError::InvalidInput)));
+
+    let executed = propose(&call(fee_args.clone())).unwrap().unwrap();
+    let revoked = propose(&call(fee_args.clone())).unwrap().unwrap();
+    assert_eq!(h.governance.get_call_proposal(&executed), Some(call(fee_args)));
+    h.vote_yes(executed);
+    h.vote_yes(revoked);
+    h.end_voting();
+    h.governance.finalize_proposal(&executed);
+    h.governance.finalize_proposal(&revoked);
+
+    h.governance.execute_proposal(&executed);
+    assert_eq!(target.fee_bps(), Some(300));
+    assert_eq!(target.caller(), Some(h.governance.address.clone()));
+
+    // Stopping a function also stops passed proposals that would call it
+    h.governance.set_callable(&h.admin, &target.address, &set_fee, &None);
+    assert_eq!(h.governance.try_execute_proposal(&revoked), Err(Ok(ContractError::CallNotAllowed)));
+    assert_eq!(h.status(revoked), ProposalStatus::Passed as u32);
+}
+
+#[test]
+fn test_emergency_track_window_quorum_and_early_close() {
+    let h = Harness::new();
+    let target = ProtocolTargetClient::new(&h.env, &h.env.register(ProtocolTarget, ()));
+    let pause = EmergencyMeasure::Pause(target.address.clone());
+
+    // Governance role holders only, with sane measures
+    let outsider = Address::generate(&h.env);
+    assert_eq!(
+        h.governance.try_create_emergency_proposal(&outsider, &symbol_short!("incident"), &h.hash(4), &pause),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    let proposer = Address::generate(&h.env);
+    h.governance.grant_governance_role(&h.admin, &proposer);
+    for measure in [
+        EmergencyMeasure::CircuitBreaker(target.address.clone(), 1_000, 0),
+        EmergencyMeasure::CircuitBreaker(target.address.clone(), -1, 500),
+        EmergencyMeasure::RotateOracle(target.address.clone(), Address::generate(&h.env), 0),
+    ] {
+        assert_eq!(
+            h.governance.try_create_emergency_proposal(&proposer, &symbol_short!("incident"), &h.hash(4), &measure),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+
+    // The window is a day, not the standard week
+    let short_of_quorum = h.emergency(&pause);
+    let standard = h.proposal();
+    let proposal = h.governance.get_proposal(&short_of_quorum);
+    assert_eq!(proposal.5, proposal.4 + DEFAULT_EMERGENCY_VOTING_SECONDS);
+    assert_eq!(
+        h.governance.get_emergency_proposal(&short_of_quorum).unwrap().quorum_percentage,
+        DEFAULT_EMERGENCY_QUORUM_PERCENT
+    );
+
+    // 30% turnout meets the standard quorum but not the emergency one
+    for proposal_id in [short_of_quorum, standard] {
+        h.governance.vote(&h.voter(300), &proposal_id, &true);
+    }
+    assert_eq!(h.governance.try_finalize_proposal(&short_of_quorum), Err(Ok(ContractError::InvalidState)));
+    h.env.ledger().with_mut(|li| li.timestamp += DEFAULT_EMERGENCY_VOTING_SECONDS + 1);
+    h.governance.finalize_proposal(&short_of_quorum);
+    assert_eq!(h.status(short_of_quorum), ProposalStatus::Expired as u32);
+    assert!(!target.paused());
+    h.end_voting();
+    h.governance.finalize_proposal(&standard);
+    assert_eq!(h.status(standard), ProposalStatus::Passed as u32);
+
+    // Enough turnout but short of the super-majority runs to the end, then passes
+    let at_window_end = h.emergency(&pause);
+    h.governance.vote(&h.voter(500), &at_window_end, &true);
+    h.governance.vote(&h.voter(100), &at_window_end, &false);
+    assert_eq!(h.governance.try_finalize_proposal(&at_window_end), Err(Ok(ContractError::InvalidState)));
+    h.env.ledger().with_mut(|li| li.timestamp += DEFAULT_EMERGENCY_VOTING_SECONDS + 1);
+    h.governance.finalize_proposal(&at_window_end);
+    assert_eq!(h.status(at_window_end), ProposalStatus::Executed as u32);
+    assert!(target.paused());
+
+    // Yes votes that are a super-majority of the whole supply close at once
+    let early = h.emergency(&EmergencyMeasure::CircuitBreaker(target.address.clone(), 2_000, 2_500));
+    h.governance.vote(&h.voter(700), &early, &true);
+    assert_eq!(h.governance.try_finalize_proposal(&early), Err(Ok(ContractError::InvalidState)));
+    h.governance.vote(&h.voter(50), &early, &true);
+    h.governance.finalize_proposal(&early);
+    assert_eq!(h.status(early), ProposalStatus::Executed as u32);
+    assert_eq!(target.stress(), Some((2_000, 2_500)));
+    assert_eq!(h.governance.try_vote(&h.voter(100), &early, &false), Err(Ok(ContractError::VotingPeriodEnded)));
+}
+
+#[test]
+fn test_each_emergency_measure_executes_when_passed() {
+    let h = Harness::new();
+    let target = ProtocolTargetClient::new(&h.env, &h.env.register(ProtocolTarget, ()));
+    let oracle = Address::generate(&h.env);
+
+    for measure in [
+        EmergencyMeasure::Pause(target.address.clone()),
+        EmergencyMeasure::CircuitBreaker(target.address.clone(), 5_000, 1_000),
+        EmergencyMeasure::RotateOracle(target.address.clone(), oracle.clone(), 3),
+        EmergencyMeasure::Pause(h.governance.address.clone()),
+    ] {
+        let proposal_id = h.emergency(&measure);
+        h.governance.vote(&h.voter(800), &proposal_id, &true);
+        h.governance.finalize_proposal(&proposal_id);
+        assert_eq!(h.status(proposal_id), ProposalStatus::Executed as u32);
+    }
+
+    assert!(target.paused());
+    assert_eq!(target.stress(), Some((5_000, 1_000)));
+    assert_eq!(target.oracle(), Some((oracle, true, 3)));
+    assert_eq!(target.caller(), Some(h.governance.address.clone()));
+    assert!(h.governance.is_contract_paused());
+}
+
+#[test]
+fn test_voter_rewards_split_pro_rata_and_pay_out_exactly() {
+    let h = Harness::new();
+    let reward_token = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    soroban_sdk::token::StellarAssetClient::new(&h.env, &reward_token).mint(&h.governance.address, &1_000);
+    h.governance.set_voter_rewards(
+        &h.admin,
+        &Some(VoterRewardConfig { token: reward_token.clone(), reward_per_proposal: 100, min_weight: 50 }),
+    );
+
+    let proposal_id = h.proposal();
+    let voters = [h.voter(100), h.voter(200), h.voter(300)];
+    let small = h.voter(40);
+    for voter in voters.iter().chain([&small]) {
+        h.governance.vote(voter, &proposal_id, &true);
+    }
+
+    // Votes below the minimum weight earn nothing
+    assert_eq!(h.governance.get_pending_voter_rewards(&small).len(), 0);
+
+    // Nothing is paid while the proposal is open
+    assert_eq!(h.governance.claim_voter_rewards(&voters[0]), 0);
+    assert_eq!(h.governance.get_pending_voter_rewards(&voters[0]), Vec::from_array(&h.env, [proposal_id]));
+
+    h.end_voting();
+    h.governance.finalize_proposal(&proposal_id);
+    assert_eq!(h.governance.get_voter_rewards_outstanding(), 100);
+
+    // The reward token cannot change while rewards are owed
+    let other_token = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    let switch = VoterRewardConfig { token: other_token, reward_per_proposal: 100, min_weight: 50 };
+    assert_eq!(
+        h.governance.try_set_voter_rewards(&h.admin, &Some(switch.clone())),
+        Err(Ok(ContractError::InvalidState))
+    );
+
+    // Shares of 100 over 600 eligible weight; the last claim takes the remainder
+    let token = soroban_sdk::token::TokenClient::new(&h.env, &reward_token);
+    for (voter, share) in voters.iter().zip([16, 33, 51]) {
+        assert_eq!(h.governance.claim_voter_rewards(voter), share);
+        assert_eq!(token.balance(voter), share);
+        assert_eq!(h.governance.get_pending_voter_rewards(voter).len(), 0);
+    }
+    assert_eq!(h.governance.claim_voter_rewards(&voters[0]), 0);
+    assert_eq!(h.governance.claim_voter_rewards(&small), 0);
+    assert_eq!(h.governance.get_voter_rewards_outstanding(), 0);
+    assert_eq!(token.balance(&h.governance.address), 900);
+
+    h.governance.set_voter_rewards(&h.admin, &Some(switch));
+}
+
+#[test]
+fn test_metadata_amends_until_the_first_vote() {
+    let h = Harness::new();
+    let proposal_id = h.proposal();
+    let proposer = h.governance.get_proposal(&proposal_id).1;
+    let tags = |count: u32| {
+        let mut tags = Vec::new(&h.env);
+        for _ in 0..count {
+            tags.push_back(symbol_short!("risk"));
+        }
+        tags
+    };
+
+    // Proposer only, with a bounded number of tags
+    assert_eq!(
+        h.governance.try_set_proposal_metadata(&h.admin, &proposal_id, &h.hash(5), &h.hash(6), &tags(1)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.governance.try_set_proposal_metadata(&proposer, &proposal_id, &h.hash(5), &h.hash(6), &tags(MAX_PROPOSAL_TAGS + 1)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(h.governance.get_proposal_metadata(&proposal_id), None);
+
+    // Each amendment bumps the version and re-anchors the proposal's text
+    assert_eq!(
+        h.governance.set_proposal_metadata(&proposer, &proposal_id, &h.hash(5), &h.hash(6), &tags(MAX_PROPOSAL_TAGS)),
+        1
+    );
+    assert_eq!(h.governance.set_proposal_metadata(&proposer, &proposal_id, &h.hash(7), &h.hash(6), &tags(1)), 2);
+    let metadata = h.governance.get_proposal_metadata(&proposal_id).unwrap();
+    assert_eq!((metadata.version, metadata.content_hash, metadata.tags.len()), (2, h.hash(7), 1));
+    assert_eq!(h.governance.get_proposal(&proposal_id).3, h.hash(7));
+    let first = h.governance.get_proposal_metadata_version(&proposal_id, &1).unwrap();
+    assert_eq!((first.content_hash, first.tags.len()), (h.hash(5), MAX_PROPOSAL_TAGS));
+
+    // The first vote freezes what is being voted on
+    h.governance.vote(&h.voter(100), &proposal_id, &true);
+    assert_eq!(
+        h.governance.try_set_proposal_metadata(&proposer, &proposal_id, &h.hash(8), &h.hash(6), &tags(1)),
+        Err(Ok(ContractError::ProposalNotActive))
+    );
+    assert_eq!(h.governance.get_proposal_metadata(&proposal_id).unwrap().version, 2);
+    assert_eq!(h.governance.get_proposal(&proposal_id).3, h.hash(7));
+}
+
+#[test]
+fn test_migration_rewrites_title_and_description_proposals() {
+    let h = Harness::new();
+    let proposer = Address::generate(&h.env);
+    let (title, description) = (symbol_short!("raise_cap"), symbol_short!("see_forum"));
+
+    // A proposal stored before slugs and content hashes, next to a current one
+    h.env.as_contract(&h.governance.address, || {
+        let legacy = (
+            1u64,
+            proposer.clone(),
+            title.clone(),
+            description.clone(),
+            1_000u64,
+            1_000u64 + 86_400 * VOTING_PERIOD_DAYS as u64,
+            THRESHOLD_PERCENT,
+            ProposalStatus::Active as u32,
+            0i128,
+            0i128,
+            0u32,
+            symbol_short!("none"),
+        );
+        h.env.storage().persistent().set(&(PROPOSAL, 1u64), &legacy);
+        h.env.storage().persistent().set(&(PROPOSAL_SNAPSHOT, 1u64), &9u32);
+        h.env.storage().persistent().set(&PROPOSAL_COUNTER, &1u64);
+    });
+    let current = h.proposal();
+
+    // Unmigrated, it is reported rather than trapping readers
+    assert_eq!(h.governance.try_get_proposal(&1), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(h.governance.try_vote(&h.voter(100), &1, &true), Err(Ok(ContractError::InvalidState)));
+    let batch = h.governance.get_proposals(&Vec::from_array(&h.env, [1, current]));
+    assert_eq!((batch.get_unchecked(0), batch.get_unchecked(1).is_some()), (None, true));
+
+    assert_eq!(h.governance.migrate_proposals(&h.admin, &1, &10), 1);
+    let migrated = h.governance.get_proposal(&1);
+    assert_eq!((migrated.1, migrated.2.clone()), (proposer, title.clone()));
+    assert_eq!(
+        migrated.3,
+        h.env.as_contract(&h.governance.address, || content_hash(&h.env, (title, description)))
+    );
+    assert_eq!(h.governance.get_proposal(&current).3, h.hash(1));
+
+    // Migrating again changes nothing, and the proposal takes votes
+    assert_eq!(h.governance.migrate_proposals(&h.admin, &1, &10), 0);
+    h.governance.vote(&h.voter(100), &1, &true);
+    assert_eq!(h.governance.get_proposal(&1).8, 100);
+}
@@ -0,0 +1,541 @@
+#![no_std]
+use soroban_sdk::{contract, contractclient, contractimpl, contracterror, symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+// Import authorization from the common library
+use insurance_contracts::authorization::{initialize_admin, require_admin};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::version::{self, ContractVersion, IFACE_VERSION};
+
+#[contract]
+pub struct GovernanceTokenContract;
+
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const BALANCE: Symbol = symbol_short!("BALANCE");
+const DELEGATE: Symbol = symbol_short!("DELEGATE");
+const VOTES_CP: Symbol = symbol_short!("VOTES_CP");
+const VOTES_CNT: Symbol = symbol_short!("VOTES_CNT");
+const SUPPLY_CP: Symbol = symbol_short!("SUPPLY_CP");
+const SUPPLY_CNT: Symbol = symbol_short!("SUPPLY_N");
+const DELEGATION_HOOK: Symbol = symbol_short!("DLG_HOOK");
+
+/// Contract notified whenever voting power is delegated. The call is
+/// isolated: a failing hook only produces a `delegation_hook_failed` event.
+#[contractclient(name = "DelegationHookClient")]
+pub trait DelegationHook {
+    fn on_delegate(env: Env, delegator: Address, from_delegatee: Address, to_delegatee: Address, amount: i128);
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ContractError {
+    Unauthorized = 1,
+    InvalidInput = 3,
+    InsufficientFunds = 4,
+    NotInitialized = 8,
+    AlreadyInitialized = 9,
+    InvalidRole = 10,
+    RoleNotFound = 11,
+    NotTrustedContract = 12,
+    FutureLookup = 13,
+    Overflow = 107,
+}
+
+impl From<insurance_contracts::authorization::AuthError> for ContractError {
+    fn from(err: insurance_contracts::authorization::AuthError) -> Self {
+        match err {
+            insurance_contracts::authorization::AuthError::Unauthorized => ContractError::Unauthorized,
+            insurance_contracts::authorization::AuthError::InvalidRole => ContractError::InvalidRole,
+            insurance_contracts::authorization::AuthError::RoleNotFound => ContractError::RoleNotFound,
+            insurance_contracts::authorization::AuthError::NotTrustedContract => ContractError::NotTrustedContract,
+        }
+    }
+}
+
+fn underlying(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)
+}
+
+fn balance_of(env: &Env, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(BALANCE, account.clone()))
+        .unwrap_or(0i128)
+}
+
+/// Undelegated balances vote for their holder
+fn delegate_of(env: &Env, account: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&(DELEGATE, account.clone()))
+        .unwrap_or_else(|| account.clone())
+}
+
+/// Latest checkpointed value in a series, or 0 when empty
+fn latest_checkpoint(env: &Env, series: &(Symbol, Option<Address>), count: u32) -> i128 {
+    if count == 0 {
+        return 0;
+    }
+    let (_, value): (u32, i128) = env
+        .storage()
+        .persistent()
+        .get(&(series.0.clone(), series.1.clone(), count - 1))
+        .unwrap_or((0, 0));
+    value
+}
+
+/// Record `value` for the current ledger, overwriting an entry already
+/// written in the same ledger
+fn write_checkpoint(env: &Env, series: &(Symbol, Option<Address>), count_key: &(Symbol, Option<Address>), value: i128) {
+    let ledger = env.ledger().sequence();
+    let count: u32 = env.storage().persistent().get(count_key).unwrap_or(0);
+
+    if count > 0 {
+        let (last_ledger, _): (u32, i128) = env
+            .storage()
+            .persistent()
+            .get(&(series.0.clone(), series.1.clone(), count - 1))
+            .unwrap_or((0, 0));
+        if last_ledger == ledger {
+            env.storage()
+                .persistent()
+                .set(&(series.0.clone(), series.1.clone(), count - 1), &(ledger, value));
+            return;
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(series.0.clone(), series.1.clone(), count), &(ledger, value));
+    env.storage().persistent().set(count_key, &(count + 1));
+}
+
+/// Value of a series as of the end of `ledger`, by binary search over its
+/// checkpoints
+fn checkpoint_at(env: &Env, series: &(Symbol, Option<Address>), count: u32, ledger: u32) -> i128 {
+    let mut low = 0u32;
+    let mut high = count;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let (mid_ledger, _): (u32, i128) = env
+            .storage()
+            .persistent()
+            .get(&(series.0.clone(), series.1.clone(), mid))
+            .unwrap_or((0, 0));
+        if mid_ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if low == 0 {
+        return 0;
+    }
+    let (_, value): (u32, i128) = env
+        .storage()
+        .persistent()
+        .get(&(series.0.clone(), series.1.clone(), low - 1))
+        .unwrap_or((0, 0));
+    value
+}
+
+fn votes_series(account: &Address) -> ((Symbol, Option<Address>), (Symbol, Option<Address>)) {
+    ((VOTES_CP, Some(account.clone())), (VOTES_CNT, Some(account.clone())))
+}
+
+fn votes_of(env: &Env, account: &Address) -> i128 {
+    let (series, count_key) = votes_series(account);
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    latest_checkpoint(env, &series, count)
+}
+
+fn adjust_votes(env: &Env, delegatee: &Address, delta: i128) -> Result<(), ContractError> {
+    let votes = votes_of(env, delegatee).checked_add(delta).ok_or(ContractError::Overflow)?;
+    let (series, count_key) = votes_series(delegatee);
+    write_checkpoint(env, &series, &count_key, votes);
+    Ok(())
+}
+
+fn total_supply(env: &Env) -> i128 {
+    let count: u32 = env.storage().persistent().get(&(SUPPLY_CNT, None::<Address>)).unwrap_or(0);
+    latest_checkpoint(env, &(SUPPLY_CP, None), count)
+}
+
+fn adjust_supply(env: &Env, delta: i128) -> Result<(), ContractError> {
+    let supply = total_supply(env).checked_add(delta).ok_or(ContractError::Overflow)?;
+    write_checkpoint(env, &(SUPPLY_CP, None), &(SUPPLY_CNT, None), supply);
+    Ok(())
+}
+
+/// Snapshot lookups are only final for ledgers that have closed
+fn require_past_ledger(env: &Env, ledger: u32) -> Result<(), ContractError> {
+    if ledger >= env.ledger().sequence() {
+        return Err(ContractError::FutureLookup);
+    }
+    Ok(())
+}
+
+fn validate_amount(amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Voting wrapper around an existing SEP-41 token. Holders deposit the
+/// underlying token 1:1 and get balances whose voting power is checkpointed
+/// per ledger, so governance can weigh votes as of a proposal's snapshot.
+#[contractimpl]
+impl GovernanceTokenContract {
+    pub fn initialize(env: Env, admin: Address, underlying_token: Address) -> Result<(), ContractError> {
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+
+        env.storage().persistent().set(&CONFIG, &underlying_token);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    /// Lock `amount` of the underlying token and mint the same voting balance
+    pub fn deposit(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
+        from.require_auth();
+        validate_amount(amount)?;
+
+        let token = underlying(&env)?;
+        token::Client::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+
+        let balance = balance_of(&env, &from).checked_add(amount).ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&(BALANCE, from.clone()), &balance);
+        adjust_votes(&env, &delegate_of(&env, &from), amount)?;
+        adjust_supply(&env, amount)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "deposited"), from),
+            (amount, balance),
+        );
+
+        Ok(())
+    }
+
+    /// Burn `amount` of voting balance and release the underlying token
+    pub fn withdraw(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
+        from.require_auth();
+        validate_amount(amount)?;
+
+        let balance = balance_of(&env, &from);
+        if amount > balance {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        env.storage().persistent().set(&(BALANCE, from.clone()), &(balance - amount));
+        adjust_votes(&env, &delegate_of(&env, &from), -amount)?;
+        adjust_supply(&env, -amount)?;
+
+        let token = underlying(&env)?;
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &from, &amount);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "withdrawn"), from),
+            (amount, balance - amount),
+        );
+
+        Ok(())
+    }
+
+    /// Move the delegator's whole voting power to `delegatee`. Delegating to
+    /// oneself restores the default.
+    pub fn delegate(env: Env, delegator: Address, delegatee: Address) -> Result<(), ContractError> {
+        delegator.require_auth();
+
+        let previous = delegate_of(&env, &delegator);
+        if previous == delegatee {
+            return Ok(());
+        }
+
+        let amount = balance_of(&env, &delegator);
+        adjust_votes(&env, &previous, -amount)?;
+        adjust_votes(&env, &delegatee, amount)?;
+
+        if delegatee == delegator {
+            env.storage().persistent().remove(&(DELEGATE, delegator.clone()));
+        } else {
+            env.storage().persistent().set(&(DELEGATE, delegator.clone()), &delegatee);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "delegate_changed"), delegator.clone()),
+            (previous.clone(), delegatee.clone(), amount),
+        );
+
+        if let Some(hook) = env.storage().persistent().get::<_, Address>(&DELEGATION_HOOK) {
+            let result = DelegationHookClient::new(&env, &hook)
+                .try_on_delegate(&delegator, &previous, &delegatee, &amount);
+            if result.is_err() {
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "delegation_hook_failed"), hook),
+                    delegator,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set or clear the contract notified on delegation changes (admin only)
+    pub fn set_delegation_hook(env: Env, admin: Address, hook: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match hook.clone() {
+            Some(hook) => env.storage().persistent().set(&DELEGATION_HOOK, &hook),
+            None => env.storage().persistent().remove(&DELEGATION_HOOK),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "delegation_hook_set"), admin),
+            hook,
+        );
+
+        Ok(())
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        balance_of(&env, &id)
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        total_supply(&env)
+    }
+
+    pub fn delegates(env: Env, account: Address) -> Address {
+        delegate_of(&env, &account)
+    }
+
+    /// Current voting power: own undelegated balance plus delegations received
+    pub fn get_votes(env: Env, account: Address) -> i128 {
+        votes_of(&env, &account)
+    }
+
+    /// Voting power of `account` at the end of a closed `ledger`
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> Result<i128, ContractError> {
+        require_past_ledger(&env, ledger)?;
+        let (series, count_key) = votes_series(&account);
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        Ok(checkpoint_at(&env, &series, count, ledger))
+    }
+
+    /// Total supply at the end of a closed `ledger`
+    pub fn get_past_total_supply(env: Env, ledger: u32) -> Result<i128, ContractError> {
+        require_past_ledger(&env, ledger)?;
+        let count: u32 = env.storage().persistent().get(&(SUPPLY_CNT, None::<Address>)).unwrap_or(0);
+        Ok(checkpoint_at(&env, &(SUPPLY_CP, None), count, ledger))
+    }
+
+    pub fn underlying_token(env: Env) -> Result<Address, ContractError> {
+        underlying(&env)
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["governance_token_v1", "votes_v1", IFACE_VERSION])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+    #[contract]
+    struct FailingHook;
+
+    #[contractimpl]
+    impl FailingHook {
+        pub fn on_delegate(_env: Env, _delegator: Address, _from_delegatee: Address, _to_delegatee: Address, _amount: i128) {
+            panic!("hook down");
+        }
+    }
+
+    fn setup(env: &Env) -> (GovernanceTokenContractClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        let admin = Address::generate(env);
+        let underlying = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let client = GovernanceTokenContractClient::new(env, &env.register(GovernanceTokenContract, ()));
+        client.initialize(&admin, &underlying);
+        (client, admin, underlying)
+    }
+
+    fn at_ledger(env: &Env, sequence: u32) {
+        env.ledger().with_mut(|li| li.sequence_number = sequence);
+    }
+
+    #[test]
+    fn test_checkpointed_votes_and_delegation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let underlying = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        StellarAssetClient::new(&env, &underlying).mint(&alice, &1_000);
+
+        let contract_id = env.register(GovernanceTokenContract, ());
+        let client = GovernanceTokenContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &underlying);
+
+        env.ledger().with_mut(|li| li.sequence_number = 10);
+        client.deposit(&alice, &600);
+        assert_eq!(client.get_votes(&alice), 600);
+
+        env.ledger().with_mut(|li| li.sequence_number = 20);
+        client.delegate(&alice, &bob);
+        assert_eq!(client.get_votes(&alice), 0);
+        assert_eq!(client.get_votes(&bob), 600);
+
+        env.ledger().with_mut(|li| li.sequence_number = 30);
+        client.withdraw(&alice, &100);
+        assert_eq!(client.get_votes(&bob), 500);
+
+        env.ledger().with_mut(|li| li.sequence_number = 40);
+        assert_eq!(client.get_past_votes(&alice, &9), 0);
+        assert_eq!(client.get_past_votes(&alice, &15), 600);
+        assert_eq!(client.get_past_votes(&bob, &20), 600);
+        assert_eq!(client.get_past_votes(&bob, &35), 500);
+        assert_eq!(client.get_past_total_supply(&25), 600);
+        assert_eq!(client.get_past_total_supply(&30), 500);
+        assert_eq!(client.try_get_past_votes(&bob, &40), Err(Ok(ContractError::FutureLookup)));
+    }
+
+    #[test]
+    fn test_redelegation_moves_whole_balance_and_later_deposits() {
+        let env = Env::default();
+        let (client, admin, underlying) = setup(&env);
+        let (alice, bob, carol) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+        StellarAssetClient::new(&env, &underlying).mint(&alice, &1_000);
+
+        // Delegating an empty balance moves nothing but sticks for deposits
+        at_ledger(&env, 10);
+        client.delegate(&alice, &bob);
+        assert_eq!(client.get_votes(&bob), 0);
+        client.deposit(&alice, &400);
+        assert_eq!((client.get_votes(&alice), client.get_votes(&bob)), (0, 400));
+
+        // Redelegating moves it all to the new delegatee, not back through alice
+        at_ledger(&env, 20);
+        client.delegate(&alice, &carol);
+        assert_eq!((client.get_votes(&bob), client.get_votes(&carol)), (0, 400));
+        assert_eq!(client.delegates(&alice), carol);
+
+        // A failing hook does not undo the delegation
+        client.set_delegation_hook(&admin, &Some(env.register(FailingHook, ())));
+        at_ledger(&env, 30);
+        client.delegate(&alice, &alice);
+        assert_eq!((client.get_votes(&alice), client.get_votes(&carol)), (400, 0));
+        assert_eq!(client.delegates(&alice), alice);
+
+        // Delegating to the current delegatee changes nothing
+        let seq = client.get_last_event_seq();
+        client.delegate(&alice, &alice);
+        assert_eq!(client.get_last_event_seq(), seq);
+        assert_eq!(client.get_votes(&alice), 400);
+    }
+
+    #[test]
+    fn test_checkpoints_keep_last_write_per_ledger() {
+        let env = Env::default();
+        let (client, _, underlying) = setup(&env);
+        let alice = Address::generate(&env);
+        StellarAssetClient::new(&env, &underlying).mint(&alice, &1_000);
+
+        // Several writes in one ledger leave its closing value
+        at_ledger(&env, 10);
+        client.deposit(&alice, &100);
+        client.deposit(&alice, &200);
+        client.withdraw(&alice, &50);
+        for (sequence, amount) in [(12, 100), (15, 100), (19, 100)] {
+            at_ledger(&env, sequence);
+            client.deposit(&alice, &amount);
+        }
+
+        at_ledger(&env, 25);
+        for (ledger, votes) in [(9, 0), (10, 250), (11, 250), (12, 350), (14, 350), (15, 450), (18, 450), (19, 550), (24, 550)] {
+            assert_eq!(client.get_past_votes(&alice, &ledger), votes);
+            assert_eq!(client.get_past_total_supply(&ledger), votes);
+        }
+        assert_eq!(client.try_get_past_total_supply(&25), Err(Ok(ContractError::FutureLookup)));
+        assert_eq!(client.try_withdraw(&alice, &551), Err(Ok(ContractError::InsufficientFunds)));
+        assert_eq!(client.try_deposit(&alice, &0), Err(Ok(ContractError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_tokens_moved_after_a_snapshot_do_not_vote_twice() {
+        let env = Env::default();
+        let (client, _, underlying) = setup(&env);
+        let (alice, bob, carol) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+        StellarAssetClient::new(&env, &underlying).mint(&alice, &1_000);
+
+        at_ledger(&env, 10);
+        client.deposit(&alice, &1_000);
+        client.delegate(&alice, &bob);
+        let snapshot = 10;
+
+        // After the snapshot alice hands her tokens to carol, who wraps them
+        at_ledger(&env, 11);
+        client.withdraw(&alice, &1_000);
+        TokenClient::new(&env, &underlying).transfer(&alice, &carol, &1_000);
+        client.deposit(&carol, &1_000);
+
+        at_ledger(&env, 12);
+        assert_eq!(client.get_past_votes(&bob, &snapshot), 1_000);
+        assert_eq!(client.get_past_votes(&carol, &snapshot), 0);
+        assert_eq!(client.get_past_total_supply(&snapshot), 1_000);
+        assert_eq!((client.get_votes(&bob), client.get_votes(&carol)), (0, 1_000));
+        assert_eq!(client.get_past_total_supply(&11), 1_000);
+    }
+}
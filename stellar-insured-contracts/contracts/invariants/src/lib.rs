@@ -11,10 +11,12 @@
 //! - I5: Authorization Consistency: Role assignments must be consistent across contracts
 //! - I6: Coverage Constraint: `claim_amount <= policy_coverage_amount`
 //! - I7: Premium Validity: `premium_amount > 0` for active policies
+//! - I8: Reserve Adequacy: `unearned_premium + claim_reserves <= total_liquidity`
 
 #![no_std]
 
-use soroban_sdk::contracterror;
+use insurance_contracts::events::publish_event;
+use soroban_sdk::{contracterror, contracttype, Env, Symbol, Vec};
 
 /// Invariant violation error codes
 #[contracterror]
@@ -42,6 +44,67 @@ pub enum InvariantError {
     PolicyNotFound = 109,
     /// Claim does not exist or is not accessible
     ClaimNotFound = 110,
+    /// I8: Unearned premium and claim reserves exceed pool liquidity
+    ReserveInadequate = 111,
+}
+
+/// Invariant identifiers used in audit reports
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Invariant {
+    LiquidityPreservation = 1,
+    PolicyStateValidity = 2,
+    ClaimStateValidity = 3,
+    AmountNonNegativity = 4,
+    AuthorizationConsistency = 5,
+    CoverageConstraint = 6,
+    PremiumValidity = 7,
+    ReserveAdequacy = 8,
+}
+
+/// Result of evaluating one invariant against live storage
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantCheck {
+    pub invariant: Invariant,
+    pub holds: bool,
+}
+
+/// Invariants a contract evaluated in a `check_invariants` call. An
+/// invariant evaluated over several records appears once, holding only if
+/// it held for all of them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    pub checks: Vec<InvariantCheck>,
+    pub violations: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+impl InvariantReport {
+    pub fn new(env: &Env) -> Self {
+        InvariantReport {
+            checks: Vec::new(env),
+            violations: 0,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        }
+    }
+
+    /// Record the outcome of an invariant, reporting it if it failed
+    pub fn record(&mut self, invariant: Invariant, holds: bool) {
+        self.checks.push_back(InvariantCheck { invariant, holds });
+        if !holds {
+            self.violations += 1;
+            report_violation(self.checks.env(), invariant);
+        }
+    }
+
+    pub fn all_hold(&self) -> bool {
+        self.violations == 0
+    }
 }
 
 /// Protocol invariant definitions
@@ -72,8 +135,11 @@ impl ProtocolInvariants {
     /// Claims must follow valid state transitions:
     /// - Submitted → UnderReview (admin-initiated)
     /// - UnderReview → Approved/Rejected (admin-decided)
-    /// - Approved → Settled (admin-executed)
-    /// - Rejected → terminal (no settlement)
+    /// - Approved → PendingSettlement/Settled (admin-executed)
+    /// - PendingSettlement → Disputed/Settled
+    /// - Disputed → PendingSettlement/Rejected
+    /// - Submitted/UnderReview → Withdrawn (claimant-initiated)
+    /// - Rejected, Settled, Withdrawn → terminal
     ///
     /// Direct jumps are prevented; claims cannot move backward.
     pub const CLAIM_STATE_VALIDITY: &'static str = "I3:ValidClaimStates";
@@ -113,67 +179,100 @@ impl ProtocolInvariants {
     ///
     /// Zero premiums are only valid in test/special scenarios.
     pub const PREMIUM_VALIDITY: &'static str = "I7:Premium>0";
+
+    /// I8: Reserve Adequacy Invariant
+    ///
+    /// The pool must hold enough liquidity to back both the premium it has
+    /// not yet earned and the claims it has reserved for.
+    /// `unearned_premium + claim_reserves <= total_liquidity`
+    ///
+    /// Stronger than I1: liquidity backing coverage that is still at risk
+    /// cannot be counted as free.
+    pub const RESERVE_ADEQUACY: &'static str = "I8:Reserves≤Liquidity";
+}
+
+/// Publish an `invariant_violation` event for a failed invariant. Called by
+/// `InvariantReport::record`, so a `check_invariants` call made in a
+/// transaction leaves a record of every violation it found.
+pub fn report_violation(env: &Env, invariant: Invariant) {
+    publish_event(
+        env,
+        (Symbol::new(env, "invariant_violation"), invariant as u32),
+        env.ledger().sequence(),
+    );
 }
 
 /// Macro for asserting invariant conditions in critical paths
-/// 
-/// Usage: `assert_invariant!(condition, error_code, message)`
-/// 
-/// Fails immediately (fail-fast) on violation with error code
+///
+/// Usage: `assert_invariant!(env, condition, error)`
+///
+/// On violation, returns `Err(error.into())` from the enclosing function,
+/// so it works in any function whose error type implements
+/// `From<InvariantError>`. The call fails and rolls back, leaving nothing
+/// on-chain; violations are recorded by `check_invariants`.
 #[macro_export]
 macro_rules! assert_invariant {
-    ($condition:expr, $error:expr, $message:expr) => {{
+    ($env:expr, $condition:expr, $error:expr) => {{
+        let _: &soroban_sdk::Env = $env;
         if !$condition {
-            panic!("Invariant violation: {} - {}", $message, stringify!($error));
+            return Err($error.into());
         }
     }};
 }
 
 /// Macro for asserting liquidity is sufficient for outstanding claims
-/// 
-/// Usage: `assert_liquidity_sufficient!(available_liquidity, reserved_claims)`
+///
+/// Usage: `assert_liquidity_sufficient!(env, available_liquidity, reserved_claims)`
 #[macro_export]
 macro_rules! assert_liquidity_sufficient {
-    ($available:expr, $reserved:expr) => {{
-        if $available < $reserved {
-            return Err($crate::InvariantError::LiquidityViolation);
-        }
+    ($env:expr, $available:expr, $reserved:expr) => {{
+        $crate::assert_invariant!($env, $available >= $reserved, $crate::InvariantError::LiquidityViolation);
+    }};
+}
+
+/// Macro for asserting the pool's reserves are covered by its liquidity
+///
+/// Usage: `assert_reserve_adequate!(env, total_liquidity, unearned_premium, claim_reserves)`
+#[macro_export]
+macro_rules! assert_reserve_adequate {
+    ($env:expr, $liquidity:expr, $unearned:expr, $reserved:expr) => {{
+        let required = ($unearned).checked_add($reserved);
+        $crate::assert_invariant!($env, required.is_some(), $crate::InvariantError::Overflow);
+        $crate::assert_invariant!(
+            $env,
+            Some($liquidity) >= required,
+            $crate::InvariantError::ReserveInadequate
+        );
     }};
 }
 
 /// Macro for asserting valid state transitions
-/// 
-/// Usage: `assert_valid_state!(current_state, allowed_next_states, actual_next_state)`
+///
+/// Usage: `assert_valid_state!(env, allowed_next_states, actual_next_state)`
 #[macro_export]
 macro_rules! assert_valid_state {
-    ($current:expr, $allowed:expr, $actual:expr) => {{
-        if !$allowed.contains(&$actual) {
-            return Err($crate::InvariantError::InvalidClaimState);
-        }
+    ($env:expr, $allowed:expr, $actual:expr) => {{
+        $crate::assert_invariant!($env, $allowed.contains(&$actual), $crate::InvariantError::InvalidClaimState);
     }};
 }
 
 /// Macro for asserting amount constraints
-/// 
-/// Usage: `assert_valid_amount!(amount, min_value)`
+///
+/// Usage: `assert_valid_amount!(env, amount, min_value)`
 #[macro_export]
 macro_rules! assert_valid_amount {
-    ($amount:expr, $min:expr) => {{
-        if $amount < $min {
-            return Err($crate::InvariantError::InvalidAmount);
-        }
+    ($env:expr, $amount:expr, $min:expr) => {{
+        $crate::assert_invariant!($env, $amount >= $min, $crate::InvariantError::InvalidAmount);
     }};
 }
 
 /// Macro for asserting coverage constraints
-/// 
-/// Usage: `assert_coverage_constraint!(claim_amount, policy_coverage)`
+///
+/// Usage: `assert_coverage_constraint!(env, claim_amount, policy_coverage)`
 #[macro_export]
 macro_rules! assert_coverage_constraint {
-    ($claim:expr, $coverage:expr) => {{
-        if $claim > $coverage {
-            return Err($crate::InvariantError::CoverageExceeded);
-        }
+    ($env:expr, $claim:expr, $coverage:expr) => {{
+        $crate::assert_invariant!($env, $claim <= $coverage, $crate::InvariantError::CoverageExceeded);
     }};
 }
 
@@ -210,6 +309,8 @@ macro_rules! safe_mul {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::contract;
+    use soroban_sdk::testutils::Events;
 
     #[test]
     fn test_invariant_error_codes() {
@@ -220,6 +321,7 @@ mod tests {
         assert_eq!(InvariantError::AuthorizationViolation as u32, 104);
         assert_eq!(InvariantError::CoverageExceeded as u32, 105);
         assert_eq!(InvariantError::InvalidPremium as u32, 106);
+        assert_eq!(InvariantError::ReserveInadequate as u32, 111);
     }
 
     #[test]
@@ -237,4 +339,65 @@ mod tests {
             "I3:ValidClaimStates"
         );
     }
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn test_invariant_report() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+
+        env.as_contract(&contract_id, || {
+            let mut report = InvariantReport::new(&env);
+            report.record(Invariant::LiquidityPreservation, true);
+            assert!(report.all_hold());
+            assert_eq!(env.events().all().events().len(), 0);
+
+            // A failed invariant is counted and published
+            report.record(Invariant::ReserveAdequacy, false);
+            assert_eq!(report.violations, 1);
+            assert!(!report.all_hold());
+            assert_eq!(report.checks.len(), 2);
+            assert_eq!(Invariant::ReserveAdequacy as u32, 8);
+            assert_eq!(env.events().all().events().len(), 1);
+            assert_eq!(insurance_contracts::events::last_event_seq(&env), 1);
+        });
+    }
+
+    fn check_reserves(env: &Env, liquidity: i128, unearned: i128, reserved: i128) -> Result<(), InvariantError> {
+        assert_reserve_adequate!(env, liquidity, unearned, reserved);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserve_adequacy() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(check_reserves(&env, 100, 40, 60), Ok(()));
+            assert_eq!(check_reserves(&env, 0, 0, 0), Ok(()));
+            assert_eq!(check_reserves(&env, 100, 41, 60), Err(InvariantError::ReserveInadequate));
+            assert_eq!(check_reserves(&env, 100, i128::MAX, 1), Err(InvariantError::Overflow));
+        });
+    }
+
+    fn check_amount(env: &Env, amount: i128) -> Result<(), InvariantError> {
+        assert_valid_amount!(env, amount, 1);
+        assert_coverage_constraint!(env, amount, 500);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assertions_return_errors() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(check_amount(&env, 200), Ok(()));
+            assert_eq!(check_amount(&env, 0), Err(InvariantError::InvalidAmount));
+            assert_eq!(check_amount(&env, 501), Err(InvariantError::CoverageExceeded));
+        });
+    }
 }
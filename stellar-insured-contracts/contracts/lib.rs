@@ -4,8 +4,6 @@
 //! This module contains common types, utilities, and error handling
 //! used across all insurance contracts in the Stellar Insured ecosystem.
 
-#![no_std]
-
 use soroban_sdk::{contracttype, Address, Env, Symbol, String, BytesN};
 
 /// Re-export authorization module for easy access
@@ -16,13 +14,61 @@ pub mod authorization {
         initialize_admin, get_admin, grant_role, revoke_role, get_role,
         has_role, require_role, require_admin, has_any_role, require_any_role,
         require_policy_management, require_claim_processing,
-        require_risk_pool_management, require_governance_permission,
+        require_risk_pool_management, require_governance_permission, require_arbitration,
         register_trusted_contract, unregister_trusted_contract,
         is_trusted_contract, require_trusted_contract,
+        set_governance_contract, get_governance_contract, is_governance_contract,
+        schedule_admin_sunset, get_admin_sunset, is_admin_sunset,
         verify_and_require_role, verify_and_check_permission,
     };
 }
 
+/// Re-export checked ratio math from the shared crate
+/// Import like: use insurance_contracts::math::{bps_of, mul_div_floor};
+pub mod math {
+    pub use shared::math::*;
+}
+
+/// Re-export the fixed-point decimal from the shared crate
+pub mod decimal {
+    pub use shared::decimal::*;
+}
+
+/// Re-export the utilization rate models from the shared crate
+pub mod rate_model {
+    pub use shared::rate_model::*;
+}
+
+/// Re-export the pagination helpers from the shared crate
+pub mod pagination {
+    pub use shared::pagination::*;
+}
+
+/// Re-export the actuarial pricing parameters from the shared crate
+pub mod actuarial {
+    pub use shared::actuarial::*;
+}
+
+/// Re-export the streamed reward accounting from the shared crate
+pub mod rewards {
+    pub use shared::rewards::*;
+}
+
+/// Re-export the temporary storage helpers from the shared crate
+pub mod ephemeral {
+    pub use shared::ephemeral::*;
+}
+
+/// Re-export the decimal conversions between assets from the shared crate
+pub mod units {
+    pub use shared::units::*;
+}
+
+/// Re-export the time-weighted averages from the shared crate
+pub mod twap {
+    pub use shared::twap::*;
+}
+
 /// Common contract types shared across all insurance contracts
 pub mod types {
     use super::*;
@@ -37,16 +83,8 @@ pub mod types {
         Claimed,
     }
 
-    /// Claim status enumeration
-    #[contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum ClaimStatus {
-        Submitted,
-        UnderReview,
-        Approved,
-        Rejected,
-        Settled,
-    }
+    /// Claim status enumeration, shared by every contract
+    pub use shared::types::ClaimStatus;
 
     /// Governance proposal status
     #[contracttype]
@@ -86,12 +124,1252 @@ pub mod types {
 
         /// Claim evidence storage
         ClaimEvidence(BytesN<32>), // claim_id → evidence
+
+        /// Sequence number of the last published event
+        EventSeq,
+
+        /// Listener contracts registered for an event topic
+        Listeners(Symbol),
+
+        /// Whether payouts are restricted to allowlisted recipients
+        AllowlistEnabled,
+
+        /// Allowlisted payout recipient → time it becomes usable
+        AllowedRecipient(Address),
+
+        /// Allowlisted settlement asset → its decimals and when it was added
+        AllowedAsset(Address),
+
+        /// Threshold, delay and guardian for large payouts
+        PayoutDelayConfig,
+
+        /// Payout id → time the delayed payout can be released
+        PendingPayout(u64),
+
+        /// Payout ids cancelled by the guardian, awaiting governance
+        HeldPayout(u64),
+
+        /// Whether the peer handshake has completed
+        Wired,
+
+        /// Hash of the wasm installed by the last upgrade
+        WasmHash,
+
+        /// Hash over the contract's key aggregates
+        StateRoot,
+
+        /// Latest registry snapshot and its export progress
+        Snapshot,
+
+        /// Last incident id issued
+        IncidentCounter,
+
+        /// Incident id → incident record
+        Incident(u64),
+    }
+}
+
+/// Monotonically indexed protocol events
+///
+/// Every event published through [`events::publish_event`] carries a
+/// contract-wide sequence number as the first element of its data, so
+/// indexers can detect gaps and resume from the last sequence they saw.
+pub mod events {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::events::Event;
+    use soroban_sdk::{IntoVal, Topics, Val, Vec};
+
+    /// Event published with exactly the topics and data it was built from
+    pub(crate) struct RawEvent {
+        pub topics: Vec<Val>,
+        pub data: Val,
+    }
+
+    impl Event for RawEvent {
+        fn topics(&self, _env: &Env) -> Vec<Val> {
+            self.topics.clone()
+        }
+
+        fn data(&self, _env: &Env) -> Val {
+            self.data
+        }
+    }
+
+    /// Sequence number of the last published event (0 if none)
+    pub fn last_event_seq(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventSeq)
+            .unwrap_or(0u64)
+    }
+
+    /// Publish an event with data `(seq, data)`; `seq` starts at 1 and
+    /// increases by exactly one per event.
+    pub fn publish_event<T, D>(env: &Env, topics: T, data: D)
+    where
+        T: Topics,
+        D: IntoVal<Env, Val>,
+    {
+        let seq = last_event_seq(env) + 1;
+        env.storage().instance().set(&DataKey::EventSeq, &seq);
+        let data: Val = data.into_val(env);
+        env.events().publish_event(&RawEvent {
+            topics: topics.into_val(env),
+            data: (seq, data).into_val(env),
+        });
+    }
+}
+
+/// Webhook-style subscriptions: trusted listener contracts registered per
+/// event topic receive an `on_event` callback when the topic is emitted.
+///
+/// Callbacks are isolated: a listener that fails or reverts does not affect
+/// the emitting call, it only produces a `listener_failed` event. The number
+/// of listeners per topic is capped to bound the cost of a notification.
+pub mod listeners {
+    use super::*;
+    use crate::events::publish_event;
+    use crate::types::DataKey;
+    use soroban_sdk::{contractclient, Vec};
+
+    /// Maximum listeners per topic
+    pub const MAX_LISTENERS_PER_TOPIC: u32 = 5;
+
+    /// Interface implemented by listener contracts
+    #[contractclient(name = "EventListenerClient")]
+    pub trait EventListener {
+        /// `source` is the emitting contract, `id` the policy/claim id and
+        /// `amount` the amount relevant to the event (coverage, payout, ...)
+        fn on_event(env: Env, source: Address, topic: Symbol, id: u64, amount: i128);
+    }
+
+    /// Listener registry errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum ListenerError {
+        AlreadyRegistered,
+        NotRegistered,
+        TooManyListeners,
+    }
+
+    /// Listeners registered for `topic`
+    pub fn get_listeners(env: &Env, topic: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Listeners(topic.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Register `listener` for `topic` (caller is responsible for authorization)
+    pub fn add_listener(env: &Env, topic: &Symbol, listener: &Address) -> Result<(), ListenerError> {
+        let mut listeners = get_listeners(env, topic);
+        if listeners.contains(listener) {
+            return Err(ListenerError::AlreadyRegistered);
+        }
+        if listeners.len() >= MAX_LISTENERS_PER_TOPIC {
+            return Err(ListenerError::TooManyListeners);
+        }
+
+        listeners.push_back(listener.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listeners(topic.clone()), &listeners);
+        Ok(())
+    }
+
+    /// Remove `listener` from `topic` (caller is responsible for authorization)
+    pub fn remove_listener(env: &Env, topic: &Symbol, listener: &Address) -> Result<(), ListenerError> {
+        let mut listeners = get_listeners(env, topic);
+        let index = listeners
+            .first_index_of(listener)
+            .ok_or(ListenerError::NotRegistered)?;
+
+        listeners.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listeners(topic.clone()), &listeners);
+        Ok(())
+    }
+
+    /// Invoke `on_event` on every listener registered for `topic`
+    pub fn notify(env: &Env, topic: &Symbol, id: u64, amount: i128) {
+        let source = env.current_contract_address();
+        for listener in get_listeners(env, topic).iter() {
+            let client = EventListenerClient::new(env, &listener);
+            if client.try_on_event(&source, topic, &id, &amount).is_err() {
+                publish_event(
+                    env,
+                    (Symbol::new(env, "listener_failed"), listener),
+                    (topic.clone(), id),
+                );
+            }
+        }
+    }
+}
+
+/// Payout recipient allowlist
+///
+/// When enabled, payouts may only go to recipients registered ahead of time.
+/// Registration only takes effect after a delay, so a compromised
+/// operational key cannot add a recipient and drain funds to it at once.
+pub mod allowlist {
+    use super::*;
+    use crate::types::DataKey;
+
+    /// Delay between registering a recipient and it becoming usable
+    pub const RECIPIENT_ACTIVATION_DELAY: u64 = 48 * 60 * 60;
+
+    /// Allowlist errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum AllowlistError {
+        RecipientNotAllowed,
+        RecipientPending,
+    }
+
+    pub fn is_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowlistEnabled)
+            .unwrap_or(false)
+    }
+
+    /// Turn allowlist mode on or off (caller is responsible for authorization)
+    pub fn set_enabled(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowlistEnabled, &enabled);
+    }
+
+    /// Register `recipient`; returns the time it becomes usable
+    /// (caller is responsible for authorization)
+    pub fn add_recipient(env: &Env, recipient: &Address) -> u64 {
+        let active_at = env.ledger().timestamp() + RECIPIENT_ACTIVATION_DELAY;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedRecipient(recipient.clone()), &active_at);
+        active_at
+    }
+
+    /// Remove `recipient` immediately (caller is responsible for authorization)
+    pub fn remove_recipient(env: &Env, recipient: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedRecipient(recipient.clone()));
+    }
+
+    /// Time `recipient` becomes usable, if registered
+    pub fn recipient_active_at(env: &Env, recipient: &Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedRecipient(recipient.clone()))
+    }
+
+    /// Check `recipient` may receive a payout. Always passes while allowlist
+    /// mode is off.
+    pub fn require_allowed(env: &Env, recipient: &Address) -> Result<(), AllowlistError> {
+        if !is_enabled(env) {
+            return Ok(());
+        }
+        match recipient_active_at(env, recipient) {
+            None => Err(AllowlistError::RecipientNotAllowed),
+            Some(active_at) if env.ledger().timestamp() < active_at => {
+                Err(AllowlistError::RecipientPending)
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+/// Settlement asset allowlist
+///
+/// Tokens used for premiums, bonds, stakes and payouts must be allowlisted
+/// by governance first. Allowlisting probes the address through the SEP-41
+/// interface, reading `decimals` and making a zero transfer to itself, so a
+/// mistyped or bogus address is refused at configuration time rather than
+/// failing on the first payout. The decimals read are recorded for amount
+/// math across assets.
+pub mod assets {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::token;
+
+    /// Most decimals an allowlisted asset may use
+    pub const MAX_ASSET_DECIMALS: u32 = 18;
+
+    /// Allowlisted asset and what its probe found
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct AssetInfo {
+        pub decimals: u32,
+        pub allowed_at: u64,
+    }
+
+    /// Asset errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum AssetError {
+        /// The address does not answer the token interface
+        NotToken,
+        UnsupportedDecimals,
+        NotAllowed,
+    }
+
+    /// Check `token` behaves as a SEP-41 token; returns its decimals
+    pub fn probe(env: &Env, token: &Address) -> Result<u32, AssetError> {
+        let client = token::Client::new(env, token);
+        let decimals = match client.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Err(AssetError::NotToken),
+        };
+        if decimals > MAX_ASSET_DECIMALS {
+            return Err(AssetError::UnsupportedDecimals);
+        }
+
+        let me = env.current_contract_address();
+        match client.try_transfer(&me, &me, &0) {
+            Ok(Ok(())) => Ok(decimals),
+            _ => Err(AssetError::NotToken),
+        }
+    }
+
+    /// Probe `token` and allowlist it (caller is responsible for
+    /// authorization)
+    pub fn allow(env: &Env, token: &Address) -> Result<AssetInfo, AssetError> {
+        let info = AssetInfo {
+            decimals: probe(env, token)?,
+            allowed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowedAsset(token.clone()), &info);
+        Ok(info)
+    }
+
+    /// Drop `token` from the allowlist (caller is responsible for
+    /// authorization). Configuration already using it is not changed.
+    pub fn disallow(env: &Env, token: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedAsset(token.clone()));
+    }
+
+    pub fn get_info(env: &Env, token: &Address) -> Option<AssetInfo> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedAsset(token.clone()))
+    }
+
+    pub fn require_allowed(env: &Env, token: &Address) -> Result<AssetInfo, AssetError> {
+        get_info(env, token).ok_or(AssetError::NotAllowed)
+    }
+
+    /// Recorded decimals of `token`; tokens configured before the allowlist
+    /// existed are taken to use the canonical seven
+    pub fn decimals(env: &Env, token: &Address) -> u32 {
+        get_info(env, token).map_or(crate::units::CANONICAL_DECIMALS, |info| info.decimals)
+    }
+}
+
+/// Time-delayed large payouts
+///
+/// Payouts above a configured threshold are not executed immediately: they
+/// are scheduled and can only be released once the delay has passed. Until
+/// then the guardian can cancel them, which puts the payout on hold until
+/// governance clears it. Ids are chosen by the calling contract (claim id,
+/// proposal id, ...). Schedules live in temporary storage and lapse if never
+/// released; holds are persistent, since a lapsed hold would free the payout.
+pub mod payout_delay {
+    use super::*;
+    use crate::ephemeral;
+    use crate::types::DataKey;
+
+    /// How long a matured payout stays releasable before its schedule lapses
+    pub const RELEASE_WINDOW_SECONDS: u64 = 30 * 86_400;
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PayoutDelayConfig {
+        /// Payouts strictly above this amount are delayed
+        pub threshold: i128,
+        /// Seconds between scheduling and release
+        pub delay: u64,
+        /// Address allowed to cancel pending payouts
+        pub guardian: Address,
+    }
+
+    /// Payout delay errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum PayoutDelayError {
+        NotPending,
+        StillLocked,
+        NotGuardian,
+        Held,
+    }
+
+    pub fn get_config(env: &Env) -> Option<PayoutDelayConfig> {
+        env.storage().instance().get(&DataKey::PayoutDelayConfig)
+    }
+
+    /// Set the delay configuration (caller is responsible for authorization)
+    pub fn set_config(env: &Env, config: &PayoutDelayConfig) {
+        env.storage()
+            .instance()
+            .set(&DataKey::PayoutDelayConfig, config);
+    }
+
+    /// Whether a payout of `amount` must go through the delay
+    pub fn requires_delay(env: &Env, amount: i128) -> bool {
+        get_config(env).is_some_and(|config| amount > config.threshold)
+    }
+
+    /// Time a scheduled payout can be released, if pending
+    pub fn pending_release_at(env: &Env, id: u64) -> Option<u64> {
+        env.storage().temporary().get(&DataKey::PendingPayout(id))
+    }
+
+    pub fn is_held(env: &Env, id: u64) -> bool {
+        env.storage().persistent().has(&DataKey::HeldPayout(id))
+    }
+
+    /// Schedule payout `id`; returns the release time. The schedule is
+    /// temporary: left unreleased for `RELEASE_WINDOW_SECONDS` after the
+    /// delay, it lapses and the payout has to be scheduled again.
+    pub fn schedule(env: &Env, id: u64) -> Result<u64, PayoutDelayError> {
+        if is_held(env, id) {
+            return Err(PayoutDelayError::Held);
+        }
+        let delay = get_config(env).map_or(0, |config| config.delay);
+        let release_at = env.ledger().timestamp() + delay;
+        ephemeral::set(
+            env,
+            &DataKey::PendingPayout(id),
+            &release_at,
+            delay.saturating_add(RELEASE_WINDOW_SECONDS),
+        );
+        Ok(release_at)
+    }
+
+    /// Consume a pending payout whose delay has passed
+    pub fn release(env: &Env, id: u64) -> Result<(), PayoutDelayError> {
+        let release_at = pending_release_at(env, id).ok_or(PayoutDelayError::NotPending)?;
+        if env.ledger().timestamp() < release_at {
+            return Err(PayoutDelayError::StillLocked);
+        }
+        env.storage().temporary().remove(&DataKey::PendingPayout(id));
+        Ok(())
+    }
+
+    /// Cancel a pending payout and put it on hold. `guardian` must be the
+    /// configured guardian and must have authorized the call.
+    pub fn cancel(env: &Env, guardian: &Address, id: u64) -> Result<(), PayoutDelayError> {
+        let config = get_config(env).ok_or(PayoutDelayError::NotGuardian)?;
+        if config.guardian != *guardian {
+            return Err(PayoutDelayError::NotGuardian);
+        }
+        if pending_release_at(env, id).is_none() {
+            return Err(PayoutDelayError::NotPending);
+        }
+        env.storage().temporary().remove(&DataKey::PendingPayout(id));
+        env.storage().persistent().set(&DataKey::HeldPayout(id), &true);
+        Ok(())
+    }
+
+    /// Lift the hold on a cancelled payout (caller is responsible for authorization)
+    pub fn clear_hold(env: &Env, id: u64) -> Result<(), PayoutDelayError> {
+        if !is_held(env, id) {
+            return Err(PayoutDelayError::NotPending);
+        }
+        env.storage().persistent().remove(&DataKey::HeldPayout(id));
+        Ok(())
+    }
+}
+
+/// Version and interface introspection
+///
+/// Every contract reports its crate version, the hash of the wasm installed
+/// by its last upgrade and the interfaces it implements, so registries and
+/// off-chain tools can check compatibility before wiring contracts together.
+/// Interface ids carry their own version suffix and are bumped on breaking
+/// changes to the interface.
+pub mod version {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::Vec;
+
+    pub const IFACE_VERSION: &str = "version_v1";
+    pub const IFACE_WIRING: &str = "wiring_v1";
+    pub const IFACE_LISTENERS: &str = "listeners_v1";
+    pub const IFACE_ALLOWLIST: &str = "allowlist_v1";
+    pub const IFACE_PAYOUT_DELAY: &str = "payout_delay_v1";
+    pub const IFACE_RESCUE: &str = "rescue_v1";
+    pub const IFACE_SNAPSHOT: &str = "snapshot_v1";
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ContractVersion {
+        /// Semantic version of the contract crate
+        pub semver: String,
+        /// Whether the contract has been upgraded since deployment
+        pub has_wasm_hash: bool,
+        /// Wasm installed by the last upgrade; all zeroes for the original
+        /// deployment
+        pub wasm_hash: BytesN<32>,
+    }
+
+    /// Version of the running contract; `semver` is the calling crate's
+    /// `CARGO_PKG_VERSION`
+    pub fn get(env: &Env, semver: &str) -> ContractVersion {
+        let wasm_hash: Option<BytesN<32>> = env.storage().persistent().get(&DataKey::WasmHash);
+        ContractVersion {
+            semver: String::from_str(env, semver),
+            has_wasm_hash: wasm_hash.is_some(),
+            wasm_hash: wasm_hash.unwrap_or_else(|| BytesN::from_array(env, &[0; 32])),
+        }
+    }
+
+    pub fn interfaces(env: &Env, ids: &[&str]) -> Vec<Symbol> {
+        let mut interfaces = Vec::new(env);
+        for id in ids {
+            interfaces.push_back(Symbol::new(env, id));
+        }
+        interfaces
+    }
+
+    /// Replace the contract code and record the new hash
+    /// (caller is responsible for authorization)
+    pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::WasmHash, new_wasm_hash);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+    }
+}
+
+/// Cross-contract wiring handshake
+///
+/// Initialization only stores peer addresses. Before a contract serves
+/// cross-contract traffic, its admin runs `finalize_wiring`, which calls
+/// `accept_wiring` on every configured peer. A peer answers with its wiring
+/// version only if it already trusts the caller, so a successful handshake
+/// proves both sides agree on each other and on the interface version.
+pub mod wiring {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::contractclient;
+
+    /// Version of the cross-contract interface spoken by this release
+    pub const WIRING_VERSION: u32 = 1;
+
+    /// Handshake implemented by every wired contract
+    #[contractclient(name = "WiringClient")]
+    pub trait Wiring {
+        /// Returns the callee's wiring version if it trusts `peer`
+        fn accept_wiring(env: Env, peer: Address) -> u32;
+    }
+
+    /// Wiring errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum WiringError {
+        NotWired,
+        HandshakeFailed,
+        VersionMismatch,
+    }
+
+    pub fn is_wired(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Wired)
+            .unwrap_or(false)
+    }
+
+    pub fn require_wired(env: &Env) -> Result<(), WiringError> {
+        if is_wired(env) {
+            Ok(())
+        } else {
+            Err(WiringError::NotWired)
+        }
+    }
+
+    /// Run the handshake against `peer`
+    pub fn verify_peer(env: &Env, peer: &Address) -> Result<(), WiringError> {
+        match WiringClient::new(env, peer).try_accept_wiring(&env.current_contract_address()) {
+            Ok(Ok(version)) if version == WIRING_VERSION => Ok(()),
+            Ok(Ok(_)) => Err(WiringError::VersionMismatch),
+            _ => Err(WiringError::HandshakeFailed),
+        }
+    }
+
+    /// Mark the contract as wired (caller is responsible for verifying peers)
+    pub fn set_wired(env: &Env) {
+        env.storage().instance().set(&DataKey::Wired, &true);
+    }
+}
+
+/// Hot configuration in instance storage
+///
+/// Pause flags, configuration, counters and running aggregates are read by
+/// nearly every call. Instance storage is loaded together with the contract
+/// instance, so each of them kept there is one ledger entry less in every
+/// footprint, and they live as long as the instance instead of each risking
+/// archival on its own. Only small, bounded values belong in the instance:
+/// all of it is loaded on every call. Registries and lists stay persistent.
+pub mod hot_storage {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::{IntoVal, Val};
+
+    /// Ledgers per day at a five second close time
+    pub const DAY_IN_LEDGERS: u32 = 17_280;
+    /// Once the instance TTL falls below the threshold it is extended to
+    /// `INSTANCE_TTL_EXTEND_TO`
+    pub const INSTANCE_TTL_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+    pub const INSTANCE_TTL_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
+
+    /// Keep the instance, and the hot items with it, from being archived
+    pub fn extend(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
+    }
+
+    /// Move `key` from persistent to instance storage, for contracts
+    /// upgraded from a release that kept it persistent. Returns whether
+    /// there was anything to move.
+    pub fn migrate<K>(env: &Env, key: &K) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let value: Option<Val> = env.storage().persistent().get(key);
+        match value {
+            Some(value) => {
+                env.storage().instance().set(key, &value);
+                env.storage().persistent().remove(key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Migrate the hot items kept by the shared modules; returns how many
+    /// were moved
+    pub fn migrate_shared(env: &Env) -> u32 {
+        [
+            DataKey::EventSeq,
+            DataKey::Wired,
+            DataKey::StateRoot,
+            DataKey::PayoutDelayConfig,
+            DataKey::AllowlistEnabled,
+        ]
+        .iter()
+        .filter(|key| migrate(env, *key))
+        .count() as u32
+    }
+}
+
+/// Recovery of tokens sent to a contract by mistake
+///
+/// Only the part of the contract's balance above what it accounts for can be
+/// moved out, so a rescue can never touch funds the contract owes to users.
+pub mod rescue {
+    use super::*;
+    use soroban_sdk::token;
+
+    /// Rescue errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum RescueError {
+        InvalidAmount,
+        ExceedsSurplus,
+    }
+
+    /// Balance of `token` held by the current contract above `accounted`
+    pub fn surplus(env: &Env, token: &Address, accounted: i128) -> i128 {
+        let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+        (balance - accounted).max(0)
+    }
+
+    /// Transfer `amount` of `token` to `recipient`, keeping at least
+    /// `accounted` in the contract (caller is responsible for authorization)
+    pub fn transfer_surplus(
+        env: &Env,
+        token: &Address,
+        amount: i128,
+        recipient: &Address,
+        accounted: i128,
+    ) -> Result<(), RescueError> {
+        if amount <= 0 {
+            return Err(RescueError::InvalidAmount);
+        }
+        if amount > surplus(env, token, accounted) {
+            return Err(RescueError::ExceedsSurplus);
+        }
+        token::Client::new(env, token).transfer(&env.current_contract_address(), recipient, &amount);
+        Ok(())
+    }
+}
+
+/// On-chain incident log
+///
+/// Operators open an incident when an outage or emergency starts, naming the
+/// components it affects, close it when it ends and later anchor the hash of
+/// the published postmortem. Pauses and unpauses can cite the incident they
+/// respond to, so every emergency action can be traced to a record.
+pub mod incident {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::Vec;
+
+    /// Most components a single incident can name
+    pub const MAX_COMPONENTS: u32 = 8;
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Incident {
+        pub id: u64,
+        pub opened_by: Address,
+        pub components: Vec<Symbol>,
+        pub started_at: u64,
+        /// Zero while the incident is open
+        pub ended_at: u64,
+        pub postmortem_hash: Option<BytesN<32>>,
+    }
+
+    /// Incident log errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum IncidentError {
+        InvalidInput,
+        NotFound,
+        AlreadyClosed,
+        StillOpen,
+    }
+
+    pub fn get(env: &Env, id: u64) -> Option<Incident> {
+        env.storage().persistent().get(&DataKey::Incident(id))
+    }
+
+    /// Number of incidents opened so far
+    pub fn count(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IncidentCounter)
+            .unwrap_or(0)
+    }
+
+    /// Open an incident affecting `components`; returns its id (caller is
+    /// responsible for authorization)
+    pub fn open(env: &Env, opened_by: &Address, components: Vec<Symbol>) -> Result<u64, IncidentError> {
+        if components.is_empty() || components.len() > MAX_COMPONENTS {
+            return Err(IncidentError::InvalidInput);
+        }
+        let id = count(env) + 1;
+        let incident = Incident {
+            id,
+            opened_by: opened_by.clone(),
+            components,
+            started_at: env.ledger().timestamp(),
+            ended_at: 0,
+            postmortem_hash: None,
+        };
+        env.storage().persistent().set(&DataKey::Incident(id), &incident);
+        env.storage().persistent().set(&DataKey::IncidentCounter, &id);
+        Ok(id)
+    }
+
+    /// Mark an open incident as ended (caller is responsible for authorization)
+    pub fn close(env: &Env, id: u64) -> Result<Incident, IncidentError> {
+        let mut incident = get(env, id).ok_or(IncidentError::NotFound)?;
+        if incident.ended_at != 0 {
+            return Err(IncidentError::AlreadyClosed);
+        }
+        incident.ended_at = env.ledger().timestamp().max(incident.started_at + 1);
+        env.storage().persistent().set(&DataKey::Incident(id), &incident);
+        Ok(incident)
+    }
+
+    /// Anchor the postmortem of a closed incident. A corrected postmortem
+    /// replaces the hash. (Caller is responsible for authorization.)
+    pub fn anchor_postmortem(env: &Env, id: u64, postmortem_hash: BytesN<32>) -> Result<(), IncidentError> {
+        let mut incident = get(env, id).ok_or(IncidentError::NotFound)?;
+        if incident.ended_at == 0 {
+            return Err(IncidentError::StillOpen);
+        }
+        incident.postmortem_hash = Some(postmortem_hash);
+        env.storage().persistent().set(&DataKey::Incident(id), &incident);
+        Ok(())
+    }
+
+    /// Check that an incident cited by an action exists
+    pub fn require_exists(env: &Env, id: Option<u64>) -> Result<(), IncidentError> {
+        match id {
+            Some(id) if get(env, id).is_none() => Err(IncidentError::NotFound),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Hash over a contract's key aggregates for light-client verification
+///
+/// Contracts call [`state_root::update`] whenever they write the aggregates
+/// the root covers, passing them in a fixed order. The root is the SHA-256 of
+/// their XDR encoding, so a monitor or bridge that tracks the expected
+/// aggregates can recompute it and detect divergence with a single read.
+pub mod state_root {
+    use super::*;
+    use crate::types::DataKey;
+    use soroban_sdk::xdr::ToXdr;
+    use soroban_sdk::{IntoVal, Val};
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct StateRoot {
+        pub root: BytesN<32>,
+        /// Ledger of the write that produced the root
+        pub ledger: u32,
+    }
+
+    /// Recompute and store the root over `aggregates`
+    pub fn update<T>(env: &Env, aggregates: T) -> BytesN<32>
+    where
+        T: IntoVal<Env, Val>,
+    {
+        let aggregates: Val = aggregates.into_val(env);
+        let root: BytesN<32> = env.crypto().sha256(&aggregates.to_xdr(env)).into();
+        env.storage().instance().set(
+            &DataKey::StateRoot,
+            &StateRoot {
+                root: root.clone(),
+                ledger: env.ledger().sequence(),
+            },
+        );
+        root
+    }
+
+    /// Root as of the last aggregate write, if any
+    pub fn get(env: &Env) -> Option<StateRoot> {
+        env.storage().instance().get(&DataKey::StateRoot)
+    }
+}
+
+/// Chunked export of a registry as events
+///
+/// An admin starts a snapshot over the first `total` entries of an index,
+/// then keepers call the contract's export entrypoint until it completes.
+/// Each call claims the next window via [`snapshot::next_chunk`], so chunks
+/// are contiguous and never repeated, and the contract emits one event per
+/// entry tagged with the snapshot id. Only the latest snapshot is tracked;
+/// starting a new one abandons any export still in progress.
+pub mod snapshot {
+    use super::*;
+    use crate::pagination::clamp_limit;
+    use crate::types::DataKey;
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Snapshot {
+        pub id: u64,
+        /// Ledger the snapshot was started at
+        pub ledger: u32,
+        /// Entries covered by the snapshot
+        pub total: u32,
+        /// Index of the next entry to export
+        pub cursor: u32,
+        pub completed: bool,
+    }
+
+    /// Snapshot errors
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum SnapshotError {
+        NotFound,
+        AlreadyCompleted,
+    }
+
+    /// Start a snapshot over the first `total` entries (caller is
+    /// responsible for authorization)
+    pub fn start(env: &Env, total: u32) -> Snapshot {
+        let id = get(env).map(|snapshot| snapshot.id).unwrap_or(0) + 1;
+        let snapshot = Snapshot {
+            id,
+            ledger: env.ledger().sequence(),
+            total,
+            cursor: 0,
+            completed: total == 0,
+        };
+        env.storage().persistent().set(&DataKey::Snapshot, &snapshot);
+        snapshot
+    }
+
+    /// Latest snapshot, if any
+    pub fn get(env: &Env) -> Option<Snapshot> {
+        env.storage().persistent().get(&DataKey::Snapshot)
+    }
+
+    /// Claim the next window of at most `limit` entries of snapshot
+    /// `snapshot_id`. Returns the window as `from..to` together with the
+    /// updated snapshot.
+    pub fn next_chunk(env: &Env, snapshot_id: u64, limit: u32) -> Result<(u32, u32, Snapshot), SnapshotError> {
+        let mut snapshot = get(env)
+            .filter(|snapshot| snapshot.id == snapshot_id)
+            .ok_or(SnapshotError::NotFound)?;
+        if snapshot.completed {
+            return Err(SnapshotError::AlreadyCompleted);
+        }
+
+        let from = snapshot.cursor;
+        let to = from.saturating_add(clamp_limit(limit)).min(snapshot.total);
+        snapshot.cursor = to;
+        snapshot.completed = to == snapshot.total;
+        env.storage().persistent().set(&DataKey::Snapshot, &snapshot);
+
+        Ok((from, to, snapshot))
+    }
+}
+
+/// Failure injection for integration tests
+///
+/// Stand-ins for the policy, risk pool and oracle contracts that behave
+/// like the real ones until told to fail. Each function can be switched to
+/// revert or to time out independently, and the ledger clock can be skewed,
+/// so tests can drive the claim lifecycle through the failures a live
+/// deployment sees and check that nothing is left half-done.
+#[cfg(any(test, feature = "testutils"))]
+pub mod chaos {
+    use super::*;
+    use crate::wiring::WIRING_VERSION;
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::{contract, contractimpl, Map};
+
+    /// How a stubbed function behaves when called
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum FailureMode {
+        /// Answer like the real contract
+        Healthy,
+        /// Abort the call, reverting the caller's cross-contract invocation
+        Revert,
+        /// Answer with nothing fresh: no submissions, data from long ago
+        Timeout,
+    }
+
+    /// Same encoding as the policy contract's `PolicyState`
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum PolicyState {
+        ACTIVE,
+        EXPIRED,
+        CANCELLED,
+        CLAIMED,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    enum ChaosKey {
+        Failure(Symbol),
+        Policy(u64),
+        Reservations,
+        PaidOut,
+        Recorded,
+        Route(u64),
+        OracleData(u64),
+        Node(u64),
+        Recipient(u64),
+        Liquidity,
+        Credit(Address),
+    }
+
+    fn failure_mode(env: &Env, function: &str) -> FailureMode {
+        env.storage()
+            .instance()
+            .get(&ChaosKey::Failure(Symbol::new(env, function)))
+            .unwrap_or(FailureMode::Healthy)
+    }
+
+    fn set_failure_mode(env: &Env, function: Symbol, mode: FailureMode) {
+        env.storage().instance().set(&ChaosKey::Failure(function), &mode);
+    }
+
+    /// Panic if `function` was switched to revert
+    fn fail_if_reverting(env: &Env, function: &str) -> FailureMode {
+        let mode = failure_mode(env, function);
+        if mode == FailureMode::Revert {
+            panic!("chaos: injected revert");
+        }
+        mode
+    }
+
+    /// Move the ledger clock by `seconds`, backwards when negative
+    pub fn skew_clock(env: &Env, seconds: i64) {
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp = if seconds < 0 {
+                ledger.timestamp.saturating_sub(seconds.unsigned_abs())
+            } else {
+                ledger.timestamp.saturating_add(seconds as u64)
+            };
+        });
+    }
+
+    /// Policy contract stand-in
+    #[contract]
+    pub struct ChaosPolicy;
+
+    #[contractimpl]
+    impl ChaosPolicy {
+        pub fn set_failure(env: Env, function: Symbol, mode: FailureMode) {
+            set_failure_mode(&env, function, mode);
+        }
+
+        /// Add a policy as returned by `get_policy_terms`
+        pub fn put_policy(
+            env: Env,
+            policy_id: u64,
+            holder: Address,
+            coverage: i128,
+            state: PolicyState,
+            start_time: u64,
+            end_time: u64,
+        ) {
+            env.storage()
+                .instance()
+                .set(&ChaosKey::Policy(policy_id), &(holder, coverage, state, start_time, end_time));
+        }
+
+        pub fn get_policy_terms(env: Env, policy_id: u64) -> (Address, i128, PolicyState, u64, u64) {
+            fail_if_reverting(&env, "get_policy_terms");
+            env.storage()
+                .instance()
+                .get(&ChaosKey::Policy(policy_id))
+                .expect("chaos: unknown policy")
+        }
+
+        pub fn get_policy_product(env: Env, _policy_id: u64) -> u32 {
+            fail_if_reverting(&env, "get_policy_product");
+            0
+        }
+
+        /// Bind a policy to a node as returned by `get_policy_node`
+        pub fn put_policy_node(env: Env, policy_id: u64, node_id: BytesN<32>) {
+            env.storage().instance().set(&ChaosKey::Node(policy_id), &node_id);
+        }
+
+        pub fn get_policy_node(env: Env, policy_id: u64) -> Option<BytesN<32>> {
+            fail_if_reverting(&env, "get_policy_node");
+            env.storage().instance().get(&ChaosKey::Node(policy_id))
+        }
+
+        pub fn record_claim(env: Env, caller: Address, policy_id: u64, amount: i128) {
+            caller.require_auth();
+            fail_if_reverting(&env, "record_claim");
+            let mut recorded: Map<u64, i128> = env
+                .storage()
+                .instance()
+                .get(&ChaosKey::Recorded)
+                .unwrap_or(Map::new(&env));
+            recorded.set(policy_id, recorded.get(policy_id).unwrap_or(0) + amount);
+            env.storage().instance().set(&ChaosKey::Recorded, &recorded);
+        }
+
+        /// Give `holder` premium credit for `offset_premium_credit` to draw on
+        pub fn put_premium_credit(env: Env, holder: Address, amount: i128) {
+            env.storage().instance().set(&ChaosKey::Credit(holder), &amount);
+        }
+
+        /// Apply up to `amount` of the holder's premium credit; returns the
+        /// amount applied
+        pub fn offset_premium_credit(env: Env, caller: Address, holder: Address, amount: i128) -> i128 {
+            caller.require_auth();
+            fail_if_reverting(&env, "offset_premium_credit");
+            let credit: i128 = env
+                .storage()
+                .instance()
+                .get(&ChaosKey::Credit(holder.clone()))
+                .unwrap_or(0);
+            let applied = credit.min(amount);
+            env.storage()
+                .instance()
+                .set(&ChaosKey::Credit(holder), &(credit - applied));
+            applied
+        }
+
+        /// Total recorded against `policy_id` by settled claims
+        pub fn get_recorded(env: Env, policy_id: u64) -> i128 {
+            env.storage()
+                .instance()
+                .get::<_, Map<u64, i128>>(&ChaosKey::Recorded)
+                .and_then(|recorded| recorded.get(policy_id))
+                .unwrap_or(0)
+        }
+
+        pub fn accept_wiring(env: Env, _peer: Address) -> u32 {
+            fail_if_reverting(&env, "accept_wiring");
+            WIRING_VERSION
+        }
+    }
+
+    /// Risk pool stand-in that tracks reservations per claim
+    #[contract]
+    pub struct ChaosPool;
+
+    fn reservations(env: &Env) -> Map<u64, i128> {
+        env.storage()
+            .instance()
+            .get(&ChaosKey::Reservations)
+            .unwrap_or(Map::new(env))
+    }
+
+    #[contractimpl]
+    impl ChaosPool {
+        pub fn set_failure(env: Env, function: Symbol, mode: FailureMode) {
+            set_failure_mode(&env, function, mode);
+        }
+
+        pub fn reserve_liquidity(env: Env, claim_id: u64, amount: i128) {
+            fail_if_reverting(&env, "reserve_liquidity");
+            let mut reserved = reservations(&env);
+            if reserved.contains_key(claim_id) {
+                panic!("chaos: claim already reserved");
+            }
+            reserved.set(claim_id, amount);
+            env.storage().instance().set(&ChaosKey::Reservations, &reserved);
+        }
+
+        pub fn payout_reserved_claim(env: Env, claim_id: u64, recipient: Address) {
+            fail_if_reverting(&env, "payout_reserved_claim");
+            let mut reserved = reservations(&env);
+            let amount = reserved.get(claim_id).expect("chaos: nothing reserved");
+            reserved.remove(claim_id);
+            env.storage().instance().set(&ChaosKey::Reservations, &reserved);
+            env.storage().instance().set(&ChaosKey::Recipient(claim_id), &recipient);
+
+            let paid: i128 = env.storage().instance().get(&ChaosKey::PaidOut).unwrap_or(0);
+            env.storage().instance().set(&ChaosKey::PaidOut, &(paid + amount));
+        }
+
+        /// Recipient a claim was paid to
+        pub fn get_recipient(env: Env, claim_id: u64) -> Option<Address> {
+            env.storage().instance().get(&ChaosKey::Recipient(claim_id))
+        }
+
+        pub fn resize_reservation(env: Env, caller: Address, claim_id: u64, amount: i128) {
+            caller.require_auth();
+            fail_if_reverting(&env, "resize_reservation");
+            let mut reserved = reservations(&env);
+            if !reserved.contains_key(claim_id) {
+                panic!("chaos: nothing reserved");
+            }
+            reserved.set(claim_id, amount);
+            env.storage().instance().set(&ChaosKey::Reservations, &reserved);
+        }
+
+        pub fn route_claim_payout(env: Env, caller: Address, claim_id: u64, assignee: Address, amount: i128) {
+            caller.require_auth();
+            fail_if_reverting(&env, "route_claim_payout");
+            env.storage()
+                .instance()
+                .set(&ChaosKey::Route(claim_id), &(assignee, amount));
+        }
+
+        /// Assignee and amount the claim's payout was routed to, if any
+        pub fn get_route(env: Env, claim_id: u64) -> Option<(Address, i128)> {
+            env.storage().instance().get(&ChaosKey::Route(claim_id))
+        }
+
+        pub fn record_product_loss(env: Env, caller: Address, _product_id: u32, _amount: i128) {
+            caller.require_auth();
+            fail_if_reverting(&env, "record_product_loss");
+        }
+
+        pub fn release_reservation(env: Env, caller: Address, claim_id: u64) -> i128 {
+            caller.require_auth();
+            fail_if_reverting(&env, "release_reservation");
+            let mut reserved = reservations(&env);
+            let amount = reserved.get(claim_id).unwrap_or(0);
+            reserved.remove(claim_id);
+            env.storage().instance().set(&ChaosKey::Reservations, &reserved);
+            amount
+        }
+
+        /// Amount still reserved for `claim_id`
+        pub fn get_reservation(env: Env, claim_id: u64) -> i128 {
+            reservations(&env).get(claim_id).unwrap_or(0)
+        }
+
+        /// Sum of all open reservations
+        pub fn get_total_reserved(env: Env) -> i128 {
+            reservations(&env).values().iter().sum()
+        }
+
+        pub fn get_total_paid_out(env: Env) -> i128 {
+            env.storage().instance().get(&ChaosKey::PaidOut).unwrap_or(0)
+        }
+
+        pub fn reverse_claim_payout(env: Env, caller: Address, _claim_id: u64, amount: i128) {
+            caller.require_auth();
+            fail_if_reverting(&env, "reverse_claim_payout");
+            let paid: i128 = env.storage().instance().get(&ChaosKey::PaidOut).unwrap_or(0);
+            env.storage().instance().set(&ChaosKey::PaidOut, &(paid - amount));
+        }
+
+        /// Cap the available liquidity reported by `get_pool_stats`, which
+        /// is unlimited until set
+        pub fn set_liquidity(env: Env, amount: i128) {
+            env.storage().instance().set(&ChaosKey::Liquidity, &amount);
+        }
+
+        pub fn get_pool_stats(env: Env) -> (i128, i128, i128, u64) {
+            fail_if_reverting(&env, "get_pool_stats");
+            let liquidity = env.storage().instance().get(&ChaosKey::Liquidity).unwrap_or(i128::MAX);
+            (liquidity, Self::get_total_paid_out(env), 0, 0)
+        }
+
+        pub fn accept_wiring(env: Env, _peer: Address) -> u32 {
+            fail_if_reverting(&env, "accept_wiring");
+            WIRING_VERSION
+        }
+    }
+
+    /// Oracle stand-in
+    #[contract]
+    pub struct ChaosOracle;
+
+    #[contractimpl]
+    impl ChaosOracle {
+        pub fn set_failure(env: Env, function: Symbol, mode: FailureMode) {
+            set_failure_mode(&env, function, mode);
+        }
+
+        /// Add data as returned by `resolve_oracle_data`, stamped now
+        pub fn put_data(env: Env, data_id: u64, value: i128, submissions: u32) {
+            let data = (value, submissions, submissions, env.ledger().timestamp());
+            env.storage().instance().set(&ChaosKey::OracleData(data_id), &data);
+        }
+
+        pub fn get_submission_count(env: Env, data_id: u64) -> u32 {
+            if fail_if_reverting(&env, "get_submission_count") == FailureMode::Timeout {
+                return 0;
+            }
+            env.storage()
+                .instance()
+                .get::<_, (i128, u32, u32, u64)>(&ChaosKey::OracleData(data_id))
+                .map(|data| data.1)
+                .unwrap_or(0)
+        }
+
+        pub fn resolve_oracle_data(env: Env, data_id: u64) -> (i128, u32, u32, u64) {
+            let mode = fail_if_reverting(&env, "resolve_oracle_data");
+            let data: (i128, u32, u32, u64) = env
+                .storage()
+                .instance()
+                .get(&ChaosKey::OracleData(data_id))
+                .expect("chaos: unknown data");
+            if mode == FailureMode::Timeout {
+                return (data.0, 0, 0, 0);
+            }
+            data
+        }
     }
 }
 
 /// Common error types for insurance contracts
 pub mod errors {
-    use soroban_sdk::{contracterror, Error};
+    use soroban_sdk::contracterror;
 
     #[contracterror]
     #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -112,6 +1390,11 @@ pub mod errors {
         RoleNotFound = 12,
         /// Contract not trusted for cross-contract calls
         NotTrustedContract = 13,
+
+        /// 🔐 Evidence-specific errors
+        EvidenceAlreadyExists = 20,
+        EvidenceNotFound = 21,
+        InvalidEvidenceHash = 22,
     }
     
     /// Convert authorization errors to contract errors
@@ -124,11 +1407,6 @@ pub mod errors {
                 super::authorization::AuthError::NotTrustedContract => ContractError::NotTrustedContract,
             }
         }
-
-        /// 🔐 Evidence-specific errors
-        EvidenceAlreadyExists = 20,
-        EvidenceNotFound = 21,
-        InvalidEvidenceHash = 22,
     }
 }
 
@@ -136,7 +1414,7 @@ pub mod errors {
 pub mod utils {
     use super::*;
     use crate::{errors::ContractError, types::*};
-    use soroban_sdk::Vec;
+    use soroban_sdk::{IntoVal, Vec};
 
     /// Validate that an address is valid
     pub fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
@@ -224,6 +1502,9 @@ pub mod utils {
 
     /// Create a simple event log entry
     pub fn log_event(env: &Env, event_type: &str, data: Vec<String>) {
-        env.events().publish((event_type, ()), data);
+        env.events().publish_event(&crate::events::RawEvent {
+            topics: (event_type, ()).into_val(env),
+            data: data.into_val(env),
+        });
     }
 }
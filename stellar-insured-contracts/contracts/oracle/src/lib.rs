@@ -1,8 +1,10 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracterror, contracttype, symbol_short, Address, Env, Symbol, Vec, Map,
+    contract, contractimpl, contracterror, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec,
 };
+use shared::ephemeral;
+use shared::math::{mul_div_floor, ratio_percent};
 
 // ============================================================================
 // Constants
@@ -10,11 +12,12 @@ use soroban_sdk::{
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const PAUSED: Symbol = symbol_short!("PAUSED");
-const CONFIG: Symbol = symbol_short!("CONFIG");
 const ORACLE_DATA: Symbol = symbol_short!("ORA_DATA");
-const ORACLE_HISTORY: Symbol = symbol_short!("ORA_HIST");
 const SUBMISSIONS: Symbol = symbol_short!("SUBS");
 const THRESHOLDS: Symbol = symbol_short!("THRESH");
+const WASM_HASH: Symbol = symbol_short!("WASM_HASH");
+const GOVERNANCE: Symbol = symbol_short!("GOV");
+const ADMIN_SUNSET: Symbol = symbol_short!("SUNSET");
 
 // Default thresholds for oracle validation
 const DEFAULT_MIN_SUBMISSIONS: u32 = 3;
@@ -61,8 +64,8 @@ pub struct ValidationThreshold {
     pub staleness_threshold_seconds: u64,
 }
 
-impl ValidationThreshold {
-    pub fn default() -> Self {
+impl Default for ValidationThreshold {
+    fn default() -> Self {
         ValidationThreshold {
             min_submissions: DEFAULT_MIN_SUBMISSIONS,
             majority_threshold_percent: DEFAULT_MAJORITY_THRESHOLD,
@@ -72,6 +75,19 @@ impl ValidationThreshold {
     }
 }
 
+/// Crate version and the wasm installed by the last upgrade
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    /// Semantic version of the contract crate
+    pub semver: String,
+    /// Whether the contract has been upgraded since deployment
+    pub has_wasm_hash: bool,
+    /// Wasm installed by the last upgrade; all zeroes for the original
+    /// deployment
+    pub wasm_hash: BytesN<32>,
+}
+
 /// Individual oracle submission
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -134,15 +150,20 @@ pub struct OracleContract;
 // Helper Functions
 // ============================================================================
 
-fn require_admin(env: &Env) -> Result<Address, OracleError> {
+fn require_admin(env: &Env, caller: &Address) -> Result<Address, OracleError> {
     let admin: Address = env
         .storage()
         .persistent()
         .get(&ADMIN)
         .ok_or(OracleError::NotInitialized)?;
 
-    env.invoker().require_auth();
-    if env.invoker() != admin {
+    // The governance contract, once set, holds the admin's powers, and
+    // after the sunset it alone does
+    let governance: Option<Address> = env.storage().persistent().get(&GOVERNANCE);
+    let sunset_at: Option<u64> = env.storage().persistent().get(&ADMIN_SUNSET);
+    let admin_active = sunset_at.is_none_or(|sunset_at| env.ledger().timestamp() < sunset_at);
+    caller.require_auth();
+    if !(*caller == admin && admin_active) && Some(caller.clone()) != governance {
         return Err(OracleError::Unauthorized);
     }
 
@@ -160,7 +181,7 @@ fn get_thresholds(env: &Env) -> ValidationThreshold {
     env.storage()
         .persistent()
         .get(&THRESHOLDS)
-        .unwrap_or_else(ValidationThreshold::default)
+        .unwrap_or_default()
 }
 
 fn set_thresholds(env: &Env, thresholds: &ValidationThreshold) {
@@ -197,6 +218,7 @@ fn calculate_median(values: &Vec<i128>) -> i128 {
 }
 
 /// Calculate weighted average (simple equal weighting for all submissions)
+#[cfg(test)]
 fn calculate_weighted_average(values: &Vec<i128>) -> i128 {
     if values.is_empty() {
         return 0;
@@ -212,11 +234,12 @@ fn calculate_weighted_average(values: &Vec<i128>) -> i128 {
 
 /// Detect outliers using interquartile range (IQR) method
 fn detect_outliers(
+    env: &Env,
     values: &Vec<i128>,
     deviation_percent: i128,
 ) -> Vec<bool> {
     let len = values.len();
-    let mut outlier_flags: Vec<bool> = Vec::new();
+    let mut outlier_flags: Vec<bool> = Vec::new(env);
 
     if len < 3 {
         // With fewer than 3 values, no outlier detection
@@ -232,7 +255,7 @@ fn detect_outliers(
     // Calculate acceptable deviation range
     let deviation_basis = if median > 0 { median } else { 1 };
     let max_deviation =
-        (deviation_basis.abs() * deviation_percent) / 100;
+        mul_div_floor(deviation_basis.abs(), deviation_percent, 100).unwrap_or(i128::MAX);
 
     // Mark values outside deviation range as outliers
     for i in 0..len {
@@ -258,6 +281,83 @@ fn is_data_stale(timestamp: u64, current_time: u64, staleness_threshold: u64) ->
     (current_time - timestamp) > staleness_threshold
 }
 
+/// Check the pending submissions for a data point against the thresholds
+/// and compute the consensus they support, without storing it
+fn evaluate_submissions(env: &Env, data_id: u64) -> Result<OracleData, OracleError> {
+    let thresholds = get_thresholds(env);
+    let current_time = env.ledger().timestamp();
+
+    let submissions: Vec<OracleSubmission> = env
+        .storage()
+        .temporary()
+        .get(&(SUBMISSIONS, data_id))
+        .ok_or(OracleError::NotFound)?;
+
+    let submission_count = submissions.len();
+
+    // Check minimum submissions
+    if submission_count < thresholds.min_submissions {
+        return Err(OracleError::InsufficientSubmissions);
+    }
+
+    // Extract values and check for staleness
+    let mut values: Vec<i128> = Vec::new(env);
+    for i in 0..submissions.len() {
+        let sub = submissions.get(i).unwrap();
+
+        // Check staleness
+        if is_data_stale(
+            sub.timestamp,
+            current_time,
+            thresholds.staleness_threshold_seconds,
+        ) {
+            return Err(OracleError::StaleData);
+        }
+
+        values.push_back(sub.value);
+    }
+
+    // Detect outliers
+    let outlier_flags = detect_outliers(env, &values, thresholds.outlier_deviation_percent);
+
+    // Filter out outliers and calculate consensus
+    let mut valid_values: Vec<i128> = Vec::new(env);
+    let mut rejected_count = 0u32;
+
+    for i in 0..values.len() {
+        if !outlier_flags.get(i).unwrap() {
+            valid_values.push_back(values.get(i).unwrap());
+        } else {
+            rejected_count += 1;
+        }
+    }
+
+    let valid_count = valid_values.len();
+
+    // Verify consensus threshold is met
+    let consensus_percentage = ratio_percent(valid_count as i128, submission_count as i128)
+        .map_err(|_| OracleError::InsufficientSubmissions)? as u32;
+
+    if consensus_percentage < thresholds.majority_threshold_percent {
+        return Err(OracleError::ConsensusNotReached);
+    }
+
+    // Calculate final consensus value (using median for robustness)
+    let consensus_value = calculate_median(&valid_values);
+
+    let oracle_data = OracleData {
+        data_id,
+        consensus_value,
+        submission_count,
+        consensus_percentage,
+        finalized_at: current_time,
+        included_submissions: valid_count,
+        rejected_submissions: rejected_count,
+    };
+
+    Ok(oracle_data)
+}
+
 // ============================================================================
 // Oracle Contract Implementation
 // ============================================================================
@@ -282,21 +382,58 @@ impl OracleContract {
     }
 
     /// Pause or unpause the contract
-    pub fn set_paused(env: Env, paused: bool) -> Result<(), OracleError> {
-        let _admin = require_admin(&env)?;
+    pub fn set_paused(env: Env, admin: Address, paused: bool) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
         env.storage().persistent().set(&PAUSED, &paused);
         Ok(())
     }
 
+    /// Let the governance contract make admin calls, or stop it with `None`
+    pub fn set_governance_contract(env: Env, admin: Address, governance: Option<Address>) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+        match &governance {
+            Some(governance) => env.storage().persistent().set(&GOVERNANCE, governance),
+            None if env.storage().persistent().has(&ADMIN_SUNSET) => return Err(OracleError::Unauthorized),
+            None => env.storage().persistent().remove(&GOVERNANCE),
+        }
+        Ok(())
+    }
+
+    /// Schedule the one-way handover to governance. From `sunset_at` only the
+    /// governance contract can make admin calls; the time can be brought
+    /// forward, never pushed back.
+    pub fn schedule_admin_sunset(env: Env, admin: Address, sunset_at: u64) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+        let scheduled: Option<u64> = env.storage().persistent().get(&ADMIN_SUNSET);
+        if !env.storage().persistent().has(&GOVERNANCE) || scheduled.is_some_and(|scheduled| sunset_at > scheduled) {
+            return Err(OracleError::Unauthorized);
+        }
+        env.storage()
+            .persistent()
+            .set(&ADMIN_SUNSET, &sunset_at.max(env.ledger().timestamp()));
+        Ok(())
+    }
+
+    /// When the admin's powers end, if a handover is scheduled
+    pub fn get_admin_sunset(env: Env) -> Option<u64> {
+        env.storage().persistent().get(&ADMIN_SUNSET)
+    }
+
+    /// Governance contract holding admin powers, if any
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE)
+    }
+
     /// Update validation thresholds
     pub fn set_thresholds(
         env: Env,
+        admin: Address,
         min_submissions: u32,
         majority_threshold_percent: u32,
         outlier_deviation_percent: i128,
         staleness_threshold_seconds: u64,
     ) -> Result<(), OracleError> {
-        let _admin = require_admin(&env)?;
+        require_admin(&env, &admin)?;
 
         if majority_threshold_percent > 100 || outlier_deviation_percent < 0 {
             return Err(OracleError::InvalidThreshold);
@@ -319,9 +456,10 @@ impl OracleContract {
     }
 
     /// Submit oracle data for a specific data point
-    /// Returns true if consensus is reached immediately
+    /// Returns true once the pending submissions are enough to resolve
     pub fn submit_oracle_data(
         env: Env,
+        oracle: Address,
         data_id: u64,
         value: i128,
     ) -> Result<bool, OracleError> {
@@ -329,17 +467,15 @@ impl OracleContract {
             return Err(OracleError::Paused);
         }
 
-        env.invoker().require_auth();
-        let oracle = env.invoker();
+        oracle.require_auth();
         let current_time = env.ledger().timestamp();
 
         // Get existing submissions or create new storage
-        let mut submissions_key = soroban_sdk::Symbol::new(&env, "SUBS");
-        submissions_key = soroban_sdk::Symbol::new(&env, &format!("SUBS_{}", data_id));
+        let submissions_key = (SUBMISSIONS, data_id);
 
         let mut submissions: Vec<OracleSubmission> = env
             .storage()
-            .persistent()
+            .temporary()
             .get(&submissions_key)
             .unwrap_or_else(|| Vec::new(&env));
 
@@ -360,12 +496,13 @@ impl OracleContract {
         };
 
         submissions.push_back(submission);
-        env.storage()
-            .persistent()
-            .set(&submissions_key, &submissions);
+        // Submissions past the staleness threshold can never resolve, so a
+        // round that does not reach consensus in time lapses on its own
+        let lifetime = get_thresholds(&env).staleness_threshold_seconds;
+        ephemeral::set(&env, &submissions_key, &submissions, lifetime);
 
-        // Try to reach consensus
-        self.try_resolve_oracle_data(&env, data_id)
+        // Report whether consensus can be reached without resolving yet
+        Ok(evaluate_submissions(&env, data_id).is_ok())
     }
 
     /// Attempt to resolve oracle data with consensus validation
@@ -373,104 +510,22 @@ impl OracleContract {
         env: Env,
         data_id: u64,
     ) -> Result<OracleData, OracleError> {
-        self.try_resolve_oracle_data(&env, data_id)
-    }
-
-    /// Internal oracle resolution with validation
-    fn try_resolve_oracle_data(
-        &self,
-        env: &Env,
-        data_id: u64,
-    ) -> Result<OracleData, OracleError> {
-        let thresholds = get_thresholds(env);
-        let current_time = env.ledger().timestamp();
-
-        let submissions_key = soroban_sdk::Symbol::new(env, &format!("SUBS_{}", data_id));
-        let submissions: Vec<OracleSubmission> = env
-            .storage()
-            .persistent()
-            .get(&submissions_key)
-            .ok_or(OracleError::NotFound)?;
-
-        let submission_count = submissions.len() as u32;
-
-        // Check minimum submissions
-        if submission_count < thresholds.min_submissions {
-            return Err(OracleError::InsufficientSubmissions);
-        }
-
-        // Extract values and check for staleness
-        let mut values: Vec<i128> = Vec::new(&env);
-        for i in 0..submissions.len() {
-            let sub = submissions.get(i).unwrap();
-
-            // Check staleness
-            if is_data_stale(
-                sub.timestamp,
-                current_time,
-                thresholds.staleness_threshold_seconds,
-            ) {
-                return Err(OracleError::StaleData);
-            }
-
-            values.push_back(sub.value);
-        }
-
-        // Detect outliers
-        let outlier_flags = detect_outliers(&values, thresholds.outlier_deviation_percent);
-
-        // Filter out outliers and calculate consensus
-        let mut valid_values: Vec<i128> = Vec::new(&env);
-        let mut rejected_count = 0u32;
-
-        for i in 0..values.len() {
-            if !outlier_flags.get(i).unwrap() {
-                valid_values.push_back(values.get(i).unwrap());
-            } else {
-                rejected_count += 1;
-            }
-        }
-
-        let valid_count = valid_values.len() as u32;
-
-        // Verify consensus threshold is met
-        let consensus_percentage =
-            (valid_count * 100) / submission_count;
-
-        if consensus_percentage < thresholds.majority_threshold_percent {
-            return Err(OracleError::ConsensusNotReached);
-        }
-
-        // Calculate final consensus value (using median for robustness)
-        let consensus_value = calculate_median(&valid_values);
-
-        // Store the resolved oracle data
-        let oracle_data = OracleData {
-            data_id,
-            consensus_value,
-            submission_count,
-            consensus_percentage,
-            finalized_at: current_time,
-            included_submissions: valid_count,
-            rejected_submissions: rejected_count,
-        };
+        let oracle_data = evaluate_submissions(&env, data_id)?;
 
         // Store the finalized data
-        let data_key = soroban_sdk::Symbol::new(env, &format!("DATA_{}", data_id));
-        env.storage().persistent().set(&data_key, &oracle_data);
+        env.storage().persistent().set(&(ORACLE_DATA, data_id), &oracle_data);
 
         // Clear submissions after resolution
-        env.storage().persistent().remove(&submissions_key);
+        env.storage().temporary().remove(&(SUBMISSIONS, data_id));
 
         Ok(oracle_data)
     }
 
     /// Get resolved oracle data
     pub fn get_oracle_data(env: Env, data_id: u64) -> Result<OracleData, OracleError> {
-        let data_key = soroban_sdk::Symbol::new(&env, &format!("DATA_{}", data_id));
         env.storage()
             .persistent()
-            .get(&data_key)
+            .get(&(ORACLE_DATA, data_id))
             .ok_or(OracleError::NotFound)
     }
 
@@ -479,58 +534,80 @@ impl OracleContract {
         env: Env,
         data_id: u64,
     ) -> Result<Vec<OracleSubmission>, OracleError> {
-        let submissions_key = soroban_sdk::Symbol::new(&env, &format!("SUBS_{}", data_id));
         env.storage()
-            .persistent()
-            .get(&submissions_key)
+            .temporary()
+            .get(&(SUBMISSIONS, data_id))
             .ok_or(OracleError::NotFound)
     }
 
     /// Get submission count for a data point
     pub fn get_submission_count(env: Env, data_id: u64) -> Result<u32, OracleError> {
-        let submissions_key = soroban_sdk::Symbol::new(&env, &format!("SUBS_{}", data_id));
         let submissions: Vec<OracleSubmission> = env
             .storage()
-            .persistent()
-            .get(&submissions_key)
+            .temporary()
+            .get(&(SUBMISSIONS, data_id))
             .ok_or(OracleError::NotFound)?;
-        Ok(submissions.len() as u32)
+        Ok(submissions.len())
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        let wasm_hash: Option<BytesN<32>> = env.storage().persistent().get(&WASM_HASH);
+        ContractVersion {
+            semver: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            has_wasm_hash: wasm_hash.is_some(),
+            wasm_hash: wasm_hash.unwrap_or_else(|| BytesN::from_array(&env, &[0; 32])),
+        }
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        let mut interfaces = Vec::new(&env);
+        interfaces.push_back(Symbol::new(&env, "oracle_v1"));
+        interfaces.push_back(Symbol::new(&env, "version_v1"));
+        interfaces
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), OracleError> {
+        require_admin(&env, &admin)?;
+        env.storage().persistent().set(&WASM_HASH, &new_wasm_hash);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::testutils::Address as _;
+
+    fn setup(env: &Env) -> (OracleContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let client = OracleContractClient::new(env, &env.register(OracleContract, ()));
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        (client, admin)
+    }
 
     #[test]
     fn test_oracle_initialization() {
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        let result = contract.initialize(env.clone(), admin.clone());
-
-        assert!(result.is_ok());
+        let (client, admin) = setup(&env);
 
         // Test idempotency - second init should fail
-        let result2 = contract.initialize(env.clone(), admin);
-        assert_eq!(result2, Err(OracleError::AlreadyInitialized));
+        let result = client.try_initialize(&admin);
+        assert_eq!(result, Err(Ok(OracleError::AlreadyInitialized)));
     }
 
     #[test]
     fn test_validation_thresholds() {
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        OracleContract {}
-            .initialize(env.clone(), admin.clone())
-            .unwrap();
+        let (client, _admin) = setup(&env);
 
         // Get default thresholds
-        let thresholds = OracleContract {}
-            .get_thresholds(env.clone())
-            .unwrap();
+        let thresholds = client.get_thresholds();
 
         assert_eq!(thresholds.min_submissions, DEFAULT_MIN_SUBMISSIONS);
         assert_eq!(
@@ -549,12 +626,12 @@ mod tests {
         values.push_back(101i128);
         values.push_back(500i128); // Outlier
 
-        let outliers = detect_outliers(&values, 15); // 15% deviation
+        let outliers = detect_outliers(&env, &values, 15); // 15% deviation
 
-        assert_eq!(outliers.get(0), false); // 100
-        assert_eq!(outliers.get(1), false); // 102
-        assert_eq!(outliers.get(2), false); // 101
-        assert_eq!(outliers.get(3), true);  // 500 is outlier
+        assert_eq!(outliers.get(0), Some(false)); // 100
+        assert_eq!(outliers.get(1), Some(false)); // 102
+        assert_eq!(outliers.get(2), Some(false)); // 101
+        assert_eq!(outliers.get(3), Some(true));  // 500 is outlier
     }
 
     #[test]
@@ -592,7 +669,7 @@ mod tests {
         assert!(!is_data_stale(900, current, threshold));
 
         // Stale data
-        assert!(is_data_stale(100, current, threshold));
+        assert!(is_data_stale(100, current, 800));
 
         // Future data (invalid)
         assert!(is_data_stale(1100, current, threshold));
@@ -608,38 +685,40 @@ mod oracle_consensus_tests {
     use super::*;
     use soroban_sdk::testutils::{Address as _, Ledger};
 
+    fn setup(env: &Env) -> (OracleContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let client = OracleContractClient::new(env, &env.register(OracleContract, ()));
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        (client, admin)
+    }
+
+    /// Submit `value` for `data_id` from a fresh oracle
+    fn submit(env: &Env, client: &OracleContractClient, data_id: u64, value: i128) -> bool {
+        client.submit_oracle_data(&Address::generate(env), &data_id, &value)
+    }
+
     #[test]
     fn test_oracle_disagreement_scenario() {
         // Scenario: Multiple oracles submit different values for same data point
         let env = Env::default();
-        let admin = Address::random(&env);
-        let oracle1 = Address::random(&env);
-        let oracle2 = Address::random(&env);
-        let oracle3 = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
 
         // Simulate three oracles with slightly different values
         let data_id = 1u64;
-        
+
         // Oracle 1 submits value 100
-        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        assert!(result1.is_ok());
+        assert!(!submit(&env, &client, data_id, 100i128));
 
         // Oracle 2 submits value 102 (within 15% deviation)
-        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
-        assert!(result2.is_ok());
+        assert!(!submit(&env, &client, data_id, 102i128));
 
-        // Oracle 3 submits value 101 (within 15% deviation)
-        let result3 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        assert!(result3.is_ok());
+        // Oracle 3 submits value 101 (within 15% deviation); enough to resolve
+        assert!(submit(&env, &client, data_id, 101i128));
 
         // Consensus should be reached with median of 101
-        let resolved = contract.resolve_oracle_data(env.clone(), data_id);
-        assert!(resolved.is_ok());
-        let oracle_data = resolved.unwrap();
-        
+        let oracle_data = client.resolve_oracle_data(&data_id);
+
         // Median of [100, 102, 101] = 101
         assert_eq!(oracle_data.consensus_value, 101i128);
         assert_eq!(oracle_data.submission_count, 3u32);
@@ -651,25 +730,20 @@ mod oracle_consensus_tests {
     fn test_oracle_outlier_rejection() {
         // Scenario: One oracle submits an outlier, should be detected and rejected
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
 
         let data_id = 2u64;
-        
+
         // Valid submissions
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
-        
+        submit(&env, &client, data_id, 100i128);
+        submit(&env, &client, data_id, 101i128);
+        submit(&env, &client, data_id, 102i128);
+
         // Outlier submission (far outside 15% deviation range)
-        let _result4 = contract.submit_oracle_data(env.clone(), data_id, 500i128);
+        submit(&env, &client, data_id, 500i128);
+
+        let oracle_data = client.resolve_oracle_data(&data_id);
 
-        let resolved = contract.resolve_oracle_data(env.clone(), data_id);
-        assert!(resolved.is_ok());
-        let oracle_data = resolved.unwrap();
-        
         // Should have 3 valid submissions, 1 rejected
         assert_eq!(oracle_data.submission_count, 4u32);
         assert_eq!(oracle_data.included_submissions, 3u32);
@@ -681,114 +755,95 @@ mod oracle_consensus_tests {
     fn test_insufficient_submissions() {
         // Scenario: Fewer submissions than minimum required
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
 
         let data_id = 3u64;
-        
+
         // Submit only one oracle value
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128);
+        submit(&env, &client, data_id, 100i128);
 
         // Try to resolve - should fail with insufficient submissions
-        let resolved = contract.resolve_oracle_data(env.clone(), data_id);
-        assert_eq!(resolved, Err(OracleError::InsufficientSubmissions));
+        let resolved = client.try_resolve_oracle_data(&data_id);
+        assert_eq!(resolved, Err(Ok(OracleError::InsufficientSubmissions)));
     }
 
     #[test]
     fn test_stale_data_rejection() {
         // Scenario: Oracle data is too old and should be rejected
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, admin) = setup(&env);
 
         // Set a very short staleness threshold
-        let _result = contract.set_thresholds(
-            env.clone(),
-            3u32,
-            66u32,
-            15i128,
-            100u64, // Only 100 seconds before stale
+        client.set_thresholds(
+            &admin,
+            &3u32,
+            &66u32,
+            &15i128,
+            &100u64, // Only 100 seconds before stale
         );
 
+        // Simulate oracle data submissions at time 100
+        env.ledger().with_mut(|li| li.timestamp = 100);
         let data_id = 4u64;
+        let data_id2 = 5u64;
+        for value in [100i128, 101, 102] {
+            submit(&env, &client, data_id, value);
+            submit(&env, &client, data_id2, value);
+        }
 
-        // Simulate oracle data submission at time 100
-        env.ledger().with_timestamp(100);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 102i128);
-
-        // Try to resolve - should succeed at current time
-        let resolved1 = contract.resolve_oracle_data(env.clone(), data_id);
-        assert!(resolved1.is_ok());
-
-        // Simulate time passing beyond staleness threshold
-        env.ledger().with_timestamp(250); // 150 seconds later, exceeds 100 second threshold
+        // Resolving at the submission time succeeds
+        assert!(client.try_resolve_oracle_data(&data_id).is_ok());
 
-        // Create new data point at future timestamp
-        let data_id2 = 5u64;
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 100i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 101i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 102i128);
+        // 150 seconds later, past the 100 second threshold
+        env.ledger().with_mut(|li| li.timestamp = 250);
 
         // This should fail because all submissions are stale
-        let resolved2 = contract.resolve_oracle_data(env.clone(), data_id2);
-        assert_eq!(resolved2, Err(OracleError::StaleData));
+        let resolved2 = client.try_resolve_oracle_data(&data_id2);
+        assert_eq!(resolved2, Err(Ok(OracleError::StaleData)));
     }
 
     #[test]
     fn test_duplicate_oracle_submission() {
         // Scenario: Same oracle tries to submit twice for same data point
         let env = Env::default();
-        let admin = Address::random(&env);
-        let oracle = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
+        let oracle = Address::generate(&env);
 
         let data_id = 6u64;
 
         // First submission should succeed
-        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        assert!(result1.is_ok());
+        assert!(client.try_submit_oracle_data(&oracle, &data_id, &100i128).is_ok());
 
         // Second submission from same oracle should fail
-        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
-        assert_eq!(result2, Err(OracleError::DuplicateSubmission));
+        let result2 = client.try_submit_oracle_data(&oracle, &data_id, &102i128);
+        assert_eq!(result2, Err(Ok(OracleError::DuplicateSubmission)));
     }
 
     #[test]
     fn test_consensus_threshold_enforcement() {
         // Scenario: Test that consensus threshold is properly enforced
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, admin) = setup(&env);
 
         // Set high majority threshold (80%)
-        let _result = contract.set_thresholds(
-            env.clone(),
-            3u32,
-            80u32, // 80% required
-            15i128,
-            3600u64,
+        client.set_thresholds(
+            &admin,
+            &3u32,
+            &80u32, // 80% required
+            &15i128,
+            &3600u64,
         );
 
         let data_id = 7u64;
 
         // Submit 3 values where 2 match and 1 is outlier
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 500i128); // Outlier
+        submit(&env, &client, data_id, 100i128);
+        submit(&env, &client, data_id, 101i128);
+        assert!(!submit(&env, &client, data_id, 500i128)); // Outlier
 
         // With only 2 valid submissions out of 3 (66%), below 80% threshold
-        let resolved = contract.resolve_oracle_data(env.clone(), data_id);
-        assert_eq!(resolved, Err(OracleError::ConsensusNotReached));
+        let resolved = client.try_resolve_oracle_data(&data_id);
+        assert_eq!(resolved, Err(Ok(OracleError::ConsensusNotReached)));
     }
 
     #[test]
@@ -836,87 +891,81 @@ mod oracle_consensus_tests {
         let mut values = Vec::new(&env);
         values.push_back(100i128);
 
-        let outliers = detect_outliers(&values, 15);
+        let outliers = detect_outliers(&env, &values, 15);
         assert_eq!(outliers.len(), 1);
-        assert_eq!(outliers.get(0), false);
+        assert_eq!(outliers.get(0), Some(false));
 
         // Two values - no outliers with current implementation
         let mut values2 = Vec::new(&env);
         values2.push_back(100i128);
         values2.push_back(200i128);
 
-        let outliers2 = detect_outliers(&values2, 15);
+        let outliers2 = detect_outliers(&env, &values2, 15);
         assert_eq!(outliers2.len(), 2);
-        assert_eq!(outliers2.get(0), false);
-        assert_eq!(outliers2.get(1), false);
+        assert_eq!(outliers2.get(0), Some(false));
+        assert_eq!(outliers2.get(1), Some(false));
     }
 
     #[test]
     fn test_threshold_validation() {
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, admin) = setup(&env);
 
         // Try to set invalid threshold (>100%)
-        let result = contract.set_thresholds(
-            env.clone(),
-            3u32,
-            150u32, // Invalid: > 100%
-            15i128,
-            3600u64,
+        let result = client.try_set_thresholds(
+            &admin,
+            &3u32,
+            &150u32, // Invalid: > 100%
+            &15i128,
+            &3600u64,
         );
-        assert_eq!(result, Err(OracleError::InvalidThreshold));
+        assert_eq!(result, Err(Ok(OracleError::InvalidThreshold)));
     }
 
     #[test]
     fn test_oracle_data_retrieval() {
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
 
         let data_id = 10u64;
 
         // Submit and resolve
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        submit(&env, &client, data_id, 100i128);
+        submit(&env, &client, data_id, 101i128);
+        submit(&env, &client, data_id, 102i128);
 
-        let resolved = contract.resolve_oracle_data(env.clone(), data_id).unwrap();
+        let resolved = client.resolve_oracle_data(&data_id);
 
         // Retrieve stored oracle data
-        let stored = contract.get_oracle_data(env.clone(), data_id).unwrap();
-        
+        let stored = client.get_oracle_data(&data_id);
+
         assert_eq!(stored.data_id, data_id);
         assert_eq!(stored.consensus_value, resolved.consensus_value);
         assert_eq!(stored.submission_count, resolved.submission_count);
+
+        // The round is closed once resolved
+        assert_eq!(client.try_get_submission_count(&data_id), Err(Ok(OracleError::NotFound)));
     }
 
     #[test]
     fn test_multiple_independent_data_points() {
         // Scenario: Multiple oracle data points with independent consensus
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, _admin) = setup(&env);
 
         // Data point 1
-        let _r1_1 = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
-        let _r1_2 = contract.submit_oracle_data(env.clone(), 1u64, 101i128);
-        let _r1_3 = contract.submit_oracle_data(env.clone(), 1u64, 102i128);
+        submit(&env, &client, 1u64, 100i128);
+        submit(&env, &client, 1u64, 101i128);
+        submit(&env, &client, 1u64, 102i128);
 
         // Data point 2
-        let _r2_1 = contract.submit_oracle_data(env.clone(), 2u64, 200i128);
-        let _r2_2 = contract.submit_oracle_data(env.clone(), 2u64, 201i128);
-        let _r2_3 = contract.submit_oracle_data(env.clone(), 2u64, 202i128);
+        submit(&env, &client, 2u64, 200i128);
+        submit(&env, &client, 2u64, 201i128);
+        submit(&env, &client, 2u64, 202i128);
 
         // Resolve both independently
-        let resolved1 = contract.resolve_oracle_data(env.clone(), 1u64).unwrap();
-        let resolved2 = contract.resolve_oracle_data(env.clone(), 2u64).unwrap();
+        let resolved1 = client.resolve_oracle_data(&1u64);
+        let resolved2 = client.resolve_oracle_data(&2u64);
 
         assert_eq!(resolved1.consensus_value, 101i128);
         assert_eq!(resolved2.consensus_value, 201i128);
@@ -927,23 +976,46 @@ mod oracle_consensus_tests {
     #[test]
     fn test_pause_functionality() {
         let env = Env::default();
-        let admin = Address::random(&env);
-
-        let contract = OracleContract {};
-        contract.initialize(env.clone(), admin.clone()).unwrap();
+        let (client, admin) = setup(&env);
+        let oracle = Address::generate(&env);
 
         // Pause the contract
-        let _result = contract.set_paused(env.clone(), true);
+        client.set_paused(&admin, &true);
 
         // Attempts to submit should fail
-        let submit_result = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
-        assert_eq!(submit_result, Err(OracleError::Paused));
+        let submit_result = client.try_submit_oracle_data(&oracle, &1u64, &100i128);
+        assert_eq!(submit_result, Err(Ok(OracleError::Paused)));
 
-        // Unpause
-        let _result = contract.set_paused(env.clone(), false);
+        // Only the admin can unpause
+        let outsider = Address::generate(&env);
+        assert_eq!(client.try_set_paused(&outsider, &false), Err(Ok(OracleError::Unauthorized)));
+        client.set_paused(&admin, &false);
 
         // Should work again
-        let submit_result2 = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
-        assert!(submit_result2.is_ok());
+        assert!(client.try_submit_oracle_data(&oracle, &1u64, &100i128).is_ok());
+    }
+
+    #[test]
+    fn test_governance_contract_holds_admin_powers_until_cleared() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        let governance = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        assert_eq!(
+            client.try_set_governance_contract(&outsider, &Some(governance.clone())),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(client.try_set_paused(&governance, &true), Err(Ok(OracleError::Unauthorized)));
+
+        client.set_governance_contract(&admin, &Some(governance.clone()));
+        assert_eq!(client.get_governance_contract(), Some(governance.clone()));
+        client.set_thresholds(&governance, &5, &60, &10, &300);
+        assert_eq!(client.get_thresholds().min_submissions, 5);
+
+        // Clearing it takes the powers back
+        client.set_governance_contract(&admin, &None);
+        assert_eq!(client.get_governance_contract(), None);
+        assert_eq!(client.try_set_paused(&governance, &true), Err(Ok(OracleError::Unauthorized)));
     }
 }
@@ -1,14 +1,30 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, token, Address, BytesN, Env, IntoVal, Symbol, Vec};
 
 // Import authorization from the common library
+use insurance_contracts::actuarial::ActuarialParams;
+use insurance_contracts::assets::{self, AssetError, AssetInfo};
 use insurance_contracts::authorization::{
-    initialize_admin, require_admin, require_policy_management,
-    register_trusted_contract, Role, get_role
+    initialize_admin, require_admin, require_governance_permission, require_policy_management,
+    register_trusted_contract, require_trusted_contract, Role, get_role
 };
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::hot_storage;
+use insurance_contracts::incident::{self, Incident, IncidentError};
+use insurance_contracts::listeners::{add_listener, get_listeners, notify, remove_listener, ListenerError};
+use insurance_contracts::decimal::Decimal;
+use insurance_contracts::ephemeral;
+use insurance_contracts::math::{bps_of, mul_div_ceil, mul_div_floor, ratio_bps, MathError};
+use insurance_contracts::pagination::{self, IdPage};
+use insurance_contracts::snapshot::{self, Snapshot, SnapshotError};
+use insurance_contracts::state_root::{self, StateRoot};
+use insurance_contracts::rate_model;
+use insurance_contracts::version::{self, ContractVersion, IFACE_LISTENERS, IFACE_SNAPSHOT, IFACE_VERSION, IFACE_WIRING};
+use insurance_contracts::units;
+use insurance_contracts::wiring::{self, WiringError, WIRING_VERSION};
 
 // Import invariant checks and error types
-use insurance_invariants::{InvariantError, ProtocolInvariants};
+use insurance_invariants::{assert_invariant, Invariant, InvariantError, InvariantReport};
 
 // Policy validation constants
 const MIN_COVERAGE_AMOUNT: i128 = 1_000_000; // 1 unit (assuming 6 decimals)
@@ -18,6 +34,48 @@ const MAX_PREMIUM_AMOUNT: i128 = 100_000_000_000_000; // 100k units
 const MIN_POLICY_DURATION_DAYS: u32 = 1;
 const MAX_POLICY_DURATION_DAYS: u32 = 365;
 
+// Upper bound on ids accepted by batch getters
+const MAX_BATCH_SIZE: u32 = 50;
+
+// Most recent policies evaluated by `check_invariants`
+const MAX_INVARIANT_SCAN: u64 = 50;
+
+// Most recent policies of a holder considered by `verify_coverage`
+const MAX_COVERAGE_SCAN: u32 = 50;
+
+// Daily issuance quota granted to newly registered integrators
+const DEFAULT_INTEGRATOR_DAILY_QUOTA: u32 = 100;
+const MAX_FEE_SHARE_BPS: u32 = 10_000;
+
+// How long a queued quote stays valid while waiting for capacity
+const QUEUE_QUOTE_VALIDITY_SECONDS: u64 = 7 * 86_400;
+// How long a requested quote is honored, unless reconfigured
+const DEFAULT_QUOTE_VALIDITY_SECONDS: u64 = 15 * 60;
+const MAX_QUOTE_VALIDITY_SECONDS: u64 = 86_400;
+// Capacity auction rounds, and the bids one settlement pass handles
+const MIN_AUCTION_ROUND_SECONDS: u64 = 60;
+const MAX_AUCTION_ROUND_SECONDS: u64 = 7 * 86_400;
+const MAX_AUCTION_BIDS: u32 = 50;
+// Fixed-point scale of a bid's premium per unit of coverage per day
+const AUCTION_RATE_SCALE: i128 = 1_000_000_000;
+const MAX_UTILIZATION_BPS: u32 = 10_000;
+const MAX_RENEWAL_INCREASE_BPS: u32 = 10_000;
+// Levies are a slice of the premium, never all of it
+const MAX_LEVY_BPS: u32 = 10_000;
+// The treasury takes at most half of a premium; the pool keeps the rest
+const MAX_TREASURY_SPLIT_BPS: u32 = 5_000;
+// Premium multiplier of a product that has never been repriced
+const BASE_MULTIPLIER_BPS: u32 = 10_000;
+
+// Member voting weight counts premiums paid over the last year, bucketed
+// into 30-day periods
+const MEMBER_PERIOD_SECONDS: u64 = 30 * 86_400;
+const MEMBER_WINDOW_PERIODS: u64 = 12;
+
+// Statistics checkpointing: one checkpoint per epoch, bounded history
+const STATS_EPOCH_SECONDS: u64 = 86_400;
+const MAX_STATS_HISTORY: u32 = 365;
+
 #[contract]
 pub struct PolicyContract;
 
@@ -30,14 +88,159 @@ pub enum DataKey {
     PolicyCounter,
     PolicyStatusHistory(u64), // history_id
     PolicyStatusHistoryCounter,
+    ActivePolicyCount,
+    StatsCheckpoint(u64), // epoch
+    StatsEpochs,
+    Integrator(Address),
+    IntegratorUsage(Address, u64), // (integrator, day)
+    PolicyIntegrator(u64),         // policy_id
+    ActiveCoverage,
+    MaxUtilizationBps,
+    PremiumToken,
+    QueueEntry(u64), // queue_id
+    QueueHead,
+    QueueTail,
+    RenewalCapBps,
+    PremiumCredit(Address),
+    MigratedTo(u64),    // policy_id
+    MigratedFrom(u64),  // policy_id
+    PolicyClaims(u64),  // policy_id
+    RenewedTo(u64),     // policy_id
+    PolicyLedger(u64),  // policy_id
+    CoverageRegistered(u64), // policy_id
+    PolicyJurisdiction(u64), // policy_id
+    PolicyRegion(u64),       // policy_id
+    PolicyNode(u64),         // policy_id
+    Levy(u32),               // jurisdiction
+    LevyCollected(u32),      // jurisdiction
+    ProductStats(u32),       // product_id
+    PricingBands(u32),       // product_id
+    PricingState(u32),       // product_id
+    Quote(u64),              // quote_id
+    QuoteCounter,
+    QuoteValidity,
+    MemberPremiums(Address, u64), // holder, period
+    PeriodPremiums(u64),          // period
+    Monitor(Address),
+    HeartbeatConfig,
+    LastHeartbeat,
+    AuctionConfig,
+    AuctionRound(u64), // round
+    AuctionBid(u64),   // bid_id
+    AuctionBidCounter,
+    HolderPolicies(Address),
+    AttestationNonce,
+    PremiumSplit,
+    PremiumRequired,
+    Product(u32), // product_id
 }
 
+// Small, frequently read items kept in instance storage; moved out of
+// persistent storage by `migrate_hot_storage`
+const HOT_KEYS: [DataKey; 18] = [
+    DataKey::Paused,
+    DataKey::Config,
+    DataKey::PolicyCounter,
+    DataKey::PolicyStatusHistoryCounter,
+    DataKey::ActivePolicyCount,
+    DataKey::ActiveCoverage,
+    DataKey::MaxUtilizationBps,
+    DataKey::PremiumToken,
+    DataKey::QueueHead,
+    DataKey::QueueTail,
+    DataKey::RenewalCapBps,
+    DataKey::QuoteCounter,
+    DataKey::QuoteValidity,
+    DataKey::HeartbeatConfig,
+    DataKey::LastHeartbeat,
+    DataKey::AuctionConfig,
+    DataKey::AuctionBidCounter,
+    DataKey::AttestationNonce,
+];
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     pub risk_pool: Address,
 }
 
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub risk_pool: Address,
+}
+
+/// Terms of a policy to issue. `jurisdiction` tags it for the regulatory
+/// levy and `region` for the pool's per-region capacity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyTerms {
+    pub holder: Address,
+    pub coverage_amount: i128,
+    pub premium_amount: i128,
+    pub duration_days: u32,
+    pub product_id: u32,
+    pub jurisdiction: Option<u32>,
+    pub region: Option<u32>,
+}
+
+/// Coverage and term limits of a product. Products without a spec are
+/// bounded only by the protocol-wide limits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProductSpec {
+    pub product_id: u32,
+    pub min_coverage: i128,
+    pub max_coverage: i128,
+    pub max_duration_days: u32,
+}
+
+/// Regulatory levy taken from the premiums of policies issued in a
+/// jurisdiction and paid to `collector`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevyConfig {
+    pub levy_bps: u32,
+    pub collector: Address,
+}
+
+/// Share of each premium, after any levy, paid to the treasury instead of
+/// the risk pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PremiumSplit {
+    pub treasury: Address,
+    pub treasury_bps: u32,
+}
+
+/// Liveness expected from monitoring bots. Monitoring is stale once no
+/// heartbeat has arrived for `period_secs`; with `pause_issuance` set, new
+/// and renewed cover is refused until a bot checks in again.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeartbeatConfig {
+    pub period_secs: u64,
+    pub pause_issuance: bool,
+    /// When the config was set, the reference before the first heartbeat
+    pub configured_at: u64,
+}
+
+/// Everything a frontend needs to bootstrap against the policy contract, in
+/// one read
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    pub risk_pool: Address,
+    pub premium_token: Option<Address>,
+    pub max_utilization_bps: Option<u32>,
+    pub renewal_cap_bps: Option<u32>,
+    pub wired: bool,
+    pub paused: bool,
+    pub version: ContractVersion,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PolicyStatusHistory {
@@ -48,6 +251,168 @@ pub struct PolicyStatusHistory {
     pub timestamp: u64,
 }
 
+/// Live policy aggregates
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyStats {
+    pub total_issued: u64,
+    pub active_policies: u64,
+}
+
+/// Policy aggregates as of the last write in an epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyStatsCheckpoint {
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub stats: PolicyStats,
+}
+
+/// Registered frontend or aggregator that originates policies
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Integrator {
+    pub daily_quota: u32,
+    pub fee_share_bps: u32,
+    pub active: bool,
+    pub total_policies: u64,
+    pub fee_share_accrued: i128,
+    pub registered_at: u64,
+}
+
+/// Premiums written and claims paid on a product's policies
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProductStats {
+    pub premiums: i128,
+    pub claims: i128,
+}
+
+/// Loss-ratio band a product's pricing is steered into. After
+/// `trigger_epochs` consecutive epochs with a loss ratio above
+/// `target_high_bps` the premium multiplier rises by `step_bps`, and after as
+/// many below `target_low_bps` it falls by it, always staying within
+/// `min_multiplier_bps..=max_multiplier_bps`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PricingBands {
+    pub target_low_bps: u32,
+    pub target_high_bps: u32,
+    pub trigger_epochs: u32,
+    pub step_bps: u32,
+    pub min_multiplier_bps: u32,
+    pub max_multiplier_bps: u32,
+}
+
+/// A product's premium multiplier and the loss experience of the epoch in
+/// progress, which is judged against the bands when the epoch rolls over
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PricingState {
+    pub epoch: u64,
+    pub epoch_stats: ProductStats,
+    pub epochs_above: u32,
+    pub epochs_below: u32,
+    pub multiplier_bps: u32,
+}
+
+/// Kind of movement posted to a policy's ledger
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LedgerEntry {
+    Premium,
+    Fee,
+    Refund,
+    Claim,
+    Interest,
+}
+
+/// Running totals of every amount booked against a policy. Premiums and
+/// interest credit the policy, fees, refunds and claims debit it, and
+/// `balance` always equals credits minus debits.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PolicyLedger {
+    pub premiums_received: i128,
+    pub fees_deducted: i128,
+    pub refunds_issued: i128,
+    pub claims_paid: i128,
+    pub interest_accrued: i128,
+    pub balance: i128,
+}
+
+/// Coverage request waiting for pool capacity, with its quote locked until `expires_at`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedPolicy {
+    pub holder: Address,
+    pub coverage_amount: i128,
+    pub premium_amount: i128,
+    pub duration_days: u32,
+    pub product_id: u32,
+    pub queued_at: u64,
+    pub expires_at: u64,
+}
+
+/// Premium locked for a holder by `request_quote` until `expires_at`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockedQuote {
+    pub holder: Address,
+    pub product_id: u32,
+    pub coverage_amount: i128,
+    pub premium_amount: i128,
+    pub duration_days: u32,
+    pub expires_at: u64,
+}
+
+/// Answer to `verify_coverage`. `policy_id` is the qualifying policy that
+/// runs longest, if any; `nonce` is unique per attestation and matches the
+/// `coverage_attested` event emitted with it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoverageAttestation {
+    pub holder: Address,
+    pub product_id: u32,
+    pub min_amount: i128,
+    pub at_time: u64,
+    pub covered: bool,
+    pub policy_id: Option<u64>,
+    pub remaining_coverage: i128,
+    pub end_time: u64,
+    pub nonce: u64,
+    pub issued_at: u64,
+}
+
+/// Batch auction for capacity. Bids placed during a round of `round_secs`
+/// are settled together once it ends; at most `max_bids` per round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionConfig {
+    pub round_secs: u64,
+    pub max_bids: u32,
+}
+
+/// Bid for cover at `premium_amount`, escrowed until its round settles
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionBid {
+    pub bidder: Address,
+    pub product_id: u32,
+    pub coverage_amount: i128,
+    pub duration_days: u32,
+    pub premium_amount: i128,
+    pub round: u64,
+}
+
+/// Bids of an auction round not yet settled
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionRound {
+    pub ends_at: u64,
+    pub bids: Vec<u64>,
+}
+
 // Step 1: Define the Policy State Enum
 /// Represents the lifecycle states of a policy.
 /// This is a closed enum with only valid states - no string states allowed.
@@ -57,6 +422,8 @@ pub enum PolicyState {
     ACTIVE,
     EXPIRED,
     CANCELLED,
+    /// Claims have paid out the full coverage
+    CLAIMED,
 }
 
 // Step 2: Define Allowed State Transitions
@@ -66,16 +433,22 @@ impl PolicyState {
     /// Valid transitions:
     /// - ACTIVE → EXPIRED
     /// - ACTIVE → CANCELLED
-    /// - EXPIRED → (no transitions)
+    /// - ACTIVE → CLAIMED
+    /// - EXPIRED → CLAIMED (claims filed within the claim window)
     /// - CANCELLED → (no transitions)
+    /// - CLAIMED → (no transitions)
     pub fn can_transition_to(self, next: PolicyState) -> bool {
         match (self, next) {
             // ACTIVE can transition to EXPIRED or CANCELLED
             (PolicyState::ACTIVE, PolicyState::EXPIRED) => true,
             (PolicyState::ACTIVE, PolicyState::CANCELLED) => true,
-            // EXPIRED and CANCELLED are terminal states - no transitions allowed
+            // Full payouts close a policy whether or not its term has ended
+            (PolicyState::ACTIVE, PolicyState::CLAIMED) => true,
+            (PolicyState::EXPIRED, PolicyState::CLAIMED) => true,
+            // CANCELLED and CLAIMED are terminal states - no transitions allowed
             (PolicyState::EXPIRED, _) => false,
             (PolicyState::CANCELLED, _) => false,
+            (PolicyState::CLAIMED, _) => false,
             // Self-transitions are not allowed
             _ => false,
         }
@@ -88,11 +461,14 @@ impl PolicyState {
 pub struct Policy {
     pub holder: Address,
     pub coverage_amount: i128,
+    /// Coverage left after settled claims; the ceiling for new claims
+    pub remaining_coverage: i128,
     pub premium_amount: i128,
     pub start_time: u64,
     pub end_time: u64,
     state: PolicyState,  // Private - controlled through methods
     pub created_at: u64,
+    pub product_id: u32,
 }
 
 // Step 4: Implement Policy Methods
@@ -105,15 +481,18 @@ impl Policy {
         start_time: u64,
         end_time: u64,
         created_at: u64,
+        product_id: u32,
     ) -> Self {
         Policy {
             holder,
             coverage_amount,
+            remaining_coverage: coverage_amount,
             premium_amount,
             start_time,
             end_time,
             state: PolicyState::ACTIVE,
             created_at,
+            product_id,
         }
     }
 
@@ -133,12 +512,12 @@ impl Policy {
 
     /// Cancels the policy (only if Active)
     pub fn cancel(&mut self) -> Result<(), ContractError> {
-        self.transition_to(PolicyState::Cancelled)
+        self.transition_to(PolicyState::CANCELLED)
     }
 
     /// Expires the policy (only if Active)
     pub fn expire(&mut self) -> Result<(), ContractError> {
-        self.transition_to(PolicyState::Expired)
+        self.transition_to(PolicyState::EXPIRED)
     }
 
     /// Checks if the policy is active
@@ -155,6 +534,11 @@ impl Policy {
     pub fn is_cancelled(&self) -> bool {
         matches!(self.state, PolicyState::CANCELLED)
     }
+
+    /// Checks if the policy's coverage has been paid out
+    pub fn is_claimed(&self) -> bool {
+        matches!(self.state, PolicyState::CLAIMED)
+    }
 }
 
 // Step 5: Policy State Machine
@@ -190,6 +574,19 @@ impl PolicyStateMachine {
             .persistent()
             .set(&DataKey::Policy(policy_id), &policy);
 
+        if previous_state == PolicyState::ACTIVE {
+            let active = active_policy_count(env);
+            env.storage()
+                .instance()
+                .set(&DataKey::ActivePolicyCount, &active.saturating_sub(1));
+            let coverage = active_coverage(env);
+            env.storage()
+                .instance()
+                .set(&DataKey::ActiveCoverage, &(coverage - policy.coverage_amount).max(0));
+            deregister_pool_coverage(env, policy_id)?;
+        }
+        checkpoint_stats(env);
+
         // Record history
         let history_id = Self::next_history_id(env);
         let history = PolicyStatusHistory {
@@ -208,8 +605,10 @@ impl PolicyStateMachine {
             PolicyState::ACTIVE => Symbol::new(env, "PolicyActivated"),
             PolicyState::EXPIRED => Symbol::new(env, "PolicyExpired"),
             PolicyState::CANCELLED => Symbol::new(env, "PolicyCancelled"),
+            PolicyState::CLAIMED => Symbol::new(env, "PolicyClaimed"),
         };
-        env.events().publish(
+        publish_event(
+            env,
             (event_name, policy_id),
             (actor, previous_state, target_state, env.ledger().timestamp()),
         );
@@ -221,22 +620,22 @@ impl PolicyStateMachine {
     fn next_history_id(env: &Env) -> u64 {
         let current_id: u64 = env
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::PolicyStatusHistoryCounter)
             .unwrap_or(0u64);
         let next_id = current_id + 1;
         env.storage()
-            .persistent()
+            .instance()
             .set(&DataKey::PolicyStatusHistoryCounter, &next_id);
         next_id
     }
 
     /// Gets policy status history for a policy
     pub fn get_policy_history(env: &Env, policy_id: u64) -> Vec<PolicyStatusHistory> {
-        let mut history = Vec::new();
+        let mut history = Vec::new(env);
         let counter: u64 = env
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::PolicyStatusHistoryCounter)
             .unwrap_or(0u64);
 
@@ -244,10 +643,10 @@ impl PolicyStateMachine {
             if let Some(h) = env
                 .storage()
                 .persistent()
-                .get(&DataKey::PolicyStatusHistory(i))
+                .get::<_, PolicyStatusHistory>(&DataKey::PolicyStatusHistory(i))
             {
                 if h.policy_id == policy_id {
-                    history.push(h);
+                    history.push_back(h);
                 }
             }
         }
@@ -287,6 +686,24 @@ pub enum ContractError {
     InvalidRole = 11,
     RoleNotFound = 12,
     NotTrustedContract = 13,
+    ListenerAlreadyRegistered = 14,
+    ListenerNotRegistered = 15,
+    TooManyListeners = 16,
+    IntegratorNotFound = 17,
+    IntegratorInactive = 18,
+    IntegratorQuotaExceeded = 19,
+    CapacityExceeded = 20,
+    CannotRenewPolicy = 21,
+    NotWired = 22,
+    WiringFailed = 23,
+    QuoteExpired = 24,
+    MonitoringStale = 25,
+    AuctionFull = 26,
+    InvalidAsset = 27,
+    AssetNotAllowed = 28,
+    PremiumTokenNotSet = 29,
+    PremiumTransferFailed = 30,
+    InvalidStateTransition = 31,
     // Invariant violation errors (100-199)
     InvalidPolicyState = 101,
     InvalidAmount = 103,
@@ -305,6 +722,62 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
     }
 }
 
+impl From<ListenerError> for ContractError {
+    fn from(err: ListenerError) -> Self {
+        match err {
+            ListenerError::AlreadyRegistered => ContractError::ListenerAlreadyRegistered,
+            ListenerError::NotRegistered => ContractError::ListenerNotRegistered,
+            ListenerError::TooManyListeners => ContractError::TooManyListeners,
+        }
+    }
+}
+
+impl From<AssetError> for ContractError {
+    fn from(err: AssetError) -> Self {
+        match err {
+            AssetError::NotToken | AssetError::UnsupportedDecimals => ContractError::InvalidAsset,
+            AssetError::NotAllowed => ContractError::AssetNotAllowed,
+        }
+    }
+}
+
+impl From<WiringError> for ContractError {
+    fn from(err: WiringError) -> Self {
+        match err {
+            WiringError::NotWired => ContractError::NotWired,
+            WiringError::HandshakeFailed | WiringError::VersionMismatch => ContractError::WiringFailed,
+        }
+    }
+}
+
+impl From<SnapshotError> for ContractError {
+    fn from(err: SnapshotError) -> Self {
+        match err {
+            SnapshotError::NotFound => ContractError::NotFound,
+            SnapshotError::AlreadyCompleted => ContractError::InvalidState,
+        }
+    }
+}
+
+impl From<MathError> for ContractError {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::Overflow => ContractError::Overflow2,
+            MathError::DivisionByZero => ContractError::InvalidInput,
+        }
+    }
+}
+
+impl From<IncidentError> for ContractError {
+    fn from(err: IncidentError) -> Self {
+        match err {
+            IncidentError::InvalidInput => ContractError::InvalidInput,
+            IncidentError::NotFound => ContractError::NotFound,
+            IncidentError::AlreadyClosed | IncidentError::StillOpen => ContractError::InvalidState,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -321,159 +794,856 @@ fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.risk_pool)?;
+    Ok(())
+}
+
+/// Every entrypoint checks the pause flag first, so this is also where the
+/// instance TTL is kept up
 fn is_paused(env: &Env) -> bool {
+    hot_storage::extend(env);
     env.storage()
-        .persistent()
+        .instance()
         .get(&DataKey::Paused)
         .unwrap_or(false)
 }
 
 fn set_paused(env: &Env, paused: bool) {
     env.storage()
-        .persistent()
+        .instance()
         .set(&DataKey::Paused, &paused);
 }
 
 fn next_policy_id(env: &Env) -> u64 {
     let current_id: u64 = env
         .storage()
-        .persistent()
+        .instance()
         .get(&DataKey::PolicyCounter)
         .unwrap_or(0u64);
     let next_id = current_id + 1;
     env.storage()
-        .persistent()
+        .instance()
         .set(&DataKey::PolicyCounter, &next_id);
     next_id
 }
 
-/// I2: Validate policy state transition
-/// Maps valid state transitions for policy lifecycle:
-/// Active -> Expired (time-based), Cancelled, or Claimed
-fn is_valid_policy_state_transition(current: PolicyStatus, next: PolicyStatus) -> bool {
-    match (&current, &next) {
-        // Valid forward transitions
-        (PolicyStatus::Active, PolicyStatus::Expired) => true,
-        (PolicyStatus::Active, PolicyStatus::Cancelled) => true,
-        (PolicyStatus::Active, PolicyStatus::Claimed) => true,
-        (PolicyStatus::Expired, PolicyStatus::Claimed) => true,
-        // Invalid transitions
-        _ => false,
+fn active_policy_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActivePolicyCount)
+        .unwrap_or(0u64)
+}
+
+fn active_coverage(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ActiveCoverage)
+        .unwrap_or(0i128)
+}
+
+/// Whether the pool can underwrite `coverage_amount` more without exceeding
+/// the utilization cap. Always true while no cap is configured.
+fn has_capacity(env: &Env, coverage_amount: i128) -> Result<bool, ContractError> {
+    let max_utilization: u32 = match env.storage().instance().get(&DataKey::MaxUtilizationBps) {
+        Some(bps) => bps,
+        None => return Ok(true),
+    };
+
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+    let pool_stats: (i128, i128, i128, u64) = env.invoke_contract(
+        &config.risk_pool,
+        &Symbol::new(env, "get_pool_stats"),
+        ().into_val(env),
+    );
+
+    let capacity = bps_of(pool_stats.0, max_utilization)?;
+    let required = active_coverage(env)
+        .checked_add(coverage_amount)
+        .ok_or(ContractError::Overflow2)?;
+
+    Ok(required <= capacity)
+}
+
+fn term_end(start_time: u64, duration_days: u32) -> Result<u64, ContractError> {
+    start_time.checked_add(u64::from(duration_days).checked_mul(86400).ok_or(ContractError::Overflow2)?).ok_or(ContractError::Overflow2)
+}
+
+/// Store a new ACTIVE policy and update the aggregates that track it
+fn create_policy(env: &Env, policy: Policy, jurisdiction: Option<u32>, region: Option<u32>) -> Result<u64, ContractError> {
+    let policy_id = next_policy_id(env);
+    let holder = policy.holder.clone();
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Policy(policy_id), &policy);
+    let mut held: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::HolderPolicies(holder.clone()))
+        .unwrap_or(Vec::new(env));
+    held.push_back(policy_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderPolicies(holder), &held);
+    if let Some(jurisdiction) = jurisdiction {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyJurisdiction(policy_id), &jurisdiction);
+    }
+    if let Some(region) = region {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyRegion(policy_id), &region);
     }
+
+    let active = active_policy_count(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ActivePolicyCount, &active.checked_add(1).ok_or(ContractError::Overflow2)?);
+    let coverage = active_coverage(env)
+        .checked_add(policy.coverage_amount)
+        .ok_or(ContractError::Overflow2)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::ActiveCoverage, &coverage);
+    checkpoint_stats(env);
+    register_pool_coverage(env, policy_id, policy.product_id, policy.coverage_amount, policy.end_time, region)?;
+
+    Ok(policy_id)
 }
 
-/// I4: Validate coverage amount within bounds
-fn validate_coverage_amount(amount: i128) -> Result<(), ContractError> {
-    if amount < MIN_COVERAGE_AMOUNT || amount > MAX_COVERAGE_AMOUNT {
-        return Err(ContractError::InvalidAmount);
+/// Report a new policy's exposure to the risk pool, which enforces any
+/// capacity limit on its region and concentration limit on its product's
+/// correlation group. Starts once the contracts are wired; earlier
+/// policies are never registered, so never deregistered.
+fn register_pool_coverage(env: &Env, policy_id: u64, product_id: u32, coverage_amount: i128, end_time: u64, region: Option<u32>) -> Result<(), ContractError> {
+    if !wiring::is_wired(env) {
+        return Ok(());
     }
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.risk_pool)?;
+
+    env.invoke_contract::<()>(
+        &config.risk_pool,
+        &Symbol::new(env, "register_coverage"),
+        (env.current_contract_address(), policy_id, product_id, coverage_amount, end_time, region).into_val(env),
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoverageRegistered(policy_id), &true);
     Ok(())
 }
 
-/// I7: Validate premium amount within bounds
-fn validate_premium_amount(premium: i128) -> Result<(), ContractError> {
-    if premium < MIN_PREMIUM_AMOUNT || premium > MAX_PREMIUM_AMOUNT {
-        return Err(ContractError::InvalidPremium);
+/// Report the node a registered policy covers to the risk pool, which
+/// enforces its per-node capacity
+fn register_pool_node(env: &Env, policy_id: u64, node_id: &BytesN<32>) -> Result<(), ContractError> {
+    if !env.storage().persistent().has(&DataKey::CoverageRegistered(policy_id)) {
+        return Ok(());
     }
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.risk_pool)?;
+
+    env.invoke_contract::<()>(
+        &config.risk_pool,
+        &Symbol::new(env, "register_node_coverage"),
+        (env.current_contract_address(), policy_id, node_id.clone()).into_val(env),
+    );
     Ok(())
 }
 
-/// Validate policy duration
-fn validate_duration(duration_days: u32) -> Result<(), ContractError> {
-    if duration_days < MIN_POLICY_DURATION_DAYS || duration_days > MAX_POLICY_DURATION_DAYS {
-        return Err(ContractError::InvalidInput);
+/// Free a registered policy's exposure in the risk pool
+fn deregister_pool_coverage(env: &Env, policy_id: u64) -> Result<(), ContractError> {
+    if !env.storage().persistent().has(&DataKey::CoverageRegistered(policy_id)) {
+        return Ok(());
     }
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+    require_trusted_contract(env, &config.risk_pool)?;
+
+    env.invoke_contract::<()>(
+        &config.risk_pool,
+        &Symbol::new(env, "deregister_coverage"),
+        (env.current_contract_address(), policy_id).into_val(env),
+    );
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CoverageRegistered(policy_id));
     Ok(())
 }
 
-#[contractimpl]
-impl PolicyContract {
-    pub fn initialize(env: Env, admin: Address, risk_pool: Address) -> Result<(), ContractError> {
-        // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
-            return Err(ContractError::AlreadyInitialized);
-        }
+/// Credit premium already sent to the risk pool as premium income for a policy
+fn credit_pool_premium(env: &Env, config: &Config, policy_id: u64, amount: i128) -> Result<(), ContractError> {
+    require_trusted_contract(env, &config.risk_pool)?;
+    env.invoke_contract::<()>(
+        &config.risk_pool,
+        &Symbol::new(env, "receive_premium"),
+        (env.current_contract_address(), policy_id, amount).into_val(env),
+    );
+    Ok(())
+}
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &risk_pool)?;
+/// Premium a bid offers per unit of coverage per day, the auction's ranking
+fn bid_rate(bid: &AuctionBid) -> Result<i128, ContractError> {
+    let exposure = bid
+        .coverage_amount
+        .checked_mul(bid.duration_days as i128)
+        .ok_or(ContractError::Overflow2)?;
+    Ok(mul_div_floor(bid.premium_amount, AUCTION_RATE_SCALE, exposure)?)
+}
 
-        // Initialize authorization system with admin
-        admin.require_auth();
-        initialize_admin(&env, admin.clone());
-        
-        // Register risk pool contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &risk_pool)?;
-        
-        let config = Config { risk_pool };
-        env.storage().persistent().set(&DataKey::Config, &config);
-        
-        env.storage()
-            .persistent()
-            .set(&DataKey::PolicyCounter, &0u64);
-        
-        set_paused(&env, false);
+fn policy_jurisdiction(env: &Env, policy_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyJurisdiction(policy_id))
+}
 
-        env.events().publish(
-            (Symbol::new(&env, "initialized"), ()),
-            admin,
-        );
+fn quote_validity(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuoteValidity)
+        .unwrap_or(DEFAULT_QUOTE_VALIDITY_SECONDS)
+}
 
-        Ok(())
+fn monitoring_stale(env: &Env) -> bool {
+    let config: HeartbeatConfig = match env.storage().instance().get(&DataKey::HeartbeatConfig) {
+        Some(config) => config,
+        None => return false,
+    };
+    let last_seen = env
+        .storage()
+        .instance()
+        .get::<_, (Address, u64)>(&DataKey::LastHeartbeat)
+        .map_or(config.configured_at, |(_, at)| at.max(config.configured_at));
+    env.ledger().timestamp().saturating_sub(last_seen) > config.period_secs
+}
+
+/// Refuse new cover while monitoring is stale, if so configured
+fn require_monitoring_live(env: &Env) -> Result<(), ContractError> {
+    let pause_issuance = env
+        .storage()
+        .instance()
+        .get::<_, HeartbeatConfig>(&DataKey::HeartbeatConfig)
+        .is_some_and(|config| config.pause_issuance);
+    if pause_issuance && monitoring_stale(env) {
+        return Err(ContractError::MonitoringStale);
     }
+    Ok(())
+}
 
-    pub fn issue_policy(
-        env: Env,
-        manager: Address,
-        holder: Address,
-        coverage_amount: i128,
-        premium_amount: i128,
-        duration_days: u32,
-    ) -> Result<u64, ContractError> {
-        // Verify identity and require policy management permission
-        manager.require_auth();
-        require_policy_management(&env, &manager)?;
+fn policy_region(env: &Env, policy_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyRegion(policy_id))
+}
 
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
-        }
+/// Premium token amount for a canonical `amount`. Premiums are charged in
+/// the token's own decimals, rounded up; escrowed premiums are passed on or
+/// refunded with the same conversion, so exactly what was taken moves on.
+fn premium_units(env: &Env, token: &Address, amount: i128) -> Result<i128, ContractError> {
+    Ok(units::to_native_ceil(amount, assets::decimals(env, token))?)
+}
 
-        validate_address(&env, &holder)?;
+/// Pay the levy of the policy's jurisdiction on `amount` of premium from the
+/// holder to the collector. Returns the levy, 0 if none applies.
+fn collect_levy(env: &Env, token: &Address, holder: &Address, policy_id: u64, amount: i128) -> Result<i128, ContractError> {
+    let jurisdiction = match policy_jurisdiction(env, policy_id) {
+        Some(jurisdiction) => jurisdiction,
+        None => return Ok(0),
+    };
+    let levy_config: LevyConfig = match env.storage().persistent().get(&DataKey::Levy(jurisdiction)) {
+        Some(levy_config) => levy_config,
+        None => return Ok(0),
+    };
+
+    let levy = bps_of(amount, levy_config.levy_bps)?;
+    if levy == 0 {
+        return Ok(0);
+    }
+    transfer_premium(env, token, holder, &levy_config.collector, levy)?;
 
-        // Validate coverage amount within bounds
-        validate_coverage_amount(coverage_amount)?;
+    let collected: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LevyCollected(jurisdiction))
+        .unwrap_or(0);
+    env.storage().persistent().set(
+        &DataKey::LevyCollected(jurisdiction),
+        &collected.checked_add(levy).ok_or(ContractError::Overflow2)?,
+    );
+    post_ledger(env, policy_id, LedgerEntry::Fee, levy)?;
+
+    publish_event(
+        env,
+        (Symbol::new(env, "levy_collected"), jurisdiction),
+        (policy_id, levy, levy_config.collector),
+    );
+
+    Ok(levy)
+}
 
-        // Validate premium amount within bounds
-        validate_premium_amount(premium_amount)?;
+/// Move a canonical premium `amount` from `from` to `to`. A failed transfer
+/// fails the call, undoing every transfer made before it in the same call,
+/// so a holder is never charged for a policy that was not issued.
+fn transfer_premium(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) -> Result<(), ContractError> {
+    let units = premium_units(env, token, amount)?;
+    match token::Client::new(env, token).try_transfer(from, to, &units) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(ContractError::PremiumTransferFailed),
+    }
+}
 
-        // Validate duration within bounds
-        validate_duration(duration_days)?;
+fn premium_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::PremiumRequired)
+        .unwrap_or(false)
+}
 
-        let policy_id = next_policy_id(&env);
-        let current_time = env.ledger().timestamp();
-        let end_time = current_time.checked_add(u64::from(duration_days).checked_mul(86400).ok_or(ContractError::Overflow2)?).ok_or(ContractError::Overflow2)?;
+/// Collect a policy's premium from the holder: any jurisdiction levy goes
+/// to its collector, the treasury share to the treasury and the rest to the
+/// risk pool, which credits it. Without a premium token nothing is
+/// collected, unless payment has been made mandatory, in which case no
+/// policy is issued.
+fn route_premium(env: &Env, holder: &Address, policy_id: u64, amount: i128) -> Result<(), ContractError> {
+    let token: Address = match env.storage().instance().get(&DataKey::PremiumToken) {
+        Some(token) => token,
+        None if premium_required(env) => return Err(ContractError::PremiumTokenNotSet),
+        None => return Ok(()),
+    };
+    let config: Config = env
+        .storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(ContractError::NotInitialized)?;
+
+    let mut net = amount - collect_levy(env, &token, holder, policy_id, amount)?;
+    let split: Option<PremiumSplit> = env.storage().instance().get(&DataKey::PremiumSplit);
+    if let Some(split) = split {
+        let share = bps_of(net, split.treasury_bps)?;
+        if share > 0 {
+            transfer_premium(env, &token, holder, &split.treasury, share)?;
+            post_ledger(env, policy_id, LedgerEntry::Fee, share)?;
+            net -= share;
+
+            publish_event(
+                env,
+                (Symbol::new(env, "premium_split"), policy_id),
+                (share, split.treasury),
+            );
+        }
+    }
 
-        // Use the new Policy constructor which initializes state to Active
-        let policy = Policy::new(
-            holder.clone(),
-            coverage_amount,
-            premium_amount,
-            current_time,
-            end_time,
-            current_time,
+    transfer_premium(env, &token, holder, &config.risk_pool, net)?;
+    credit_pool_premium(env, &config, policy_id, net)
+}
+
+fn get_policy_ledger(env: &Env, policy_id: u64) -> PolicyLedger {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyLedger(policy_id))
+        .unwrap_or_default()
+}
+
+/// Book `amount` against a policy's ledger and emit the posting so off-chain
+/// indexers can be reconciled against `get_policy_ledger`
+fn post_ledger(env: &Env, policy_id: u64, entry: LedgerEntry, amount: i128) -> Result<(), ContractError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    if amount < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let mut ledger = get_policy_ledger(env, policy_id);
+    let (total, credit) = match entry {
+        LedgerEntry::Premium => (&mut ledger.premiums_received, true),
+        LedgerEntry::Fee => (&mut ledger.fees_deducted, false),
+        LedgerEntry::Refund => (&mut ledger.refunds_issued, false),
+        LedgerEntry::Claim => (&mut ledger.claims_paid, false),
+        LedgerEntry::Interest => (&mut ledger.interest_accrued, true),
+    };
+    *total = total.checked_add(amount).ok_or(ContractError::Overflow2)?;
+    ledger.balance = if credit {
+        ledger.balance.checked_add(amount)
+    } else {
+        ledger.balance.checked_sub(amount)
+    }
+    .ok_or(ContractError::Overflow2)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyLedger(policy_id), &ledger);
+
+    if matches!(entry, LedgerEntry::Premium | LedgerEntry::Claim | LedgerEntry::Refund) {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        match entry {
+            LedgerEntry::Premium => {
+                record_product_experience(env, policy.product_id, entry, amount)?;
+                record_member_premium(env, &policy.holder, amount)?;
+            }
+            LedgerEntry::Claim => record_product_experience(env, policy.product_id, entry, amount)?,
+            _ => record_member_premium(env, &policy.holder, -amount)?,
+        }
+    }
+
+    publish_event(
+        env,
+        (Symbol::new(env, "ledger_posted"), policy_id),
+        (entry, amount, ledger.balance),
+    );
+
+    Ok(())
+}
+
+/// Add a premium or claim posting to its product's stats and to the loss
+/// experience of the pricing epoch in progress
+fn record_product_experience(env: &Env, product_id: u32, entry: LedgerEntry, amount: i128) -> Result<(), ContractError> {
+    let mut stats = product_stats(env, product_id);
+    let mut state = roll_pricing(env, product_id)?;
+    let (total, in_epoch) = match entry {
+        LedgerEntry::Claim => (&mut stats.claims, &mut state.epoch_stats.claims),
+        _ => (&mut stats.premiums, &mut state.epoch_stats.premiums),
+    };
+    *total = total.checked_add(amount).ok_or(ContractError::Overflow2)?;
+    *in_epoch = in_epoch.checked_add(amount).ok_or(ContractError::Overflow2)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ProductStats(product_id), &stats);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PricingState(product_id), &state);
+    Ok(())
+}
+
+/// Add premium paid by a holder to the current member period, or take a
+/// refund of it back out with a negative `amount`
+fn record_member_premium(env: &Env, holder: &Address, amount: i128) -> Result<(), ContractError> {
+    let period = env.ledger().timestamp() / MEMBER_PERIOD_SECONDS;
+
+    let key = DataKey::MemberPremiums(holder.clone(), period);
+    let paid: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &paid.checked_add(amount).ok_or(ContractError::Overflow2)?);
+
+    let key = DataKey::PeriodPremiums(period);
+    let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &total.checked_add(amount).ok_or(ContractError::Overflow2)?);
+    Ok(())
+}
+
+/// Sum a per-period premium total over the `MEMBER_WINDOW_PERIODS` complete
+/// periods before the one containing `as_of`. The period in progress is
+/// left out so premiums paid after `as_of` never count. Refunds booked
+/// after the premium they return can leave a period negative, so the sum
+/// is floored at zero.
+fn sum_member_window(env: &Env, as_of: u64, key: impl Fn(u64) -> DataKey) -> i128 {
+    let current = as_of / MEMBER_PERIOD_SECONDS;
+    let mut total = 0i128;
+    for period in current.saturating_sub(MEMBER_WINDOW_PERIODS)..current {
+        let paid: i128 = env.storage().persistent().get(&key(period)).unwrap_or(0);
+        total = total.saturating_add(paid);
+    }
+    total.max(0)
+}
+
+/// Whether a holder has an active policy in force at `as_of`. Only the
+/// most recent `MAX_COVERAGE_SCAN` policies of the holder are looked at.
+fn is_active_member(env: &Env, holder: &Address, as_of: u64) -> bool {
+    let held: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::HolderPolicies(holder.clone()))
+        .unwrap_or(Vec::new(env));
+    let scan_from = held.len().saturating_sub(MAX_COVERAGE_SCAN);
+    held.slice(scan_from..).iter().any(|policy_id| {
+        env.storage()
+            .persistent()
+            .get::<_, Policy>(&DataKey::Policy(policy_id))
+            .is_some_and(|policy| policy.is_active() && policy.start_time <= as_of && as_of < policy.end_time)
+    })
+}
+
+fn product_stats(env: &Env, product_id: u32) -> ProductStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProductStats(product_id))
+        .unwrap_or_default()
+}
+
+fn pricing_state(env: &Env, product_id: u32) -> PricingState {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PricingState(product_id))
+        .unwrap_or(PricingState {
+            epoch: env.ledger().timestamp() / STATS_EPOCH_SECONDS,
+            epoch_stats: ProductStats::default(),
+            epochs_above: 0,
+            epochs_below: 0,
+            multiplier_bps: BASE_MULTIPLIER_BPS,
+        })
+}
+
+/// Close a product's pricing epoch once a new one has begun: judge the
+/// closed epoch's loss ratio against the product's bands and reprice when
+/// it has been out of band for long enough. Epochs without premiums or
+/// claims leave the streaks as they are.
+fn roll_pricing(env: &Env, product_id: u32) -> Result<PricingState, ContractError> {
+    let mut state = pricing_state(env, product_id);
+    let epoch = env.ledger().timestamp() / STATS_EPOCH_SECONDS;
+    if state.epoch >= epoch {
+        return Ok(state);
+    }
+
+    let closed = state.epoch_stats.clone();
+    state.epoch = epoch;
+    state.epoch_stats = ProductStats::default();
+
+    let bands: PricingBands = match env.storage().persistent().get(&DataKey::PricingBands(product_id)) {
+        Some(bands) => bands,
+        None => return Ok(state),
+    };
+
+    if closed.premiums > 0 || closed.claims > 0 {
+        let loss_ratio = if closed.premiums == 0 {
+            i128::MAX
+        } else {
+            ratio_bps(closed.claims, closed.premiums)?
+        };
+        if loss_ratio > bands.target_high_bps as i128 {
+            state.epochs_above += 1;
+            state.epochs_below = 0;
+        } else if loss_ratio < bands.target_low_bps as i128 {
+            state.epochs_below += 1;
+            state.epochs_above = 0;
+        } else {
+            state.epochs_above = 0;
+            state.epochs_below = 0;
+        }
+    }
+
+    let old_multiplier = state.multiplier_bps;
+    if state.epochs_above >= bands.trigger_epochs {
+        state.multiplier_bps = state
+            .multiplier_bps
+            .saturating_add(bands.step_bps)
+            .min(bands.max_multiplier_bps);
+        state.epochs_above = 0;
+    } else if state.epochs_below >= bands.trigger_epochs {
+        state.multiplier_bps = state
+            .multiplier_bps
+            .saturating_sub(bands.step_bps)
+            .max(bands.min_multiplier_bps);
+        state.epochs_below = 0;
+    }
+
+    if state.multiplier_bps != old_multiplier {
+        publish_event(
+            env,
+            (Symbol::new(env, "product_repriced"), product_id),
+            (old_multiplier, state.multiplier_bps, closed.premiums, closed.claims),
         );
+    }
+
+    Ok(state)
+}
+
+fn current_stats(env: &Env) -> PolicyStats {
+    PolicyStats {
+        total_issued: env
+            .storage()
+            .instance()
+            .get(&DataKey::PolicyCounter)
+            .unwrap_or(0u64),
+        active_policies: active_policy_count(env),
+    }
+}
 
+/// Write the live aggregates into the checkpoint for the current epoch.
+/// Later writes in the same epoch overwrite earlier ones, so each checkpoint
+/// holds the closing values of its epoch. Only the newest
+/// `MAX_STATS_HISTORY` epochs are retained.
+fn checkpoint_stats(env: &Env) {
+    let timestamp = env.ledger().timestamp();
+    let epoch = timestamp / STATS_EPOCH_SECONDS;
+
+    let mut epochs: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StatsEpochs)
+        .unwrap_or_else(|| Vec::new(env));
+    if epochs.last() != Some(epoch) {
+        epochs.push_back(epoch);
+        if epochs.len() > MAX_STATS_HISTORY {
+            if let Some(oldest) = epochs.pop_front() {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::StatsCheckpoint(oldest));
+            }
+        }
         env.storage()
             .persistent()
-            .set(&DataKey::Policy(policy_id), &policy);
+            .set(&DataKey::StatsEpochs, &epochs);
+    }
+
+    let stats = current_stats(env);
+    state_root::update(
+        env,
+        (stats.total_issued, stats.active_policies, active_coverage(env)),
+    );
+
+    let checkpoint = PolicyStatsCheckpoint {
+        epoch,
+        timestamp,
+        stats,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::StatsCheckpoint(epoch), &checkpoint);
+}
+
+fn get_integrator(env: &Env, integrator: &Address) -> Result<Integrator, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integrator(integrator.clone()))
+        .ok_or(ContractError::IntegratorNotFound)
+}
+
+/// Attribute a policy to an integrator, enforcing its daily quota.
+/// Returns the premium share owed to the integrator.
+fn record_integrator_policy(
+    env: &Env,
+    integrator: &Address,
+    policy_id: u64,
+    premium_amount: i128,
+) -> Result<i128, ContractError> {
+    let mut record = get_integrator(env, integrator)?;
+    if !record.active {
+        return Err(ContractError::IntegratorInactive);
+    }
+
+    let day = env.ledger().timestamp() / 86400;
+    let usage_key = DataKey::IntegratorUsage(integrator.clone(), day);
+    let used: u32 = env.storage().persistent().get(&usage_key).unwrap_or(0);
+    if used >= record.daily_quota {
+        return Err(ContractError::IntegratorQuotaExceeded);
+    }
+    env.storage().persistent().set(&usage_key, &(used + 1));
+
+    let fee_share = bps_of(premium_amount, record.fee_share_bps)?;
+    record.total_policies = record.total_policies.checked_add(1).ok_or(ContractError::Overflow2)?;
+    record.fee_share_accrued = record
+        .fee_share_accrued
+        .checked_add(fee_share)
+        .ok_or(ContractError::Overflow2)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Integrator(integrator.clone()), &record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyIntegrator(policy_id), integrator);
+
+    Ok(fee_share)
+}
+
+/// I4: Validate coverage amount within bounds
+fn validate_coverage_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    assert_invariant!(
+        env,
+        (MIN_COVERAGE_AMOUNT..=MAX_COVERAGE_AMOUNT).contains(&amount),
+        InvariantError::InvalidAmount
+    );
+    Ok(())
+}
+
+/// I7: Validate premium amount within bounds
+fn validate_premium_amount(env: &Env, premium: i128) -> Result<(), ContractError> {
+    assert_invariant!(
+        env,
+        (MIN_PREMIUM_AMOUNT..=MAX_PREMIUM_AMOUNT).contains(&premium),
+        InvariantError::InvalidPremium
+    );
+    Ok(())
+}
+
+/// Validate policy duration
+fn validate_duration(duration_days: u32) -> Result<(), ContractError> {
+    if !(MIN_POLICY_DURATION_DAYS..=MAX_POLICY_DURATION_DAYS).contains(&duration_days) {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Check cover against the limits of its product, if the product has any
+fn validate_product_terms(env: &Env, product_id: u32, coverage_amount: i128, duration_days: u32) -> Result<(), ContractError> {
+    let spec: ProductSpec = match env.storage().persistent().get(&DataKey::Product(product_id)) {
+        Some(spec) => spec,
+        None => return Ok(()),
+    };
+    if !(spec.min_coverage..=spec.max_coverage).contains(&coverage_amount) {
+        return Err(ContractError::InvalidAmount);
+    }
+    if duration_days > spec.max_duration_days {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Issue a policy once the manager is authorized
+fn issue(env: &Env, manager: Address, terms: PolicyTerms, integrator: Option<Address>) -> Result<u64, ContractError> {
+    if is_paused(env) {
+        return Err(ContractError::Paused);
+    }
+    require_monitoring_live(env)?;
+
+    let PolicyTerms { holder, coverage_amount, premium_amount, duration_days, product_id, jurisdiction, region } = terms;
+
+    validate_address(env, &holder)?;
+
+    // Validate coverage amount within bounds
+    validate_coverage_amount(env, coverage_amount)?;
+
+    // Validate premium amount within bounds
+    validate_premium_amount(env, premium_amount)?;
+
+    // Validate duration within bounds
+    validate_duration(duration_days)?;
+    validate_product_terms(env, product_id, coverage_amount, duration_days)?;
+
+    // Utilization cap: callers can join the waiting queue instead
+    if !has_capacity(env, coverage_amount)? {
+        return Err(ContractError::CapacityExceeded);
+    }
+
+    let current_time = env.ledger().timestamp();
+    let policy_id = create_policy(
+        env,
+        Policy::new(holder.clone(), coverage_amount, premium_amount, current_time, term_end(current_time, duration_days)?, current_time, product_id),
+        jurisdiction,
+        region,
+    )?;
+    route_premium(env, &holder, policy_id, premium_amount)?;
+    post_ledger(env, policy_id, LedgerEntry::Premium, premium_amount)?;
+
+    publish_event(
+        env,
+        (Symbol::new(env, "PolicyIssued"), policy_id),
+        (holder, coverage_amount, premium_amount, duration_days, manager, current_time),
+    );
+
+    if let Some(integrator) = integrator {
+        let fee_share = record_integrator_policy(env, &integrator, policy_id, premium_amount)?;
+        post_ledger(env, policy_id, LedgerEntry::Fee, fee_share)?;
+        publish_event(
+            env,
+            (Symbol::new(env, "integrator_policy"), integrator),
+            (policy_id, premium_amount, fee_share),
+        );
+    }
+
+    notify(env, &Symbol::new(env, "policy_issued"), policy_id, coverage_amount);
+
+    Ok(policy_id)
+}
+
+#[contractimpl]
+impl PolicyContract {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
+        // Check if already initialized
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        validate_init_config(&env, &config)?;
+        let InitConfig { admin, risk_pool } = config;
+
+        // Initialize authorization system with admin
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+        
+        // Register risk pool contract as trusted for cross-contract calls
+        register_trusted_contract(&env, &admin, &risk_pool)?;
+        
+        let config = Config { risk_pool };
+        env.storage().instance().set(&DataKey::Config, &config);
+        
+        env.storage()
+            .instance()
+            .set(&DataKey::PolicyCounter, &0u64);
+        
+        set_paused(&env, false);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    /// Issue a policy on `terms`, optionally attributed to `integrator`
+    pub fn issue_policy(env: Env, manager: Address, terms: PolicyTerms, integrator: Option<Address>) -> Result<u64, ContractError> {
+        // Verify identity and require policy management permission
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
+
+        issue(&env, manager, terms, integrator)
+    }
+
+    /// Issue a policy covering a validator or infrastructure node, for
+    /// downtime products. The node counts against the pool's per-node
+    /// capacity (policy management only).
+    pub fn issue_node_policy(env: Env, manager: Address, terms: PolicyTerms, node_id: BytesN<32>) -> Result<u64, ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
 
-        env.events().publish(
-            (Symbol::new(&env, "PolicyIssued"), policy_id),
-            (holder, coverage_amount, premium_amount, duration_days, manager, current_time),
+        let policy_id = issue(&env, manager, terms, None)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PolicyNode(policy_id), &node_id);
+        register_pool_node(&env, policy_id, &node_id)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "node_policy_issued"), policy_id),
+            node_id,
         );
 
         Ok(policy_id)
     }
 
+    /// Node covered by a downtime policy
+    pub fn get_policy_node(env: Env, policy_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PolicyNode(policy_id))
+    }
+
     pub fn get_policy(env: Env, policy_id: u64) -> Result<Policy, ContractError> {
         env.storage()
             .persistent()
@@ -481,6 +1651,106 @@ impl PolicyContract {
             .ok_or(ContractError::NotFound)
     }
 
+    /// Attest whether `holder` is insured under `product_id` for at least
+    /// `min_amount` at `at_time`, for protocols that require cover before
+    /// acting. A policy qualifies if it is active, its term covers
+    /// `at_time` and its remaining coverage meets `min_amount`. Always
+    /// returns an attestation, covered or not, and emits it with a fresh
+    /// nonce so the check can be traced to the transaction that relied on it.
+    pub fn verify_coverage(
+        env: Env,
+        holder: Address,
+        product_id: u32,
+        min_amount: i128,
+        at_time: u64,
+    ) -> Result<CoverageAttestation, ContractError> {
+        if min_amount < 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let held: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HolderPolicies(holder.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        let mut best: Option<(u64, Policy)> = None;
+        let scan_from = held.len().saturating_sub(MAX_COVERAGE_SCAN);
+        for policy_id in held.slice(scan_from..).iter() {
+            let policy: Policy = match env.storage().persistent().get(&DataKey::Policy(policy_id)) {
+                Some(policy) => policy,
+                None => continue,
+            };
+            let qualifies = policy.is_active()
+                && policy.product_id == product_id
+                && policy.start_time <= at_time
+                && at_time < policy.end_time
+                && policy.remaining_coverage >= min_amount;
+            if qualifies && best.as_ref().is_none_or(|(_, current)| policy.end_time > current.end_time) {
+                best = Some((policy_id, policy));
+            }
+        }
+
+        let nonce: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AttestationNonce)
+            .unwrap_or(0u64)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::AttestationNonce, &nonce);
+
+        let attestation = CoverageAttestation {
+            holder: holder.clone(),
+            product_id,
+            min_amount,
+            at_time,
+            covered: best.is_some(),
+            policy_id: best.as_ref().map(|(policy_id, _)| *policy_id),
+            remaining_coverage: best.as_ref().map_or(0, |(_, policy)| policy.remaining_coverage),
+            end_time: best.as_ref().map_or(0, |(_, policy)| policy.end_time),
+            nonce,
+            issued_at: env.ledger().timestamp(),
+        };
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "coverage_attested"), holder),
+            attestation.clone(),
+        );
+
+        Ok(attestation)
+    }
+
+    /// Batch lookup of policies. The result is aligned with `policy_ids`;
+    /// ids with no stored policy yield `None`.
+    pub fn get_policies(env: Env, policy_ids: Vec<u64>) -> Result<Vec<Option<Policy>>, ContractError> {
+        if policy_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut policies = Vec::new(&env);
+        for policy_id in policy_ids.iter() {
+            policies.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::Policy(policy_id)),
+            );
+        }
+        Ok(policies)
+    }
+
+    /// Product the policy was issued under
+    pub fn get_policy_product(env: Env, policy_id: u64) -> Result<u32, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.product_id)
+    }
+
     pub fn get_policy_holder(env: Env, policy_id: u64) -> Result<Address, ContractError> {
         let policy: Policy = env
             .storage()
@@ -526,6 +1796,26 @@ impl PolicyContract {
         Ok((policy.start_time, policy.end_time))
     }
 
+    /// `(holder, remaining_coverage, state, start_time, end_time)`: everything
+    /// the claims contract needs to validate a claim in one call
+    pub fn get_policy_terms(env: Env, policy_id: u64) -> Result<(Address, i128, PolicyState, u64, u64), ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok((policy.holder.clone(), policy.remaining_coverage, policy.state(), policy.start_time, policy.end_time))
+    }
+
+    pub fn get_remaining_coverage(env: Env, policy_id: u64) -> Result<i128, ContractError> {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        Ok(policy.remaining_coverage)
+    }
+
     /// Cancels a policy. Only allowed when the policy is ACTIVE.
     pub fn cancel_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
         require_admin(&env, &actor)?;
@@ -553,15 +1843,34 @@ impl PolicyContract {
 
     pub fn get_config(env: Env) -> Result<Config, ContractError> {
         env.storage()
-            .persistent()
+            .instance()
             .get(&DataKey::Config)
             .ok_or(ContractError::NotInitialized)
     }
 
+    /// Policy configuration, peers, pause state and version in one call
+    pub fn get_protocol_config(env: Env) -> Result<ProtocolConfig, ContractError> {
+        let config: Config = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+
+        Ok(ProtocolConfig {
+            risk_pool: config.risk_pool,
+            premium_token: env.storage().instance().get(&DataKey::PremiumToken),
+            max_utilization_bps: env.storage().instance().get(&DataKey::MaxUtilizationBps),
+            renewal_cap_bps: env.storage().instance().get(&DataKey::RenewalCapBps),
+            wired: wiring::is_wired(&env),
+            paused: is_paused(&env),
+            version: version::get(&env, env!("CARGO_PKG_VERSION")),
+        })
+    }
+
     pub fn get_risk_pool(env: Env) -> Result<Address, ContractError> {
         let config: Config = env
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::Config)
             .ok_or(ContractError::NotInitialized)?;
         Ok(config.risk_pool)
@@ -569,40 +1878,1761 @@ impl PolicyContract {
 
     pub fn get_policy_count(env: Env) -> u64 {
         env.storage()
-            .persistent()
+            .instance()
             .get(&DataKey::PolicyCounter)
             .unwrap_or(0u64)
     }
 
+    /// Policy ids in issuance order, `limit` at a time from `cursor`
+    pub fn list_policies(env: Env, cursor: u32, limit: u32) -> IdPage {
+        let count = Self::get_policy_count(env.clone());
+        pagination::page_of_ids(&env, count, cursor, limit).into()
+    }
+
+    /// Start a snapshot of the policies issued so far (admin only). Export
+    /// it with `export_holder_snapshot`.
+    pub fn start_holder_snapshot(env: Env, admin: Address) -> Result<u64, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let count = Self::get_policy_count(env.clone());
+        let snapshot = snapshot::start(&env, u32::try_from(count).map_err(|_| ContractError::Overflow2)?);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "snapshot_started"), snapshot.id),
+            (snapshot.ledger, snapshot.total),
+        );
+
+        Ok(snapshot.id)
+    }
+
+    /// Emit a `holder_snapshot` event with `(policy_id, holder,
+    /// remaining_coverage)` for each active policy among the next `limit`
+    /// policy ids of the snapshot. Permissionless so keepers can drive the
+    /// export.
+    pub fn export_holder_snapshot(env: Env, snapshot_id: u64, limit: u32) -> Result<Snapshot, ContractError> {
+        let (from, to, snapshot) = snapshot::next_chunk(&env, snapshot_id, limit)?;
+
+        for policy_id in (from as u64 + 1)..=(to as u64) {
+            let policy: Policy = match env.storage().persistent().get(&DataKey::Policy(policy_id)) {
+                Some(policy) => policy,
+                None => continue,
+            };
+            if !policy.is_active() {
+                continue;
+            }
+            publish_event(
+                &env,
+                (Symbol::new(&env, "holder_snapshot"), snapshot_id),
+                (policy_id, policy.holder, policy.remaining_coverage),
+            );
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Latest snapshot and how far its export has progressed
+    pub fn get_snapshot(env: Env) -> Option<Snapshot> {
+        snapshot::get(&env)
+    }
+
+    /// Live policy aggregates
+    pub fn get_stats(env: Env) -> PolicyStats {
+        current_stats(&env)
+    }
+
+    /// Evaluate the policy invariants (I2, I4, I7) against live storage: the
+    /// aggregates and the `MAX_INVARIANT_SCAN` most recently issued
+    /// policies. For monitoring; each violation is published as an
+    /// `invariant_violation` event, so calling it in a transaction records
+    /// what it found.
+    pub fn check_invariants(env: Env) -> InvariantReport {
+        let count = Self::get_policy_count(env.clone());
+
+        let mut valid_states = active_policy_count(&env) <= count;
+        let mut valid_amounts = active_coverage(&env) >= 0;
+        let mut valid_premiums = true;
+
+        for policy_id in (count.saturating_sub(MAX_INVARIANT_SCAN) + 1)..=count {
+            let policy: Policy = match env.storage().persistent().get(&DataKey::Policy(policy_id)) {
+                Some(policy) => policy,
+                None => continue,
+            };
+            valid_states &= policy.start_time < policy.end_time
+                && (policy.state() != PolicyState::CLAIMED || policy.remaining_coverage == 0);
+            valid_amounts &= policy.coverage_amount > 0
+                && policy.remaining_coverage >= 0
+                && policy.remaining_coverage <= policy.coverage_amount;
+            valid_premiums &= !policy.is_active() || policy.premium_amount > 0;
+        }
+
+        let mut report = InvariantReport::new(&env);
+        report.record(Invariant::PolicyStateValidity, valid_states);
+        report.record(Invariant::AmountNonNegativity, valid_amounts);
+        report.record(Invariant::PremiumValidity, valid_premiums);
+        report
+    }
+
+    /// Hash over `(total_issued, active_policies, active_coverage)` as of the
+    /// last checkpoint, in that order.
+    pub fn get_state_root(env: Env) -> Option<StateRoot> {
+        state_root::get(&env)
+    }
+
+    /// Record a checkpoint for the current epoch. Permissionless so keepers can
+    /// fill epochs in which no policy was issued or transitioned.
+    pub fn checkpoint_stats(env: Env) {
+        checkpoint_stats(&env);
+    }
+
+    /// Checkpoints with `from_epoch <= epoch <= to_epoch`, oldest first.
+    /// An epoch is `timestamp / 86400`.
+    pub fn get_stats_history(env: Env, from_epoch: u64, to_epoch: u64) -> Result<Vec<PolicyStatsCheckpoint>, ContractError> {
+        if from_epoch > to_epoch {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let epochs: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatsEpochs)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut history = Vec::new(&env);
+        for epoch in epochs.iter() {
+            if epoch < from_epoch || epoch > to_epoch {
+                continue;
+            }
+            if let Some(checkpoint) = env
+                .storage()
+                .persistent()
+                .get::<_, PolicyStatsCheckpoint>(&DataKey::StatsCheckpoint(epoch))
+            {
+                history.push_back(checkpoint);
+            }
+        }
+        Ok(history)
+    }
+
+    /// Renew a policy for the same holder, coverage and product. The new term
+    /// starts when the old one ends (or now, if already expired). For policies
+    /// with no recorded claims the premium is capped at the previous premium
+    /// plus the governed renewal cap, whatever the pricing quote.
+    pub fn renew_policy(
+        env: Env,
+        manager: Address,
+        policy_id: u64,
+        quoted_premium: i128,
+        duration_days: u32,
+    ) -> Result<u64, ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        require_monitoring_live(&env)?;
+
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        if policy.is_cancelled() {
+            return Err(ContractError::CannotRenewPolicy);
+        }
+        if env.storage().persistent().has(&DataKey::RenewedTo(policy_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        validate_premium_amount(&env, quoted_premium)?;
+        validate_duration(duration_days)?;
+        validate_product_terms(&env, policy.product_id, policy.coverage_amount, duration_days)?;
+
+        let claim_count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PolicyClaims(policy_id))
+            .unwrap_or(0);
+        let cap_bps: Option<u32> = env.storage().instance().get(&DataKey::RenewalCapBps);
+
+        let mut premium_amount = quoted_premium;
+        if let (0, Some(cap_bps)) = (claim_count, cap_bps) {
+            let max_premium = policy
+                .premium_amount
+                .checked_add(bps_of(policy.premium_amount, cap_bps)?)
+                .ok_or(ContractError::Overflow2)?;
+            premium_amount = premium_amount.min(max_premium);
+        }
+
+        if !has_capacity(&env, policy.coverage_amount)? {
+            return Err(ContractError::CapacityExceeded);
+        }
+
+        let start_time = policy.end_time.max(env.ledger().timestamp());
+        let renewed_id = create_policy(
+            &env,
+            Policy::new(policy.holder.clone(), policy.coverage_amount, premium_amount, start_time, term_end(start_time, duration_days)?, env.ledger().timestamp(), policy.product_id),
+            policy_jurisdiction(&env, policy_id),
+            policy_region(&env, policy_id),
+        )?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::RenewedTo(policy_id), &renewed_id);
+        route_premium(&env, &policy.holder, renewed_id, premium_amount)?;
+        post_ledger(&env, renewed_id, LedgerEntry::Premium, premium_amount)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "PolicyRenewed"), policy_id),
+            (renewed_id, quoted_premium, premium_amount, manager),
+        );
+
+        notify(&env, &Symbol::new(&env, "policy_issued"), renewed_id, policy.coverage_amount);
+
+        Ok(renewed_id)
+    }
+
+    /// Move an active policy to another product for the rest of its term.
+    /// `new_term_premium` is the new product's premium for the policy's full
+    /// term; the holder is credited the unearned share of the old premium and
+    /// charged the prorated share of the new one. A net charge is pulled from
+    /// the holder to the risk pool (after applying any stored credit); a net
+    /// refund is kept as premium credit for the holder.
+    pub fn migrate_policy(
+        env: Env,
+        manager: Address,
+        policy_id: u64,
+        new_product_id: u32,
+        new_coverage_amount: i128,
+        new_term_premium: i128,
+    ) -> Result<u64, ContractError> {
+        manager.require_auth();
+        require_policy_management(&env, &manager)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        policy.holder.require_auth();
+
+        let now = env.ledger().timestamp();
+        if !policy.is_active() || now >= policy.end_time {
+            return Err(ContractError::InvalidState);
+        }
+        if new_product_id == policy.product_id {
+            return Err(ContractError::InvalidInput);
+        }
+
+        validate_coverage_amount(&env, new_coverage_amount)?;
+        validate_premium_amount(&env, new_term_premium)?;
+        let remaining_days = (policy.end_time - now).div_ceil(86400) as u32;
+        validate_product_terms(&env, new_product_id, new_coverage_amount, remaining_days)?;
+
+        // Prorate both premiums over the unexpired part of the term, rounding
+        // the refund down and the charge up
+        let term = (policy.end_time - policy.start_time) as i128;
+        let remaining = (policy.end_time - now.max(policy.start_time)) as i128;
+        let credit = mul_div_floor(policy.premium_amount, remaining, term)?;
+        let new_cost = mul_div_ceil(new_term_premium, remaining, term)?;
+
+        let credit_key = DataKey::PremiumCredit(policy.holder.clone());
+        let stored_credit: i128 = env.storage().persistent().get(&credit_key).unwrap_or(0);
+        let due = new_cost
+            .checked_sub(credit)
+            .and_then(|d| d.checked_sub(stored_credit))
+            .ok_or(ContractError::Overflow2)?;
+
+        // Release the old coverage before checking capacity for the new one
+        PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, manager.clone())?;
+        if !has_capacity(&env, new_coverage_amount)? {
+            return Err(ContractError::CapacityExceeded);
+        }
+
+        if due > 0 {
+            let token: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::PremiumToken)
+                .ok_or(ContractError::NotInitialized)?;
+            let config: Config = env
+                .storage()
+                .instance()
+                .get(&DataKey::Config)
+                .ok_or(ContractError::NotInitialized)?;
+            token::Client::new(&env, &token).transfer(&policy.holder, &config.risk_pool, &premium_units(&env, &token, due)?);
+            env.storage().persistent().remove(&credit_key);
+        } else {
+            env.storage().persistent().set(&credit_key, &(-due));
+        }
+
+        let new_policy_id = create_policy(
+            &env,
+            Policy::new(policy.holder.clone(), new_coverage_amount, new_term_premium, now, policy.end_time, now, new_product_id),
+            policy_jurisdiction(&env, policy_id),
+            policy_region(&env, policy_id),
+        )?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::MigratedTo(policy_id), &new_policy_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::MigratedFrom(new_policy_id), &policy_id);
+
+        // Payouts already made under the old product still count against
+        // the migrated policy's coverage
+        let consumed = policy.coverage_amount - policy.remaining_coverage;
+        if consumed > 0 {
+            let mut migrated: Policy = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Policy(new_policy_id))
+                .ok_or(ContractError::NotFound)?;
+            migrated.remaining_coverage = (new_coverage_amount - consumed).max(0);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Policy(new_policy_id), &migrated);
+        }
+        if due > 0 {
+            let config: Config = env
+                .storage()
+                .instance()
+                .get(&DataKey::Config)
+                .ok_or(ContractError::NotInitialized)?;
+            credit_pool_premium(&env, &config, new_policy_id, due)?;
+        }
+
+        // The unearned premium leaves the old policy; the new one is only
+        // credited the prorated premium it was charged
+        post_ledger(&env, policy_id, LedgerEntry::Refund, credit)?;
+        post_ledger(&env, new_policy_id, LedgerEntry::Premium, new_cost)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "PolicyMigrated"), policy_id),
+            (new_policy_id, new_product_id, credit, new_cost, due),
+        );
+
+        notify(&env, &Symbol::new(&env, "policy_issued"), new_policy_id, new_coverage_amount);
+
+        Ok(new_policy_id)
+    }
+
+    /// Premium credit owed to a holder from downgrades
+    pub fn get_premium_credit(env: Env, holder: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PremiumCredit(holder))
+            .unwrap_or(0)
+    }
+
+    /// Apply up to `amount` of a holder's premium credit against a debt they
+    /// owe the protocol (trusted contracts only). Returns the amount applied.
+    pub fn offset_premium_credit(env: Env, caller_contract: Address, holder: Address, amount: i128) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let credit_key = DataKey::PremiumCredit(holder.clone());
+        let credit: i128 = env.storage().persistent().get(&credit_key).unwrap_or(0);
+        let applied = credit.min(amount);
+        if applied <= 0 {
+            return Ok(0);
+        }
+
+        if applied == credit {
+            env.storage().persistent().remove(&credit_key);
+        } else {
+            env.storage().persistent().set(&credit_key, &(credit - applied));
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "premium_credit_offset"), holder),
+            (caller_contract, applied),
+        );
+
+        Ok(applied)
+    }
+
+    /// Links created by migration: `(migrated_from, migrated_to)`
+    pub fn get_migration_links(env: Env, policy_id: u64) -> (Option<u64>, Option<u64>) {
+        (
+            env.storage().persistent().get(&DataKey::MigratedFrom(policy_id)),
+            env.storage().persistent().get(&DataKey::MigratedTo(policy_id)),
+        )
+    }
+
+    /// Maximum renewal premium increase for claim-free policies (governance only)
+    pub fn set_renewal_cap(env: Env, caller: Address, max_increase_bps: u32) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if max_increase_bps > MAX_RENEWAL_INCREASE_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RenewalCapBps, &max_increase_bps);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "renewal_cap_set"), ()),
+            max_increase_bps,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_renewal_cap(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::RenewalCapBps)
+    }
+
+    /// Set or clear the premium levy for a jurisdiction (governance only).
+    /// Applies to premiums routed after the change, including renewals of
+    /// policies already tagged with the jurisdiction.
+    pub fn set_jurisdiction_levy(env: Env, caller: Address, jurisdiction: u32, levy: Option<LevyConfig>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &levy {
+            Some(levy) => {
+                if levy.levy_bps == 0 || levy.levy_bps >= MAX_LEVY_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Levy(jurisdiction), levy);
+            }
+            None => env.storage().persistent().remove(&DataKey::Levy(jurisdiction)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "levy_set"), jurisdiction),
+            levy,
+        );
+
+        Ok(())
+    }
+
+    /// Set or clear the share of premiums, after any levy, paid to the
+    /// treasury (governance only)
+    pub fn set_premium_split(env: Env, caller: Address, split: Option<PremiumSplit>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &split {
+            Some(split) => {
+                if split.treasury_bps == 0 || split.treasury_bps > MAX_TREASURY_SPLIT_BPS {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage().instance().set(&DataKey::PremiumSplit, split);
+            }
+            None => env.storage().instance().remove(&DataKey::PremiumSplit),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "premium_split_set"), caller),
+            split,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_premium_split(env: Env) -> Option<PremiumSplit> {
+        env.storage().instance().get(&DataKey::PremiumSplit)
+    }
+
+    /// Offer a product with coverage and term limits, or change the limits
+    /// of one already offered (governance only). Cover issued, renewed,
+    /// quoted or migrated into the product afterwards must fit them.
+    pub fn set_product(env: Env, caller: Address, spec: ProductSpec) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if spec.min_coverage < MIN_COVERAGE_AMOUNT
+            || spec.min_coverage > spec.max_coverage
+            || spec.max_coverage > MAX_COVERAGE_AMOUNT
+            || !(MIN_POLICY_DURATION_DAYS..=MAX_POLICY_DURATION_DAYS).contains(&spec.max_duration_days)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Product(spec.product_id), &spec);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "product_set"), spec.product_id),
+            (spec.min_coverage, spec.max_coverage, spec.max_duration_days),
+        );
+
+        Ok(())
+    }
+
+    /// Limits of a product, if it has been set up with `set_product`
+    pub fn get_product(env: Env, product_id: u32) -> Option<ProductSpec> {
+        env.storage().persistent().get(&DataKey::Product(product_id))
+    }
+
+    /// Make premium payment mandatory for issuance, or not (governance
+    /// only). Deployments from before premiums were collected issue without
+    /// payment until a premium token is set; once payment is required,
+    /// issuance fails with `PremiumTokenNotSet` while no token is set.
+    pub fn set_premium_required(env: Env, caller: Address, required: bool) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PremiumRequired, &required);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "premium_required_set"), caller),
+            required,
+        );
+
+        Ok(())
+    }
+
+    pub fn is_premium_required(env: Env) -> bool {
+        premium_required(&env)
+    }
+
+    pub fn get_jurisdiction_levy(env: Env, jurisdiction: u32) -> Option<LevyConfig> {
+        env.storage().persistent().get(&DataKey::Levy(jurisdiction))
+    }
+
+    /// Total levy paid to the collector of a jurisdiction
+    pub fn get_levy_collected(env: Env, jurisdiction: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LevyCollected(jurisdiction))
+            .unwrap_or(0)
+    }
+
+    /// Jurisdiction a policy was issued in, if tagged
+    pub fn get_policy_jurisdiction(env: Env, policy_id: u64) -> Option<u32> {
+        policy_jurisdiction(&env, policy_id)
+    }
+
+    /// Region whose capacity a policy counts against, if tagged
+    pub fn get_policy_region(env: Env, policy_id: u64) -> Option<u32> {
+        policy_region(&env, policy_id)
+    }
+
+    /// Set or clear the loss-ratio bands that steer a product's premium
+    /// multiplier (governance only). A multiplier outside new bounds is
+    /// pulled back inside them.
+    pub fn set_pricing_bands(env: Env, caller: Address, product_id: u32, bands: Option<PricingBands>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &bands {
+            Some(bands) => {
+                if bands.target_low_bps > bands.target_high_bps
+                    || bands.trigger_epochs == 0
+                    || bands.step_bps == 0
+                    || bands.min_multiplier_bps == 0
+                    || bands.min_multiplier_bps > BASE_MULTIPLIER_BPS
+                    || bands.max_multiplier_bps < BASE_MULTIPLIER_BPS
+                {
+                    return Err(ContractError::InvalidInput);
+                }
+
+                let mut state = roll_pricing(&env, product_id)?;
+                state.multiplier_bps = state
+                    .multiplier_bps
+                    .clamp(bands.min_multiplier_bps, bands.max_multiplier_bps);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::PricingState(product_id), &state);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::PricingBands(product_id), bands);
+            }
+            None => env.storage().persistent().remove(&DataKey::PricingBands(product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "pricing_bands_set"), product_id),
+            bands,
+        );
+
+        Ok(())
+    }
+
+    /// Roll a product's pricing epoch over. Permissionless so keepers can
+    /// apply repricing for products with no new postings.
+    pub fn roll_product_pricing(env: Env, product_id: u32) -> Result<PricingState, ContractError> {
+        let state = roll_pricing(&env, product_id)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PricingState(product_id), &state);
+        Ok(state)
+    }
+
+    pub fn get_pricing_bands(env: Env, product_id: u32) -> Option<PricingBands> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PricingBands(product_id))
+    }
+
+    pub fn get_pricing_state(env: Env, product_id: u32) -> PricingState {
+        pricing_state(&env, product_id)
+    }
+
+    /// Premiums written and claims paid on a product's policies to date
+    pub fn get_product_stats(env: Env, product_id: u32) -> ProductStats {
+        product_stats(&env, product_id)
+    }
+
+    /// Premiums a holder paid over the year before `as_of`, net of refunds,
+    /// the holder's voting weight when governance runs as a mutual. Only
+    /// holders with an active policy in force at `as_of` carry weight.
+    pub fn get_member_weight(env: Env, holder: Address, as_of: u64) -> i128 {
+        if !is_active_member(&env, &holder, as_of) {
+            return 0;
+        }
+        sum_member_window(&env, as_of, |period| DataKey::MemberPremiums(holder.clone(), period))
+    }
+
+    /// Premiums all holders paid over the year before `as_of`, net of
+    /// refunds. Lapsed members still count here, so quorum is measured
+    /// against everyone who paid in the window.
+    pub fn get_total_member_weight(env: Env, as_of: u64) -> i128 {
+        sum_member_window(&env, as_of, DataKey::PeriodPremiums)
+    }
+
+    /// Policy issued as the renewal of `policy_id`, if any
+    pub fn get_renewal(env: Env, policy_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::RenewedTo(policy_id))
+    }
+
+    /// Register the claims contract so it can record claims against policies (admin only)
+    pub fn register_claims_contract(env: Env, admin: Address, claims_contract: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        register_trusted_contract(&env, &admin, &claims_contract)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claims_contract_registered"), ()),
+            claims_contract,
+        );
+
+        Ok(())
+    }
+
+    /// Verify the risk pool and activate claim recording (admin only). The
+    /// pool must already trust this contract.
+    pub fn finalize_wiring(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let config: Config = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+
+        wiring::verify_peer(&env, &config.risk_pool)?;
+        register_trusted_contract(&env, &admin, &config.risk_pool)?;
+        wiring::set_wired(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "wiring_finalized"), ()),
+            WIRING_VERSION,
+        );
+
+        Ok(())
+    }
+
+    /// Handshake called by a peer's `finalize_wiring`
+    pub fn accept_wiring(env: Env, peer: Address) -> Result<u32, ContractError> {
+        peer.require_auth();
+        require_trusted_contract(&env, &peer)?;
+        Ok(WIRING_VERSION)
+    }
+
+    pub fn is_wired(env: Env) -> bool {
+        wiring::is_wired(&env)
+    }
+
+    /// Record a settled claim and its payout against a policy (trusted
+    /// contracts only). The payout is deducted from the remaining coverage,
+    /// policies with claims lose renewal price protection, and a policy with
+    /// no coverage left moves to CLAIMED.
+    pub fn record_claim(env: Env, caller_contract: Address, policy_id: u64, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+        wiring::require_wired(&env)?;
+
+        if !env.storage().persistent().has(&DataKey::Policy(policy_id)) {
+            return Err(ContractError::NotFound);
+        }
+
+        let key = DataKey::PolicyClaims(policy_id);
+        let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(count + 1));
+        post_ledger(&env, policy_id, LedgerEntry::Claim, amount)?;
+
+        let mut policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Policy(policy_id))
+            .ok_or(ContractError::NotFound)?;
+        policy.remaining_coverage = (policy.remaining_coverage - amount).max(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Policy(policy_id), &policy);
+
+        if policy.remaining_coverage == 0 && policy.state().can_transition_to(PolicyState::CLAIMED) {
+            PolicyStateMachine::transition(&env, policy_id, PolicyState::CLAIMED, caller_contract)?;
+        }
+
+        Ok(())
+    }
+
+    /// Credit interest earned on a policy's premium (trusted contracts only)
+    pub fn record_interest(env: Env, caller_contract: Address, policy_id: u64, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if !env.storage().persistent().has(&DataKey::Policy(policy_id)) {
+            return Err(ContractError::NotFound);
+        }
+
+        post_ledger(&env, policy_id, LedgerEntry::Interest, amount)
+    }
+
+    /// Premiums, fees, refunds, claims and interest booked against a policy
+    pub fn get_policy_ledger(env: Env, policy_id: u64) -> Result<PolicyLedger, ContractError> {
+        if !env.storage().persistent().has(&DataKey::Policy(policy_id)) {
+            return Err(ContractError::NotFound);
+        }
+        Ok(get_policy_ledger(&env, policy_id))
+    }
+
+    pub fn get_policy_claim_count(env: Env, policy_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PolicyClaims(policy_id))
+            .unwrap_or(0)
+    }
+
+    /// Cap total active coverage at `max_utilization_bps` of pool liquidity (admin only)
+    pub fn set_max_utilization(env: Env, admin: Address, max_utilization_bps: u32) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if max_utilization_bps == 0 || max_utilization_bps > MAX_UTILIZATION_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxUtilizationBps, &max_utilization_bps);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "max_utilization_set"), ()),
+            max_utilization_bps,
+        );
+
+        Ok(())
+    }
+
+    /// Token premiums are paid in outside of direct issuance (admin only).
+    /// It must be on the asset allowlist.
+    pub fn set_premium_token(env: Env, admin: Address, token: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        assets::require_allowed(&env, &token)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PremiumToken, &token);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "premium_token_set"), ()),
+            token,
+        );
+
+        Ok(())
+    }
+
+    /// Probe `token` through the SEP-41 interface and allowlist it as a
+    /// settlement asset, recording its decimals (governance only)
+    pub fn allow_asset(env: Env, caller: Address, token: Address) -> Result<AssetInfo, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let info = assets::allow(&env, &token)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_allowed"), token),
+            info.decimals,
+        );
+
+        Ok(info)
+    }
+
+    /// Remove `token` from the asset allowlist (governance only). Settings
+    /// already using it keep working until they are changed.
+    pub fn disallow_asset(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        assets::disallow(&env, &token);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_disallowed"), token),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_asset(env: Env, token: Address) -> Option<AssetInfo> {
+        assets::get_info(&env, &token)
+    }
+
+    pub fn get_active_coverage(env: Env) -> i128 {
+        active_coverage(&env)
+    }
+
+    /// Premium for new cover priced off the risk pool's rate model at the
+    /// utilization the cover would bring the pool to, but never below the
+    /// loaded expected loss of the product. Rounded up.
+    pub fn quote_premium(env: Env, product_id: u32, coverage_amount: i128, duration_days: u32) -> Result<i128, ContractError> {
+        validate_coverage_amount(&env, coverage_amount)?;
+        validate_duration(duration_days)?;
+        validate_product_terms(&env, product_id, coverage_amount, duration_days)?;
+
+        let config: Config = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+        let pool_stats: (i128, i128, i128, u64) = env.invoke_contract(
+            &config.risk_pool,
+            &Symbol::new(&env, "get_pool_stats"),
+            ().into_val(&env),
+        );
+
+        let covered = active_coverage(&env)
+            .checked_add(coverage_amount)
+            .ok_or(ContractError::Overflow2)?;
+        let utilization = rate_model::utilization(covered, pool_stats.0)?;
+        let rate: Decimal = env.invoke_contract(
+            &config.risk_pool,
+            &Symbol::new(&env, "get_rate_at"),
+            (utilization,).into_val(&env),
+        );
+
+        let mut quote = mul_div_ceil(rate.mul_int_ceil(coverage_amount)?, duration_days as i128, 365)?;
+
+        let params: Option<ActuarialParams> = env.invoke_contract(
+            &config.risk_pool,
+            &Symbol::new(&env, "get_actuarial_params"),
+            (product_id,).into_val(&env),
+        );
+        if let Some(params) = params {
+            quote = quote.max(params.premium(coverage_amount, duration_days)?);
+        }
+
+        let multiplier_bps = pricing_state(&env, product_id).multiplier_bps;
+        Ok(mul_div_ceil(quote, multiplier_bps as i128, BASE_MULTIPLIER_BPS as i128)?)
+    }
+
+    /// Lock the current quote for cover for `quote_validity` seconds.
+    /// `issue_policy_with_quote` honors the locked premium until then.
+    pub fn request_quote(env: Env, holder: Address, product_id: u32, coverage_amount: i128, duration_days: u32) -> Result<u64, ContractError> {
+        holder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let premium_amount = Self::quote_premium(env.clone(), product_id, coverage_amount, duration_days)?;
+        validate_premium_amount(&env, premium_amount)?;
+
+        let quote_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::QuoteCounter)
+            .unwrap_or(0u64)
+            + 1;
+        let validity = quote_validity(&env);
+        let quote = LockedQuote {
+            holder: holder.clone(),
+            product_id,
+            coverage_amount,
+            premium_amount,
+            duration_days,
+            expires_at: env.ledger().timestamp() + validity,
+        };
+
+        // Unused quotes lapse from temporary storage by themselves
+        ephemeral::set(&env, &DataKey::Quote(quote_id), &quote, validity);
+        env.storage()
+            .instance()
+            .set(&DataKey::QuoteCounter, &quote_id);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "quote_locked"), quote_id),
+            (holder, coverage_amount, premium_amount, quote.expires_at),
+        );
+
+        Ok(quote_id)
+    }
+
+    /// Issue a policy at a locked quote's premium. Fails once the quote has
+    /// expired or if the pool no longer has capacity for the cover. The
+    /// holder must approve the premium to this contract.
+    pub fn issue_policy_with_quote(env: Env, holder: Address, quote_id: u64) -> Result<u64, ContractError> {
+        holder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        require_monitoring_live(&env)?;
+
+        let quote: LockedQuote = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Quote(quote_id))
+            .ok_or(ContractError::NotFound)?;
+        if quote.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+        let now = env.ledger().timestamp();
+        if now > quote.expires_at {
+            return Err(ContractError::QuoteExpired);
+        }
+        // A locked premium is only honored if it can actually be collected
+        if !env.storage().instance().has(&DataKey::PremiumToken) {
+            return Err(ContractError::InvalidState);
+        }
+        if !has_capacity(&env, quote.coverage_amount)? {
+            return Err(ContractError::CapacityExceeded);
+        }
+
+        env.storage()
+            .temporary()
+            .remove(&DataKey::Quote(quote_id));
+
+        let policy_id = create_policy(
+            &env,
+            Policy::new(holder.clone(), quote.coverage_amount, quote.premium_amount, now, term_end(now, quote.duration_days)?, now, quote.product_id),
+            None,
+            None,
+        )?;
+        route_premium(&env, &holder, policy_id, quote.premium_amount)?;
+        post_ledger(&env, policy_id, LedgerEntry::Premium, quote.premium_amount)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "quote_issued"), quote_id),
+            (policy_id, holder, quote.coverage_amount, quote.premium_amount),
+        );
+
+        notify(&env, &Symbol::new(&env, "policy_issued"), policy_id, quote.coverage_amount);
+
+        Ok(policy_id)
+    }
+
+    /// Locked quote, until it is used or lapses shortly after expiring
+    pub fn get_quote(env: Env, quote_id: u64) -> Option<LockedQuote> {
+        env.storage().temporary().get(&DataKey::Quote(quote_id))
+    }
+
+    /// How long requested quotes stay valid (admin only)
+    pub fn set_quote_validity(env: Env, admin: Address, seconds: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if seconds == 0 || seconds > MAX_QUOTE_VALIDITY_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::QuoteValidity, &seconds);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "quote_validity_set"), ()),
+            seconds,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_quote_validity(env: Env) -> u64 {
+        quote_validity(&env)
+    }
+
+    /// Register or remove a monitoring bot allowed to send heartbeats
+    /// (admin only)
+    pub fn set_monitor(env: Env, admin: Address, monitor: Address, enabled: bool) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if enabled {
+            env.storage().persistent().set(&DataKey::Monitor(monitor.clone()), &true);
+        } else {
+            env.storage().persistent().remove(&DataKey::Monitor(monitor.clone()));
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "monitor_set"), monitor),
+            enabled,
+        );
+
+        Ok(())
+    }
+
+    /// Set how often monitoring bots must check in and whether issuance
+    /// pauses when they stop (admin only); `None` turns liveness off
+    pub fn set_heartbeat_config(
+        env: Env,
+        admin: Address,
+        period_secs: Option<u64>,
+        pause_issuance: bool,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match period_secs {
+            Some(0) => return Err(ContractError::InvalidInput),
+            Some(period_secs) => env.storage().instance().set(
+                &DataKey::HeartbeatConfig,
+                &HeartbeatConfig {
+                    period_secs,
+                    pause_issuance,
+                    configured_at: env.ledger().timestamp(),
+                },
+            ),
+            None => env.storage().instance().remove(&DataKey::HeartbeatConfig),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "heartbeat_config_set"), admin),
+            (period_secs, pause_issuance),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_heartbeat_config(env: Env) -> Option<HeartbeatConfig> {
+        env.storage().instance().get(&DataKey::HeartbeatConfig)
+    }
+
+    /// Attest that off-chain monitoring is alive (registered monitors only)
+    pub fn heartbeat(env: Env, monitor: Address) -> Result<(), ContractError> {
+        monitor.require_auth();
+        if !env.storage().persistent().has(&DataKey::Monitor(monitor.clone())) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let was_stale = monitoring_stale(&env);
+        let now = env.ledger().timestamp();
+        env.storage()
+            .instance()
+            .set(&DataKey::LastHeartbeat, &(monitor.clone(), now));
+
+        if was_stale {
+            publish_event(
+                &env,
+                (Symbol::new(&env, "monitoring_restored"), monitor),
+                now,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Monitor that last checked in and when
+    pub fn get_last_heartbeat(env: Env) -> Option<(Address, u64)> {
+        env.storage().instance().get(&DataKey::LastHeartbeat)
+    }
+
+    /// Whether monitoring has missed its heartbeat period
+    pub fn is_monitoring_stale(env: Env) -> bool {
+        monitoring_stale(&env)
+    }
+
+    /// Queue a coverage request while the pool is at capacity. The quote is
+    /// locked for `QUEUE_QUOTE_VALIDITY_SECONDS`; the holder must approve the
+    /// premium to this contract so it can be pulled at activation.
+    pub fn join_waiting_queue(
+        env: Env,
+        holder: Address,
+        coverage_amount: i128,
+        premium_amount: i128,
+        duration_days: u32,
+        product_id: u32,
+    ) -> Result<u64, ContractError> {
+        holder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_coverage_amount(&env, coverage_amount)?;
+        validate_premium_amount(&env, premium_amount)?;
+        validate_duration(duration_days)?;
+        validate_product_terms(&env, product_id, coverage_amount, duration_days)?;
+
+        let queue_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::QueueTail)
+            .unwrap_or(0u64);
+        let queued_at = env.ledger().timestamp();
+        let entry = QueuedPolicy {
+            holder: holder.clone(),
+            coverage_amount,
+            premium_amount,
+            duration_days,
+            product_id,
+            queued_at,
+            expires_at: queued_at + QUEUE_QUOTE_VALIDITY_SECONDS,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueueEntry(queue_id), &entry);
+        env.storage()
+            .instance()
+            .set(&DataKey::QueueTail, &(queue_id + 1));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "queue_joined"), queue_id),
+            (holder, coverage_amount, premium_amount, entry.expires_at),
+        );
+
+        Ok(queue_id)
+    }
+
+    /// Withdraw a queued request before it is activated
+    pub fn leave_waiting_queue(env: Env, holder: Address, queue_id: u64) -> Result<(), ContractError> {
+        holder.require_auth();
+
+        let entry: QueuedPolicy = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QueueEntry(queue_id))
+            .ok_or(ContractError::NotFound)?;
+        if entry.holder != holder {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QueueEntry(queue_id));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "queue_left"), queue_id),
+            holder,
+        );
+
+        Ok(())
+    }
+
+    /// Activate queued policies in order while capacity allows, dropping
+    /// expired quotes. Stops at the first request that does not fit so
+    /// later, smaller requests cannot jump the queue. Permissionless so any
+    /// keeper can run it; returns the number of policies activated.
+    pub fn process_waiting_queue(env: Env, max_n: u32) -> Result<u32, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let config: Config = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+        let premium_token: Option<Address> = env.storage().instance().get(&DataKey::PremiumToken);
+
+        let mut head: u64 = env.storage().instance().get(&DataKey::QueueHead).unwrap_or(0u64);
+        let tail: u64 = env.storage().instance().get(&DataKey::QueueTail).unwrap_or(0u64);
+        let now = env.ledger().timestamp();
+        let mut activated = 0u32;
+
+        while head < tail && activated < max_n {
+            let entry: QueuedPolicy = match env.storage().persistent().get(&DataKey::QueueEntry(head)) {
+                Some(entry) => entry,
+                None => {
+                    // Left the queue
+                    head += 1;
+                    continue;
+                }
+            };
+
+            if now > entry.expires_at {
+                env.storage().persistent().remove(&DataKey::QueueEntry(head));
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "queue_expired"), head),
+                    entry.holder,
+                );
+                head += 1;
+                continue;
+            }
+
+            if !has_capacity(&env, entry.coverage_amount)? {
+                break;
+            }
+
+            if let Some(token) = premium_token.clone() {
+                token::Client::new(&env, &token).transfer_from(
+                    &env.current_contract_address(),
+                    &entry.holder,
+                    &config.risk_pool,
+                    &premium_units(&env, &token, entry.premium_amount)?,
+                );
+            }
+
+            let policy_id = create_policy(
+                &env,
+                Policy::new(entry.holder.clone(), entry.coverage_amount, entry.premium_amount, now, term_end(now, entry.duration_days)?, now, entry.product_id),
+                None,
+                None,
+            )?;
+            env.storage().persistent().remove(&DataKey::QueueEntry(head));
+            if premium_token.is_some() {
+                credit_pool_premium(&env, &config, policy_id, entry.premium_amount)?;
+            }
+            post_ledger(&env, policy_id, LedgerEntry::Premium, entry.premium_amount)?;
+
+            publish_event(
+                &env,
+                (Symbol::new(&env, "queue_activated"), head),
+                (policy_id, entry.holder.clone(), entry.coverage_amount, entry.premium_amount),
+            );
+            notify(&env, &Symbol::new(&env, "policy_issued"), policy_id, entry.coverage_amount);
+
+            activated += 1;
+            head += 1;
+        }
+
+        env.storage().instance().set(&DataKey::QueueHead, &head);
+
+        Ok(activated)
+    }
+
+    pub fn get_queue_entry(env: Env, queue_id: u64) -> Result<QueuedPolicy, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::QueueEntry(queue_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Queue ids not yet processed, as `(head, tail)`; live entries lie in `head..tail`
+    pub fn get_queue_bounds(env: Env) -> (u64, u64) {
+        (
+            env.storage().instance().get(&DataKey::QueueHead).unwrap_or(0u64),
+            env.storage().instance().get(&DataKey::QueueTail).unwrap_or(0u64),
+        )
+    }
+
+    /// Configure the capacity auction, or `None` to stop taking bids (admin
+    /// only). Rounds already bid on still settle.
+    pub fn set_auction_config(env: Env, admin: Address, config: Option<AuctionConfig>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match config.clone() {
+            Some(config) => {
+                if config.round_secs < MIN_AUCTION_ROUND_SECONDS
+                    || config.round_secs > MAX_AUCTION_ROUND_SECONDS
+                    || config.max_bids == 0
+                    || config.max_bids > MAX_AUCTION_BIDS
+                {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AuctionConfig, &config);
+            }
+            None => env.storage().instance().remove(&DataKey::AuctionConfig),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "auction_config_set"), ()),
+            config,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_auction_config(env: Env) -> Option<AuctionConfig> {
+        env.storage().instance().get(&DataKey::AuctionConfig)
+    }
+
+    /// Bid for cover in the current auction round, offering at least the
+    /// base quote. The premium is escrowed in this contract: it goes to the
+    /// pool if the bid wins capacity and back to the bidder if it loses.
+    pub fn bid_for_cover(
+        env: Env,
+        bidder: Address,
+        product_id: u32,
+        coverage_amount: i128,
+        duration_days: u32,
+        premium_amount: i128,
+    ) -> Result<u64, ContractError> {
+        bidder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        require_monitoring_live(&env)?;
+
+        let config: AuctionConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::AuctionConfig)
+            .ok_or(ContractError::InvalidState)?;
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PremiumToken)
+            .ok_or(ContractError::InvalidState)?;
+
+        validate_premium_amount(&env, premium_amount)?;
+        let base = Self::quote_premium(env.clone(), product_id, coverage_amount, duration_days)?;
+        if premium_amount < base {
+            return Err(ContractError::InvalidPremium);
+        }
+
+        let now = env.ledger().timestamp();
+        let round_id = now / config.round_secs;
+        let mut round: AuctionRound = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionRound(round_id))
+            .unwrap_or(AuctionRound {
+                ends_at: (round_id + 1) * config.round_secs,
+                bids: Vec::new(&env),
+            });
+        if round.bids.len() >= config.max_bids {
+            return Err(ContractError::AuctionFull);
+        }
+
+        token::Client::new(&env, &token).transfer(&bidder, env.current_contract_address(), &premium_units(&env, &token, premium_amount)?);
+
+        let bid_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AuctionBidCounter)
+            .unwrap_or(0u64)
+            + 1;
+        let bid = AuctionBid {
+            bidder: bidder.clone(),
+            product_id,
+            coverage_amount,
+            duration_days,
+            premium_amount,
+            round: round_id,
+        };
+        round.bids.push_back(bid_id);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionBid(bid_id), &bid);
+        env.storage()
+            .instance()
+            .set(&DataKey::AuctionBidCounter, &bid_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionRound(round_id), &round);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "auction_bid"), round_id),
+            (bid_id, bidder, coverage_amount, premium_amount),
+        );
+
+        Ok(bid_id)
+    }
+
+    /// Settle an ended auction round. Bids are taken from the highest
+    /// premium per unit of coverage per day down, each winning if the pool
+    /// still has capacity for it; winners are issued their policy at their
+    /// bid and the rest are refunded. Permissionless so any keeper can run
+    /// it; returns the number of policies issued.
+    pub fn settle_auction(env: Env, round_id: u64) -> Result<u32, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let round: AuctionRound = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionRound(round_id))
+            .ok_or(ContractError::NotFound)?;
+        let now = env.ledger().timestamp();
+        if now < round.ends_at {
+            return Err(ContractError::InvalidState);
+        }
+
+        let config: Config = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(ContractError::NotInitialized)?;
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PremiumToken)
+            .ok_or(ContractError::InvalidState)?;
+        let decimals = assets::decimals(&env, &token);
+        let token = token::Client::new(&env, &token);
+
+        // Highest rate first; equal rates keep bidding order
+        let mut ranked: Vec<(i128, u64)> = Vec::new(&env);
+        for bid_id in round.bids.iter() {
+            let bid: AuctionBid = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AuctionBid(bid_id))
+                .ok_or(ContractError::NotFound)?;
+            let rate = bid_rate(&bid)?;
+            let position = ranked
+                .iter()
+                .position(|(ranked_rate, _)| ranked_rate < rate)
+                .unwrap_or(ranked.len() as usize);
+            ranked.insert(position as u32, (rate, bid_id));
+        }
+
+        let mut winners = 0u32;
+        let mut clearing_rate: Option<i128> = None;
+        for (rate, bid_id) in ranked.iter() {
+            let bid: AuctionBid = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AuctionBid(bid_id))
+                .ok_or(ContractError::NotFound)?;
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AuctionBid(bid_id));
+
+            if !has_capacity(&env, bid.coverage_amount)? {
+                let escrowed = units::to_native_ceil(bid.premium_amount, decimals)?;
+                token.transfer(&env.current_contract_address(), &bid.bidder, &escrowed);
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "auction_refunded"), bid_id),
+                    (bid.bidder, bid.premium_amount),
+                );
+                continue;
+            }
+
+            let policy_id = create_policy(
+                &env,
+                Policy::new(bid.bidder.clone(), bid.coverage_amount, bid.premium_amount, now, term_end(now, bid.duration_days)?, now, bid.product_id),
+                None,
+                None,
+            )?;
+            let escrowed = units::to_native_ceil(bid.premium_amount, decimals)?;
+            token.transfer(&env.current_contract_address(), &config.risk_pool, &escrowed);
+            credit_pool_premium(&env, &config, policy_id, bid.premium_amount)?;
+            post_ledger(&env, policy_id, LedgerEntry::Premium, bid.premium_amount)?;
+
+            publish_event(
+                &env,
+                (Symbol::new(&env, "auction_won"), bid_id),
+                (policy_id, bid.bidder, bid.coverage_amount, bid.premium_amount),
+            );
+            notify(&env, &Symbol::new(&env, "policy_issued"), policy_id, bid.coverage_amount);
+
+            winners += 1;
+            clearing_rate = Some(rate);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AuctionRound(round_id));
+
+        // The lowest winning rate clears the round
+        publish_event(
+            &env,
+            (Symbol::new(&env, "auction_cleared"), round_id),
+            (winners, round.bids.len() - winners, clearing_rate),
+        );
+
+        Ok(winners)
+    }
+
+    pub fn get_auction_round(env: Env, round_id: u64) -> Option<AuctionRound> {
+        env.storage().persistent().get(&DataKey::AuctionRound(round_id))
+    }
+
+    pub fn get_auction_bid(env: Env, bid_id: u64) -> Option<AuctionBid> {
+        env.storage().persistent().get(&DataKey::AuctionBid(bid_id))
+    }
+
+    /// Register the caller as an integrator with the default quota and no fee share
+    pub fn register_integrator(env: Env, integrator: Address) -> Result<(), ContractError> {
+        integrator.require_auth();
+
+        let key = DataKey::Integrator(integrator.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let record = Integrator {
+            daily_quota: DEFAULT_INTEGRATOR_DAILY_QUOTA,
+            fee_share_bps: 0,
+            active: true,
+            total_policies: 0,
+            fee_share_accrued: 0,
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &record);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "integrator_registered"), integrator),
+            DEFAULT_INTEGRATOR_DAILY_QUOTA,
+        );
+
+        Ok(())
+    }
+
+    /// Set an integrator's daily quota and fee share (governance only)
+    pub fn set_integrator_limits(
+        env: Env,
+        caller: Address,
+        integrator: Address,
+        daily_quota: u32,
+        fee_share_bps: u32,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if fee_share_bps > MAX_FEE_SHARE_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut record = get_integrator(&env, &integrator)?;
+        record.daily_quota = daily_quota;
+        record.fee_share_bps = fee_share_bps;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Integrator(integrator.clone()), &record);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "integrator_limits"), integrator),
+            (daily_quota, fee_share_bps),
+        );
+
+        Ok(())
+    }
+
+    /// Enable or disable an integrator (governance only)
+    pub fn set_integrator_active(
+        env: Env,
+        caller: Address,
+        integrator: Address,
+        active: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut record = get_integrator(&env, &integrator)?;
+        record.active = active;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Integrator(integrator.clone()), &record);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "integrator_active"), integrator),
+            active,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_integrator(env: Env, integrator: Address) -> Result<Integrator, ContractError> {
+        get_integrator(&env, &integrator)
+    }
+
+    /// Policies issued through an integrator on the current day
+    pub fn get_integrator_usage(env: Env, integrator: Address) -> u32 {
+        let day = env.ledger().timestamp() / 86400;
+        env.storage()
+            .persistent()
+            .get(&DataKey::IntegratorUsage(integrator, day))
+            .unwrap_or(0)
+    }
+
+    /// Integrator a policy was attributed to, if any
+    pub fn get_policy_integrator(env: Env, policy_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PolicyIntegrator(policy_id))
+    }
+
+    /// Register a listener contract for an event topic (admin only)
+    pub fn register_listener(env: Env, admin: Address, topic: Symbol, listener: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        add_listener(&env, &topic, &listener)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "listener_registered"), listener),
+            topic,
+        );
+
+        Ok(())
+    }
+
+    /// Remove a listener contract from an event topic (admin only)
+    pub fn unregister_listener(env: Env, admin: Address, topic: Symbol, listener: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        remove_listener(&env, &topic, &listener)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "listener_unregistered"), listener),
+            topic,
+        );
+
+        Ok(())
+    }
+
+    /// Listener contracts registered for an event topic
+    pub fn get_listeners(env: Env, topic: Symbol) -> Vec<Address> {
+        get_listeners(&env, &topic)
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["policy_v1", IFACE_VERSION, IFACE_WIRING, IFACE_LISTENERS, IFACE_SNAPSHOT])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Move the hot configuration, counters and aggregates of a contract
+    /// upgraded from a release that kept them in persistent storage (admin
+    /// only). Run right after the upgrade: until then they read as unset.
+    /// Safe to repeat; returns how many items were moved.
+    pub fn migrate_hot_storage(env: Env, admin: Address) -> Result<u32, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let mut moved = hot_storage::migrate_shared(&env);
+        for key in HOT_KEYS.iter() {
+            if hot_storage::migrate(&env, key) {
+                moved += 1;
+            }
+        }
+        hot_storage::extend(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "hot_storage_migrated"), ()),
+            moved,
+        );
+
+        Ok(moved)
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
     pub fn is_paused(env: Env) -> bool {
         is_paused(&env)
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+    /// Pause the contract (admin only), optionally citing the incident it
+    /// responds to
+    pub fn pause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
         
         set_paused(&env, true);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "paused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+    pub fn unpause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
         
         set_paused(&env, false);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "unpaused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
@@ -615,7 +3645,8 @@ impl PolicyContract {
         
         insurance_contracts::authorization::grant_role(&env, &admin, &manager, Role::PolicyManager)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_granted"), manager.clone()),
             admin,
         );
@@ -630,7 +3661,8 @@ impl PolicyContract {
         
         insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_revoked"), manager.clone()),
             admin,
         );
@@ -638,6 +3670,109 @@ impl PolicyContract {
         Ok(())
     }
     
+    /// Open an incident affecting `components` (governance only); pauses
+    /// and unpauses can cite the returned id
+    pub fn open_incident(env: Env, caller: Address, components: Vec<Symbol>) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident_id = incident::open(&env, &caller, components.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_opened"), incident_id),
+            (caller, components),
+        );
+
+        Ok(incident_id)
+    }
+
+    /// Record the end of an incident (governance only)
+    pub fn close_incident(env: Env, caller: Address, incident_id: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident = incident::close(&env, incident_id)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_closed"), incident_id),
+            (caller, incident.ended_at - incident.started_at),
+        );
+
+        Ok(())
+    }
+
+    /// Anchor the hash of a closed incident's postmortem (governance only)
+    pub fn anchor_postmortem(env: Env, caller: Address, incident_id: u64, postmortem_hash: BytesN<32>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        incident::anchor_postmortem(&env, incident_id, postmortem_hash.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "postmortem_anchored"), incident_id),
+            postmortem_hash,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_incident(env: Env, incident_id: u64) -> Option<Incident> {
+        incident::get(&env, incident_id)
+    }
+
+    pub fn get_incident_count(env: Env) -> u64 {
+        incident::count(&env)
+    }
+
+    /// Let the governance contract call admin and governance setters, or
+    /// stop it with `None` (admin only)
+    pub fn set_governance_contract(env: Env, admin: Address, governance: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::set_governance_contract(&env, &admin, governance.as_ref())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "governance_contract_set"), admin),
+            governance,
+        );
+
+        Ok(())
+    }
+
+    /// Governance contract holding admin powers, if any
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        insurance_contracts::authorization::get_governance_contract(&env)
+    }
+
+    /// Schedule the one-way handover to governance (admin only). From
+    /// `sunset_at` the admin's powers end and only the governance contract
+    /// can make admin calls; the time can be brought forward, never pushed
+    /// back.
+    pub fn schedule_admin_sunset(env: Env, admin: Address, sunset_at: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::schedule_admin_sunset(&env, &admin, sunset_at)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "admin_sunset_scheduled"), admin),
+            insurance_contracts::authorization::get_admin_sunset(&env),
+        );
+
+        Ok(())
+    }
+
+    /// When the admin's powers end, if a handover is scheduled
+    pub fn get_admin_sunset(env: Env) -> Option<u64> {
+        insurance_contracts::authorization::get_admin_sunset(&env)
+    }
+
     /// Get the role of an address
     pub fn get_user_role(env: Env, address: Address) -> Role {
         get_role(&env, &address)
@@ -647,65 +3782,89 @@ impl PolicyContract {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::{Address as _, Env as _};
+    use soroban_sdk::symbol_short;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    const COVERAGE: i128 = MIN_COVERAGE_AMOUNT + 1000;
+    const PREMIUM: i128 = MIN_PREMIUM_AMOUNT + 100;
+
+    /// Registered policy contract with an admin and a policy manager
+    fn setup(env: &Env) -> (PolicyContractClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        let client = PolicyContractClient::new(env, &env.register(PolicyContract, ()));
+        let admin = Address::generate(env);
+        let manager = Address::generate(env);
+
+        client.initialize(&InitConfig { admin: admin.clone(), risk_pool: Address::generate(env) });
+        client.grant_manager_role(&admin, &manager);
+
+        (client, admin, manager)
+    }
+
+    fn terms(holder: &Address, coverage: i128, premium: i128, duration: u32) -> PolicyTerms {
+        PolicyTerms {
+            holder: holder.clone(),
+            coverage_amount: coverage,
+            premium_amount: premium,
+            duration_days: duration,
+            product_id: 0,
+            jurisdiction: None,
+            region: None,
+        }
+    }
+
+    fn issue(
+        client: &PolicyContractClient,
+        manager: &Address,
+        holder: &Address,
+        coverage: i128,
+        premium: i128,
+        duration: u32,
+    ) -> Result<u64, ContractError> {
+        match client.try_issue_policy(manager, &terms(holder, coverage, premium, duration), &None) {
+            Ok(Ok(policy_id)) => Ok(policy_id),
+            Err(Ok(error)) => Err(error),
+            _ => panic!("issue_policy did not return a contract result"),
+        }
+    }
 
     #[test]
     fn test_valid_policy_issuance() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
-
-        // Initialize contract
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-
-        // Grant manager role
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
 
         // Issue policy
-        let coverage = MIN_COVERAGE_AMOUNT + 1000;
-        let premium = MIN_PREMIUM_AMOUNT + 100;
-        let duration = 30;
-
-        let policy_id = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
 
         assert_eq!(policy_id, 1);
 
         // Verify policy
-        let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+        let policy = client.get_policy(&policy_id);
         assert_eq!(policy.holder, holder);
-        assert_eq!(policy.coverage_amount, coverage);
-        assert_eq!(policy.premium_amount, premium);
+        assert_eq!(policy.coverage_amount, COVERAGE);
+        assert_eq!(policy.premium_amount, PREMIUM);
+        assert_eq!(policy.remaining_coverage, COVERAGE);
         assert_eq!(policy.state(), PolicyState::ACTIVE);
     }
 
     #[test]
-    fn test_invalid_coverage_too_low() {
+    fn test_issuance_requires_manager_role() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, _manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        let result = issue(&client, &holder, &holder, COVERAGE, PREMIUM, 30);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+    }
 
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MIN_COVERAGE_AMOUNT - 1,
-            MIN_PREMIUM_AMOUNT + 100,
-            30,
-        );
+    #[test]
+    fn test_invalid_coverage_too_low() {
+        let env = Env::default();
+        let (client, _admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+
+        let result = issue(&client, &manager, &holder, MIN_COVERAGE_AMOUNT - 1, PREMIUM, 30);
 
         assert_eq!(result, Err(ContractError::InvalidAmount));
     }
@@ -713,22 +3872,10 @@ mod tests {
     #[test]
     fn test_invalid_coverage_too_high() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MAX_COVERAGE_AMOUNT + 1,
-            MIN_PREMIUM_AMOUNT + 100,
-            30,
-        );
+        let result = issue(&client, &manager, &holder, MAX_COVERAGE_AMOUNT + 1, PREMIUM, 30);
 
         assert_eq!(result, Err(ContractError::InvalidAmount));
     }
@@ -736,22 +3883,10 @@ mod tests {
     #[test]
     fn test_invalid_premium_too_low() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MIN_COVERAGE_AMOUNT + 1000,
-            MIN_PREMIUM_AMOUNT - 1,
-            30,
-        );
+        let result = issue(&client, &manager, &holder, COVERAGE, MIN_PREMIUM_AMOUNT - 1, 30);
 
         assert_eq!(result, Err(ContractError::InvalidPremium));
     }
@@ -759,22 +3894,10 @@ mod tests {
     #[test]
     fn test_invalid_premium_too_high() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MIN_COVERAGE_AMOUNT + 1000,
-            MAX_PREMIUM_AMOUNT + 1,
-            30,
-        );
+        let result = issue(&client, &manager, &holder, COVERAGE, MAX_PREMIUM_AMOUNT + 1, 30);
 
         assert_eq!(result, Err(ContractError::InvalidPremium));
     }
@@ -782,22 +3905,10 @@ mod tests {
     #[test]
     fn test_invalid_duration_too_short() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MIN_COVERAGE_AMOUNT + 1000,
-            MIN_PREMIUM_AMOUNT + 100,
-            MIN_POLICY_DURATION_DAYS - 1,
-        );
+        let result = issue(&client, &manager, &holder, COVERAGE, PREMIUM, MIN_POLICY_DURATION_DAYS - 1);
 
         assert_eq!(result, Err(ContractError::InvalidInput));
     }
@@ -805,22 +3916,10 @@ mod tests {
     #[test]
     fn test_invalid_duration_too_long() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let result = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            MIN_COVERAGE_AMOUNT + 1000,
-            MIN_PREMIUM_AMOUNT + 100,
-            MAX_POLICY_DURATION_DAYS + 1,
-        );
+        let result = issue(&client, &manager, &holder, COVERAGE, PREMIUM, MAX_POLICY_DURATION_DAYS + 1);
 
         assert_eq!(result, Err(ContractError::InvalidInput));
     }
@@ -830,35 +3929,11 @@ mod tests {
         // Since policy IDs are unique via counter, duplicate issuance isn't possible
         // This test ensures the counter increments properly
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, _admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
-
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let coverage = MIN_COVERAGE_AMOUNT + 1000;
-        let premium = MIN_PREMIUM_AMOUNT + 100;
-        let duration = 30;
-
-        let policy_id1 = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
-
-        let policy_id2 = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
+
+        let policy_id1 = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        let policy_id2 = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
 
         assert_eq!(policy_id1, 1);
         assert_eq!(policy_id2, 2);
@@ -866,102 +3941,676 @@ mod tests {
     }
 
     #[test]
-    fn test_state_machine_valid_transitions() {
+    fn test_get_policies_batch() {
+        let env = Env::default();
+        let (client, _admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+
+        let mut ids = Vec::new(&env);
+        ids.push_back(policy_id);
+        ids.push_back(999);
+
+        let policies = client.get_policies(&ids);
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies.get(0).unwrap().unwrap().holder, holder);
+        assert_eq!(policies.get(1).unwrap(), None);
+
+        let mut too_many = Vec::new(&env);
+        for i in 0..(MAX_BATCH_SIZE as u64 + 1) {
+            too_many.push_back(i);
+        }
+        let result = client.try_get_policies(&too_many);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    }
+
+    #[test]
+    fn test_member_weight_window_refunds_and_lapse() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+        let period = MEMBER_PERIOD_SECONDS;
+
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, MAX_POLICY_DURATION_DAYS).unwrap();
+
+        // The period a premium is paid in only counts once it is complete,
+        // and it drops out after the twelve periods that follow
+        assert_eq!(client.get_member_weight(&holder, &(period - 1)), 0);
+        assert_eq!(client.get_member_weight(&holder, &period), PREMIUM);
+        assert_eq!(client.get_member_weight(&holder, &(MEMBER_WINDOW_PERIODS * period)), PREMIUM);
+        assert_eq!(client.get_total_member_weight(&(MEMBER_WINDOW_PERIODS * period)), PREMIUM);
+        assert_eq!(client.get_total_member_weight(&((MEMBER_WINDOW_PERIODS + 1) * period)), 0);
+
+        // Migrating refunds the unearned premium, so the holder is weighted
+        // by what the old and new cover actually cost
+        env.ledger().set_timestamp(6 * period);
+        let migrated = client.migrate_policy(&manager, &policy_id, &1, &COVERAGE, &MIN_PREMIUM_AMOUNT);
+        let refunded = client.get_policy_ledger(&policy_id).refunds_issued;
+        let charged = client.get_policy_ledger(&migrated).premiums_received;
+        assert!(refunded > 0 && charged > 0);
+        let net = PREMIUM - refunded + charged;
+        assert_eq!(client.get_member_weight(&holder, &(7 * period)), net);
+        assert_eq!(client.get_total_member_weight(&(7 * period)), net);
+
+        // A holder whose cover lapsed has no vote, though the premium still
+        // counts towards the total
+        client.cancel_policy(&admin, &migrated);
+        assert_eq!(client.get_member_weight(&holder, &(7 * period)), 0);
+        assert_eq!(client.get_total_member_weight(&(7 * period)), net);
+    }
+
+    #[test]
+    fn test_product_limits_bound_new_cover() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+        let spec = ProductSpec {
+            product_id: 3,
+            min_coverage: COVERAGE,
+            max_coverage: COVERAGE * 2,
+            max_duration_days: 90,
+        };
+
+        assert_eq!(client.try_set_product(&holder, &spec), Err(Ok(ContractError::Unauthorized)));
+        let inverted = ProductSpec { min_coverage: COVERAGE * 3, ..spec.clone() };
+        assert_eq!(client.try_set_product(&admin, &inverted), Err(Ok(ContractError::InvalidInput)));
+        let too_long = ProductSpec { max_duration_days: MAX_POLICY_DURATION_DAYS + 1, ..spec.clone() };
+        assert_eq!(client.try_set_product(&admin, &too_long), Err(Ok(ContractError::InvalidInput)));
+
+        client.set_product(&admin, &spec);
+        assert_eq!(client.get_product(&3), Some(spec));
+
+        let product_terms = |coverage, duration| PolicyTerms { product_id: 3, ..terms(&holder, coverage, PREMIUM, duration) };
+        let result = client.try_issue_policy(&manager, &product_terms(COVERAGE - 1, 30), &None);
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+        let result = client.try_issue_policy(&manager, &product_terms(COVERAGE, 91), &None);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+        client.issue_policy(&manager, &product_terms(COVERAGE * 2, 90), &None);
+
+        // Products without limits keep the protocol-wide bounds
+        assert!(issue(&client, &manager, &holder, COVERAGE * 3, PREMIUM, 365).is_ok());
+    }
+
+    /// Risk pool stand-in recording the premium credited per policy
+    #[contract]
+    struct PremiumPool;
+
+    #[contractimpl]
+    impl PremiumPool {
+        pub fn receive_premium(env: Env, _policy_contract: Address, policy_id: u64, amount: i128) {
+            env.storage().instance().set(&policy_id, &amount);
+        }
+
+        pub fn credited(env: Env, policy_id: u64) -> Option<i128> {
+            env.storage().instance().get(&policy_id)
+        }
+    }
+
+    #[test]
+    fn test_verify_coverage_attestation() {
+        let env = Env::default();
+        // The holder authorizes the premium transfer below the manager's call
+        env.mock_all_auths_allowing_non_root_auth();
+        let client = PolicyContractClient::new(&env, &env.register(PolicyContract, ()));
+        let pool = PremiumPoolClient::new(&env, &env.register(PremiumPool, ()));
+        let admin = Address::generate(&env);
+        let manager = Address::generate(&env);
+        let holder = Address::generate(&env);
+
+        client.initialize(&InitConfig { admin: admin.clone(), risk_pool: pool.address.clone() });
+        client.grant_manager_role(&admin, &manager);
+
+        // Premiums are paid in a real token so issuance routes them to the pool
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        token::StellarAssetClient::new(&env, &token).mint(&holder, &PREMIUM);
+        client.allow_asset(&admin, &token);
+        client.set_premium_token(&admin, &token);
+
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&holder), 0);
+        assert_eq!(token_client.balance(&pool.address), PREMIUM);
+        assert_eq!(pool.credited(&policy_id), Some(PREMIUM));
+
+        let attestation = client.verify_coverage(&holder, &0, &COVERAGE, &86_400);
+        assert!(attestation.covered);
+        assert_eq!(attestation.policy_id, Some(policy_id));
+        assert_eq!(attestation.remaining_coverage, COVERAGE);
+        assert_eq!(attestation.nonce, 1);
+
+        // Too much coverage asked for, another product, or after the term
+        let too_much = client.verify_coverage(&holder, &0, &(COVERAGE + 1), &86_400);
+        assert!(!too_much.covered);
+        assert_eq!(too_much.policy_id, None);
+        assert_eq!(too_much.nonce, 2);
+        assert!(!client.verify_coverage(&holder, &1, &0, &86_400).covered);
+        assert!(!client.verify_coverage(&holder, &0, &0, &(31 * 86_400)).covered);
+
+        // A holder who cannot pay gets no cover to attest
+        let unfunded = Address::generate(&env);
+        let result = issue(&client, &manager, &unfunded, COVERAGE, PREMIUM, 30);
+        assert_eq!(result, Err(ContractError::PremiumTransferFailed));
+        assert!(!client.verify_coverage(&unfunded, &0, &0, &86_400).covered);
+
+        // Cancelled cover no longer attests
+        client.cancel_policy(&admin, &policy_id);
+        assert!(!client.verify_coverage(&holder, &0, &0, &86_400).covered);
+    }
+
+    #[test]
+    fn test_premium_split_bound_and_required_payment() {
         let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let client = PolicyContractClient::new(&env, &env.register(PolicyContract, ()));
+        let pool = PremiumPoolClient::new(&env, &env.register(PremiumPool, ()));
         let admin = Address::generate(&env);
         let manager = Address::generate(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        client.initialize(&InitConfig { admin: admin.clone(), risk_pool: pool.address.clone() });
+        client.grant_manager_role(&admin, &manager);
+
+        let split = |treasury_bps| Some(PremiumSplit { treasury: treasury.clone(), treasury_bps });
+        assert_eq!(client.try_set_premium_split(&admin, &split(0)), Err(Ok(ContractError::InvalidInput)));
+        assert_eq!(
+            client.try_set_premium_split(&admin, &split(MAX_TREASURY_SPLIT_BPS + 1)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+        client.set_premium_split(&admin, &split(MAX_TREASURY_SPLIT_BPS));
+
+        // Without a premium token, cover is issued unpaid until payment is required
+        let unpaid = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        assert_eq!(pool.credited(&unpaid), None);
+
+        client.set_premium_required(&admin, &true);
+        assert!(client.is_premium_required());
+        let result = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30);
+        assert_eq!(result, Err(ContractError::PremiumTokenNotSet));
+
+        // With a token the treasury takes its share and the pool the rest
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        token::StellarAssetClient::new(&env, &token).mint(&holder, &PREMIUM);
+        client.allow_asset(&admin, &token);
+        client.set_premium_token(&admin, &token);
+
+        let paid = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        let treasury_share = PREMIUM * MAX_TREASURY_SPLIT_BPS as i128 / 10_000;
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&treasury), treasury_share);
+        assert_eq!(token_client.balance(&pool.address), PREMIUM - treasury_share);
+        assert_eq!(pool.credited(&paid), Some(PREMIUM - treasury_share));
+    }
+
+    /// Risk pool stand-in for underwriting: reports the liquidity it was
+    /// given at a flat 5% rate, prices the products it holds parameters for,
+    /// records premium credited and refuses coverage beyond a region's cap
+    #[contract]
+    struct UnderwritingPool;
+
+    #[contractimpl]
+    impl UnderwritingPool {
+        pub fn accept_wiring(_env: Env, _peer: Address) -> u32 {
+            WIRING_VERSION
+        }
+
+        pub fn set_liquidity(env: Env, amount: i128) {
+            env.storage().instance().set(&symbol_short!("LIQ"), &amount);
+        }
+
+        pub fn set_params(env: Env, product_id: u32, params: ActuarialParams) {
+            env.storage().instance().set(&(symbol_short!("PARAMS"), product_id), &params);
+        }
+
+        pub fn set_region_cap(env: Env, region: u32, cap: i128) {
+            env.storage().instance().set(&(symbol_short!("CAP"), region), &cap);
+        }
+
+        pub fn get_pool_stats(env: Env) -> (i128, i128, i128, u64) {
+            let liquidity: i128 = env.storage().instance().get(&symbol_short!("LIQ")).unwrap_or(0);
+            (liquidity, 0, 0, 0)
+        }
+
+        pub fn get_rate_at(_env: Env, _utilization: Decimal) -> Decimal {
+            Decimal(Decimal::ONE.0 / 20)
+        }
+
+        pub fn get_actuarial_params(env: Env, product_id: u32) -> Option<ActuarialParams> {
+            env.storage().instance().get(&(symbol_short!("PARAMS"), product_id))
+        }
+
+        pub fn receive_premium(env: Env, _policy_contract: Address, policy_id: u64, amount: i128) {
+            env.storage().instance().set(&policy_id, &amount);
+        }
+
+        pub fn register_coverage(
+            env: Env,
+            _policy_contract: Address,
+            _policy_id: u64,
+            _product_id: u32,
+            coverage_amount: i128,
+            _expiry: u64,
+            region: Option<u32>,
+        ) {
+            if let Some(region) = region {
+                let exposure = Self::region_exposure(env.clone(), region) + coverage_amount;
+                let cap: Option<i128> = env.storage().instance().get(&(symbol_short!("CAP"), region));
+                if cap.is_some_and(|cap| exposure > cap) {
+                    panic!("region capacity exceeded");
+                }
+                env.storage().instance().set(&(symbol_short!("RGN"), region), &exposure);
+            }
+        }
+
+        pub fn region_exposure(env: Env, region: u32) -> i128 {
+            env.storage().instance().get(&(symbol_short!("RGN"), region)).unwrap_or(0)
+        }
+
+        pub fn credited(env: Env, policy_id: u64) -> Option<i128> {
+            env.storage().instance().get(&policy_id)
+        }
+    }
+
+    /// Policy contract backed by an underwriting pool holding ample liquidity
+    fn underwritten(env: &Env) -> (PolicyContractClient<'_>, UnderwritingPoolClient<'_>, Address, Address) {
+        env.mock_all_auths_allowing_non_root_auth();
+        let client = PolicyContractClient::new(env, &env.register(PolicyContract, ()));
+        let pool = UnderwritingPoolClient::new(env, &env.register(UnderwritingPool, ()));
+        let admin = Address::generate(env);
+        let manager = Address::generate(env);
+
+        client.initialize(&InitConfig { admin: admin.clone(), risk_pool: pool.address.clone() });
+        client.grant_manager_role(&admin, &manager);
+        pool.set_liquidity(&(COVERAGE * 1_000));
+
+        (client, pool, admin, manager)
+    }
+
+    #[test]
+    fn test_quote_never_below_loaded_expected_loss() {
+        let env = Env::default();
+        let (client, pool, _admin, _manager) = underwritten(&env);
+
+        // 5% a year of the cover, rounded up
+        let rate_quote = client.quote_premium(&0, &COVERAGE, &365);
+        assert_eq!(rate_quote, 50_050);
+
+        // A 10% expected loss with a 20% margin costs more than the rate
+        pool.set_params(&0, &ActuarialParams { expected_loss_bps: 1_000, risk_margin_bps: 2_000 });
+        assert_eq!(client.quote_premium(&0, &COVERAGE, &365), 120_120);
+        assert_eq!(client.quote_premium(&0, &COVERAGE, &30), 9_873);
+        assert_eq!(client.quote_premium(&1, &COVERAGE, &365), rate_quote);
+
+        // A cheaper floor leaves the rate quote standing
+        pool.set_params(&0, &ActuarialParams { expected_loss_bps: 100, risk_margin_bps: 0 });
+        assert_eq!(client.quote_premium(&0, &COVERAGE, &365), rate_quote);
+    }
+
+    #[test]
+    fn test_region_is_registered_with_the_pool_once_wired() {
+        let env = Env::default();
+        let (client, pool, admin, manager) = underwritten(&env);
+        let holder = Address::generate(&env);
+        let regional = PolicyTerms { region: Some(4), ..terms(&holder, COVERAGE, PREMIUM, 30) };
+
+        // Before wiring nothing is registered with the pool
+        let early = client.issue_policy(&manager, &regional, &None);
+        assert_eq!(client.get_policy_region(&early), Some(4));
+        assert_eq!(pool.region_exposure(&4), 0);
+
+        client.finalize_wiring(&admin);
+        pool.set_region_cap(&4, &(COVERAGE * 2));
+        client.issue_policy(&manager, &regional, &None);
+        client.issue_policy(&manager, &regional, &None);
+        assert_eq!(pool.region_exposure(&4), COVERAGE * 2);
+
+        // The pool's capacity check refuses the policy outright
+        assert!(client.try_issue_policy(&manager, &regional, &None).is_err());
+        assert_eq!(client.get_policy_count(), 3);
+        issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+    }
+
+    #[test]
+    fn test_loss_ratio_bands_reprice_product() {
+        let env = Env::default();
+        let (client, _pool, admin, manager) = underwritten(&env);
+        let claims = Address::generate(&env);
+        let holder = Address::generate(&env);
+        client.register_claims_contract(&admin, &claims);
+        client.finalize_wiring(&admin);
+
+        let bands = PricingBands {
+            target_low_bps: 2_000,
+            target_high_bps: 6_000,
+            trigger_epochs: 2,
+            step_bps: 1_000,
+            min_multiplier_bps: 8_000,
+            max_multiplier_bps: 12_000,
+        };
+        assert_eq!(
+            client.try_set_pricing_bands(&manager, &0, &Some(bands.clone())),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        for invalid in [
+            PricingBands { target_low_bps: 7_000, ..bands.clone() },
+            PricingBands { trigger_epochs: 0, ..bands.clone() },
+            PricingBands { min_multiplier_bps: BASE_MULTIPLIER_BPS + 1, ..bands.clone() },
+            PricingBands { max_multiplier_bps: BASE_MULTIPLIER_BPS - 1, ..bands.clone() },
+        ] {
+            assert_eq!(
+                client.try_set_pricing_bands(&admin, &0, &Some(invalid)),
+                Err(Ok(ContractError::InvalidInput))
+            );
+        }
+        client.set_pricing_bands(&admin, &0, &Some(bands.clone()));
+        let base_quote = client.quote_premium(&0, &COVERAGE, &365);
+
+        // Two epochs paying out as much as they took in raise the price
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        client.record_claim(&claims, &policy_id, &PREMIUM);
+        env.ledger().set_timestamp(STATS_EPOCH_SECONDS);
+        let state = client.roll_product_pricing(&0);
+        assert_eq!((state.epochs_above, state.multiplier_bps), (1, BASE_MULTIPLIER_BPS));
+
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        client.record_claim(&claims, &policy_id, &PREMIUM);
+        env.ledger().set_timestamp(2 * STATS_EPOCH_SECONDS);
+        assert_eq!(client.roll_product_pricing(&0).multiplier_bps, 11_000);
+        assert_eq!(client.quote_premium(&0, &COVERAGE, &365), (base_quote * 11 + 9) / 10);
+
+        // Narrower bands pull the multiplier inside them
+        client.set_pricing_bands(&admin, &0, &Some(PricingBands { max_multiplier_bps: 10_500, ..bands }));
+        assert_eq!(client.get_pricing_state(&0).multiplier_bps, 10_500);
+
+        // Claim-free epochs lower it again; an idle epoch does not count
+        issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        env.ledger().set_timestamp(4 * STATS_EPOCH_SECONDS);
+        assert_eq!(client.roll_product_pricing(&0).epochs_below, 1);
+        issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        env.ledger().set_timestamp(5 * STATS_EPOCH_SECONDS);
+        assert_eq!(client.roll_product_pricing(&0).multiplier_bps, 9_500);
+
+        assert_eq!(client.get_product_stats(&0), ProductStats { premiums: 4 * PREMIUM, claims: 2 * PREMIUM });
+    }
+
+    #[test]
+    fn test_capacity_auction_ranks_bids_and_refunds_losers() {
+        let env = Env::default();
+        let (client, pool, admin, manager) = underwritten(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let token_client = token::Client::new(&env, &token);
+        let bidders = [Address::generate(&env), Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        for bidder in bidders.iter() {
+            token::StellarAssetClient::new(&env, &token).mint(bidder, &(3 * PREMIUM));
+        }
+        client.allow_asset(&admin, &token);
+        client.set_premium_token(&admin, &token);
+
+        let config = AuctionConfig { round_secs: 3_600, max_bids: 3 };
+        assert_eq!(
+            client.try_bid_for_cover(&bidders[0], &0, &COVERAGE, &30, &PREMIUM),
+            Err(Ok(ContractError::InvalidState))
+        );
+        assert_eq!(
+            client.try_set_auction_config(&manager, &Some(config.clone())),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        for invalid in [
+            AuctionConfig { round_secs: MIN_AUCTION_ROUND_SECONDS - 1, ..config.clone() },
+            AuctionConfig { max_bids: 0, ..config.clone() },
+            AuctionConfig { max_bids: MAX_AUCTION_BIDS + 1, ..config.clone() },
+        ] {
+            assert_eq!(
+                client.try_set_auction_config(&admin, &Some(invalid)),
+                Err(Ok(ContractError::InvalidInput))
+            );
+        }
+        client.set_auction_config(&admin, &Some(config));
+
+        // Capacity for two of the three bids
+        pool.set_liquidity(&(COVERAGE * 2));
+        client.set_max_utilization(&admin, &MAX_UTILIZATION_BPS);
+        assert_eq!(
+            client.try_bid_for_cover(&bidders[0], &0, &COVERAGE, &30, &(MIN_PREMIUM_AMOUNT - 1)),
+            Err(Ok(ContractError::InvalidPremium))
+        );
+        for (bidder, premium) in bidders.iter().zip([PREMIUM, 2 * PREMIUM, 3 * PREMIUM]) {
+            client.bid_for_cover(bidder, &0, &COVERAGE, &30, &premium);
+        }
+        assert_eq!(
+            client.try_bid_for_cover(&bidders[3], &0, &COVERAGE, &30, &PREMIUM),
+            Err(Ok(ContractError::AuctionFull))
+        );
+        assert_eq!(token_client.balance(&client.address), 6 * PREMIUM);
+        assert_eq!(client.try_settle_auction(&0), Err(Ok(ContractError::InvalidState)));
+
+        // The highest rates win at their own bids; the lowest is refunded
+        env.ledger().set_timestamp(3_600);
+        assert_eq!(client.settle_auction(&0), 2);
+        assert_eq!(client.get_policy(&1).holder, bidders[2]);
+        assert_eq!(client.get_policy(&2).premium_amount, 2 * PREMIUM);
+        assert_eq!(pool.credited(&1), Some(3 * PREMIUM));
+        assert_eq!(token_client.balance(&bidders[0]), 3 * PREMIUM);
+        assert_eq!(token_client.balance(&pool.address), 5 * PREMIUM);
+        assert_eq!(token_client.balance(&client.address), 0);
+        assert_eq!(client.get_active_coverage(), COVERAGE * 2);
+
+        assert_eq!(client.get_auction_bid(&1), None);
+        assert_eq!(client.try_settle_auction(&0), Err(Ok(ContractError::NotFound)));
+    }
+
+    #[test]
+    fn test_stats_checkpoints_per_epoch() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+
+        let first = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+        issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
+
+        env.ledger().set_timestamp(STATS_EPOCH_SECONDS + 10);
+        client.cancel_policy(&admin, &first);
+
+        let stats = client.get_stats();
+        assert_eq!(stats.total_issued, 2);
+        assert_eq!(stats.active_policies, 1);
+
+        let history = client.get_stats_history(&0, &1);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().stats.active_policies, 2);
+        assert_eq!(history.get(1).unwrap().stats.active_policies, 1);
+
+        let history = client.get_stats_history(&1, &1);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().epoch, 1);
+    }
+
+    #[test]
+    fn test_integrator_quota_and_attribution() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+        let integrator = Address::generate(&env);
+
+        client.register_integrator(&integrator);
+        client.set_integrator_limits(&admin, &integrator, &1, &500);
+
+        let coverage = COVERAGE;
+        let premium = MIN_PREMIUM_AMOUNT;
+        let via_integrator = Some(integrator.clone());
+
+        let policy_id = client.issue_policy(&manager, &terms(&holder, coverage, premium, 30), &via_integrator);
+        assert_eq!(client.get_policy_integrator(&policy_id), Some(integrator.clone()));
+
+        let record = client.get_integrator(&integrator);
+        assert_eq!(record.total_policies, 1);
+        assert_eq!(record.fee_share_accrued, premium * 500 / 10_000);
+
+        let ledger = client.get_policy_ledger(&policy_id);
+        assert_eq!(ledger.premiums_received, premium);
+        assert_eq!(ledger.fees_deducted, premium * 500 / 10_000);
+        assert_eq!(ledger.balance, premium - premium * 500 / 10_000);
+
+        let result = client.try_issue_policy(&manager, &terms(&holder, coverage, premium, 30), &via_integrator);
+        assert_eq!(result, Err(Ok(ContractError::IntegratorQuotaExceeded)));
+
+        client.set_integrator_active(&admin, &integrator, &false);
+        client.set_integrator_limits(&admin, &integrator, &10, &500);
+        let result = client.try_issue_policy(&manager, &terms(&holder, coverage, premium, 30), &via_integrator);
+        assert_eq!(result, Err(Ok(ContractError::IntegratorInactive)));
+    }
+
+    #[test]
+    fn test_waiting_queue_activation_and_expiry() {
+        let env = Env::default();
+        let (client, _admin, _manager) = setup(&env);
+        let holder = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let first = client.join_waiting_queue(&holder, &COVERAGE, &PREMIUM, &30, &0);
+        let second = client.join_waiting_queue(&other, &COVERAGE, &PREMIUM, &30, &0);
+        let third = client.join_waiting_queue(&holder, &COVERAGE, &PREMIUM, &30, &0);
+
+        assert_eq!(
+            client.try_leave_waiting_queue(&holder, &second),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        client.leave_waiting_queue(&other, &second);
+
+        // No utilization cap configured, so the first live entry activates
+        assert_eq!(client.process_waiting_queue(&1), 1);
+        assert_eq!(client.try_get_queue_entry(&first), Err(Ok(ContractError::NotFound)));
+        assert_eq!(client.get_active_coverage(), COVERAGE);
+
+        env.ledger().set_timestamp(QUEUE_QUOTE_VALIDITY_SECONDS + 1);
+        assert_eq!(client.process_waiting_queue(&10), 0);
+        assert_eq!(client.try_get_queue_entry(&third), Err(Ok(ContractError::NotFound)));
+        assert_eq!(client.get_queue_bounds(), (3, 3));
+        assert_eq!(client.get_policy_count(), 1);
+    }
+
+    #[test]
+    fn test_stale_heartbeat_pauses_issuance() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
+        let monitor = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        client.set_monitor(&admin, &monitor, &true);
+        assert_eq!(
+            client.try_set_heartbeat_config(&admin, &Some(0), &true),
+            Err(Ok(ContractError::InvalidInput))
+        );
+        client.set_heartbeat_config(&admin, &Some(3_600), &true);
+
+        assert_eq!(client.try_heartbeat(&holder), Err(Ok(ContractError::Unauthorized)));
+        env.ledger().set_timestamp(1_000);
+        client.heartbeat(&monitor);
+        assert_eq!(client.get_last_heartbeat(), Some((monitor.clone(), 1_000)));
+
+        env.ledger().set_timestamp(1_000 + 3_601);
+        assert!(client.is_monitoring_stale());
+        let result = issue(&client, &manager, &holder, COVERAGE, MIN_PREMIUM_AMOUNT, 30);
+        assert_eq!(result, Err(ContractError::MonitoringStale));
+
+        client.heartbeat(&monitor);
+        assert!(!client.is_monitoring_stale());
+        issue(&client, &manager, &holder, COVERAGE, MIN_PREMIUM_AMOUNT, 30).unwrap();
+
+        // Alert-only mode keeps issuing while stale; a disabled monitor is refused
+        env.ledger().set_timestamp(1_000 + 2 * 3_601);
+        client.set_heartbeat_config(&admin, &Some(3_600), &false);
+        env.ledger().set_timestamp(1_000 + 3 * 3_601);
+        assert!(client.is_monitoring_stale());
+        issue(&client, &manager, &holder, COVERAGE, MIN_PREMIUM_AMOUNT, 30).unwrap();
+
+        client.set_monitor(&admin, &monitor, &false);
+        assert_eq!(client.try_heartbeat(&monitor), Err(Ok(ContractError::Unauthorized)));
+
+        // Removing the config turns monitoring off entirely
+        client.set_heartbeat_config(&admin, &None, &true);
+        assert!(!client.is_monitoring_stale());
+    }
 
-        let coverage = MIN_COVERAGE_AMOUNT + 1000;
-        let premium = MIN_PREMIUM_AMOUNT + 100;
-        let duration = 30;
+    #[test]
+    fn test_state_machine_valid_transitions() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let holder = Address::generate(&env);
 
-        let policy_id = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
 
         // Test ACTIVE -> CANCELLED
-        PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone()).unwrap();
-        let policy = PolicyContract::get_policy(env.clone(), policy_id).unwrap();
+        env.as_contract(&client.address, || {
+            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone()).unwrap();
+        });
+        let policy = client.get_policy(&policy_id);
         assert_eq!(policy.state(), PolicyState::CANCELLED);
 
         // Check history
-        let history = PolicyStateMachine::get_policy_history(&env, policy_id);
+        let history = env.as_contract(&client.address, || PolicyStateMachine::get_policy_history(&env, policy_id));
         assert_eq!(history.len(), 1);
-        assert_eq!(history[0].previous_state, PolicyState::ACTIVE);
-        assert_eq!(history[0].new_state, PolicyState::CANCELLED);
+        assert_eq!(history.get(0).unwrap().previous_state, PolicyState::ACTIVE);
+        assert_eq!(history.get(0).unwrap().new_state, PolicyState::CANCELLED);
     }
 
     #[test]
     fn test_state_machine_invalid_transitions() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
 
-        let coverage = MIN_COVERAGE_AMOUNT + 1000;
-        let premium = MIN_PREMIUM_AMOUNT + 100;
-        let duration = 30;
+        env.as_contract(&client.address, || {
+            // Transition to CANCELLED
+            PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone()).unwrap();
 
-        let policy_id = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
-
-        // Transition to CANCELLED
-        PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, admin.clone()).unwrap();
-
-        // Try invalid transition from CANCELLED to EXPIRED
-        let result = PolicyStateMachine::transition(&env, policy_id, PolicyState::EXPIRED, admin.clone());
-        assert_eq!(result, Err(ContractError::InvalidStateTransition));
+            // Try invalid transition from CANCELLED to EXPIRED
+            let result = PolicyStateMachine::transition(&env, policy_id, PolicyState::EXPIRED, admin.clone());
+            assert_eq!(result, Err(ContractError::InvalidStateTransition));
+        });
     }
 
     #[test]
     fn test_state_based_access_control() {
         let env = Env::default();
-        let admin = Address::generate(&env);
-        let manager = Address::generate(&env);
+        let (client, admin, manager) = setup(&env);
         let holder = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
 
-        PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-        PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
-
-        let coverage = MIN_COVERAGE_AMOUNT + 1000;
-        let premium = MIN_PREMIUM_AMOUNT + 100;
-        let duration = 30;
-
-        let policy_id = PolicyContract::issue_policy(
-            env.clone(),
-            manager.clone(),
-            holder.clone(),
-            coverage,
-            premium,
-            duration,
-        ).unwrap();
+        let policy_id = issue(&client, &manager, &holder, COVERAGE, PREMIUM, 30).unwrap();
 
         // Cancel the policy
-        PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id).unwrap();
+        client.cancel_policy(&admin, &policy_id);
 
         // Try to cancel again - should fail due to state
-        let result = PolicyContract::cancel_policy(env.clone(), admin.clone(), policy_id);
-        assert_eq!(result, Err(ContractError::InvalidStateTransition));
+        let result = client.try_cancel_policy(&admin, &policy_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidStateTransition)));
+    }
+
+    #[test]
+    fn test_governance_contract_holds_admin_powers_until_cleared() {
+        let env = Env::default();
+        let (client, admin, manager) = setup(&env);
+        let governance = Address::generate(&env);
+
+        assert_eq!(
+            client.try_set_governance_contract(&manager, &Some(governance.clone())),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(client.try_set_renewal_cap(&governance, &500), Err(Ok(ContractError::Unauthorized)));
+
+        client.set_governance_contract(&admin, &Some(governance.clone()));
+        assert_eq!(client.get_governance_contract(), Some(governance.clone()));
+        client.set_max_utilization(&governance, &8_000);
+        client.set_renewal_cap(&governance, &500);
+        assert_eq!(client.get_renewal_cap(), Some(500));
+
+        // Clearing it takes the powers back
+        client.set_governance_contract(&admin, &None);
+        assert_eq!(client.get_governance_contract(), None);
+        assert_eq!(client.try_set_renewal_cap(&governance, &600), Err(Ok(ContractError::Unauthorized)));
+        assert_eq!(client.try_set_max_utilization(&governance, &9_000), Err(Ok(ContractError::Unauthorized)));
     }
 }
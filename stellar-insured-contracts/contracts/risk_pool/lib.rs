@@ -1,24 +1,418 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol};
+use soroban_sdk::{contract, contractclient, contractimpl, contracterror, contracttype, token, Address, BytesN, Env, Symbol, symbol_short, Vec};
 
 // Import authorization from the common library
+use insurance_contracts::assets::{self, AssetError, AssetInfo};
 use insurance_contracts::authorization::{
-    initialize_admin, require_admin, require_risk_pool_management,
+    initialize_admin, require_admin, require_governance_permission, require_risk_pool_management,
     require_trusted_contract, register_trusted_contract, Role, get_role
 };
+use insurance_contracts::actuarial::{ActuarialChange, ActuarialParams};
+use insurance_contracts::allowlist::{self, AllowlistError};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::hot_storage;
+use insurance_contracts::incident::{self, Incident, IncidentError};
+use insurance_contracts::decimal::Decimal;
+use insurance_contracts::math::{bps_of, bps_of_ceil, mul_div_ceil, mul_div_floor, MathError};
+use insurance_contracts::pagination::{self, AddressPage};
+use insurance_contracts::rate_model::{self, InterestRateModel, RateModel};
+use insurance_contracts::rescue::{self, RescueError};
+use insurance_contracts::rewards::RewardStream;
+use insurance_contracts::snapshot::{self, Snapshot, SnapshotError};
+use insurance_contracts::state_root::{self, StateRoot};
+use insurance_contracts::units;
+use insurance_contracts::version::{self, ContractVersion, IFACE_ALLOWLIST, IFACE_RESCUE, IFACE_SNAPSHOT, IFACE_VERSION, IFACE_WIRING};
+use insurance_contracts::wiring::{self, WiringError, WIRING_VERSION};
 
 // Import invariant checks and error types
-use insurance_invariants::{InvariantError, ProtocolInvariants};
+use insurance_invariants::{
+    assert_liquidity_sufficient, assert_reserve_adequate, assert_valid_amount, Invariant, InvariantError,
+    InvariantReport,
+};
 
 #[contract]
 pub struct RiskPoolContract;
 
-const PAUSED: Symbol = Symbol::short("PAUSED");
-const CONFIG: Symbol = Symbol::short("CONFIG");
-const POOL_STATS: Symbol = Symbol::short("POOL_ST");
-const PROVIDER: Symbol = Symbol::short("PROVIDER");
-const RESERVED_TOTAL: Symbol = Symbol::short("RSV_TOT");
-const CLAIM_RESERVATION: Symbol = Symbol::short("CLM_RSV");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const POOL_STATS: Symbol = symbol_short!("POOL_ST");
+const CLAIMS_CONTRACT: Symbol = symbol_short!("CLAIMS");
+const PROVIDER: Symbol = symbol_short!("PROVIDER");
+const RESERVED_TOTAL: Symbol = symbol_short!("RSV_TOT");
+const CLAIM_RESERVATION: Symbol = symbol_short!("CLM_RSV");
+const STATS_CHECKPOINT: Symbol = symbol_short!("STATS_CP");
+const STATS_EPOCHS: Symbol = symbol_short!("STATS_EP");
+const TOTAL_STAKE: Symbol = symbol_short!("TOT_STK");
+const REWARD_INDEX: Symbol = symbol_short!("RWD_IDX");
+const REWARD_STREAM: Symbol = symbol_short!("RWD_STRM");
+const REWARDS: Symbol = symbol_short!("REWARDS");
+const TRANSFER_HOOK: Symbol = symbol_short!("XFER_HOOK");
+const STRESS_MODE: Symbol = symbol_short!("STRESS");
+const IOU: Symbol = symbol_short!("IOU");
+const IOU_TOTAL: Symbol = symbol_short!("IOU_TOT");
+const IOU_ENTRY: Symbol = symbol_short!("IOU_ENT");
+const IOU_HEAD: Symbol = symbol_short!("IOU_HEAD");
+const IOU_TAIL: Symbol = symbol_short!("IOU_TAIL");
+const MANAGER_LIMITS: Symbol = symbol_short!("MGR_LIM");
+const MANAGER_USAGE: Symbol = symbol_short!("MGR_USE");
+const RATE_MODEL: Symbol = symbol_short!("RATE_MDL");
+const WITHDRAWAL_RECEIPT: Symbol = symbol_short!("WD_RCPT");
+const WITHDRAWAL_COUNTER: Symbol = symbol_short!("WD_CNT");
+const WITHDRAWAL_PENDING: Symbol = symbol_short!("WD_PEND");
+const LAST_DEPOSIT: Symbol = symbol_short!("LAST_DEP");
+const DEPOSIT_LOCK: Symbol = symbol_short!("DEP_LOCK");
+const WITHDRAWAL_HEAD: Symbol = symbol_short!("WD_HEAD");
+const WITHDRAWAL_FILLED: Symbol = symbol_short!("WD_FILL");
+const WITHDRAWAL_QUEUE_START: Symbol = symbol_short!("WD_QSTART");
+const WITHDRAWAL_QUEUED_TOTAL: Symbol = symbol_short!("WD_QTOT");
+const WITHDRAWAL_FILLED_TOTAL: Symbol = symbol_short!("WD_FTOT");
+const WITHDRAWAL_COOLDOWN: Symbol = symbol_short!("WD_COOL");
+const PROVIDER_LIST: Symbol = symbol_short!("PROV_LIST");
+const RESERVATION_LIST: Symbol = symbol_short!("RSV_LIST");
+const PREMIUM_INCOME: Symbol = symbol_short!("PREM_INC");
+const POLICY_PREMIUM: Symbol = symbol_short!("POL_PREM");
+const COVERAGE: Symbol = symbol_short!("COVERAGE");
+const COVERAGE_TOTAL: Symbol = symbol_short!("COV_TOT");
+const PREMIUM_RESERVE: Symbol = symbol_short!("PREM_RSV");
+const UNEARNED_TOTAL: Symbol = symbol_short!("UNEARNED");
+const COVERAGE_REGION: Symbol = symbol_short!("COV_RGN");
+const REGION_EXPOSURE: Symbol = symbol_short!("RGN_EXP");
+const REGION_CAP: Symbol = symbol_short!("RGN_CAP");
+const PRODUCT_ASSET: Symbol = symbol_short!("PROD_AST");
+const COVERAGE_ASSET: Symbol = symbol_short!("COV_AST");
+const ASSET_EXPOSURE: Symbol = symbol_short!("AST_EXP");
+const ASSET_CAP: Symbol = symbol_short!("AST_CAP");
+const COVERAGE_NODE: Symbol = symbol_short!("COV_NODE");
+const NODE_EXPOSURE: Symbol = symbol_short!("NODE_EXP");
+const NODE_CAP: Symbol = symbol_short!("NODE_CAP");
+const PRODUCT_GROUP: Symbol = symbol_short!("PROD_GRP");
+const COVERAGE_GROUP: Symbol = symbol_short!("COV_GRP");
+const GROUP_EXPOSURE: Symbol = symbol_short!("GRP_EXP");
+const GROUP_LIMIT: Symbol = symbol_short!("GRP_LIM");
+const ACTUARIAL: Symbol = symbol_short!("ACTUARL");
+const ACTUARIAL_HISTORY: Symbol = symbol_short!("ACT_HIST");
+const EXPECTED_LOSS: Symbol = symbol_short!("EXP_LOSS");
+const EXPECTED_LOSS_TOTAL: Symbol = symbol_short!("EXP_LTOT");
+const FEES_CHARGED: Symbol = symbol_short!("FEE_CHRG");
+const FEES_OWED: Symbol = symbol_short!("FEE_OWED");
+const PROTOCOL_DUST: Symbol = symbol_short!("DUST");
+const LIQUIDITY_BUFFER: Symbol = symbol_short!("LIQ_BUF");
+const COVERAGE_PRODUCT: Symbol = symbol_short!("COV_PROD");
+const PRODUCT_EXPOSURE: Symbol = symbol_short!("PROD_EXP");
+const PRODUCT_RISK: Symbol = symbol_short!("PROD_RISK");
+const RISK_ORDER: Symbol = symbol_short!("RISK_ORD");
+const RISK_ORDER_COUNTER: Symbol = symbol_short!("RISK_OCNT");
+const RISK_SLICE: Symbol = symbol_short!("RISK_SLC");
+const RISK_SLICE_COUNTER: Symbol = symbol_short!("RISK_SCNT");
+const RISK_LOCKED: Symbol = symbol_short!("RISK_LOCK");
+const RISK_CEDED: Symbol = symbol_short!("RISK_CEDE");
+const CLAIM_ROUTE: Symbol = symbol_short!("CLM_ROUTE");
+const PROVIDER_LOCK: Symbol = symbol_short!("PROV_LOCK");
+const REWARDS_HELD: Symbol = symbol_short!("RWD_HELD");
+
+// Small, frequently read items kept in instance storage; moved out of
+// persistent storage by `migrate_hot_storage`
+const HOT_KEYS: [Symbol; 31] = [
+    PAUSED,
+    CONFIG,
+    POOL_STATS,
+    CLAIMS_CONTRACT,
+    RESERVED_TOTAL,
+    TOTAL_STAKE,
+    REWARD_INDEX,
+    REWARD_STREAM,
+    TRANSFER_HOOK,
+    STRESS_MODE,
+    IOU_TOTAL,
+    IOU_HEAD,
+    IOU_TAIL,
+    RATE_MODEL,
+    WITHDRAWAL_COUNTER,
+    WITHDRAWAL_PENDING,
+    DEPOSIT_LOCK,
+    WITHDRAWAL_HEAD,
+    WITHDRAWAL_QUEUED_TOTAL,
+    WITHDRAWAL_FILLED_TOTAL,
+    WITHDRAWAL_COOLDOWN,
+    PREMIUM_INCOME,
+    COVERAGE_TOTAL,
+    UNEARNED_TOTAL,
+    EXPECTED_LOSS_TOTAL,
+    FEES_CHARGED,
+    FEES_OWED,
+    PROTOCOL_DUST,
+    LIQUIDITY_BUFFER,
+    RISK_ORDER_COUNTER,
+    RISK_SLICE_COUNTER,
+];
+
+const MAX_HAIRCUT_BPS: u32 = 10_000;
+const MAX_GROUP_SHARE_BPS: u32 = 10_000;
+const MAX_BUFFER_BPS: u32 = 10_000;
+
+// Time between requesting a withdrawal and redeeming its receipt
+const DEFAULT_WITHDRAWAL_COOLDOWN_SECONDS: u64 = 7 * 86_400;
+const MAX_WITHDRAWAL_COOLDOWN_SECONDS: u64 = 90 * 86_400;
+
+// Manager payout limits: usage is bucketed per day, the weekly window
+// covers the current day and the six before it
+const PAYOUT_WINDOW_SECONDS: u64 = 86_400;
+const WEEKLY_WINDOW_DAYS: u64 = 7;
+
+// Bound on IOU entries settled by a single amortization pass
+const MAX_IOU_AMORTIZATIONS: u32 = 20;
+
+// Ledgers after a deposit during which the provider's shares cannot leave;
+// one blocks depositing and withdrawing in the same ledger
+const DEFAULT_DEPOSIT_LOCK_LEDGERS: u32 = 1;
+const MAX_DEPOSIT_LOCK_LEDGERS: u32 = 17_280;
+
+// Bound on withdrawal receipts filled by a single pass over the exit queue
+const MAX_EXIT_FILLS: u32 = 20;
+
+// Distributed rewards are streamed to providers over this period
+const REWARD_STREAM_SECONDS: u64 = 7 * 86_400;
+
+// Rounding always favors the pool: what leaves it (withdrawable stake,
+// rewards, the part of a payout paid now) rounds down, what stays or is
+// owed to it rounds up. Amounts rounding leaves unallocated accrue to the
+// protocol dust bucket instead of being lost.
+
+// Risk transfer between providers: fixed-point scale of the per-product
+// premium and loss indexes, and the terms a slice may run for
+const RISK_INDEX_SCALE: i128 = 1_000_000_000_000;
+const MIN_RISK_TERM_SECONDS: u64 = 86_400;
+const MAX_RISK_TERM_SECONDS: u64 = 365 * 86_400;
+
+// Bound on policies accrued by a single `accrue_premiums` call
+const MAX_ACCRUAL_BATCH: u32 = 50;
+
+// Statistics checkpointing: one checkpoint per epoch, bounded history
+const STATS_EPOCH_SECONDS: u64 = 86_400;
+const MAX_STATS_HISTORY: u32 = 365;
+
+// Parameter changes retained per product for audit
+const MAX_ACTUARIAL_HISTORY: u32 = 50;
+
+/// Pool aggregates as of the last write in an epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolStatsCheckpoint {
+    pub epoch: u64,
+    pub timestamp: u64,
+    pub total_liquidity: i128,
+    pub reserved_total: i128,
+    pub total_paid_out: i128,
+    pub total_deposited: i128,
+}
+
+/// Payout haircuts applied while governance has the pool in stress mode.
+/// The part of a payout above `threshold` is cut by `haircut_bps`; the cut
+/// is owed to the recipient as an IOU.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StressConfig {
+    pub active: bool,
+    pub threshold: i128,
+    pub haircut_bps: u32,
+    pub activated_at: u64,
+}
+
+/// Liquidity the pool keeps no matter what leaves it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LiquidityBuffer {
+    /// A fixed amount
+    Absolute(i128),
+    /// Basis points of the outstanding coverage, rounded up
+    CoverageBps(u32),
+}
+
+/// Payout caps for a risk pool manager over rolling windows
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutLimits {
+    pub daily: i128,
+    pub weekly: i128,
+}
+
+/// Deferred payout owed to a claimant, repaid in creation order
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IouEntry {
+    pub creditor: Address,
+    pub amount: i128,
+    pub created_at: u64,
+}
+
+/// Receipt for a queued withdrawal. The shares behind it stop earning
+/// rewards when it is minted, but stay in the pool backing claims until the
+/// receipt is filled. Matured receipts are filled oldest first, partially if
+/// liquidity is short, and whoever owns the receipt is paid what has been
+/// filled when redeeming it. `amount` is what is still to be paid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalReceipt {
+    pub owner: Address,
+    pub provider: Address,
+    pub amount: i128,
+    pub requested_at: u64,
+    pub matures_at: u64,
+}
+
+/// Where a withdrawal receipt stands in the exit queue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExitQueueStatus {
+    /// Receipts ahead of this one still waiting for liquidity
+    pub position: u32,
+    /// Unfilled amount queued ahead of this receipt
+    pub ahead: i128,
+    /// Filled and ready to redeem
+    pub filled: i128,
+    /// Still waiting for liquidity
+    pub unfilled: i128,
+    /// Part of `unfilled` the free liquidity would cover if the queue were
+    /// filled now
+    pub fillable_now: i128,
+}
+
+/// Premium a policy has paid into the pool, split into the part already
+/// earned and the part still covering future risk. The unearned balance is
+/// earned straight-line until the coverage expires, so each installment is
+/// spread over the term left when it arrives.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PremiumReserve {
+    pub unearned: i128,
+    pub earned: i128,
+    /// When `unearned` was last brought up to date
+    pub accrued_at: u64,
+    pub expiry: u64,
+}
+
+/// Aggregates recomputed from provider records and claim reservations next
+/// to the values the pool has stored
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DriftReport {
+    pub providers: u32,
+    pub reservations: u32,
+    pub computed_stake: i128,
+    pub recorded_stake: i128,
+    pub computed_deposited: i128,
+    pub recorded_deposited: i128,
+    pub computed_reserved: i128,
+    pub recorded_reserved: i128,
+    pub has_drift: bool,
+}
+
+/// Most of the pool's outstanding exposure that products in one correlation
+/// group may make up. Not enforced until total exposure reaches
+/// `min_total_exposure`, so a young pool can write its first policies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupLimit {
+    pub max_share_bps: u32,
+    pub min_total_exposure: i128,
+}
+
+/// Premium earned and claims paid per unit of a product's outstanding
+/// coverage since inception, scaled by `RISK_INDEX_SCALE`
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProductRiskIndex {
+    pub premium_index: i128,
+    pub loss_index: i128,
+}
+
+/// Side of a risk transfer order. A cedent hands exposure to another
+/// provider and pays the price for it; an assumer takes exposure on and is
+/// paid the price.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiskSide {
+    Cede,
+    Assume,
+}
+
+/// Open offer to transfer `exposure` of a product's risk for `term_secs`
+/// at `price`, paid in pool shares from the cedent to the assumer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskOrder {
+    pub maker: Address,
+    pub side: RiskSide,
+    pub product_id: u32,
+    pub exposure: i128,
+    pub price: i128,
+    pub term_secs: u64,
+    pub expires_at: u64,
+}
+
+/// Exposure the assumer carries for the cedent until `ends_at`. Settling
+/// pays the assumer the premium the slice earned and the cedent the
+/// claims it incurred since the indexes were last checkpointed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskSlice {
+    pub cedent: Address,
+    pub assumer: Address,
+    pub product_id: u32,
+    pub exposure: i128,
+    pub premium_index: i128,
+    pub loss_index: i128,
+    pub ends_at: u64,
+}
+
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub xlm_token: Address,
+    pub min_provider_stake: i128,
+    pub claims_contract: Address,
+}
+
+/// Rate model as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateModelSetting {
+    Disabled,
+    Enabled(RateModel),
+}
+
+/// Stress mode as reported by `get_protocol_config`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StressSetting {
+    Disabled,
+    Enabled(StressConfig),
+}
+
+/// Everything a frontend needs to bootstrap against the pool, in one read
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    pub token: Address,
+    pub min_provider_stake: i128,
+    pub claims_contract: Address,
+    pub withdrawal_cooldown: u64,
+    pub rate_model: RateModelSetting,
+    pub stress: StressSetting,
+    pub allowlist_enabled: bool,
+    pub wired: bool,
+    pub paused: bool,
+    pub version: ContractVersion,
+}
+
+/// Contract notified of every share transfer. The call is not isolated, so a
+/// hook can veto a transfer by failing.
+#[contractclient(name = "TransferHookClient")]
+pub trait TransferHook {
+    fn on_share_transfer(env: Env, from: Address, to: Address, amount: i128);
+}
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -35,10 +429,30 @@ pub enum ContractError {
     InvalidRole = 11,
     RoleNotFound = 12,
     NotTrustedContract = 13,
+    PayoutLimitExceeded = 14,
+    RecipientNotAllowed = 15,
+    RecipientPending = 16,
+    NotWired = 17,
+    WiringFailed = 18,
+    WithdrawalNotMatured = 19,
+    LiquidityLocked = 20,
+    RegionCapacityExceeded = 21,
+    GroupConcentrationExceeded = 22,
+    SolvencyShortfall = 23,
+    BufferBreached = 24,
+    DepositLocked = 25,
+    ExposureLocked = 26,
+    OrderExpired = 27,
+    InvalidAsset = 28,
+    AssetNotAllowed = 29,
+    TransferFailed = 30,
+    AssetCapacityExceeded = 31,
+    NodeCapacityExceeded = 32,
     // Invariant violation errors (100-199)
     LiquidityViolation = 100,
     InvalidAmount = 103,
     Overflow = 107,
+    ReserveInadequate = 111,
 }
 
 impl From<insurance_contracts::authorization::AuthError> for ContractError {
@@ -52,12 +466,77 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
     }
 }
 
+impl From<AllowlistError> for ContractError {
+    fn from(err: AllowlistError) -> Self {
+        match err {
+            AllowlistError::RecipientNotAllowed => ContractError::RecipientNotAllowed,
+            AllowlistError::RecipientPending => ContractError::RecipientPending,
+        }
+    }
+}
+
+impl From<SnapshotError> for ContractError {
+    fn from(err: SnapshotError) -> Self {
+        match err {
+            SnapshotError::NotFound => ContractError::NotFound,
+            SnapshotError::AlreadyCompleted => ContractError::InvalidState,
+        }
+    }
+}
+
+impl From<RescueError> for ContractError {
+    fn from(err: RescueError) -> Self {
+        match err {
+            RescueError::InvalidAmount => ContractError::InvalidAmount,
+            RescueError::ExceedsSurplus => ContractError::InsufficientFunds,
+        }
+    }
+}
+
+impl From<AssetError> for ContractError {
+    fn from(err: AssetError) -> Self {
+        match err {
+            AssetError::NotToken | AssetError::UnsupportedDecimals => ContractError::InvalidAsset,
+            AssetError::NotAllowed => ContractError::AssetNotAllowed,
+        }
+    }
+}
+
+impl From<WiringError> for ContractError {
+    fn from(err: WiringError) -> Self {
+        match err {
+            WiringError::NotWired => ContractError::NotWired,
+            WiringError::HandshakeFailed | WiringError::VersionMismatch => ContractError::WiringFailed,
+        }
+    }
+}
+
+impl From<MathError> for ContractError {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::Overflow => ContractError::Overflow,
+            MathError::DivisionByZero => ContractError::InvalidState,
+        }
+    }
+}
+
+impl From<IncidentError> for ContractError {
+    fn from(err: IncidentError) -> Self {
+        match err {
+            IncidentError::InvalidInput => ContractError::InvalidInput,
+            IncidentError::NotFound => ContractError::NotFound,
+            IncidentError::AlreadyClosed | IncidentError::StillOpen => ContractError::InvalidState,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
             InvariantError::LiquidityViolation => ContractError::LiquidityViolation,
             InvariantError::InvalidAmount => ContractError::InvalidAmount,
             InvariantError::Overflow => ContractError::Overflow,
+            InvariantError::ReserveInadequate => ContractError::ReserveInadequate,
             _ => ContractError::InvalidState,
         }
     }
@@ -67,16 +546,30 @@ fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.xlm_token)?;
+    validate_address(env, &config.claims_contract)?;
+
+    if config.min_provider_stake <= 0 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Checked on entry by nearly every call, so it keeps the instance TTL
+/// extended as well
 fn is_paused(env: &Env) -> bool {
+    hot_storage::extend(env);
     env.storage()
-        .persistent()
+        .instance()
         .get(&PAUSED)
         .unwrap_or(false)
 }
 
 fn set_paused(env: &Env, paused: bool) {
     env.storage()
-        .persistent()
+        .instance()
         .set(&PAUSED, &paused);
 }
 
@@ -85,175 +578,3274 @@ fn set_paused(env: &Env, paused: bool) {
 fn check_liquidity_invariant(env: &Env) -> Result<(), ContractError> {
     let stats: (i128, i128, i128, u64) = env
         .storage()
-        .persistent()
+        .instance()
         .get(&POOL_STATS)
         .ok_or(ContractError::NotFound)?;
 
     let reserved_total: i128 = env
         .storage()
-        .persistent()
+        .instance()
         .get(&RESERVED_TOTAL)
         .unwrap_or(0i128);
 
     // I1: Liquidity Preservation: available_liquidity >= reserved_claims
-    if stats.0 < reserved_total {
-        return Err(ContractError::LiquidityViolation);
-    }
+    assert_liquidity_sufficient!(env, stats.0, reserved_total);
 
     Ok(())
 }
 
-/// I4: Validate amount is positive and within safe range
-fn validate_amount(amount: i128) -> Result<(), ContractError> {
-    if amount <= 0 {
-        return Err(ContractError::InvalidAmount);
-    }
+// I8: Unearned premium and claim reservations must both be backed
+fn check_reserve_adequacy(env: &Env) -> Result<(), InvariantError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .unwrap_or((0, 0, 0, 0));
+    let reserved_total: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
+
+    assert_reserve_adequate!(env, stats.0, unearned_total(env), reserved_total);
     Ok(())
 }
 
-#[contractimpl]
-impl RiskPoolContract {
-    pub fn initialize(env: Env, admin: Address, xlm_token: Address, min_provider_stake: i128, claims_contract: Address) -> Result<(), ContractError> {
-        // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
-            return Err(ContractError::AlreadyInitialized);
-        }
+/// Write the live pool aggregates into the checkpoint for the current epoch.
+/// Later writes in the same epoch overwrite earlier ones, so each checkpoint
+/// holds the closing values of its epoch. Only the newest
+/// `MAX_STATS_HISTORY` epochs are retained.
+fn checkpoint_stats(env: &Env) -> Result<(), ContractError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &xlm_token)?;
-        validate_address(&env, &claims_contract)?;
+    let timestamp = env.ledger().timestamp();
+    let epoch = timestamp / STATS_EPOCH_SECONDS;
 
-        if min_provider_stake <= 0 {
-            return Err(ContractError::InvalidInput);
+    let mut epochs: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&STATS_EPOCHS)
+        .unwrap_or_else(|| Vec::new(env));
+    if epochs.last() != Some(epoch) {
+        epochs.push_back(epoch);
+        if epochs.len() > MAX_STATS_HISTORY {
+            if let Some(oldest) = epochs.pop_front() {
+                env.storage()
+                    .persistent()
+                    .remove(&(STATS_CHECKPOINT, oldest));
+            }
         }
-
-        // Initialize authorization system with admin
-        admin.require_auth();
-        initialize_admin(&env, admin.clone());
-        
-        // Register claims contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &claims_contract)?;
-
-        env.storage().persistent().set(&CONFIG, &(xlm_token, min_provider_stake));
-        
-        let stats = (0i128, 0i128, 0i128, 0u64);
-        env.storage().persistent().set(&POOL_STATS, &stats);
-        
-        env.events().publish(
-            (Symbol::new(&env, "initialized"), ()),
-            admin,
-        );
-        
-        Ok(())
+        env.storage().persistent().set(&STATS_EPOCHS, &epochs);
     }
 
-    pub fn deposit_liquidity(env: Env, provider: Address, amount: i128) -> Result<(), ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
-        }
+    let checkpoint = PoolStatsCheckpoint {
+        epoch,
+        timestamp,
+        total_liquidity: stats.0,
+        reserved_total,
+        total_paid_out: stats.1,
+        total_deposited: stats.2,
+    };
+    env.storage()
+        .persistent()
+        .set(&(STATS_CHECKPOINT, epoch), &checkpoint);
 
-        validate_address(&env, &provider)?;
-        
-        // I4: Amount Non-Negativity - amount must be positive
-        validate_amount(amount)?;
+    state_root::update(
+        env,
+        (
+            stats.0,
+            stats.1,
+            stats.2,
+            reserved_total,
+            unearned_total(env),
+            outstanding_exposure(env),
+        ),
+    );
 
-        let config: (Address, i128) = env
-            .storage()
-            .persistent()
-            .get(&CONFIG)
-            .ok_or(ContractError::NotInitialized)?;
+    Ok(())
+}
 
-        let mut provider_info: (i128, i128, u64) = env
-            .storage()
-            .persistent()
-            .get(&(PROVIDER, provider.clone()))
-            .unwrap_or((0i128, 0i128, env.ledger().timestamp()));
+fn total_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&TOTAL_STAKE)
+        .unwrap_or(0i128)
+}
 
-        if provider_info.1 + amount < config.1 {
-            return Err(ContractError::InvalidInput);
-        }
+/// Stored reward stream; pools from before streaming continue from their
+/// lump-sum index
+fn reward_stream(env: &Env) -> RewardStream {
+    env.storage()
+        .instance()
+        .get(&REWARD_STREAM)
+        .unwrap_or_else(|| {
+            let index: i128 = env.storage().instance().get(&REWARD_INDEX).unwrap_or(0i128);
+            RewardStream::from_index(index, env.ledger().timestamp())
+        })
+}
 
-        let mut stats: (i128, i128, i128, u64) = env
-            .storage()
-            .persistent()
-            .get(&POOL_STATS)
-            .ok_or(ContractError::NotFound)?;
+/// Fold the emission since the last update into the reward index over the
+/// current total stake. Must run before the total stake changes; what was
+/// emitted while nothing was staked goes to the dust bucket.
+fn accrue_rewards(env: &Env) -> Result<RewardStream, ContractError> {
+    let mut stream = reward_stream(env);
+    let unclaimable = stream.accrue(env.ledger().timestamp(), total_stake(env))?;
+    add_protocol_dust(env, unclaimable)?;
+    adjust_rewards_held(env, -unclaimable)?;
+    env.storage().instance().set(&REWARD_STREAM, &stream);
+    Ok(stream)
+}
 
-        // Safe arithmetic with overflow check
-        provider_info.0 = provider_info.0.checked_add(amount).ok_or(ContractError::Overflow)?;
-        provider_info.1 = provider_info.1.checked_add(amount).ok_or(ContractError::Overflow)?;
-        stats.0 = stats.0.checked_add(amount).ok_or(ContractError::Overflow)?;
-        stats.2 = stats.2.checked_add(amount).ok_or(ContractError::Overflow)?;
+/// Reward funds collected and not yet claimed or moved to the dust bucket
+fn rewards_held(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&REWARDS_HELD)
+        .unwrap_or(0i128)
+}
 
-        env.storage()
-            .persistent()
-            .set(&(PROVIDER, provider.clone()), &provider_info);
-        env.storage()
-            .persistent()
-            .set(&POOL_STATS, &stats);
+fn adjust_rewards_held(env: &Env, delta: i128) -> Result<(), ContractError> {
+    if delta == 0 {
+        return Ok(());
+    }
+    let held = rewards_held(env).checked_add(delta).ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&REWARDS_HELD, &held.max(0));
+    Ok(())
+}
 
-        // I1: Assert liquidity invariant holds after deposit
-        check_liquidity_invariant(&env)?;
+/// Settle rewards earned by `stake` since the provider's last checkpoint and
+/// move the checkpoint to the current index. Must run before the provider's
+/// stake changes so rewards accrued on the old stake stay with the provider.
+fn checkpoint_rewards(env: &Env, provider: &Address, stake: i128) -> Result<i128, ContractError> {
+    let stream = accrue_rewards(env)?;
+    let (paid_index, accrued): (i128, i128) = env
+        .storage()
+        .persistent()
+        .get(&(REWARDS, provider.clone()))
+        .unwrap_or((stream.index, 0i128));
 
-        env.events().publish(
-            (Symbol::new(&env, "liquidity_deposited"), provider.clone()),
-            (amount, provider_info.1),
-        );
+    let earned = stream.earned(stake, paid_index)?;
+    let accrued = accrued.checked_add(earned).ok_or(ContractError::Overflow)?;
 
-        Ok(())
-    }
+    env.storage()
+        .persistent()
+        .set(&(REWARDS, provider.clone()), &(stream.index, accrued));
 
-    pub fn get_pool_stats(env: Env) -> Result<(i128, i128, i128, u64), ContractError> {
-        let stats: (i128, i128, i128, u64) = env
+    Ok(accrued)
+}
+
+/// Payouts by a manager over the daily and rolling weekly windows
+fn manager_usage(env: &Env, manager: &Address) -> (i128, i128) {
+    let today = env.ledger().timestamp() / PAYOUT_WINDOW_SECONDS;
+    let mut daily = 0i128;
+    let mut weekly = 0i128;
+    for offset in 0..WEEKLY_WINDOW_DAYS {
+        if offset > today {
+            break;
+        }
+        let used: i128 = env
             .storage()
             .persistent()
-            .get(&POOL_STATS)
-            .ok_or(ContractError::NotFound)?;
-        
-        Ok(stats)
+            .get(&(MANAGER_USAGE, manager.clone(), today - offset))
+            .unwrap_or(0i128);
+        if offset == 0 {
+            daily = used;
+        }
+        weekly += used;
     }
+    (daily, weekly)
+}
 
-    pub fn get_provider_info(env: Env, provider: Address) -> Result<(i128, i128, u64), ContractError> {
-        validate_address(&env, &provider)?;
-        
-        let provider_info: (i128, i128, u64) = env
-            .storage()
-            .persistent()
-            .get(&(PROVIDER, provider))
-            .ok_or(ContractError::NotFound)?;
-        
-        Ok(provider_info)
+/// Charge a payout against the manager's limits. Managers without limits
+/// are unrestricted; admins bypass limits as the emergency override.
+fn charge_manager_limits(env: &Env, manager: &Address, amount: i128) -> Result<(), ContractError> {
+    if get_role(env, manager) == Role::Admin {
+        publish_event(
+            env,
+            (Symbol::new(env, "limit_override"), manager.clone()),
+            amount,
+        );
+        return Ok(());
     }
 
-    pub fn reserve_liquidity(env: Env, caller_contract: Address, claim_id: u64, amount: i128) -> Result<(), ContractError> {
-        // Verify that the caller is a trusted contract (e.g., claims contract)
-        caller_contract.require_auth();
-        require_trusted_contract(&env, &caller_contract)?;
+    let limits: PayoutLimits = match env.storage().persistent().get(&(MANAGER_LIMITS, manager.clone())) {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
 
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
-        }
+    let (daily, weekly) = manager_usage(env, manager);
+    let daily = daily.checked_add(amount).ok_or(ContractError::Overflow)?;
+    let weekly = weekly.checked_add(amount).ok_or(ContractError::Overflow)?;
+    if daily > limits.daily || weekly > limits.weekly {
+        return Err(ContractError::PayoutLimitExceeded);
+    }
 
-        // I4: Amount Non-Negativity - amount must be positive
-        validate_amount(amount)?;
+    let today = env.ledger().timestamp() / PAYOUT_WINDOW_SECONDS;
+    env.storage()
+        .persistent()
+        .set(&(MANAGER_USAGE, manager.clone(), today), &daily);
 
-        if env
-            .storage()
-            .persistent()
-            .has(&(CLAIM_RESERVATION, claim_id))
-        {
-            return Err(ContractError::AlreadyExists);
-        }
+    Ok(())
+}
 
-        let stats: (i128, i128, i128, u64) = env
-            .storage()
-            .persistent()
-            .get(&POOL_STATS)
-            .ok_or(ContractError::NotFound)?;
+/// Share of pool liquidity reserved for approved claims
+fn pool_utilization(env: &Env) -> Result<Decimal, ContractError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
 
-        let reserved_total: i128 = env
+    Ok(rate_model::utilization(reserved_total, stats.0)?)
+}
+
+/// Annualized rate of the configured model at `utilization`
+fn rate_at(env: &Env, utilization: Decimal) -> Result<Decimal, ContractError> {
+    let model: RateModel = env
+        .storage()
+        .instance()
+        .get(&RATE_MODEL)
+        .ok_or(ContractError::NotFound)?;
+    let utilization = utilization.max(Decimal::ZERO).min(Decimal::ONE);
+
+    Ok(model.rate(utilization)?)
+}
+
+/// Split a payout into the amount paid now and the haircut owed as an IOU
+fn apply_stress_haircut(env: &Env, amount: i128) -> Result<(i128, i128), ContractError> {
+    let stress: Option<StressConfig> = env.storage().instance().get(&STRESS_MODE);
+    let stress = match stress {
+        Some(stress) if stress.active && amount > stress.threshold => stress,
+        _ => return Ok((amount, 0)),
+    };
+
+    let haircut = bps_of_ceil(amount - stress.threshold, stress.haircut_bps)?;
+    Ok((amount - haircut, haircut))
+}
+
+/// Adjust a creditor's outstanding IOU balance and the pool-wide total
+fn adjust_iou_balance(env: &Env, creditor: &Address, delta: i128) -> Result<(), ContractError> {
+    let owed: i128 = env
+        .storage()
+        .persistent()
+        .get(&(IOU, creditor.clone()))
+        .unwrap_or(0i128);
+    let total: i128 = env.storage().instance().get(&IOU_TOTAL).unwrap_or(0i128);
+
+    let owed = owed.checked_add(delta).ok_or(ContractError::Overflow)?;
+    if owed == 0 {
+        env.storage().persistent().remove(&(IOU, creditor.clone()));
+    } else {
+        env.storage().persistent().set(&(IOU, creditor.clone()), &owed);
+    }
+    env.storage()
+        .instance()
+        .set(&IOU_TOTAL, &total.checked_add(delta).ok_or(ContractError::Overflow)?);
+
+    Ok(())
+}
+
+/// Append an IOU to the back of the repayment queue
+fn record_iou(env: &Env, creditor: &Address, amount: i128) -> Result<(), ContractError> {
+    adjust_iou_balance(env, creditor, amount)?;
+
+    let id: u64 = env.storage().instance().get(&IOU_TAIL).unwrap_or(0u64);
+    let entry = IouEntry {
+        creditor: creditor.clone(),
+        amount,
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&(IOU_ENTRY, id), &entry);
+    env.storage().instance().set(&IOU_TAIL, &(id + 1));
+
+    publish_event(
+        env,
+        (Symbol::new(env, "iou_recorded"), creditor.clone()),
+        (id, amount),
+    );
+
+    Ok(())
+}
+
+/// Repay queued IOUs oldest first out of `funds`. Returns the amount used;
+/// the caller books it as paid out and keeps the rest as liquidity.
+fn amortize_ious(env: &Env, funds: i128) -> Result<i128, ContractError> {
+    let mut head: u64 = env.storage().instance().get(&IOU_HEAD).unwrap_or(0u64);
+    let tail: u64 = env.storage().instance().get(&IOU_TAIL).unwrap_or(0u64);
+    let mut remaining = funds;
+    let mut settled = 0u32;
+
+    while head < tail && remaining > 0 && settled < MAX_IOU_AMORTIZATIONS {
+        let mut entry: IouEntry = match env.storage().persistent().get(&(IOU_ENTRY, head)) {
+            Some(entry) => entry,
+            None => {
+                head += 1;
+                continue;
+            }
+        };
+
+        let payment = entry.amount.min(remaining);
+        entry.amount -= payment;
+        remaining -= payment;
+        adjust_iou_balance(env, &entry.creditor, -payment)?;
+        pay(env, &entry.creditor, payment)?;
+
+        publish_event(
+            env,
+            (Symbol::new(env, "iou_amortized"), entry.creditor.clone()),
+            (head, payment, entry.amount),
+        );
+
+        if entry.amount > 0 {
+            env.storage().persistent().set(&(IOU_ENTRY, head), &entry);
+            break;
+        }
+        env.storage().persistent().remove(&(IOU_ENTRY, head));
+        head += 1;
+        settled += 1;
+    }
+
+    env.storage().instance().set(&IOU_HEAD, &head);
+
+    Ok(funds - remaining)
+}
+
+/// Add a provider to the reconciliation index the first time it gets a record
+fn index_provider(env: &Env, provider: &Address) {
+    if env.storage().persistent().has(&(PROVIDER, provider.clone())) {
+        return;
+    }
+    let mut providers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&PROVIDER_LIST)
+        .unwrap_or_else(|| Vec::new(env));
+    providers.push_back(provider.clone());
+    env.storage().persistent().set(&PROVIDER_LIST, &providers);
+}
+
+fn index_reservation(env: &Env, claim_id: u64, add: bool) {
+    let mut reservations: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&RESERVATION_LIST)
+        .unwrap_or_else(|| Vec::new(env));
+    if add {
+        reservations.push_back(claim_id);
+    } else if let Some(index) = reservations.first_index_of(claim_id) {
+        reservations.remove(index);
+    }
+    env.storage().persistent().set(&RESERVATION_LIST, &reservations);
+}
+
+/// Recompute stake, deposit and reservation totals from the individual
+/// records and compare them to the stored aggregates
+fn compute_drift(env: &Env) -> Result<DriftReport, ContractError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let providers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&PROVIDER_LIST)
+        .unwrap_or_else(|| Vec::new(env));
+    let reservations: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&RESERVATION_LIST)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut computed_stake = 0i128;
+    let mut computed_deposited = 0i128;
+    for provider in providers.iter() {
+        let info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider))
+            .unwrap_or((0i128, 0i128, 0u64));
+        computed_deposited = computed_deposited.checked_add(info.0).ok_or(ContractError::Overflow)?;
+        computed_stake = computed_stake.checked_add(info.1).ok_or(ContractError::Overflow)?;
+    }
+
+    let mut computed_reserved = 0i128;
+    for claim_id in reservations.iter() {
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_RESERVATION, claim_id))
+            .unwrap_or(0i128);
+        computed_reserved = computed_reserved.checked_add(amount).ok_or(ContractError::Overflow)?;
+    }
+
+    let recorded_stake = total_stake(env);
+    let recorded_reserved: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
+
+    Ok(DriftReport {
+        providers: providers.len(),
+        reservations: reservations.len(),
+        computed_stake,
+        recorded_stake,
+        computed_deposited,
+        recorded_deposited: stats.2,
+        computed_reserved,
+        recorded_reserved,
+        has_drift: computed_stake != recorded_stake
+            || computed_deposited != stats.2
+            || computed_reserved != recorded_reserved,
+    })
+}
+
+/// Value backing the shares outstanding: liquidity less what is owed to
+/// queued withdrawal receipts and to IOU holders
+fn pool_value(env: &Env, stats: &(i128, i128, i128, u64)) -> Result<i128, ContractError> {
+    let queued: i128 = env
+        .storage()
+        .instance()
+        .get(&WITHDRAWAL_QUEUED_TOTAL)
+        .unwrap_or(0i128);
+    let filled: i128 = env
+        .storage()
+        .instance()
+        .get(&WITHDRAWAL_FILLED_TOTAL)
+        .unwrap_or(0i128);
+    let ious: i128 = env.storage().instance().get(&IOU_TOTAL).unwrap_or(0i128);
+    let owed = (queued - filled).checked_add(ious).ok_or(ContractError::Overflow)?;
+    Ok(stats.0.checked_sub(owed).ok_or(ContractError::Overflow)?.max(0))
+}
+
+/// Shares minted for depositing `amount`, rounded down. The first deposit,
+/// or one into a pool whose value was wiped out, mints one share per unit.
+fn shares_for_deposit(env: &Env, stats: &(i128, i128, i128, u64), amount: i128) -> Result<i128, ContractError> {
+    let total = total_stake(env);
+    let value = pool_value(env, stats)?;
+    if total <= 0 || value <= 0 {
+        return Ok(amount);
+    }
+    Ok(mul_div_floor(amount, total, value)?)
+}
+
+/// Pro rata value of `shares`, rounded down
+fn share_value(env: &Env, stats: &(i128, i128, i128, u64), shares: i128) -> Result<i128, ContractError> {
+    let total = total_stake(env);
+    if total <= 0 {
+        return Ok(0);
+    }
+    Ok(mul_div_floor(shares, pool_value(env, stats)?, total)?)
+}
+
+/// Liquidity locked behind open claim reservations and the unearned
+/// premium that still backs live coverage
+fn locked_liquidity(env: &Env) -> Result<i128, ContractError> {
+    let reserved_total: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
+    reserved_total.checked_add(unearned_total(env)).ok_or(ContractError::Overflow)
+}
+
+/// Split a provider's stake into the part that can leave now and the part
+/// locked behind open claim reservations. Each provider carries the reserved
+/// share of the pool pro rata, so `locked = stake * locked / liquidity`.
+/// The lock a provider carried at its last withdrawal stays with it, scaled
+/// by how much of the locked liquidity is still locked, so repeated
+/// withdrawals cannot shrink it.
+fn withdrawable_split(env: &Env, provider: &Address, stake: i128) -> Result<(i128, i128), ContractError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let locked = locked_liquidity(env)?;
+
+    if locked <= 0 {
+        return Ok((stake, 0));
+    }
+    if stats.0 <= 0 {
+        return Ok((0, stake));
+    }
+
+    let unreserved = stats.0.checked_sub(locked).ok_or(ContractError::Overflow)?.max(0);
+    let pro_rata = stake - mul_div_floor(stake, unreserved, stats.0)?;
+    let carried = match env.storage().persistent().get::<_, (i128, i128)>(&(PROVIDER_LOCK, provider.clone())) {
+        Some((shares, locked_at)) if locked_at > 0 => mul_div_ceil(shares, locked, locked_at)?,
+        _ => 0,
+    };
+
+    let locked_shares = pro_rata.max(carried).min(stake);
+    Ok((stake - locked_shares, locked_shares))
+}
+
+/// Remember the shares `provider` keeps locked after a withdrawal, against
+/// the liquidity locked at the time
+fn record_provider_lock(env: &Env, provider: &Address, locked_shares: i128) -> Result<(), ContractError> {
+    let locked = locked_liquidity(env)?;
+    if locked_shares > 0 && locked > 0 {
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER_LOCK, provider.clone()), &(locked_shares, locked));
+    } else {
+        env.storage()
+            .persistent()
+            .remove(&(PROVIDER_LOCK, provider.clone()));
+    }
+    Ok(())
+}
+
+/// Credit `amount` left over by rounding to the protocol dust bucket
+fn add_protocol_dust(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let dust: i128 = env.storage().instance().get(&PROTOCOL_DUST).unwrap_or(0i128);
+    let dust = dust.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage().instance().set(&PROTOCOL_DUST, &dust);
+    Ok(())
+}
+
+fn unearned_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&UNEARNED_TOTAL)
+        .unwrap_or(0i128)
+}
+
+/// Recognize the premium a policy has earned since it was last accrued and
+/// return the amount recognized. Coverage that has expired, or is no longer
+/// tracked when `release` is set, earns its whole remaining balance.
+fn accrue_premium(env: &Env, policy_id: u64, release: bool) -> Result<i128, ContractError> {
+    let mut reserve: PremiumReserve = match env.storage().persistent().get(&(PREMIUM_RESERVE, policy_id)) {
+        Some(reserve) => reserve,
+        None => return Ok(0),
+    };
+
+    let now = env.ledger().timestamp();
+    let earned = if release || now >= reserve.expiry {
+        reserve.unearned
+    } else if now > reserve.accrued_at {
+        mul_div_floor(
+            reserve.unearned,
+            (now - reserve.accrued_at) as i128,
+            (reserve.expiry - reserve.accrued_at) as i128,
+        )?
+    } else {
+        0
+    };
+
+    reserve.unearned = reserve.unearned.checked_sub(earned).ok_or(ContractError::Overflow)?;
+    reserve.earned = reserve.earned.checked_add(earned).ok_or(ContractError::Overflow)?;
+    reserve.accrued_at = now.max(reserve.accrued_at);
+    env.storage()
+        .persistent()
+        .set(&(PREMIUM_RESERVE, policy_id), &reserve);
+
+    if earned > 0 {
+        let total = unearned_total(env).checked_sub(earned).ok_or(ContractError::Overflow)?.max(0);
+        env.storage().instance().set(&UNEARNED_TOTAL, &total);
+
+        if let Some(product_id) = env.storage().persistent().get::<_, u32>(&(COVERAGE_PRODUCT, policy_id)) {
+            bump_product_index(env, product_id, earned, false)?;
+        }
+
+        publish_event(
+            env,
+            (Symbol::new(env, "premium_earned"), policy_id),
+            (earned, reserve.unearned),
+        );
+    }
+
+    Ok(earned)
+}
+
+fn outstanding_exposure(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&COVERAGE_TOTAL)
+        .unwrap_or(0i128)
+}
+
+/// Liquidity the governed buffer currently requires, zero if none is set
+fn required_buffer(env: &Env) -> Result<i128, ContractError> {
+    let buffer: Option<LiquidityBuffer> = env.storage().instance().get(&LIQUIDITY_BUFFER);
+    Ok(match buffer {
+        Some(LiquidityBuffer::Absolute(amount)) => amount,
+        Some(LiquidityBuffer::CoverageBps(bps)) => bps_of_ceil(outstanding_exposure(env), bps)?,
+        None => 0,
+    })
+}
+
+/// Every outflow must leave at least the buffer in the pool
+fn require_buffer(env: &Env, liquidity_after: i128) -> Result<(), ContractError> {
+    if liquidity_after < required_buffer(env)? {
+        return Err(ContractError::BufferBreached);
+    }
+    Ok(())
+}
+
+fn region_exposure(env: &Env, region: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(REGION_EXPOSURE, region))
+        .unwrap_or(0i128)
+}
+
+fn node_exposure(env: &Env, node_id: &BytesN<32>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(NODE_EXPOSURE, node_id.clone()))
+        .unwrap_or(0i128)
+}
+
+fn covered_asset_exposure(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(ASSET_EXPOSURE, asset.clone()))
+        .unwrap_or(0i128)
+}
+
+fn expected_losses(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&EXPECTED_LOSS_TOTAL)
+        .unwrap_or(0i128)
+}
+
+/// Book the loaded loss a new policy of a priced product is expected to
+/// cost over its term, and require the unreserved liquidity to cover the
+/// expected losses of all registered policies
+fn add_expected_loss(env: &Env, policy_id: u64, product_id: u32, coverage_amount: i128, expiry: u64) -> Result<(), ContractError> {
+    let params: ActuarialParams = match env.storage().persistent().get(&(ACTUARIAL, product_id)) {
+        Some(params) => params,
+        None => return Ok(()),
+    };
+
+    let term = expiry.saturating_sub(env.ledger().timestamp());
+    let loss = params.loss_over(coverage_amount, term)?;
+    let total = expected_losses(env)
+        .checked_add(loss)
+        .ok_or(ContractError::Overflow)?;
+
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let reserved_total: i128 = env
+        .storage()
+        .instance()
+        .get(&RESERVED_TOTAL)
+        .unwrap_or(0i128);
+    if stats.0 - reserved_total < total {
+        return Err(ContractError::SolvencyShortfall);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(EXPECTED_LOSS, policy_id), &loss);
+    env.storage()
+        .instance()
+        .set(&EXPECTED_LOSS_TOTAL, &total);
+    Ok(())
+}
+
+fn group_exposure(env: &Env, group: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(GROUP_EXPOSURE, group))
+        .unwrap_or(0i128)
+}
+
+/// Book `coverage_amount` of a new policy against its product's correlation
+/// group, rejecting it if the group would exceed its share of `exposure`,
+/// the pool's total exposure including the new policy
+fn add_group_exposure(env: &Env, policy_id: u64, product_id: u32, coverage_amount: i128, exposure: i128) -> Result<(), ContractError> {
+    let group: u32 = match env.storage().persistent().get(&(PRODUCT_GROUP, product_id)) {
+        Some(group) => group,
+        None => return Ok(()),
+    };
+
+    let grouped = group_exposure(env, group)
+        .checked_add(coverage_amount)
+        .ok_or(ContractError::Overflow)?;
+    let limit: Option<GroupLimit> = env.storage().persistent().get(&(GROUP_LIMIT, group));
+    if let Some(limit) = limit {
+        if exposure >= limit.min_total_exposure && grouped > bps_of(exposure, limit.max_share_bps)? {
+            return Err(ContractError::GroupConcentrationExceeded);
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(COVERAGE_GROUP, policy_id), &group);
+    env.storage()
+        .persistent()
+        .set(&(GROUP_EXPOSURE, group), &grouped);
+    Ok(())
+}
+
+fn product_exposure(env: &Env, product_id: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(PRODUCT_EXPOSURE, product_id))
+        .unwrap_or(0i128)
+}
+
+fn product_risk_index(env: &Env, product_id: u32) -> ProductRiskIndex {
+    env.storage()
+        .persistent()
+        .get(&(PRODUCT_RISK, product_id))
+        .unwrap_or_default()
+}
+
+/// Spread premium earned, or a claim paid when `loss` is set, over the
+/// product's outstanding coverage. Amounts arriving while nothing of the
+/// product is outstanding cannot be attributed to any slice.
+fn bump_product_index(env: &Env, product_id: u32, amount: i128, loss: bool) -> Result<(), ContractError> {
+    let exposure = product_exposure(env, product_id);
+    if amount <= 0 || exposure <= 0 {
+        return Ok(());
+    }
+
+    let increment = mul_div_floor(amount, RISK_INDEX_SCALE, exposure)?;
+    let mut index = product_risk_index(env, product_id);
+    let target = if loss { &mut index.loss_index } else { &mut index.premium_index };
+    *target = target.checked_add(increment).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&(PRODUCT_RISK, product_id), &index);
+    Ok(())
+}
+
+fn risk_locked(env: &Env, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(RISK_LOCKED, provider.clone()))
+        .unwrap_or(0i128)
+}
+
+fn risk_ceded(env: &Env, provider: &Address, product_id: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(RISK_CEDED, provider.clone(), product_id))
+        .unwrap_or(0i128)
+}
+
+/// An assumer's stake backs the exposure it took on, so it cannot drop
+/// below that through withdrawals or share transfers
+fn require_exposure_unlocked(env: &Env, provider: &Address, stake_after: i128) -> Result<(), ContractError> {
+    if stake_after < risk_locked(env, provider) {
+        return Err(ContractError::ExposureLocked);
+    }
+    Ok(())
+}
+
+/// Move up to `amount` of stake from `from` to `to` to settle a risk trade,
+/// checkpointing both providers' rewards first. Returns what was moved,
+/// less than `amount` if `from` no longer holds it.
+fn move_stake(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<i128, ContractError> {
+    let mut from_info: (i128, i128, u64) = env
+        .storage()
+        .persistent()
+        .get(&(PROVIDER, from.clone()))
+        .ok_or(ContractError::NotFound)?;
+    let amount = amount.min(from_info.1);
+    if amount <= 0 {
+        return Ok(0);
+    }
+    let mut to_info: (i128, i128, u64) = env
+        .storage()
+        .persistent()
+        .get(&(PROVIDER, to.clone()))
+        .unwrap_or((0i128, 0i128, env.ledger().timestamp()));
+
+    checkpoint_rewards(env, from, from_info.1)?;
+    checkpoint_rewards(env, to, to_info.1)?;
+
+    from_info.1 -= amount;
+    to_info.1 = to_info.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    index_provider(env, to);
+    env.storage()
+        .persistent()
+        .set(&(PROVIDER, from.clone()), &from_info);
+    env.storage()
+        .persistent()
+        .set(&(PROVIDER, to.clone()), &to_info);
+
+    if let Some(hook) = env.storage().instance().get::<_, Address>(&TRANSFER_HOOK) {
+        TransferHookClient::new(env, &hook).on_share_transfer(from, to, &amount);
+    }
+
+    Ok(amount)
+}
+
+/// Settle a slice's premium and losses since its last checkpoint and move
+/// the net between the two providers. Returns the net paid to the assumer,
+/// negative when the assumer paid the cedent.
+fn settle_slice(env: &Env, slice: &mut RiskSlice) -> Result<i128, ContractError> {
+    let index = product_risk_index(env, slice.product_id);
+    let premium = mul_div_floor(
+        slice.exposure,
+        index.premium_index - slice.premium_index,
+        RISK_INDEX_SCALE,
+    )?;
+    let losses = mul_div_floor(
+        slice.exposure,
+        index.loss_index - slice.loss_index,
+        RISK_INDEX_SCALE,
+    )?;
+    slice.premium_index = index.premium_index;
+    slice.loss_index = index.loss_index;
+
+    let net = premium.checked_sub(losses).ok_or(ContractError::Overflow)?;
+    if net > 0 {
+        move_stake(env, &slice.cedent, &slice.assumer, net)
+    } else if net < 0 {
+        Ok(-move_stake(env, &slice.assumer, &slice.cedent, -net)?)
+    } else {
+        Ok(0)
+    }
+}
+
+fn withdrawal_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&WITHDRAWAL_COOLDOWN)
+        .unwrap_or(DEFAULT_WITHDRAWAL_COOLDOWN_SECONDS)
+}
+
+fn deposit_lock_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DEPOSIT_LOCK)
+        .unwrap_or(DEFAULT_DEPOSIT_LOCK_LEDGERS)
+}
+
+/// First ledger in which `provider`'s shares may leave after its last
+/// deposit
+fn deposit_unlock_ledger(env: &Env, provider: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<_, u32>(&(LAST_DEPOSIT, provider.clone()))
+        .map(|ledger| ledger.saturating_add(deposit_lock_ledgers(env)))
+        .unwrap_or(0)
+}
+
+/// Shares cannot be withdrawn or moved in the ledgers right after a
+/// deposit, so a flash deposit cannot sit through a reward checkpoint
+fn require_deposit_unlocked(env: &Env, provider: &Address) -> Result<(), ContractError> {
+    if env.ledger().sequence() < deposit_unlock_ledger(env, provider) {
+        return Err(ContractError::DepositLocked);
+    }
+    Ok(())
+}
+
+fn get_withdrawal_receipt(env: &Env, receipt_id: u64) -> Result<WithdrawalReceipt, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(WITHDRAWAL_RECEIPT, receipt_id))
+        .ok_or(ContractError::NotFound)
+}
+
+/// Filled and not yet redeemed part of a receipt
+fn receipt_filled(env: &Env, receipt_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(WITHDRAWAL_FILLED, receipt_id))
+        .unwrap_or(0i128)
+}
+
+fn exit_queue_head(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&WITHDRAWAL_HEAD)
+        .unwrap_or(1u64)
+}
+
+/// Liquidity exits may take: what is not reserved for claims, backing
+/// unearned premium or held as the buffer
+fn free_liquidity(env: &Env, stats: &(i128, i128, i128, u64)) -> Result<i128, ContractError> {
+    let locked = locked_liquidity(env)?
+        .checked_add(required_buffer(env)?)
+        .ok_or(ContractError::Overflow)?;
+    Ok(stats.0.checked_sub(locked).ok_or(ContractError::Overflow)?.max(0))
+}
+
+/// Fill matured withdrawal receipts oldest first out of the free liquidity,
+/// passing at most `max_receipts` queue slots, redeemed ones included.
+/// A receipt the liquidity cannot cover takes what is free and keeps its
+/// place, so the receipts behind it wait. Filled amounts leave the pool's
+/// liquidity and are held for the receipt owner. Returns the amount filled.
+fn fill_exit_queue(env: &Env, max_receipts: u32) -> Result<i128, ContractError> {
+    let mut stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let mut free = free_liquidity(env, &stats)?;
+    let mut head = exit_queue_head(env);
+    let last: u64 = env.storage().instance().get(&WITHDRAWAL_COUNTER).unwrap_or(0u64);
+    let now = env.ledger().timestamp();
+    let mut filled_total = 0i128;
+    let mut visited = 0u32;
+
+    while head <= last && free > 0 && visited < max_receipts {
+        let receipt = match env.storage().persistent().get::<_, WithdrawalReceipt>(&(WITHDRAWAL_RECEIPT, head)) {
+            Some(receipt) => receipt,
+            None => {
+                head += 1;
+                visited += 1;
+                continue;
+            }
+        };
+        if now < receipt.matures_at {
+            break;
+        }
+
+        let filled = receipt_filled(env, head);
+        let fill = (receipt.amount - filled).min(free);
+        free -= fill;
+        filled_total += fill;
+        env.storage()
+            .persistent()
+            .set(&(WITHDRAWAL_FILLED, head), &(filled + fill));
+
+        publish_event(
+            env,
+            (Symbol::new(env, "withdrawal_filled"), head),
+            (fill, receipt.amount - filled - fill),
+        );
+
+        if filled + fill < receipt.amount {
+            break;
+        }
+        head += 1;
+        visited += 1;
+    }
+
+    env.storage().instance().set(&WITHDRAWAL_HEAD, &head);
+    if filled_total > 0 {
+        stats.0 = stats.0.checked_sub(filled_total).ok_or(ContractError::Overflow)?;
+        env.storage().instance().set(&POOL_STATS, &stats);
+
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_FILLED_TOTAL)
+            .unwrap_or(0i128);
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_FILLED_TOTAL, &(total + filled_total));
+    }
+
+    Ok(filled_total)
+}
+
+/// Pool asset the contract holds on someone's behalf: the liquidity,
+/// processing fees set aside, filled exits not yet redeemed, reward funds
+/// not yet claimed and the dust bucket
+fn accounted_balance(env: &Env) -> Result<i128, ContractError> {
+    let stats: (i128, i128, i128, u64) = env
+        .storage()
+        .instance()
+        .get(&POOL_STATS)
+        .ok_or(ContractError::NotFound)?;
+    let fees_owed: i128 = env.storage().instance().get(&FEES_OWED).unwrap_or(0i128);
+    let queued: i128 = env
+        .storage()
+        .instance()
+        .get(&WITHDRAWAL_QUEUED_TOTAL)
+        .unwrap_or(0i128);
+    let filled: i128 = env
+        .storage()
+        .instance()
+        .get(&WITHDRAWAL_FILLED_TOTAL)
+        .unwrap_or(0i128);
+    let pending: i128 = env
+        .storage()
+        .instance()
+        .get(&WITHDRAWAL_PENDING)
+        .unwrap_or(0i128);
+    let dust: i128 = env.storage().instance().get(&PROTOCOL_DUST).unwrap_or(0i128);
+
+    // Receipts are redeemed only out of what was filled for them
+    let redeemed = queued - pending;
+    stats.0
+        .checked_add(fees_owed)
+        .and_then(|total| total.checked_add((filled - redeemed).max(0)))
+        .and_then(|total| total.checked_add(rewards_held(env)))
+        .and_then(|total| total.checked_add(dust))
+        .ok_or(ContractError::Overflow)
+}
+
+/// I4: Validate amount is positive and within safe range
+fn validate_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    assert_valid_amount!(env, amount, 1);
+    Ok(())
+}
+
+/// The pool asset and its decimals
+fn pool_token(env: &Env) -> Result<(Address, u32), ContractError> {
+    let config: (Address, i128) = env
+        .storage()
+        .instance()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)?;
+    let decimals = assets::decimals(env, &config.0);
+    Ok((config.0, decimals))
+}
+
+/// Pull a canonical `amount` of the pool asset from `from`, rounded up to
+/// the token's decimals. A failed transfer is returned as an error, so the
+/// entrypoint's state changes are rolled back with it.
+fn collect(env: &Env, from: &Address, amount: i128) -> Result<(), ContractError> {
+    let (token, decimals) = pool_token(env)?;
+    let native = units::to_native_ceil(amount, decimals)?;
+    match token::Client::new(env, &token).try_transfer(from, env.current_contract_address(), &native) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(ContractError::TransferFailed),
+    }
+}
+
+/// Send a canonical `amount` of the pool asset to `to`, rounded down to the
+/// token's decimals. Nothing moves for a zero amount.
+fn pay(env: &Env, to: &Address, amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Ok(());
+    }
+    let (token, decimals) = pool_token(env)?;
+    let native = units::to_native_floor(amount, decimals)?;
+    if native == 0 {
+        return Ok(());
+    }
+    match token::Client::new(env, &token).try_transfer(&env.current_contract_address(), to, &native) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(ContractError::TransferFailed),
+    }
+}
+
+#[contractimpl]
+impl RiskPoolContract {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
+        // Check if already initialized
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        validate_init_config(&env, &config)?;
+        let InitConfig { admin, xlm_token, min_provider_stake, claims_contract } = config;
+
+        // Initialize authorization system with admin
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+        
+        // Register claims contract as trusted for cross-contract calls
+        register_trusted_contract(&env, &admin, &claims_contract)?;
+        env.storage().instance().set(&CLAIMS_CONTRACT, &claims_contract);
+
+        // The pool's asset is probed and becomes the first allowlisted one
+        assets::allow(&env, &xlm_token)?;
+        env.storage().instance().set(&CONFIG, &(xlm_token, min_provider_stake));
+        
+        let stats = (0i128, 0i128, 0i128, 0u64);
+        env.storage().instance().set(&POOL_STATS, &stats);
+        
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+        
+        Ok(())
+    }
+
+    /// Probe `token` through the SEP-41 interface and allowlist it as a
+    /// settlement asset, recording its decimals (governance only)
+    pub fn allow_asset(env: Env, caller: Address, token: Address) -> Result<AssetInfo, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let info = assets::allow(&env, &token)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_allowed"), token),
+            info.decimals,
+        );
+
+        Ok(info)
+    }
+
+    /// Remove `token` from the asset allowlist (governance only). Settings
+    /// already using it keep working until they are changed.
+    pub fn disallow_asset(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        assets::disallow(&env, &token);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_disallowed"), token),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_asset(env: Env, token: Address) -> Option<AssetInfo> {
+        assets::get_info(&env, &token)
+    }
+
+    /// Trust the policy contract for premium transfers and wiring (admin only)
+    pub fn register_policy_contract(env: Env, admin: Address, policy_contract: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        register_trusted_contract(&env, &admin, &policy_contract)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "policy_contract_registered"), ()),
+            policy_contract,
+        );
+
+        Ok(())
+    }
+
+    /// Verify the claims contract and activate reservations (admin only).
+    /// The claims contract must already trust the pool.
+    pub fn finalize_wiring(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let claims_contract: Address = env
+            .storage()
+            .instance()
+            .get(&CLAIMS_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+
+        wiring::verify_peer(&env, &claims_contract)?;
+        wiring::set_wired(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "wiring_finalized"), ()),
+            WIRING_VERSION,
+        );
+
+        Ok(())
+    }
+
+    /// Handshake called by a peer's `finalize_wiring`
+    pub fn accept_wiring(env: Env, peer: Address) -> Result<u32, ContractError> {
+        peer.require_auth();
+        require_trusted_contract(&env, &peer)?;
+        Ok(WIRING_VERSION)
+    }
+
+    pub fn is_wired(env: Env) -> bool {
+        wiring::is_wired(&env)
+    }
+
+    pub fn deposit_liquidity(env: Env, provider: Address, amount: i128) -> Result<(), ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_address(&env, &provider)?;
+        
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(&env, amount)?;
+
+        let config: (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .unwrap_or((0i128, 0i128, env.ledger().timestamp()));
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+
+        // Shares are priced before the deposit changes the pool's value
+        if share_value(&env, &stats, provider_info.1)? + amount < config.1 {
+            return Err(ContractError::InvalidInput);
+        }
+        let minted = shares_for_deposit(&env, &stats, amount)?;
+        if minted <= 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        checkpoint_rewards(&env, &provider, provider_info.1)?;
+
+        collect(&env, &provider, amount)?;
+
+        // Outstanding IOUs are repaid before the deposit becomes liquidity
+        let amortized = amortize_ious(&env, amount)?;
+
+        // Safe arithmetic with overflow check
+        provider_info.0 = provider_info.0.checked_add(amount).ok_or(ContractError::Overflow)?;
+        provider_info.1 = provider_info.1.checked_add(minted).ok_or(ContractError::Overflow)?;
+        stats.0 = stats.0.checked_add(amount - amortized).ok_or(ContractError::Overflow)?;
+        stats.1 = stats.1.checked_add(amortized).ok_or(ContractError::Overflow)?;
+        stats.2 = stats.2.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+        let new_total_stake = total_stake(&env).checked_add(minted).ok_or(ContractError::Overflow)?;
+
+        index_provider(&env, &provider);
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER, provider.clone()), &provider_info);
+        env.storage()
+            .persistent()
+            .set(&(LAST_DEPOSIT, provider.clone()), &env.ledger().sequence());
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        env.storage()
+            .instance()
+            .set(&TOTAL_STAKE, &new_total_stake);
+
+        // I1/I8: Assert liquidity and reserve invariants hold after deposit
+        check_liquidity_invariant(&env)?;
+        check_reserve_adequacy(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "liquidity_deposited"), provider.clone()),
+            (amount, minted, provider_info.1),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_pool_stats(env: Env) -> Result<(i128, i128, i128, u64), ContractError> {
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        
+        Ok(stats)
+    }
+
+    /// Hash over `(total_liquidity, total_paid_out, total_deposited,
+    /// reserved_total, unearned_premium, outstanding_exposure)` as of the last
+    /// checkpoint, in that order.
+    pub fn get_state_root(env: Env) -> Option<StateRoot> {
+        state_root::get(&env)
+    }
+
+    /// Record a checkpoint for the current epoch. Permissionless so keepers can
+    /// fill epochs without pool activity.
+    pub fn checkpoint_stats(env: Env) -> Result<(), ContractError> {
+        checkpoint_stats(&env)
+    }
+
+    /// Checkpoints with `from_epoch <= epoch <= to_epoch`, oldest first.
+    /// An epoch is `timestamp / 86400`.
+    pub fn get_stats_history(env: Env, from_epoch: u64, to_epoch: u64) -> Result<Vec<PoolStatsCheckpoint>, ContractError> {
+        if from_epoch > to_epoch {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let epochs: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&STATS_EPOCHS)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut history = Vec::new(&env);
+        for epoch in epochs.iter() {
+            if epoch < from_epoch || epoch > to_epoch {
+                continue;
+            }
+            if let Some(checkpoint) = env
+                .storage()
+                .persistent()
+                .get::<_, PoolStatsCheckpoint>(&(STATS_CHECKPOINT, epoch))
+            {
+                history.push_back(checkpoint);
+            }
+        }
+        Ok(history)
+    }
+
+    /// Evaluate the pool's invariants (I1, I4, I8) against live storage.
+    /// For monitoring; each violation is published as an
+    /// `invariant_violation` event, so calling it in a transaction records
+    /// what it found.
+    pub fn check_invariants(env: Env) -> InvariantReport {
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .unwrap_or((0, 0, 0, 0));
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
+            .get(&RESERVED_TOTAL)
+            .unwrap_or(0i128);
+        let unearned = unearned_total(&env);
+
+        let mut report = InvariantReport::new(&env);
+        report.record(Invariant::LiquidityPreservation, stats.0 >= reserved_total);
+        report.record(
+            Invariant::AmountNonNegativity,
+            stats.0 >= 0
+                && stats.1 >= 0
+                && stats.2 >= 0
+                && reserved_total >= 0
+                && unearned >= 0
+                && total_stake(&env) >= 0
+                && outstanding_exposure(&env) >= 0,
+        );
+        report.record(
+            Invariant::ReserveAdequacy,
+            unearned.checked_add(reserved_total).is_some_and(|required| stats.0 >= required),
+        );
+        report
+    }
+
+    /// Recompute pool aggregates from provider records and claim
+    /// reservations and report any drift from the stored totals.
+    /// Permissionless; the report is also emitted as an event.
+    pub fn reconcile(env: Env) -> Result<DriftReport, ContractError> {
+        let report = compute_drift(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "reconciliation"), report.has_drift),
+            report.clone(),
+        );
+
+        Ok(report)
+    }
+
+    /// Overwrite drifted aggregates with the recomputed values (admin only).
+    /// Returns the report taken before the correction.
+    pub fn correct_aggregates(env: Env, admin: Address) -> Result<DriftReport, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let report = compute_drift(&env)?;
+        if !report.has_drift {
+            return Ok(report);
+        }
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        stats.2 = report.computed_deposited;
+
+        // Emission so far is shared over the stake as it was recorded
+        accrue_rewards(&env)?;
+        env.storage()
+            .instance()
+            .set(&TOTAL_STAKE, &report.computed_stake);
+        env.storage()
+            .instance()
+            .set(&RESERVED_TOTAL, &report.computed_reserved);
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+
+        // I1: The corrected reservations must still be covered
+        check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "aggregates_corrected"), admin),
+            report.clone(),
+        );
+
+        Ok(report)
+    }
+
+    /// Liquidity providers in first-deposit order, `limit` at a time
+    pub fn get_providers(env: Env, cursor: u32, limit: u32) -> AddressPage {
+        let providers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&PROVIDER_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        pagination::page_of(&env, &providers, cursor, limit).into()
+    }
+
+    /// Start a snapshot of the providers registered so far (admin only).
+    /// Export it with `export_provider_snapshot`.
+    pub fn start_provider_snapshot(env: Env, admin: Address) -> Result<u64, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let providers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&PROVIDER_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        let snapshot = snapshot::start(&env, providers.len());
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "snapshot_started"), snapshot.id),
+            (snapshot.ledger, snapshot.total),
+        );
+
+        Ok(snapshot.id)
+    }
+
+    /// Emit a `provider_snapshot` event with `(provider, balance)` for each
+    /// of the next `limit` providers of the snapshot. Permissionless so
+    /// keepers can drive the export.
+    pub fn export_provider_snapshot(env: Env, snapshot_id: u64, limit: u32) -> Result<Snapshot, ContractError> {
+        let (from, to, snapshot) = snapshot::next_chunk(&env, snapshot_id, limit)?;
+
+        let providers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&PROVIDER_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        for index in from..to {
+            let provider = match providers.get(index) {
+                Some(provider) => provider,
+                None => break,
+            };
+            let provider_info: (i128, i128, u64) = env
+                .storage()
+                .persistent()
+                .get(&(PROVIDER, provider.clone()))
+                .unwrap_or((0, 0, 0));
+            publish_event(
+                &env,
+                (Symbol::new(&env, "provider_snapshot"), snapshot_id),
+                (provider, provider_info.0),
+            );
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Latest snapshot and how far its export has progressed
+    pub fn get_snapshot(env: Env) -> Option<Snapshot> {
+        snapshot::get(&env)
+    }
+
+    /// Current value of a provider's shares
+    pub fn get_provider_value(env: Env, provider: Address) -> Result<i128, ContractError> {
+        let provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider))
+            .ok_or(ContractError::NotFound)?;
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        share_value(&env, &stats, provider_info.1)
+    }
+
+    /// `(deposited, shares, first deposit time)` of a provider
+    pub fn get_provider_info(env: Env, provider: Address) -> Result<(i128, i128, u64), ContractError> {
+        validate_address(&env, &provider)?;
+        
+        let provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider))
+            .ok_or(ContractError::NotFound)?;
+        
+        Ok(provider_info)
+    }
+
+    /// Move pool shares between providers. Rewards accrued before the
+    /// transfer stay with the sender; the recipient only earns on the
+    /// transferred shares from this point on.
+    pub fn transfer_shares(env: Env, from: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+        from.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_address(&env, &to)?;
+        validate_amount(&env, amount)?;
+
+        if from == to {
+            return Err(ContractError::InvalidInput);
+        }
+        require_deposit_unlocked(&env, &from)?;
+
+        let config: (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut from_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, from.clone()))
+            .ok_or(ContractError::NotFound)?;
+        if from_info.1 < amount {
+            return Err(ContractError::InsufficientFunds);
+        }
+        require_exposure_unlocked(&env, &from, from_info.1 - amount)?;
+
+        let mut to_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, to.clone()))
+            .unwrap_or((0i128, 0i128, env.ledger().timestamp()));
+        if to_info.1 + amount < config.1 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        checkpoint_rewards(&env, &from, from_info.1)?;
+        checkpoint_rewards(&env, &to, to_info.1)?;
+
+        from_info.1 = from_info.1.checked_sub(amount).ok_or(ContractError::Overflow)?;
+        to_info.1 = to_info.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+        index_provider(&env, &to);
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER, from.clone()), &from_info);
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER, to.clone()), &to_info);
+
+        if let Some(hook) = env.storage().instance().get::<_, Address>(&TRANSFER_HOOK) {
+            TransferHookClient::new(&env, &hook).on_share_transfer(&from, &to, &amount);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "shares_transferred"), from),
+            (to, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Queue a withdrawal of `amount` shares and mint a transferable receipt
+    /// for their value. The shares are burned immediately at the current
+    /// pool value; the liquidity is released to the receipt owner once the
+    /// cooldown matures. Only the provider's unlocked share can be queued
+    /// while claims are reserved; the rest unlocks as reservations resolve.
+    pub fn request_withdrawal(env: Env, provider: Address, amount: i128) -> Result<u64, ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(&env, amount)?;
+
+        let config: (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .ok_or(ContractError::NotFound)?;
+        if provider_info.1 < amount {
+            return Err(ContractError::InsufficientFunds);
+        }
+        require_deposit_unlocked(&env, &provider)?;
+        require_exposure_unlocked(&env, &provider, provider_info.1 - amount)?;
+
+        let (withdrawable, locked_shares) = withdrawable_split(&env, &provider, provider_info.1)?;
+        if amount > withdrawable {
+            return Err(ContractError::LiquidityLocked);
+        }
+
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let value = share_value(&env, &stats, amount)?;
+
+        // A provider either stays above the minimum stake or exits fully
+        let remaining = provider_info.1 - amount;
+        if value <= 0 || (remaining > 0 && share_value(&env, &stats, remaining)? < config.1) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        checkpoint_rewards(&env, &provider, provider_info.1)?;
+
+        provider_info.1 = remaining;
+        let new_total_stake = total_stake(&env).checked_sub(amount).ok_or(ContractError::Overflow)?;
+        let pending: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_PENDING)
+            .unwrap_or(0i128);
+        let pending = pending.checked_add(value).ok_or(ContractError::Overflow)?;
+
+        let now = env.ledger().timestamp();
+        let receipt_id: u64 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_COUNTER)
+            .unwrap_or(0u64) + 1;
+        let receipt = WithdrawalReceipt {
+            owner: provider.clone(),
+            provider: provider.clone(),
+            amount: value,
+            requested_at: now,
+            matures_at: now.saturating_add(withdrawal_cooldown(&env)),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER, provider.clone()), &provider_info);
+        record_provider_lock(&env, &provider, locked_shares)?;
+        env.storage()
+            .instance()
+            .set(&TOTAL_STAKE, &new_total_stake);
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_PENDING, &pending);
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_COUNTER, &receipt_id);
+        env.storage()
+            .persistent()
+            .set(&(WITHDRAWAL_RECEIPT, receipt_id), &receipt);
+
+        // Everything queued before this receipt is filled ahead of it
+        let queued: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_QUEUED_TOTAL)
+            .unwrap_or(0i128);
+        env.storage()
+            .persistent()
+            .set(&(WITHDRAWAL_QUEUE_START, receipt_id), &queued);
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_QUEUED_TOTAL, &queued.checked_add(value).ok_or(ContractError::Overflow)?);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "withdrawal_requested"), provider),
+            (receipt_id, amount, value, receipt.matures_at),
+        );
+
+        Ok(receipt_id)
+    }
+
+    /// Burn `amount` shares and withdraw their value at once, without a
+    /// receipt. Only liquidity that exits may take and that no queued
+    /// receipt is waiting for can be withdrawn, so the pool never drops
+    /// below its reservations (I1) and queued exits are never overtaken;
+    /// anything more goes through `request_withdrawal`.
+    pub fn withdraw_liquidity(env: Env, provider: Address, amount: i128) -> Result<(), ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(&env, amount)?;
+
+        let config: (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .ok_or(ContractError::NotFound)?;
+        if provider_info.1 < amount {
+            return Err(ContractError::InsufficientFunds);
+        }
+        require_deposit_unlocked(&env, &provider)?;
+        require_exposure_unlocked(&env, &provider, provider_info.1 - amount)?;
+
+        let (withdrawable, locked_shares) = withdrawable_split(&env, &provider, provider_info.1)?;
+        if amount > withdrawable {
+            return Err(ContractError::LiquidityLocked);
+        }
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let value = share_value(&env, &stats, amount)?;
+
+        // A provider either stays above the minimum stake or exits fully
+        let remaining = provider_info.1 - amount;
+        if value <= 0 || (remaining > 0 && share_value(&env, &stats, remaining)? < config.1) {
+            return Err(ContractError::InvalidInput);
+        }
+        let queued: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_QUEUED_TOTAL)
+            .unwrap_or(0i128);
+        let filled: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_FILLED_TOTAL)
+            .unwrap_or(0i128);
+        let available = free_liquidity(&env, &stats)?
+            .checked_sub(queued - filled)
+            .ok_or(ContractError::Overflow)?;
+        if value > available {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        checkpoint_rewards(&env, &provider, provider_info.1)?;
+
+        provider_info.1 = remaining;
+        stats.0 = stats.0.checked_sub(value).ok_or(ContractError::Overflow)?;
+        require_buffer(&env, stats.0)?;
+        let new_total_stake = total_stake(&env).checked_sub(amount).ok_or(ContractError::Overflow)?;
+
+        env.storage()
+            .persistent()
+            .set(&(PROVIDER, provider.clone()), &provider_info);
+        record_provider_lock(&env, &provider, locked_shares)?;
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        env.storage()
+            .instance()
+            .set(&TOTAL_STAKE, &new_total_stake);
+
+        // I1/I8: Assert liquidity and reserve invariants hold after withdrawal
+        check_liquidity_invariant(&env)?;
+        check_reserve_adequacy(&env)?;
+        checkpoint_stats(&env)?;
+
+        pay(&env, &provider, value)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "liquidity_withdrawn"), provider),
+            (amount, value, remaining),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer a withdrawal receipt, e.g. to sell a pending exit
+    pub fn transfer_withdrawal_receipt(env: Env, from: Address, to: Address, receipt_id: u64) -> Result<(), ContractError> {
+        from.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_address(&env, &to)?;
+
+        let mut receipt = get_withdrawal_receipt(&env, receipt_id)?;
+        if receipt.owner != from {
+            return Err(ContractError::Unauthorized);
+        }
+        if from == to {
+            return Err(ContractError::InvalidInput);
+        }
+
+        receipt.owner = to.clone();
+        env.storage()
+            .persistent()
+            .set(&(WITHDRAWAL_RECEIPT, receipt_id), &receipt);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "withdrawal_receipt_transferred"), receipt_id),
+            (from, to),
+        );
+
+        Ok(())
+    }
+
+    /// Pay the owner of a matured receipt what has been filled of it,
+    /// filling the exit queue first. The receipt is burned once fully paid.
+    /// Fails while nothing of it is filled, e.g. while receipts ahead of it
+    /// take all free liquidity.
+    pub fn redeem_withdrawal(env: Env, owner: Address, receipt_id: u64) -> Result<i128, ContractError> {
+        owner.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut receipt = get_withdrawal_receipt(&env, receipt_id)?;
+        if receipt.owner != owner {
+            return Err(ContractError::Unauthorized);
+        }
+        if env.ledger().timestamp() < receipt.matures_at {
+            return Err(ContractError::WithdrawalNotMatured);
+        }
+
+        fill_exit_queue(&env, MAX_EXIT_FILLS)?;
+        let paid = receipt_filled(&env, receipt_id);
+        if paid <= 0 {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        let pending: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_PENDING)
+            .unwrap_or(0i128);
+
+        receipt.amount -= paid;
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_PENDING, &(pending - paid).max(0));
+        env.storage()
+            .persistent()
+            .remove(&(WITHDRAWAL_FILLED, receipt_id));
+        if receipt.amount == 0 {
+            env.storage()
+                .persistent()
+                .remove(&(WITHDRAWAL_RECEIPT, receipt_id));
+            env.storage()
+                .persistent()
+                .remove(&(WITHDRAWAL_QUEUE_START, receipt_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&(WITHDRAWAL_RECEIPT, receipt_id), &receipt);
+        }
+
+        // I1/I8: Assert liquidity and reserve invariants hold after withdrawal
+        check_liquidity_invariant(&env)?;
+        check_reserve_adequacy(&env)?;
+        checkpoint_stats(&env)?;
+
+        pay(&env, &owner, paid)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "withdrawal_redeemed"), receipt_id),
+            (owner, paid, receipt.amount),
+        );
+
+        Ok(paid)
+    }
+
+    /// Fill matured receipts in up to `max_n` slots at the front of the exit
+    /// queue out of the liquidity freed since the last pass. Anyone may call
+    /// it; returns the amount filled.
+    pub fn process_exit_queue(env: Env, max_n: u32) -> Result<i128, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let filled = fill_exit_queue(&env, max_n.min(MAX_EXIT_FILLS))?;
+        if filled > 0 {
+            check_liquidity_invariant(&env)?;
+            check_reserve_adequacy(&env)?;
+            checkpoint_stats(&env)?;
+        }
+
+        Ok(filled)
+    }
+
+    /// Position of a receipt in the exit queue, what is queued ahead of it
+    /// and how much of it the free liquidity would cover right now
+    pub fn get_exit_queue_status(env: Env, receipt_id: u64) -> Result<ExitQueueStatus, ContractError> {
+        let receipt = get_withdrawal_receipt(&env, receipt_id)?;
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+
+        let filled = receipt_filled(&env, receipt_id);
+        let unfilled = receipt.amount - filled;
+        let filled_total: i128 = env
+            .storage()
+            .instance()
+            .get(&WITHDRAWAL_FILLED_TOTAL)
+            .unwrap_or(0i128);
+        let start: i128 = env
+            .storage()
+            .persistent()
+            .get(&(WITHDRAWAL_QUEUE_START, receipt_id))
+            .unwrap_or(0i128);
+
+        let (position, ahead) = if unfilled > 0 {
+            (
+                receipt_id.saturating_sub(exit_queue_head(&env)) as u32,
+                start.saturating_sub(filled_total).max(0),
+            )
+        } else {
+            (0, 0)
+        };
+        let fillable_now = unfilled.min(free_liquidity(&env, &stats)?.saturating_sub(ahead).max(0));
+
+        Ok(ExitQueueStatus { position, ahead, filled, unfilled, fillable_now })
+    }
+
+    pub fn get_withdrawal_receipt(env: Env, receipt_id: u64) -> Result<WithdrawalReceipt, ContractError> {
+        get_withdrawal_receipt(&env, receipt_id)
+    }
+
+    /// `(withdrawable, locked)` parts of a provider's stake under the current
+    /// claim reservations and unearned premium
+    pub fn get_withdrawable(env: Env, provider: Address) -> Result<(i128, i128), ContractError> {
+        let provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .ok_or(ContractError::NotFound)?;
+        withdrawable_split(&env, &provider, provider_info.1)
+    }
+
+    /// Value of withdrawal receipts not yet redeemed
+    pub fn get_pending_withdrawals(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&WITHDRAWAL_PENDING)
+            .unwrap_or(0i128)
+    }
+
+    /// Set the cooldown applied to new withdrawal requests (governance only)
+    pub fn set_withdrawal_cooldown(env: Env, caller: Address, cooldown_seconds: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if cooldown_seconds > MAX_WITHDRAWAL_COOLDOWN_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .instance()
+            .set(&WITHDRAWAL_COOLDOWN, &cooldown_seconds);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "withdrawal_cooldown_set"), caller),
+            cooldown_seconds,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_withdrawal_cooldown(env: Env) -> u64 {
+        withdrawal_cooldown(&env)
+    }
+
+    /// Set how many ledgers after a deposit the provider's shares stay
+    /// locked (governance only). At least one, which rules out depositing
+    /// and withdrawing in the same ledger.
+    pub fn set_deposit_lock(env: Env, caller: Address, ledgers: u32) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if ledgers == 0 || ledgers > MAX_DEPOSIT_LOCK_LEDGERS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DEPOSIT_LOCK, &ledgers);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "deposit_lock_set"), caller),
+            ledgers,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_deposit_lock(env: Env) -> u32 {
+        deposit_lock_ledgers(&env)
+    }
+
+    /// First ledger in which the provider may withdraw or transfer shares
+    pub fn get_deposit_unlock_ledger(env: Env, provider: Address) -> u32 {
+        deposit_unlock_ledger(&env, &provider)
+    }
+
+    /// Change the minimum provider stake (governance only). The pool token is
+    /// fixed at initialization.
+    pub fn update_config(env: Env, caller: Address, min_provider_stake: i128) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if min_provider_stake <= 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let (token, old_min_stake): (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        env.storage()
+            .instance()
+            .set(&CONFIG, &(token, min_provider_stake));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "config_updated"), caller),
+            (old_min_stake, min_provider_stake),
+        );
+
+        Ok(())
+    }
+
+    /// Pool configuration, peers, pause state and version in one call
+    pub fn get_protocol_config(env: Env) -> Result<ProtocolConfig, ContractError> {
+        let (token, min_provider_stake): (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&CONFIG)
+            .ok_or(ContractError::NotInitialized)?;
+        let claims_contract: Address = env
+            .storage()
+            .instance()
+            .get(&CLAIMS_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+
+        Ok(ProtocolConfig {
+            token,
+            min_provider_stake,
+            claims_contract,
+            withdrawal_cooldown: withdrawal_cooldown(&env),
+            rate_model: env
+                .storage()
+                .instance()
+                .get(&RATE_MODEL)
+                .map_or(RateModelSetting::Disabled, RateModelSetting::Enabled),
+            stress: env
+                .storage()
+                .instance()
+                .get(&STRESS_MODE)
+                .map_or(StressSetting::Disabled, StressSetting::Enabled),
+            allowlist_enabled: allowlist::is_enabled(&env),
+            wired: wiring::is_wired(&env),
+            paused: is_paused(&env),
+            version: version::get(&env, env!("CARGO_PKG_VERSION")),
+        })
+    }
+
+    /// Stream rewards to providers over the next `REWARD_STREAM_SECONDS`,
+    /// together with what the current stream has not paid out yet. Each
+    /// provider earns pro rata to its stake for as long as it is staked, so
+    /// stake added just before a distribution gains nothing from earlier
+    /// emission.
+    pub fn distribute_rewards(env: Env, manager: Address, amount: i128) -> Result<(), ContractError> {
+        manager.require_auth();
+        require_risk_pool_management(&env, &manager)?;
+
+        validate_amount(&env, amount)?;
+
+        if total_stake(&env) <= 0 {
+            return Err(ContractError::InvalidState);
+        }
+
+        collect(&env, &manager, amount)?;
+
+        // Whatever the rate cannot represent is kept as dust rather than
+        // credited to no one
+        let mut stream = accrue_rewards(&env)?;
+        let dust = stream.fund(env.ledger().timestamp(), amount, REWARD_STREAM_SECONDS)?;
+        if stream.rate == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        env.storage().instance().set(&REWARD_STREAM, &stream);
+        add_protocol_dust(&env, dust)?;
+        adjust_rewards_held(&env, amount - dust)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "rewards_distributed"), manager),
+            (amount, stream.rate, stream.ends_at, dust),
+        );
+
+        Ok(())
+    }
+
+    /// Current reward index, emission rate and end of the emission period
+    pub fn get_reward_stream(env: Env) -> RewardStream {
+        reward_stream(&env)
+    }
+
+    /// Amounts left unallocated by rounding in the pool's favor
+    pub fn get_protocol_dust(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&PROTOCOL_DUST)
+            .unwrap_or(0i128)
+    }
+
+    /// Rewards accrued by a provider and not yet claimed
+    pub fn get_pending_rewards(env: Env, provider: Address) -> Result<i128, ContractError> {
+        let provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .ok_or(ContractError::NotFound)?;
+        let mut stream = reward_stream(&env);
+        stream.accrue(env.ledger().timestamp(), total_stake(&env))?;
+        let (paid_index, accrued): (i128, i128) = env
+            .storage()
+            .persistent()
+            .get(&(REWARDS, provider))
+            .unwrap_or((stream.index, 0i128));
+
+        let earned = stream.earned(provider_info.1, paid_index)?;
+        accrued.checked_add(earned).ok_or(ContractError::Overflow)
+    }
+
+    pub fn claim_rewards(env: Env, provider: Address) -> Result<i128, ContractError> {
+        provider.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let provider_info: (i128, i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(PROVIDER, provider.clone()))
+            .ok_or(ContractError::NotFound)?;
+
+        let accrued = checkpoint_rewards(&env, &provider, provider_info.1)?;
+        env.storage()
+            .persistent()
+            .set(&(REWARDS, provider.clone()), &(reward_stream(&env).index, 0i128));
+        adjust_rewards_held(&env, -accrued)?;
+
+        pay(&env, &provider, accrued)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "rewards_claimed"), provider),
+            accrued,
+        );
+
+        Ok(accrued)
+    }
+
+    /// Set or clear the contract notified on share transfers (admin only)
+    pub fn set_transfer_hook(env: Env, admin: Address, hook: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        match hook.clone() {
+            Some(hook) => env.storage().instance().set(&TRANSFER_HOOK, &hook),
+            None => env.storage().instance().remove(&TRANSFER_HOOK),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "transfer_hook_set"), ()),
+            hook,
+        );
+
+        Ok(())
+    }
+
+    /// Credit premium the policy contract has transferred to the pool
+    /// (trusted contracts only). Premium income becomes pool liquidity.
+    pub fn receive_premium(env: Env, caller_contract: Address, policy_id: u64, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(&env, amount)?;
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let income: i128 = env
+            .storage()
+            .instance()
+            .get(&PREMIUM_INCOME)
+            .unwrap_or(0i128);
+        let policy_premium: i128 = env
+            .storage()
+            .persistent()
+            .get(&(POLICY_PREMIUM, policy_id))
+            .unwrap_or(0i128);
+
+        stats.0 = stats.0.checked_add(amount).ok_or(ContractError::Overflow)?;
+        let income = income.checked_add(amount).ok_or(ContractError::Overflow)?;
+        let policy_premium = policy_premium.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        env.storage()
+            .instance()
+            .set(&PREMIUM_INCOME, &income);
+        env.storage()
+            .persistent()
+            .set(&(POLICY_PREMIUM, policy_id), &policy_premium);
+
+        // Premium for coverage still running is held as unearned until the
+        // coverage period it pays for has passed
+        let now = env.ledger().timestamp();
+        if let Some((_, expiry)) = env.storage().persistent().get::<_, (i128, u64)>(&(COVERAGE, policy_id)) {
+            if expiry > now {
+                accrue_premium(&env, policy_id, false)?;
+                let mut reserve: PremiumReserve = env
+                    .storage()
+                    .persistent()
+                    .get(&(PREMIUM_RESERVE, policy_id))
+                    .unwrap_or(PremiumReserve {
+                        unearned: 0,
+                        earned: 0,
+                        accrued_at: now,
+                        expiry,
+                    });
+                reserve.unearned = reserve.unearned.checked_add(amount).ok_or(ContractError::Overflow)?;
+                reserve.accrued_at = now;
+                env.storage()
+                    .persistent()
+                    .set(&(PREMIUM_RESERVE, policy_id), &reserve);
+
+                let total = unearned_total(&env).checked_add(amount).ok_or(ContractError::Overflow)?;
+                env.storage().instance().set(&UNEARNED_TOTAL, &total);
+            }
+        }
+
+        // I8: The premium arrives with the liquidity backing it
+        check_reserve_adequacy(&env)?;
+
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "premium_received"), policy_id),
+            (amount, income),
+        );
+
+        Ok(())
+    }
+
+    /// Track a newly issued policy's exposure (trusted contracts only).
+    /// Policies tagged with a region also count against that region's
+    /// capacity limit, policies of a product covering an asset (such as a
+    /// depeg product) against that asset's capacity limit, and policies of a
+    /// grouped product against their correlation group's share of total
+    /// exposure.
+    pub fn register_coverage(env: Env, caller_contract: Address, policy_id: u64, product_id: u32, coverage_amount: i128, expiry: u64, region: Option<u32>) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, coverage_amount)?;
+
+        if env.storage().persistent().has(&(COVERAGE, policy_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let exposure = outstanding_exposure(&env)
+            .checked_add(coverage_amount)
+            .ok_or(ContractError::Overflow)?;
+
+        if let Some(region) = region {
+            let regional = region_exposure(&env, region)
+                .checked_add(coverage_amount)
+                .ok_or(ContractError::Overflow)?;
+            let cap: Option<i128> = env.storage().persistent().get(&(REGION_CAP, region));
+            if cap.is_some_and(|cap| regional > cap) {
+                return Err(ContractError::RegionCapacityExceeded);
+            }
+
+            env.storage()
+                .persistent()
+                .set(&(COVERAGE_REGION, policy_id), &region);
+            env.storage()
+                .persistent()
+                .set(&(REGION_EXPOSURE, region), &regional);
+        }
+        let asset: Option<Address> = env.storage().persistent().get(&(PRODUCT_ASSET, product_id));
+        if let Some(asset) = asset {
+            let covered = covered_asset_exposure(&env, &asset)
+                .checked_add(coverage_amount)
+                .ok_or(ContractError::Overflow)?;
+            let cap: Option<i128> = env.storage().persistent().get(&(ASSET_CAP, asset.clone()));
+            if cap.is_some_and(|cap| covered > cap) {
+                return Err(ContractError::AssetCapacityExceeded);
+            }
+
+            env.storage()
+                .persistent()
+                .set(&(COVERAGE_ASSET, policy_id), &asset);
+            env.storage()
+                .persistent()
+                .set(&(ASSET_EXPOSURE, asset), &covered);
+        }
+        add_group_exposure(&env, policy_id, product_id, coverage_amount, exposure)?;
+        add_expected_loss(&env, policy_id, product_id, coverage_amount, expiry)?;
+
+        let product_total = product_exposure(&env, product_id)
+            .checked_add(coverage_amount)
+            .ok_or(ContractError::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&(COVERAGE_PRODUCT, policy_id), &product_id);
+        env.storage()
+            .persistent()
+            .set(&(PRODUCT_EXPOSURE, product_id), &product_total);
+
+        env.storage()
+            .persistent()
+            .set(&(COVERAGE, policy_id), &(coverage_amount, expiry));
+        env.storage()
+            .instance()
+            .set(&COVERAGE_TOTAL, &exposure);
+
+        // I8: New policies may not be written against an under-reserved pool
+        check_reserve_adequacy(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "coverage_registered"), policy_id),
+            (coverage_amount, expiry, exposure),
+        );
+
+        Ok(())
+    }
+
+    /// Stop tracking a policy that expired, was cancelled or was claimed
+    /// (trusted contracts only)
+    pub fn deregister_coverage(env: Env, caller_contract: Address, policy_id: u64) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        let (coverage_amount, _): (i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(COVERAGE, policy_id))
+            .ok_or(ContractError::NotFound)?;
+        let exposure = (outstanding_exposure(&env) - coverage_amount).max(0);
+
+        // The pool no longer carries the risk, so the premium is earned
+        accrue_premium(&env, policy_id, true)?;
+
+        env.storage()
+            .persistent()
+            .remove(&(COVERAGE, policy_id));
+        env.storage()
+            .instance()
+            .set(&COVERAGE_TOTAL, &exposure);
+
+        let region: Option<u32> = env.storage().persistent().get(&(COVERAGE_REGION, policy_id));
+        if let Some(region) = region {
+            let regional = (region_exposure(&env, region) - coverage_amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&(REGION_EXPOSURE, region), &regional);
+            env.storage()
+                .persistent()
+                .remove(&(COVERAGE_REGION, policy_id));
+        }
+
+        let asset: Option<Address> = env.storage().persistent().get(&(COVERAGE_ASSET, policy_id));
+        if let Some(asset) = asset {
+            let covered = (covered_asset_exposure(&env, &asset) - coverage_amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&(ASSET_EXPOSURE, asset), &covered);
+            env.storage()
+                .persistent()
+                .remove(&(COVERAGE_ASSET, policy_id));
+        }
+
+        let node_id: Option<BytesN<32>> = env.storage().persistent().get(&(COVERAGE_NODE, policy_id));
+        if let Some(node_id) = node_id {
+            let covered = (node_exposure(&env, &node_id) - coverage_amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&(NODE_EXPOSURE, node_id), &covered);
+            env.storage()
+                .persistent()
+                .remove(&(COVERAGE_NODE, policy_id));
+        }
+
+        let group: Option<u32> = env.storage().persistent().get(&(COVERAGE_GROUP, policy_id));
+        if let Some(group) = group {
+            let grouped = (group_exposure(&env, group) - coverage_amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&(GROUP_EXPOSURE, group), &grouped);
+            env.storage()
+                .persistent()
+                .remove(&(COVERAGE_GROUP, policy_id));
+        }
+
+        let product_id: Option<u32> = env.storage().persistent().get(&(COVERAGE_PRODUCT, policy_id));
+        if let Some(product_id) = product_id {
+            let product_total = (product_exposure(&env, product_id) - coverage_amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&(PRODUCT_EXPOSURE, product_id), &product_total);
+            env.storage()
+                .persistent()
+                .remove(&(COVERAGE_PRODUCT, policy_id));
+        }
+
+        let loss: Option<i128> = env.storage().persistent().get(&(EXPECTED_LOSS, policy_id));
+        if let Some(loss) = loss {
+            env.storage()
+                .instance()
+                .set(&EXPECTED_LOSS_TOTAL, &(expected_losses(&env) - loss).max(0));
+            env.storage()
+                .persistent()
+                .remove(&(EXPECTED_LOSS, policy_id));
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "coverage_deregistered"), policy_id),
+            (coverage_amount, exposure),
+        );
+
+        Ok(())
+    }
+
+    /// `(coverage_amount, expiry)` of a registered policy
+    pub fn get_coverage(env: Env, policy_id: u64) -> Option<(i128, u64)> {
+        env.storage()
+            .persistent()
+            .get(&(COVERAGE, policy_id))
+    }
+
+    /// Coverage of all registered policies
+    pub fn get_outstanding_exposure(env: Env) -> i128 {
+        outstanding_exposure(&env)
+    }
+
+    /// Set or clear the most coverage the pool will carry in one region
+    /// (governance only). Lowering a cap below the current exposure only
+    /// blocks new policies in the region.
+    pub fn set_region_capacity(env: Env, caller: Address, region: u32, max_exposure: Option<i128>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match max_exposure {
+            Some(max_exposure) => {
+                if max_exposure <= 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(REGION_CAP, region), &max_exposure);
+            }
+            None => env.storage().persistent().remove(&(REGION_CAP, region)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "region_capacity_set"), region),
+            max_exposure,
+        );
+
+        Ok(())
+    }
+
+    /// Set or clear the minimum liquidity no withdrawal, payout or fee may
+    /// take the pool below (governance only)
+    pub fn set_liquidity_buffer(env: Env, caller: Address, buffer: Option<LiquidityBuffer>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match buffer.clone() {
+            Some(buffer) => {
+                let valid = match buffer {
+                    LiquidityBuffer::Absolute(amount) => amount > 0,
+                    LiquidityBuffer::CoverageBps(bps) => bps > 0 && bps <= MAX_BUFFER_BPS,
+                };
+                if !valid {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage().instance().set(&LIQUIDITY_BUFFER, &buffer);
+            }
+            None => env.storage().instance().remove(&LIQUIDITY_BUFFER),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "liquidity_buffer_set"), caller),
+            buffer,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_liquidity_buffer(env: Env) -> Option<LiquidityBuffer> {
+        env.storage().instance().get(&LIQUIDITY_BUFFER)
+    }
+
+    /// Liquidity the buffer requires at the current outstanding coverage
+    pub fn get_required_buffer(env: Env) -> Result<i128, ContractError> {
+        required_buffer(&env)
+    }
+
+    pub fn get_region_capacity(env: Env, region: u32) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&(REGION_CAP, region))
+    }
+
+    /// Coverage of all registered policies in a region
+    pub fn get_region_exposure(env: Env, region: u32) -> i128 {
+        region_exposure(&env, region)
+    }
+
+    /// Book a registered downtime policy against the node it covers
+    /// (trusted contracts only). Fails if the node would carry more than
+    /// the per-node capacity.
+    pub fn register_node_coverage(env: Env, caller_contract: Address, policy_id: u64, node_id: BytesN<32>) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        let (coverage_amount, _): (i128, u64) = env
+            .storage()
+            .persistent()
+            .get(&(COVERAGE, policy_id))
+            .ok_or(ContractError::NotFound)?;
+        if env.storage().persistent().has(&(COVERAGE_NODE, policy_id)) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let covered = node_exposure(&env, &node_id)
+            .checked_add(coverage_amount)
+            .ok_or(ContractError::Overflow)?;
+        let cap: Option<i128> = env.storage().instance().get(&NODE_CAP);
+        if cap.is_some_and(|cap| covered > cap) {
+            return Err(ContractError::NodeCapacityExceeded);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(COVERAGE_NODE, policy_id), &node_id);
+        env.storage()
+            .persistent()
+            .set(&(NODE_EXPOSURE, node_id.clone()), &covered);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "node_coverage_registered"), policy_id),
+            (node_id, covered),
+        );
+
+        Ok(())
+    }
+
+    /// Set or clear the most coverage the pool will carry on any single
+    /// node (governance only)
+    pub fn set_node_capacity(env: Env, caller: Address, max_exposure: Option<i128>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match max_exposure {
+            Some(max_exposure) => {
+                if max_exposure <= 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage().instance().set(&NODE_CAP, &max_exposure);
+            }
+            None => env.storage().instance().remove(&NODE_CAP),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "node_capacity_set"), caller),
+            max_exposure,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_node_capacity(env: Env) -> Option<i128> {
+        env.storage().instance().get(&NODE_CAP)
+    }
+
+    /// Coverage of all registered policies on a node
+    pub fn get_node_exposure(env: Env, node_id: BytesN<32>) -> i128 {
+        node_exposure(&env, &node_id)
+    }
+
+    /// Tag a product as covering an asset, such as the stablecoin of a depeg
+    /// product, or clear the tag (governance only). Policies already
+    /// registered stay booked against the asset they were written on.
+    pub fn set_product_asset(env: Env, caller: Address, product_id: u32, asset: Option<Address>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &asset {
+            Some(asset) => env
+                .storage()
+                .persistent()
+                .set(&(PRODUCT_ASSET, product_id), asset),
+            None => env.storage().persistent().remove(&(PRODUCT_ASSET, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "product_asset_set"), product_id),
+            asset,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_product_asset(env: Env, product_id: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&(PRODUCT_ASSET, product_id))
+    }
+
+    /// Set or clear the most coverage the pool will carry on one covered
+    /// asset (governance only). Lowering a cap below the current exposure
+    /// only blocks new policies on the asset.
+    pub fn set_asset_capacity(env: Env, caller: Address, asset: Address, max_exposure: Option<i128>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match max_exposure {
+            Some(max_exposure) => {
+                if max_exposure <= 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(ASSET_CAP, asset.clone()), &max_exposure);
+            }
+            None => env.storage().persistent().remove(&(ASSET_CAP, asset.clone())),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "asset_capacity_set"), asset),
+            max_exposure,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_asset_capacity(env: Env, asset: Address) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&(ASSET_CAP, asset))
+    }
+
+    /// Coverage of all registered policies on a covered asset
+    pub fn get_asset_exposure(env: Env, asset: Address) -> i128 {
+        covered_asset_exposure(&env, &asset)
+    }
+
+    /// Assign a product to a correlation group, or remove it from its group
+    /// (governance only). Policies already registered stay in the group they
+    /// were booked against.
+    pub fn set_product_group(env: Env, caller: Address, product_id: u32, group: Option<u32>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match group {
+            Some(group) => env
+                .storage()
+                .persistent()
+                .set(&(PRODUCT_GROUP, product_id), &group),
+            None => env.storage().persistent().remove(&(PRODUCT_GROUP, product_id)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "product_group_set"), product_id),
+            group,
+        );
+
+        Ok(())
+    }
+
+    /// Set or clear a correlation group's concentration limit (governance only)
+    pub fn set_group_limit(env: Env, caller: Address, group: u32, limit: Option<GroupLimit>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        match &limit {
+            Some(limit) => {
+                if limit.max_share_bps == 0 || limit.max_share_bps > MAX_GROUP_SHARE_BPS || limit.min_total_exposure < 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&(GROUP_LIMIT, group), limit);
+            }
+            None => env.storage().persistent().remove(&(GROUP_LIMIT, group)),
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "group_limit_set"), group),
+            limit,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_product_group(env: Env, product_id: u32) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&(PRODUCT_GROUP, product_id))
+    }
+
+    pub fn get_group_limit(env: Env, group: u32) -> Option<GroupLimit> {
+        env.storage()
+            .persistent()
+            .get(&(GROUP_LIMIT, group))
+    }
+
+    /// Coverage of all registered policies booked against a correlation group
+    pub fn get_group_exposure(env: Env, group: u32) -> i128 {
+        group_exposure(&env, group)
+    }
+
+    /// Outstanding coverage of a product and its premium and loss indexes
+    pub fn get_product_risk(env: Env, product_id: u32) -> (i128, ProductRiskIndex) {
+        (product_exposure(&env, product_id), product_risk_index(&env, product_id))
+    }
+
+    /// Attribute a paid claim to its product so the providers who assumed
+    /// that product's risk bear it (trusted contracts only)
+    pub fn record_product_loss(env: Env, caller_contract: Address, product_id: u32, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, amount)?;
+        bump_product_index(&env, product_id, amount, true)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "product_loss_recorded"), product_id),
+            (amount, product_exposure(&env, product_id)),
+        );
+
+        Ok(())
+    }
+
+    /// Post an order to cede or assume `exposure` of a product's risk for
+    /// `term_secs` at `price`, open until `expires_at`, signed by its
+    /// `maker`. Nothing is locked until the order is filled.
+    pub fn post_risk_order(env: Env, order: RiskOrder) -> Result<u64, ContractError> {
+        order.maker.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(&env, order.exposure)?;
+        if order.price < 0
+            || !(MIN_RISK_TERM_SECONDS..=MAX_RISK_TERM_SECONDS).contains(&order.term_secs)
+            || order.expires_at <= env.ledger().timestamp()
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        if !env.storage().persistent().has(&(PROVIDER, order.maker.clone())) {
+            return Err(ContractError::NotFound);
+        }
+
+        let order_id: u64 = env
+            .storage()
+            .instance()
+            .get(&RISK_ORDER_COUNTER)
+            .unwrap_or(0u64)
+            + 1;
+
+        env.storage()
+            .persistent()
+            .set(&(RISK_ORDER, order_id), &order);
+        env.storage()
+            .instance()
+            .set(&RISK_ORDER_COUNTER, &order_id);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "risk_order_posted"), order_id),
+            (order.maker, order.side, order.product_id, order.exposure, order.price),
+        );
+
+        Ok(order_id)
+    }
+
+    pub fn cancel_risk_order(env: Env, maker: Address, order_id: u64) -> Result<(), ContractError> {
+        maker.require_auth();
+
+        let order: RiskOrder = env
+            .storage()
+            .persistent()
+            .get(&(RISK_ORDER, order_id))
+            .ok_or(ContractError::NotFound)?;
+        if order.maker != maker {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&(RISK_ORDER, order_id));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "risk_order_cancelled"), order_id),
+            maker,
+        );
+
+        Ok(())
+    }
+
+    /// Take the other side of an open order. The price moves from the
+    /// cedent's stake to the assumer's, the assumer's stake stays locked for
+    /// the exposure until the slice ends, and the cedent may not cede more
+    /// of a product than its pro-rata share of the product's coverage.
+    pub fn fill_risk_order(env: Env, taker: Address, order_id: u64) -> Result<u64, ContractError> {
+        taker.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let order: RiskOrder = env
+            .storage()
+            .persistent()
+            .get(&(RISK_ORDER, order_id))
+            .ok_or(ContractError::NotFound)?;
+        let now = env.ledger().timestamp();
+        if now > order.expires_at {
+            return Err(ContractError::OrderExpired);
+        }
+        if taker == order.maker {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let (cedent, assumer) = match order.side {
+            RiskSide::Cede => (order.maker.clone(), taker.clone()),
+            RiskSide::Assume => (taker.clone(), order.maker.clone()),
+        };
+        let cedent_stake = env
+            .storage()
+            .persistent()
+            .get::<_, (i128, i128, u64)>(&(PROVIDER, cedent.clone()))
+            .ok_or(ContractError::NotFound)?
+            .1;
+        let assumer_stake = env
+            .storage()
+            .persistent()
+            .get::<_, (i128, i128, u64)>(&(PROVIDER, assumer.clone()))
+            .ok_or(ContractError::NotFound)?
+            .1;
+
+        let ceded = risk_ceded(&env, &cedent, order.product_id)
+            .checked_add(order.exposure)
+            .ok_or(ContractError::Overflow)?;
+        let share = mul_div_floor(cedent_stake, product_exposure(&env, order.product_id), total_stake(&env).max(1))?;
+        if ceded > share || cedent_stake < order.price {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        // The price has been paid once the assumer holds it, so the locked
+        // exposure is checked against the stake after payment
+        let locked = risk_locked(&env, &assumer)
+            .checked_add(order.exposure)
+            .ok_or(ContractError::Overflow)?;
+        if assumer_stake + order.price < locked {
+            return Err(ContractError::ExposureLocked);
+        }
+
+        move_stake(&env, &cedent, &assumer, order.price)?;
+
+        let index = product_risk_index(&env, order.product_id);
+        let slice_id: u64 = env
+            .storage()
+            .instance()
+            .get(&RISK_SLICE_COUNTER)
+            .unwrap_or(0u64)
+            + 1;
+        let slice = RiskSlice {
+            cedent: cedent.clone(),
+            assumer: assumer.clone(),
+            product_id: order.product_id,
+            exposure: order.exposure,
+            premium_index: index.premium_index,
+            loss_index: index.loss_index,
+            ends_at: now.checked_add(order.term_secs).ok_or(ContractError::Overflow)?,
+        };
+
+        env.storage()
+            .persistent()
+            .remove(&(RISK_ORDER, order_id));
+        env.storage()
+            .persistent()
+            .set(&(RISK_SLICE, slice_id), &slice);
+        env.storage()
+            .instance()
+            .set(&RISK_SLICE_COUNTER, &slice_id);
+        env.storage()
+            .persistent()
+            .set(&(RISK_LOCKED, assumer.clone()), &locked);
+        env.storage()
+            .persistent()
+            .set(&(RISK_CEDED, cedent.clone(), order.product_id), &ceded);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "risk_order_filled"), order_id),
+            (slice_id, cedent, assumer, order.exposure, order.price),
+        );
+
+        Ok(slice_id)
+    }
+
+    /// Settle a slice's premium and losses so far (permissionless). A slice
+    /// past its end is closed by its final settlement and its exposure
+    /// unlocked. Returns the net paid to the assumer, negative when the
+    /// assumer paid the cedent.
+    pub fn settle_risk_slice(env: Env, slice_id: u64) -> Result<i128, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut slice: RiskSlice = env
+            .storage()
+            .persistent()
+            .get(&(RISK_SLICE, slice_id))
+            .ok_or(ContractError::NotFound)?;
+        let net = settle_slice(&env, &mut slice)?;
+        let closed = env.ledger().timestamp() >= slice.ends_at;
+
+        if closed {
+            let locked = (risk_locked(&env, &slice.assumer) - slice.exposure).max(0);
+            let ceded = (risk_ceded(&env, &slice.cedent, slice.product_id) - slice.exposure).max(0);
+            env.storage()
+                .persistent()
+                .set(&(RISK_LOCKED, slice.assumer.clone()), &locked);
+            env.storage()
+                .persistent()
+                .set(&(RISK_CEDED, slice.cedent.clone(), slice.product_id), &ceded);
+            env.storage()
+                .persistent()
+                .remove(&(RISK_SLICE, slice_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&(RISK_SLICE, slice_id), &slice);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "risk_slice_settled"), slice_id),
+            (net, closed),
+        );
+
+        Ok(net)
+    }
+
+    pub fn get_risk_order(env: Env, order_id: u64) -> Option<RiskOrder> {
+        env.storage().persistent().get(&(RISK_ORDER, order_id))
+    }
+
+    pub fn get_risk_slice(env: Env, slice_id: u64) -> Option<RiskSlice> {
+        env.storage().persistent().get(&(RISK_SLICE, slice_id))
+    }
+
+    /// Exposure a provider has assumed and must keep staked, and the
+    /// exposure it has ceded of `product_id`
+    pub fn get_risk_position(env: Env, provider: Address, product_id: u32) -> (i128, i128) {
+        (risk_locked(&env, &provider), risk_ceded(&env, &provider, product_id))
+    }
+
+    /// Set a product's expected loss and risk margin (governance only). They
+    /// floor the product's quotes and size the expected losses new policies
+    /// of it book against the pool's solvency. Policies already registered
+    /// keep the expected loss they were booked with.
+    pub fn set_actuarial_params(env: Env, caller: Address, product_id: u32, params: ActuarialParams) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if !params.is_valid() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(ACTUARIAL, product_id), &params);
+
+        let mut history: Vec<ActuarialChange> = env
+            .storage()
+            .persistent()
+            .get(&(ACTUARIAL_HISTORY, product_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        history.push_back(ActuarialChange {
+            params: params.clone(),
+            changed_by: caller.clone(),
+            changed_at: env.ledger().timestamp(),
+        });
+        if history.len() > MAX_ACTUARIAL_HISTORY {
+            history.pop_front();
+        }
+        env.storage()
+            .persistent()
+            .set(&(ACTUARIAL_HISTORY, product_id), &history);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "actuarial_params_set"), product_id),
+            (params.expected_loss_bps, params.risk_margin_bps, caller),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_actuarial_params(env: Env, product_id: u32) -> Option<ActuarialParams> {
+        env.storage()
+            .persistent()
+            .get(&(ACTUARIAL, product_id))
+    }
+
+    /// Past parameter changes for a product, oldest first. Only the newest
+    /// `MAX_ACTUARIAL_HISTORY` are retained.
+    pub fn get_actuarial_history(env: Env, product_id: u32) -> Vec<ActuarialChange> {
+        env.storage()
+            .persistent()
+            .get(&(ACTUARIAL_HISTORY, product_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Loaded losses expected on all registered policies of priced products
+    pub fn get_expected_losses(env: Env) -> i128 {
+        expected_losses(&env)
+    }
+
+    /// Set aside up to `amount` of premium income for a settled claim's
+    /// processing fee (trusted contracts only). Capped at the income not
+    /// already charged and the unreserved liquidity; returns the amount set
+    /// aside, which leaves the pool's liquidity until it is paid out.
+    pub fn charge_processing_fee(env: Env, caller_contract: Address, claim_id: u64, amount: i128) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, amount)?;
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
+            .get(&RESERVED_TOTAL)
+            .unwrap_or(0i128);
+        let income: i128 = env
+            .storage()
+            .instance()
+            .get(&PREMIUM_INCOME)
+            .unwrap_or(0i128);
+        let (charged_total, owed) = Self::get_processing_fee_totals(env.clone());
+
+        let charged = amount
+            .min(income - charged_total)
+            .min(stats.0 - reserved_total)
+            .max(0);
+        if charged == 0 {
+            return Ok(0);
+        }
+
+        stats.0 -= charged;
+        require_buffer(&env, stats.0)?;
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        env.storage()
+            .instance()
+            .set(&FEES_CHARGED, &(charged_total + charged));
+        env.storage()
+            .instance()
+            .set(&FEES_OWED, &(owed + charged));
+
+        check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processing_fee_charged"), claim_id),
+            (amount, charged),
+        );
+
+        Ok(charged)
+    }
+
+    /// Pay out processing fees already set aside (trusted contracts only)
+    pub fn pay_processing_fee(env: Env, caller_contract: Address, recipient: Address, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, amount)?;
+
+        let (_, owed) = Self::get_processing_fee_totals(env.clone());
+        if amount > owed {
+            return Err(ContractError::InsufficientFunds);
+        }
+        env.storage()
+            .instance()
+            .set(&FEES_OWED, &(owed - amount));
+        pay(&env, &recipient, amount)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "processing_fee_paid"), recipient),
+            amount,
+        );
+
+        Ok(())
+    }
+
+    /// `(charged, owed)`: processing fees set aside to date and the part of
+    /// them not yet paid out
+    pub fn get_processing_fee_totals(env: Env) -> (i128, i128) {
+        (
+            env.storage()
+                .instance()
+                .get(&FEES_CHARGED)
+                .unwrap_or(0i128),
+            env.storage()
+                .instance()
+                .get(&FEES_OWED)
+                .unwrap_or(0i128),
+        )
+    }
+
+    /// Total premium income credited to the pool
+    pub fn get_premium_income(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&PREMIUM_INCOME)
+            .unwrap_or(0i128)
+    }
+
+    /// Premium credited to the pool for one policy
+    pub fn get_policy_premium(env: Env, policy_id: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(POLICY_PREMIUM, policy_id))
+            .unwrap_or(0i128)
+    }
+
+    /// Recognize premium earned since the last accrual for up to
+    /// `MAX_ACCRUAL_BATCH` policies. Permissionless so keepers can keep the
+    /// unearned reserve current; returns the total recognized.
+    pub fn accrue_premiums(env: Env, policy_ids: Vec<u64>) -> Result<i128, ContractError> {
+        if policy_ids.len() > MAX_ACCRUAL_BATCH {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut total: i128 = 0;
+        for policy_id in policy_ids.iter() {
+            let earned = accrue_premium(&env, policy_id, false)?;
+            total = total.checked_add(earned).ok_or(ContractError::Overflow)?;
+        }
+
+        Ok(total)
+    }
+
+    /// Earned/unearned split of the premium a policy paid into the pool
+    pub fn get_premium_reserve(env: Env, policy_id: u64) -> Option<PremiumReserve> {
+        env.storage()
+            .persistent()
+            .get(&(PREMIUM_RESERVE, policy_id))
+    }
+
+    /// Premium held for coverage that is still at risk, as of the last
+    /// accrual of each policy
+    pub fn get_unearned_premium(env: Env) -> i128 {
+        unearned_total(&env)
+    }
+
+    pub fn reserve_liquidity(env: Env, caller_contract: Address, claim_id: u64, amount: i128) -> Result<(), ContractError> {
+        // Verify that the caller is a trusted contract (e.g., claims contract)
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+        wiring::require_wired(&env)?;
+
+        // I4: Amount Non-Negativity - amount must be positive
+        validate_amount(&env, amount)?;
+
+        if env
             .storage()
             .persistent()
+            .has(&(CLAIM_RESERVATION, claim_id))
+        {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
             .get(&RESERVED_TOTAL)
             .unwrap_or(0i128);
 
@@ -266,16 +3858,19 @@ impl RiskPoolContract {
         let new_reserved_total = reserved_total.checked_add(amount).ok_or(ContractError::Overflow)?;
 
         env.storage()
-            .persistent()
+            .instance()
             .set(&RESERVED_TOTAL, &new_reserved_total);
         env.storage()
             .persistent()
             .set(&(CLAIM_RESERVATION, claim_id), &amount);
+        index_reservation(&env, claim_id, true);
 
         // I1: Assert liquidity invariant holds after reservation
         check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "liquidity_reserved"), claim_id),
             (amount, new_reserved_total),
         );
@@ -283,6 +3878,119 @@ impl RiskPoolContract {
         Ok(())
     }
 
+    /// Change a claim's reservation to `amount`, for claims whose payout in
+    /// pool units is only fixed at settlement (trusted contracts only). An
+    /// increase must fit in the available liquidity.
+    pub fn resize_reservation(env: Env, caller_contract: Address, claim_id: u64, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, amount)?;
+
+        let current: i128 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_RESERVATION, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
+            .get(&RESERVED_TOTAL)
+            .unwrap_or(0i128);
+
+        let reserved_total = reserved_total
+            .checked_sub(current)
+            .and_then(|total| total.checked_add(amount))
+            .ok_or(ContractError::Overflow)?;
+        if amount > current && reserved_total > stats.0 {
+            return Err(ContractError::InsufficientFunds);
+        }
+
+        env.storage()
+            .instance()
+            .set(&RESERVED_TOTAL, &reserved_total);
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_RESERVATION, claim_id), &amount);
+
+        check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "reservation_resized"), claim_id),
+            (current, amount, reserved_total),
+        );
+
+        Ok(())
+    }
+
+    /// Drop the reservation of a claim that will not be paid (trusted
+    /// contracts only). Returns the amount released back to available
+    /// liquidity.
+    pub fn release_reservation(env: Env, caller_contract: Address, claim_id: u64) -> Result<i128, ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM_RESERVATION, claim_id))
+            .ok_or(ContractError::NotFound)?;
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
+            .get(&RESERVED_TOTAL)
+            .unwrap_or(0i128);
+        let reserved_total = reserved_total.checked_sub(amount).ok_or(ContractError::Overflow)?;
+
+        env.storage()
+            .instance()
+            .set(&RESERVED_TOTAL, &reserved_total);
+        env.storage()
+            .persistent()
+            .remove(&(CLAIM_RESERVATION, claim_id));
+        env.storage()
+            .persistent()
+            .remove(&(CLAIM_ROUTE, claim_id));
+        index_reservation(&env, claim_id, false);
+
+        check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "reservation_released"), claim_id),
+            (amount, reserved_total),
+        );
+
+        Ok(amount)
+    }
+
+    /// Route the first `amount` of a reserved claim's payout to `assignee`
+    /// instead of the claimant, for a claim assigned to a lender (trusted
+    /// contracts only). Applies to the claim's next payout.
+    pub fn route_claim_payout(env: Env, caller_contract: Address, claim_id: u64, assignee: Address, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_address(&env, &assignee)?;
+        validate_amount(&env, amount)?;
+        if !env.storage().persistent().has(&(CLAIM_RESERVATION, claim_id)) {
+            return Err(ContractError::NotFound);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_ROUTE, claim_id), &(assignee, amount));
+        Ok(())
+    }
+
     pub fn payout_reserved_claim(env: Env, caller_contract: Address, claim_id: u64, recipient: Address) -> Result<(), ContractError> {
         // Verify that the caller is a trusted contract (e.g., claims contract)
         caller_contract.require_auth();
@@ -296,13 +4004,13 @@ impl RiskPoolContract {
 
         let mut stats: (i128, i128, i128, u64) = env
             .storage()
-            .persistent()
+            .instance()
             .get(&POOL_STATS)
             .ok_or(ContractError::NotFound)?;
 
         let mut reserved_total: i128 = env
             .storage()
-            .persistent()
+            .instance()
             .get(&RESERVED_TOTAL)
             .unwrap_or(0i128);
 
@@ -320,31 +4028,102 @@ impl RiskPoolContract {
             return Err(ContractError::InvalidState);
         }
 
-        if stats.0 < amount {
-            return Err(ContractError::InsufficientFunds);
-        }
+        // Stress mode: pay part now, owe the rest
+        let (paid, haircut) = apply_stress_haircut(&env, amount)?;
+
+        // A shortfall is deferred as an IOU rather than failing the settlement
+        let paid_now = paid.min(stats.0.max(0));
+        let deferred = haircut + (paid - paid_now);
+        let paid = paid_now;
 
         // Safe arithmetic for payout
         reserved_total = reserved_total.checked_sub(amount).ok_or(ContractError::Overflow)?;
-        stats.0 = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
-        stats.1 = stats.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+        stats.0 = stats.0.checked_sub(paid).ok_or(ContractError::Overflow)?;
+        stats.1 = stats.1.checked_add(paid).ok_or(ContractError::Overflow)?;
+        require_buffer(&env, stats.0)?;
 
         env.storage()
-            .persistent()
+            .instance()
             .set(&RESERVED_TOTAL, &reserved_total);
         env.storage()
             .persistent()
             .remove(&(CLAIM_RESERVATION, claim_id));
+        index_reservation(&env, claim_id, false);
         env.storage()
-            .persistent()
+            .instance()
             .set(&POOL_STATS, &stats);
 
-        // I1: Assert liquidity invariant holds after payout
+        // A routed assignee is paid first, out of what is paid now before
+        // any deferral, and is owed the rest of its share as an IOU
+        let route: Option<(Address, i128)> = env.storage().persistent().get(&(CLAIM_ROUTE, claim_id));
+        let (paid, deferred) = match route {
+            Some((assignee, assigned)) => {
+                env.storage()
+                    .persistent()
+                    .remove(&(CLAIM_ROUTE, claim_id));
+                let share = assigned.min(amount);
+                let assignee_paid = share.min(paid);
+                let assignee_deferred = (share - assignee_paid).min(deferred);
+                if assignee_deferred > 0 {
+                    record_iou(&env, &assignee, assignee_deferred)?;
+                }
+                pay(&env, &assignee, assignee_paid)?;
+
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "claim_payout_routed"), claim_id),
+                    (assignee, assignee_paid, assignee_deferred),
+                );
+                (paid - assignee_paid, deferred - assignee_deferred)
+            }
+            None => (paid, deferred),
+        };
+        if deferred > 0 {
+            record_iou(&env, &recipient, deferred)?;
+        }
+        pay(&env, &recipient, paid)?;
+
+        // I1/I8: Assert liquidity and reserve invariants hold after payout
         check_liquidity_invariant(&env)?;
+        check_reserve_adequacy(&env)?;
+        checkpoint_stats(&env)?;
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "reserved_claim_payout"), claim_id),
-            (recipient, amount),
+            (recipient, paid, deferred),
+        );
+
+        Ok(())
+    }
+
+    /// Take a reversed claim's payout out of the paid-out total (trusted
+    /// contracts only). Amounts recovered from the claimant are tracked by
+    /// the claims contract.
+    pub fn reverse_claim_payout(env: Env, caller_contract: Address, claim_id: u64, amount: i128) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        require_trusted_contract(&env, &caller_contract)?;
+
+        validate_amount(&env, amount)?;
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        if stats.1 < amount {
+            return Err(ContractError::InvalidState);
+        }
+        stats.1 = stats.1.checked_sub(amount).ok_or(ContractError::Overflow)?;
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_payout_reversed"), claim_id),
+            (caller_contract, amount),
         );
 
         Ok(())
@@ -362,16 +4141,291 @@ impl RiskPoolContract {
         validate_address(&env, &recipient)?;
         
         // I4: Amount Non-Negativity - amount must be positive
-        validate_amount(amount)?;
+        validate_amount(&env, amount)?;
+
+        allowlist::require_allowed(&env, &recipient)?;
+        charge_manager_limits(&env, &manager, amount)?;
 
         let mut stats: (i128, i128, i128, u64) = env
             .storage()
-            .persistent()
+            .instance()
             .get(&POOL_STATS)
             .ok_or(ContractError::NotFound)?;
         let reserved_total: i128 = env
             .storage()
+            .instance()
+            .get(&RESERVED_TOTAL)
+            .unwrap_or(0i128);
+
+        let available = stats.0.checked_sub(reserved_total).ok_or(ContractError::Overflow)?;
+
+        // Stress mode: pay part now, owe the rest
+        let (paid, haircut) = apply_stress_haircut(&env, amount)?;
+
+        // A shortfall is deferred as an IOU rather than failing the payout
+        let paid_now = paid.min(available.max(0));
+        let deferred = haircut + (paid - paid_now);
+        let paid = paid_now;
+
+        // Safe arithmetic for payout
+        stats.0 = stats.0.checked_sub(paid).ok_or(ContractError::Overflow)?;
+        stats.1 = stats.1.checked_add(paid).ok_or(ContractError::Overflow)?;
+        require_buffer(&env, stats.0)?;
+
+        env.storage()
+            .instance()
+            .set(&POOL_STATS, &stats);
+        if deferred > 0 {
+            record_iou(&env, &recipient, deferred)?;
+        }
+
+        // I1/I8: Assert liquidity and reserve invariants hold after payout
+        check_liquidity_invariant(&env)?;
+        check_reserve_adequacy(&env)?;
+        checkpoint_stats(&env)?;
+
+        pay(&env, &recipient, paid)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "claim_payout"), recipient.clone()),
+            (paid, deferred),
+        );
+
+        Ok(())
+    }
+
+    /// Turn payout recipient allowlisting on or off (governance only)
+    pub fn set_allowlist_mode(env: Env, caller: Address, enabled: bool) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        allowlist::set_enabled(&env, enabled);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "allowlist_mode_set"), caller),
+            enabled,
+        );
+
+        Ok(())
+    }
+
+    /// Allowlist a payout recipient; usable after the activation delay (governance only)
+    pub fn add_allowed_recipient(env: Env, caller: Address, recipient: Address) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let active_at = allowlist::add_recipient(&env, &recipient);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "recipient_allowlisted"), recipient),
+            active_at,
+        );
+
+        Ok(active_at)
+    }
+
+    /// Remove a payout recipient from the allowlist (governance only)
+    pub fn remove_allowed_recipient(env: Env, caller: Address, recipient: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        allowlist::remove_recipient(&env, &recipient);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "recipient_removed"), recipient),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    /// Time an allowlisted recipient becomes usable, if registered
+    pub fn get_allowed_recipient(env: Env, recipient: Address) -> Option<u64> {
+        allowlist::recipient_active_at(&env, &recipient)
+    }
+
+    pub fn is_allowlist_enabled(env: Env) -> bool {
+        allowlist::is_enabled(&env)
+    }
+
+    /// Set a manager's daily and weekly payout limits (admin or governance)
+    pub fn set_manager_limits(env: Env, caller: Address, manager: Address, daily: i128, weekly: i128) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        validate_amount(&env, daily)?;
+        if weekly < daily {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(MANAGER_LIMITS, manager.clone()), &PayoutLimits { daily, weekly });
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "manager_limits_set"), manager),
+            (daily, weekly),
+        );
+
+        Ok(())
+    }
+
+    /// Remove a manager's payout limits (admin or governance)
+    pub fn clear_manager_limits(env: Env, caller: Address, manager: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        env.storage()
             .persistent()
+            .remove(&(MANAGER_LIMITS, manager.clone()));
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "manager_limits_cleared"), manager),
+            caller,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_manager_limits(env: Env, manager: Address) -> Option<PayoutLimits> {
+        env.storage().persistent().get(&(MANAGER_LIMITS, manager))
+    }
+
+    /// Payouts by a manager as `(today, rolling 7 days)`
+    pub fn get_manager_usage(env: Env, manager: Address) -> (i128, i128) {
+        manager_usage(&env, &manager)
+    }
+
+    /// Set the curve used to price cover and project LP yield (governance only)
+    pub fn set_rate_model(env: Env, caller: Address, model: RateModel) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if !model.is_valid() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().instance().set(&RATE_MODEL, &model);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "rate_model_set"), caller),
+            model,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_rate_model(env: Env) -> Option<RateModel> {
+        env.storage().instance().get(&RATE_MODEL)
+    }
+
+    pub fn get_utilization(env: Env) -> Result<Decimal, ContractError> {
+        pool_utilization(&env)
+    }
+
+    /// Annualized rate at an arbitrary utilization, used to quote premiums
+    /// for cover that would change utilization
+    pub fn get_rate_at(env: Env, utilization: Decimal) -> Result<Decimal, ContractError> {
+        rate_at(&env, utilization)
+    }
+
+    /// Annualized rate at the current utilization
+    pub fn get_current_rate(env: Env) -> Result<Decimal, ContractError> {
+        rate_at(&env, pool_utilization(&env)?)
+    }
+
+    /// Annualized yield LPs can expect at the current utilization: the rate
+    /// earned on the utilized share of the pool
+    pub fn get_expected_lp_yield(env: Env) -> Result<Decimal, ContractError> {
+        let utilization = pool_utilization(&env)?;
+        Ok(rate_at(&env, utilization)?.checked_mul(utilization)?)
+    }
+
+    /// Enter stress mode: payouts above `threshold` are cut by `haircut_bps`
+    /// and the cut recorded as an IOU (governance only)
+    pub fn activate_stress_mode(env: Env, caller: Address, threshold: i128, haircut_bps: u32) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        if threshold < 0 || haircut_bps == 0 || haircut_bps > MAX_HAIRCUT_BPS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let stress = StressConfig {
+            active: true,
+            threshold,
+            haircut_bps,
+            activated_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&STRESS_MODE, &stress);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "stress_mode_on"), caller),
+            (threshold, haircut_bps),
+        );
+
+        Ok(())
+    }
+
+    /// Leave stress mode; outstanding IOUs remain owed (governance only)
+    pub fn deactivate_stress_mode(env: Env, caller: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut stress: StressConfig = env
+            .storage()
+            .instance()
+            .get(&STRESS_MODE)
+            .ok_or(ContractError::NotFound)?;
+        stress.active = false;
+        env.storage().instance().set(&STRESS_MODE, &stress);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "stress_mode_off"), caller),
+            (),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_stress_config(env: Env) -> Option<StressConfig> {
+        env.storage().instance().get(&STRESS_MODE)
+    }
+
+    /// Repay up to `amount` of queued IOUs, oldest first, from free
+    /// liquidity once the pool is out of stress mode
+    pub fn repay_ious(env: Env, manager: Address, amount: i128) -> Result<i128, ContractError> {
+        manager.require_auth();
+        require_risk_pool_management(&env, &manager)?;
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(&env, amount)?;
+
+        let stress: Option<StressConfig> = env.storage().instance().get(&STRESS_MODE);
+        if stress.map(|s| s.active).unwrap_or(false) {
+            return Err(ContractError::InvalidState);
+        }
+
+        let mut stats: (i128, i128, i128, u64) = env
+            .storage()
+            .instance()
+            .get(&POOL_STATS)
+            .ok_or(ContractError::NotFound)?;
+        let reserved_total: i128 = env
+            .storage()
+            .instance()
             .get(&RESERVED_TOTAL)
             .unwrap_or(0i128);
 
@@ -380,53 +4434,166 @@ impl RiskPoolContract {
             return Err(ContractError::InsufficientFunds);
         }
 
-        // Safe arithmetic for payout
-        stats.0 = stats.0.checked_sub(amount).ok_or(ContractError::Overflow)?;
-        stats.1 = stats.1.checked_add(amount).ok_or(ContractError::Overflow)?;
+        let repaid = amortize_ious(&env, amount)?;
+        stats.0 = stats.0.checked_sub(repaid).ok_or(ContractError::Overflow)?;
+        require_buffer(&env, stats.0)?;
+        stats.1 = stats.1.checked_add(repaid).ok_or(ContractError::Overflow)?;
+        env.storage().instance().set(&POOL_STATS, &stats);
+
+        // I1: Assert liquidity invariant holds after repayment
+        check_liquidity_invariant(&env)?;
+        checkpoint_stats(&env)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "ious_repaid"), manager),
+            repaid,
+        );
+
+        Ok(repaid)
+    }
 
+    pub fn get_iou_entry(env: Env, iou_id: u64) -> Result<IouEntry, ContractError> {
         env.storage()
             .persistent()
-            .set(&POOL_STATS, &stats);
+            .get(&(IOU_ENTRY, iou_id))
+            .ok_or(ContractError::NotFound)
+    }
 
-        // I1: Assert liquidity invariant holds after payout
-        check_liquidity_invariant(&env)?;
+    /// IOU queue ids not yet repaid, as `(head, tail)`
+    pub fn get_iou_queue_bounds(env: Env) -> (u64, u64) {
+        (
+            env.storage().instance().get(&IOU_HEAD).unwrap_or(0u64),
+            env.storage().instance().get(&IOU_TAIL).unwrap_or(0u64),
+        )
+    }
+
+    /// Amount owed to a creditor from haircuts and shortfalls
+    pub fn get_iou(env: Env, creditor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(IOU, creditor))
+            .unwrap_or(0i128)
+    }
+
+    /// Total IOUs outstanding across all creditors
+    pub fn get_iou_total(env: Env) -> i128 {
+        env.storage().instance().get(&IOU_TOTAL).unwrap_or(0i128)
+    }
 
-        // TODO: Actually transfer XLM tokens to recipient
-        // This would require token contract integration
+    /// Recover tokens sent to the pool by mistake (governance only). For the
+    /// pool's own asset only the balance above what the pool holds for
+    /// providers, exits, fees and rewards can be moved.
+    pub fn rescue_tokens(env: Env, caller: Address, token: Address, amount: i128, recipient: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
 
-        env.events().publish(
-            (Symbol::new(&env, "claim_payout"), recipient.clone()),
-            (amount,),
+        let (pool_asset, decimals) = pool_token(&env)?;
+        let accounted = if token == pool_asset {
+            units::to_native_ceil(accounted_balance(&env)?, decimals)?
+        } else {
+            0
+        };
+
+        rescue::transfer_surplus(&env, &token, amount, &recipient, accounted)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "tokens_rescued"), token),
+            (recipient, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["risk_pool_v1", IFACE_VERSION, IFACE_WIRING, IFACE_ALLOWLIST, IFACE_RESCUE, IFACE_SNAPSHOT])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
         );
 
         Ok(())
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+    /// Move the hot configuration, counters and aggregates of a contract
+    /// upgraded from a release that kept them in persistent storage (admin
+    /// only). Run right after the upgrade: until then they read as unset.
+    /// Safe to repeat; returns how many items were moved.
+    pub fn migrate_hot_storage(env: Env, admin: Address) -> Result<u32, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        let mut moved = hot_storage::migrate_shared(&env);
+        for key in HOT_KEYS.iter() {
+            if hot_storage::migrate(&env, key) {
+                moved += 1;
+            }
+        }
+        hot_storage::extend(&env);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "hot_storage_migrated"), ()),
+            moved,
+        );
+
+        Ok(moved)
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
+    /// Pause the contract (admin only), optionally citing the incident it
+    /// responds to
+    pub fn pause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
 
         set_paused(&env, true);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "paused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+    pub fn unpause(env: Env, admin: Address, incident_id: Option<u64>) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
         require_admin(&env, &admin)?;
+        incident::require_exists(&env, incident_id)?;
 
         set_paused(&env, false);
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "unpaused"), ()),
-            admin,
+            (admin, incident_id),
         );
         
         Ok(())
@@ -439,7 +4606,8 @@ impl RiskPoolContract {
         
         insurance_contracts::authorization::grant_role(&env, &admin, &manager, Role::RiskPoolManager)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_granted"), manager.clone()),
             admin,
         );
@@ -454,7 +4622,8 @@ impl RiskPoolContract {
         
         insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
         
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_revoked"), manager.clone()),
             admin,
         );
@@ -462,8 +4631,116 @@ impl RiskPoolContract {
         Ok(())
     }
     
+    /// Open an incident affecting `components` (governance only); pauses
+    /// and unpauses can cite the returned id
+    pub fn open_incident(env: Env, caller: Address, components: Vec<Symbol>) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident_id = incident::open(&env, &caller, components.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_opened"), incident_id),
+            (caller, components),
+        );
+
+        Ok(incident_id)
+    }
+
+    /// Record the end of an incident (governance only)
+    pub fn close_incident(env: Env, caller: Address, incident_id: u64) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let incident = incident::close(&env, incident_id)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "incident_closed"), incident_id),
+            (caller, incident.ended_at - incident.started_at),
+        );
+
+        Ok(())
+    }
+
+    /// Anchor the hash of a closed incident's postmortem (governance only)
+    pub fn anchor_postmortem(env: Env, caller: Address, incident_id: u64, postmortem_hash: BytesN<32>) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        incident::anchor_postmortem(&env, incident_id, postmortem_hash.clone())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "postmortem_anchored"), incident_id),
+            postmortem_hash,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_incident(env: Env, incident_id: u64) -> Option<Incident> {
+        incident::get(&env, incident_id)
+    }
+
+    pub fn get_incident_count(env: Env) -> u64 {
+        incident::count(&env)
+    }
+
+    /// Let the governance contract call admin and governance setters, or
+    /// stop it with `None` (admin only)
+    pub fn set_governance_contract(env: Env, admin: Address, governance: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::set_governance_contract(&env, &admin, governance.as_ref())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "governance_contract_set"), admin),
+            governance,
+        );
+
+        Ok(())
+    }
+
+    /// Governance contract holding admin powers, if any
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        insurance_contracts::authorization::get_governance_contract(&env)
+    }
+
+    /// Schedule the one-way handover to governance (admin only). From
+    /// `sunset_at` the admin's powers end and only the governance contract
+    /// can make admin calls; the time can be brought forward, never pushed
+    /// back.
+    pub fn schedule_admin_sunset(env: Env, admin: Address, sunset_at: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::schedule_admin_sunset(&env, &admin, sunset_at)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "admin_sunset_scheduled"), admin),
+            insurance_contracts::authorization::get_admin_sunset(&env),
+        );
+
+        Ok(())
+    }
+
+    /// When the admin's powers end, if a handover is scheduled
+    pub fn get_admin_sunset(env: Env) -> Option<u64> {
+        insurance_contracts::authorization::get_admin_sunset(&env)
+    }
+
     /// Get the role of an address
     pub fn get_user_role(env: Env, address: Address) -> Role {
         get_role(&env, &address)
     }
 }
+
+mod test;
+mod test_invariants;
+mod test_payouts;
+mod test_underwriting;
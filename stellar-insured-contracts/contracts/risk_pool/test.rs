@@ -0,0 +1,775 @@
+#![cfg(test)]
+//! Liquidity provider accounting
+//!
+//! The pool runs against a Stellar asset contract and a claims stand-in
+//! that only answers the wiring handshake, so reservations can be opened
+//! and released directly as the claims contract would.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+pub(crate) const MIN_STAKE: i128 = 100;
+
+/// Claims contract stand-in that accepts the wiring handshake
+#[contract]
+struct PeerClaims;
+
+#[contractimpl]
+impl PeerClaims {
+    pub fn accept_wiring(_env: Env, _peer: Address) -> u32 {
+        WIRING_VERSION
+    }
+}
+
+/// Transfer hook stand-in that remembers the last transfer and vetoes any
+/// of more than 500 shares
+#[contract]
+struct ShareRegistry;
+
+#[contractimpl]
+impl ShareRegistry {
+    pub fn on_share_transfer(env: Env, from: Address, to: Address, amount: i128) {
+        if amount > 500 {
+            panic!("transfer vetoed");
+        }
+        env.storage().instance().set(&symbol_short!("LAST"), &(from, to, amount));
+    }
+
+    pub fn last(env: Env) -> Option<(Address, Address, i128)> {
+        env.storage().instance().get(&symbol_short!("LAST"))
+    }
+}
+
+pub(crate) struct Harness<'a> {
+    pub(crate) env: Env,
+    pub(crate) admin: Address,
+    pub(crate) claims: Address,
+    pub(crate) pool: RiskPoolContractClient<'a>,
+    pub(crate) token: TokenClient<'a>,
+}
+
+impl<'a> Harness<'a> {
+    pub(crate) fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let admin = Address::generate(&env);
+        let claims = env.register(PeerClaims, ());
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let pool = RiskPoolContractClient::new(&env, &env.register(RiskPoolContract, ()));
+        pool.initialize(&InitConfig {
+            admin: admin.clone(),
+            xlm_token: token.clone(),
+            min_provider_stake: MIN_STAKE,
+            claims_contract: claims.clone(),
+        });
+        pool.finalize_wiring(&admin);
+
+        let token = TokenClient::new(&env, &token);
+        Harness { env, admin, claims, pool, token }
+    }
+
+    pub(crate) fn mint(&self, to: &Address, amount: i128) {
+        StellarAssetClient::new(&self.env, &self.token.address).mint(to, &amount);
+    }
+
+    /// A provider funded with `amount` and depositing all of it
+    pub(crate) fn provider(&self, amount: i128) -> Address {
+        let provider = Address::generate(&self.env);
+        self.mint(&provider, amount);
+        self.pool.deposit_liquidity(&provider, &amount);
+        provider
+    }
+
+    /// A risk pool manager funded with `amount`
+    pub(crate) fn manager(&self, amount: i128) -> Address {
+        let manager = Address::generate(&self.env);
+        self.pool.grant_manager_role(&self.admin, &manager);
+        self.mint(&manager, amount);
+        manager
+    }
+
+    /// Premium paid in by the policy contract
+    pub(crate) fn premium(&self, policy_id: u64, amount: i128) {
+        self.mint(&self.pool.address, amount);
+        self.pool.receive_premium(&self.claims, &policy_id, &amount);
+    }
+
+    pub(crate) fn advance(&self, seconds: u64) {
+        self.env.ledger().with_mut(|li| li.timestamp += seconds);
+    }
+
+    /// Move past the withdrawal cooldown
+    pub(crate) fn mature(&self) {
+        self.advance(DEFAULT_WITHDRAWAL_COOLDOWN_SECONDS);
+    }
+
+    /// Move past the deposit lock
+    pub(crate) fn next_ledger(&self) {
+        self.env.ledger().with_mut(|li| li.sequence_number += 1);
+    }
+
+    pub(crate) fn shares(&self, provider: &Address) -> i128 {
+        self.pool.get_provider_info(provider).1
+    }
+}
+
+#[test]
+fn test_repeated_withdrawals_keep_locked_share() {
+    let h = Harness::new();
+    let provider = h.provider(1_000);
+    h.next_ledger();
+
+    h.pool.reserve_liquidity(&h.claims, &1, &500);
+    assert_eq!(h.pool.get_withdrawable(&provider), (500, 500));
+
+    // Queued exits leave the liquidity in place until they are filled, so
+    // each request must not free more of the locked share
+    h.pool.request_withdrawal(&provider, &250);
+    assert_eq!(h.pool.get_withdrawable(&provider), (250, 500));
+    h.pool.request_withdrawal(&provider, &250);
+    assert_eq!(h.pool.get_withdrawable(&provider), (0, 500));
+    assert_eq!(
+        h.pool.try_request_withdrawal(&provider, &100),
+        Err(Ok(ContractError::LiquidityLocked))
+    );
+    assert_eq!(h.shares(&provider), 500);
+
+    // The lock follows the reservation as it is released
+    h.pool.release_reservation(&h.claims, &1);
+    assert_eq!(h.pool.get_withdrawable(&provider), (500, 0));
+}
+
+#[test]
+fn test_repeated_direct_withdrawals_keep_locked_share() {
+    let h = Harness::new();
+    let provider = h.provider(600);
+    let other = h.provider(400);
+    h.next_ledger();
+
+    h.pool.reserve_liquidity(&h.claims, &1, &500);
+    assert_eq!(h.pool.get_withdrawable(&provider), (300, 300));
+
+    h.pool.withdraw_liquidity(&provider, &150);
+    h.pool.withdraw_liquidity(&provider, &150);
+    assert_eq!(h.pool.get_withdrawable(&provider), (0, 300));
+    assert_eq!(
+        h.pool.try_withdraw_liquidity(&provider, &100),
+        Err(Ok(ContractError::LiquidityLocked))
+    );
+    assert_eq!(h.token.balance(&provider), 300);
+
+    // The other provider still carries its own pro rata share of the lock
+    let (withdrawable, locked) = h.pool.get_withdrawable(&other);
+    assert_eq!(withdrawable + locked, 400);
+    assert!(locked > 0);
+}
+
+#[test]
+fn test_withdrawable_split_locks_reservations_and_unearned_premium() {
+    let h = Harness::new();
+    let provider = h.provider(600);
+    let other = h.provider(400);
+    h.next_ledger();
+
+    // 200 of unearned premium and a 400 reservation lock half of the 1_200
+    h.pool.register_coverage(&h.claims, &1, &0, &5_000, &2_000, &None);
+    h.premium(1, 200);
+    h.pool.reserve_liquidity(&h.claims, &1, &400);
+    assert_eq!(h.pool.get_withdrawable(&provider), (300, 300));
+    assert_eq!(h.pool.get_withdrawable(&other), (200, 200));
+    assert_eq!(
+        h.pool.try_withdraw_liquidity(&provider, &301),
+        Err(Ok(ContractError::LiquidityLocked))
+    );
+
+    // The lock shrinks as the premium is earned and released
+    h.advance(500);
+    assert_eq!(h.pool.accrue_premiums(&Vec::from_array(&h.env, [1u64])), 100);
+    assert_eq!(h.pool.get_withdrawable(&provider), (350, 250));
+    h.pool.deregister_coverage(&h.claims, &1);
+    assert_eq!(h.pool.get_withdrawable(&provider), (400, 200));
+    h.pool.release_reservation(&h.claims, &1);
+    assert_eq!(h.pool.get_withdrawable(&provider), (600, 0));
+}
+
+#[test]
+fn test_rescue_tokens_leaves_held_balances() {
+    let h = Harness::new();
+    let provider = h.provider(1_000);
+    let manager = h.manager(70);
+    let recipient = Address::generate(&h.env);
+    h.next_ledger();
+
+    // Premium income partly set aside as a processing fee
+    h.premium(1, 200);
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &1, &100), 100);
+
+    // Reward funds waiting to be claimed and an exit filled but not redeemed
+    h.pool.distribute_rewards(&manager, &70);
+    let receipt_id = h.pool.request_withdrawal(&provider, &300);
+    h.mature();
+    assert_eq!(h.pool.process_exit_queue(&1), 330);
+    assert_eq!(h.pool.get_pool_stats().0, 770);
+
+    // Only the stray transfer is surplus
+    h.mint(&h.pool.address, 50);
+    assert_eq!(
+        h.pool.try_rescue_tokens(&h.admin, &h.token.address, &51, &recipient),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    h.pool.rescue_tokens(&h.admin, &h.token.address, &50, &recipient);
+    assert_eq!(h.token.balance(&recipient), 50);
+
+    // Paying out what is held never frees anything to rescue
+    assert!(h.pool.claim_rewards(&provider) > 0);
+    assert_eq!(h.pool.redeem_withdrawal(&provider, &receipt_id), 330);
+    h.pool.pay_processing_fee(&h.claims, &recipient, &100);
+    assert_eq!(
+        h.pool.try_rescue_tokens(&h.admin, &h.token.address, &1, &recipient),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+}
+
+#[test]
+fn test_rescue_tokens_other_asset_and_access() {
+    let h = Harness::new();
+    h.provider(1_000);
+    let recipient = Address::generate(&h.env);
+
+    let stray = h.env.register_stellar_asset_contract_v2(h.admin.clone()).address();
+    StellarAssetClient::new(&h.env, &stray).mint(&h.pool.address, &25);
+
+    assert_eq!(
+        h.pool.try_rescue_tokens(&recipient, &stray, &25, &recipient),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.pool.try_rescue_tokens(&h.admin, &stray, &0, &recipient),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    h.pool.rescue_tokens(&h.admin, &stray, &25, &recipient);
+    assert_eq!(TokenClient::new(&h.env, &stray).balance(&recipient), 25);
+    assert_eq!(h.token.balance(&h.pool.address), 1_000);
+}
+
+#[test]
+fn test_processing_fee_accrual_and_claim() {
+    let h = Harness::new();
+    h.provider(1_000);
+    let processor = Address::generate(&h.env);
+    h.premium(1, 200);
+
+    // Fees come out of premium income not already charged
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &1, &150), 150);
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &2, &100), 50);
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &3, &10), 0);
+    assert_eq!(h.pool.get_processing_fee_totals(), (200, 200));
+    assert_eq!(h.pool.get_pool_stats().0, 1_000);
+
+    assert_eq!(
+        h.pool.try_pay_processing_fee(&processor, &processor, &10),
+        Err(Ok(ContractError::NotTrustedContract))
+    );
+    assert_eq!(
+        h.pool.try_pay_processing_fee(&h.claims, &processor, &201),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    h.pool.pay_processing_fee(&h.claims, &processor, &120);
+    assert_eq!(h.token.balance(&processor), 120);
+    assert_eq!(h.pool.get_processing_fee_totals(), (200, 80));
+
+    // What is still owed stays out of the rescuable surplus
+    assert_eq!(
+        h.pool.try_rescue_tokens(&h.admin, &h.token.address, &1, &processor),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    h.pool.pay_processing_fee(&h.claims, &processor, &80);
+    assert_eq!(h.pool.get_processing_fee_totals(), (200, 0));
+    assert_eq!(h.token.balance(&h.pool.address), 1_000);
+}
+
+#[test]
+fn test_processing_fee_leaves_reservations_funded() {
+    let h = Harness::new();
+    h.provider(MIN_STAKE);
+    h.premium(1, 100);
+    h.pool.reserve_liquidity(&h.claims, &1, &150);
+
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &1, &100), 50);
+    assert_eq!(h.pool.get_pool_stats().0, 150);
+    assert_eq!(h.pool.charge_processing_fee(&h.claims, &2, &100), 0);
+}
+
+#[test]
+fn test_exit_queue_fills_first_in_first_out() {
+    let h = Harness::new();
+    let first = h.provider(1_000);
+    let second = h.provider(1_000);
+    h.next_ledger();
+
+    // 300 of the 2_000 is free, half of it each provider's to queue
+    h.pool.reserve_liquidity(&h.claims, &1, &1_700);
+    let first_receipt = h.pool.request_withdrawal(&first, &150);
+    let second_receipt = h.pool.request_withdrawal(&second, &150);
+    h.pool.reserve_liquidity(&h.claims, &2, &100);
+
+    let status = h.pool.get_exit_queue_status(&second_receipt);
+    assert_eq!((status.position, status.ahead, status.unfilled), (1, 150, 150));
+    assert_eq!(
+        h.pool.try_redeem_withdrawal(&first, &first_receipt),
+        Err(Ok(ContractError::WithdrawalNotMatured))
+    );
+
+    // One receipt per pass, oldest first; the second takes what is left
+    h.mature();
+    assert_eq!(h.pool.process_exit_queue(&1), 150);
+    assert_eq!(h.pool.process_exit_queue(&1), 50);
+    assert_eq!(h.pool.process_exit_queue(&1), 0);
+    let status = h.pool.get_exit_queue_status(&second_receipt);
+    assert_eq!((status.position, status.filled, status.unfilled), (0, 50, 100));
+
+    // Partial fills are redeemed as they come and the receipt keeps the rest
+    assert_eq!(h.pool.redeem_withdrawal(&second, &second_receipt), 50);
+    assert_eq!(h.pool.get_withdrawal_receipt(&second_receipt).amount, 100);
+    assert_eq!(
+        h.pool.try_redeem_withdrawal(&second, &second_receipt),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    assert_eq!(h.pool.redeem_withdrawal(&first, &first_receipt), 150);
+    assert_eq!(
+        h.pool.try_get_withdrawal_receipt(&first_receipt),
+        Err(Ok(ContractError::NotFound))
+    );
+
+    // Released liquidity completes the receipt, which is then burned
+    h.pool.release_reservation(&h.claims, &2);
+    assert_eq!(h.pool.redeem_withdrawal(&second, &second_receipt), 100);
+    assert_eq!(
+        h.pool.try_get_withdrawal_receipt(&second_receipt),
+        Err(Ok(ContractError::NotFound))
+    );
+    assert_eq!(h.token.balance(&first), 150);
+    assert_eq!(h.token.balance(&second), 150);
+    assert_eq!(h.pool.get_pending_withdrawals(), 0);
+}
+
+#[test]
+fn test_exit_queue_counts_empty_slots_toward_limit() {
+    let h = Harness::new();
+    let provider = h.provider(1_000);
+    h.next_ledger();
+
+    for _ in 0..3 {
+        h.pool.request_withdrawal(&provider, &100);
+    }
+    h.mature();
+
+    // Slots whose receipts are gone still use up the pass
+    h.env.as_contract(&h.pool.address, || {
+        h.env.storage().persistent().remove(&(WITHDRAWAL_RECEIPT, 1u64));
+        h.env.storage().persistent().remove(&(WITHDRAWAL_RECEIPT, 2u64));
+    });
+    assert_eq!(h.pool.process_exit_queue(&2), 0);
+    assert_eq!(h.pool.process_exit_queue(&2), 100);
+    assert_eq!(h.pool.get_exit_queue_status(&3).filled, 100);
+}
+
+#[test]
+fn test_rewards_stream_pro_rata_with_mid_stream_deposit() {
+    let h = Harness::new();
+    let early = h.provider(1_000);
+    let manager = h.manager(REWARD_STREAM_SECONDS as i128);
+
+    // One unit a second for the whole stream
+    h.pool.distribute_rewards(&manager, &(REWARD_STREAM_SECONDS as i128));
+    h.advance(REWARD_STREAM_SECONDS / 2);
+    assert_eq!(h.pool.get_pending_rewards(&early), 302_400);
+
+    // A deposit mid-stream earns only from then on
+    let late = h.provider(1_000);
+    assert_eq!(h.pool.get_pending_rewards(&late), 0);
+    h.advance(REWARD_STREAM_SECONDS);
+    assert_eq!(h.pool.get_pending_rewards(&early), 453_600);
+    assert_eq!(h.pool.get_pending_rewards(&late), 151_200);
+
+    assert_eq!(h.pool.claim_rewards(&early), 453_600);
+    assert_eq!(h.pool.claim_rewards(&early), 0);
+    assert_eq!(h.pool.claim_rewards(&late), 151_200);
+    assert_eq!(h.token.balance(&early), 453_600);
+    assert_eq!(h.token.balance(&h.pool.address), 2_000);
+}
+
+#[test]
+fn test_rewards_round_down_in_the_pool_favor() {
+    let h = Harness::new();
+    let providers = [h.provider(1_000), h.provider(1_000), h.provider(1_000)];
+    let manager = h.manager(1_000_001);
+
+    h.pool.distribute_rewards(&manager, &1_000_001);
+    h.advance(REWARD_STREAM_SECONDS);
+
+    // Each share of the emission is rounded down, never paying out more
+    // than was funded
+    let claimed: i128 = providers.iter().map(|provider| h.pool.claim_rewards(provider)).sum();
+    assert!(claimed <= 1_000_001);
+    assert!(1_000_001 - claimed <= providers.len() as i128);
+    assert_eq!(h.token.balance(&h.pool.address), 3_000 + 1_000_001 - claimed);
+}
+
+#[test]
+fn test_rewards_claimable_after_withdrawal() {
+    let h = Harness::new();
+    let leaver = h.provider(1_000);
+    let stayer = h.provider(1_000);
+    let manager = h.manager(REWARD_STREAM_SECONDS as i128);
+    h.next_ledger();
+
+    h.pool.distribute_rewards(&manager, &(REWARD_STREAM_SECONDS as i128));
+    h.advance(REWARD_STREAM_SECONDS / 2);
+
+    // Exiting settles what the stake earned; the rest of the stream goes
+    // to those still staked
+    h.pool.request_withdrawal(&leaver, &1_000);
+    h.advance(REWARD_STREAM_SECONDS);
+    assert_eq!(h.pool.get_pending_rewards(&leaver), 151_200);
+    assert_eq!(h.pool.get_pending_rewards(&stayer), 453_600);
+    assert_eq!(h.pool.claim_rewards(&leaver), 151_200);
+    assert_eq!(h.token.balance(&leaver), 151_200);
+}
+
+#[test]
+fn test_rewards_interleaved_with_deposits_withdrawals_and_top_ups() {
+    let h = Harness::new();
+    let quarter = REWARD_STREAM_SECONDS / 4;
+    let stream = REWARD_STREAM_SECONDS as i128;
+    let manager = h.manager(2 * stream);
+    let first = h.provider(1_000);
+
+    // One unit a second, first alone for a quarter of the stream
+    h.pool.distribute_rewards(&manager, &stream);
+    h.advance(quarter);
+    let second = h.provider(1_000);
+    h.advance(quarter);
+    assert_eq!(h.pool.claim_rewards(&first), 226_800);
+    assert_eq!(h.pool.get_pending_rewards(&second), 75_600);
+
+    // A top-up streams what is left with it over a fresh period, half as
+    // fast again; stake joining at the top-up earns from then on
+    h.pool.distribute_rewards(&manager, &stream);
+    assert_eq!(h.pool.get_reward_stream().ends_at, 1_000 + 3 * REWARD_STREAM_SECONDS / 2);
+    let third = h.provider(2_000);
+    h.advance(quarter);
+    assert_eq!(h.pool.get_pending_rewards(&first), 56_700);
+    assert_eq!(h.pool.get_pending_rewards(&third), 113_400);
+
+    // Withdrawing keeps what the stake earned and earns less from then on
+    h.next_ledger();
+    h.pool.withdraw_liquidity(&second, &500);
+    assert_eq!(h.pool.get_pending_rewards(&second), 132_300);
+    h.advance(quarter);
+    assert_eq!(h.pool.claim_rewards(&first), 121_500);
+    assert_eq!(h.pool.get_pending_rewards(&second), 164_700);
+    assert_eq!(h.pool.get_pending_rewards(&third), 243_000);
+
+    // Nothing accrues past the end of the stream
+    h.advance(REWARD_STREAM_SECONDS);
+    assert_eq!(h.pool.claim_rewards(&first), 129_600);
+    assert_eq!(h.pool.claim_rewards(&second), 229_500);
+    assert_eq!(h.pool.claim_rewards(&third), 502_200);
+    assert_eq!(h.pool.claim_rewards(&third), 0);
+
+    // Everything funded was paid out; the pool holds only the stake
+    assert_eq!(h.token.balance(&first), 477_900);
+    assert_eq!(h.token.balance(&second), 500 + 229_500);
+    assert_eq!(h.token.balance(&h.pool.address), 3_500);
+    assert_eq!(h.pool.get_protocol_dust(), 0);
+}
+
+#[test]
+fn test_rewards_conserved_across_uneven_interleavings() {
+    let h = Harness::new();
+    let manager = h.manager(10_000_000);
+    let providers = [h.provider(1_234), h.provider(5_003), h.provider(777)];
+    let mut funded = 0;
+    let mut claimed = 0;
+
+    // Each round tops the stream up by an uneven amount, then every
+    // provider deposits, claims or withdraws in turn
+    for round in 0..9usize {
+        let amount = 100_003 * (round as i128 + 1);
+        h.pool.distribute_rewards(&manager, &amount);
+        funded += amount;
+        h.next_ledger();
+
+        for (i, provider) in providers.iter().enumerate() {
+            h.advance(7_919 * (i as u64 + 1));
+            match (round + i) % 3 {
+                0 => {
+                    h.mint(provider, 311);
+                    h.pool.deposit_liquidity(provider, &311);
+                }
+                1 => claimed += h.pool.claim_rewards(provider),
+                _ => h.pool.withdraw_liquidity(provider, &101),
+            }
+        }
+    }
+    h.advance(REWARD_STREAM_SECONDS);
+    for provider in providers.iter() {
+        claimed += h.pool.claim_rewards(provider);
+        assert_eq!(h.pool.get_pending_rewards(provider), 0);
+    }
+
+    // Rounding never pays out more than was funded, and what it holds back
+    // stays in the pool rather than leaking
+    let held = funded - claimed - h.pool.get_protocol_dust();
+    assert!((0..=40).contains(&held), "{held}");
+    assert_eq!(h.token.balance(&h.pool.address), h.pool.get_pool_stats().0 + held);
+}
+
+#[test]
+fn test_rewards_need_stake_and_a_manager() {
+    let h = Harness::new();
+    let manager = h.manager(1_000);
+    assert_eq!(
+        h.pool.try_distribute_rewards(&manager, &1_000),
+        Err(Ok(ContractError::InvalidState))
+    );
+
+    let provider = h.provider(1_000);
+    assert_eq!(
+        h.pool.try_distribute_rewards(&provider, &1_000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.pool.try_distribute_rewards(&manager, &0),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    h.next_ledger();
+
+    // Emission while nobody is staked goes to the dust bucket
+    h.pool.distribute_rewards(&manager, &1_000);
+    h.pool.request_withdrawal(&provider, &1_000);
+    h.advance(REWARD_STREAM_SECONDS);
+    assert_eq!(h.pool.claim_rewards(&provider), 0);
+    assert!(h.pool.get_protocol_dust() >= 999);
+}
+
+#[test]
+fn test_first_deposit_mints_one_share_per_unit() {
+    let h = Harness::new();
+    let small = Address::generate(&h.env);
+    h.mint(&small, MIN_STAKE - 1);
+    assert_eq!(
+        h.pool.try_deposit_liquidity(&small, &(MIN_STAKE - 1)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let first = h.provider(1_000);
+    assert_eq!(h.pool.get_provider_info(&first).1, 1_000);
+    assert_eq!(h.pool.get_provider_value(&first), 1_000);
+}
+
+#[test]
+fn test_shares_priced_at_pool_value_through_gains_and_losses() {
+    let h = Harness::new();
+    let first = h.provider(1_000);
+    let manager = h.manager(0);
+    let claimant = Address::generate(&h.env);
+
+    // Premium income raises the share price for everyone already in
+    h.premium(1, 500);
+    assert_eq!(h.pool.get_provider_value(&first), 1_500);
+    let second = h.provider(300);
+    assert_eq!(h.shares(&second), 200);
+    assert_eq!(h.pool.get_provider_value(&second), 300);
+
+    // A loss lowers it, so later deposits buy more shares
+    h.pool.payout_claim(&manager, &claimant, &900);
+    assert_eq!(h.pool.get_provider_value(&first), 750);
+    assert_eq!(h.pool.get_provider_value(&second), 150);
+    let third = h.provider(300);
+    assert_eq!(h.shares(&third), 400);
+
+    // Burning shares pays their value at the current price
+    h.next_ledger();
+    h.pool.withdraw_liquidity(&first, &400);
+    assert_eq!(h.token.balance(&first), 300);
+    assert_eq!(h.shares(&first), 600);
+    assert_eq!(h.pool.get_provider_value(&first), 450);
+    assert_eq!(h.pool.get_provider_value(&third), 300);
+}
+
+#[test]
+fn test_share_rounding_favors_the_pool() {
+    let h = Harness::new();
+    let first = h.provider(1_000);
+    h.premium(1, 1);
+
+    // 100 at a price of 1.001 buys 99.9 shares, rounded down
+    let second = h.provider(100);
+    assert_eq!(h.shares(&second), 99);
+    assert_eq!(h.pool.get_provider_value(&second), 99);
+
+    // A deposit worth less than a share mints nothing and is refused
+    h.mint(&first, 1);
+    assert_eq!(
+        h.pool.try_deposit_liquidity(&first, &1),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    h.next_ledger();
+    h.pool.withdraw_liquidity(&second, &99);
+    assert_eq!(h.token.balance(&second), 99);
+    assert_eq!(h.pool.get_provider_value(&first), 1_002);
+    assert_eq!(h.token.balance(&h.pool.address), 1_002);
+}
+
+#[test]
+fn test_governance_contract_holds_admin_powers_until_cleared() {
+    let h = Harness::new();
+    let governance = Address::generate(&h.env);
+    let outsider = Address::generate(&h.env);
+
+    assert_eq!(
+        h.pool.try_set_governance_contract(&outsider, &Some(governance.clone())),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(h.pool.try_set_withdrawal_cooldown(&governance, &60), Err(Ok(ContractError::Unauthorized)));
+
+    h.pool.set_governance_contract(&h.admin, &Some(governance.clone()));
+    assert_eq!(h.pool.get_governance_contract(), Some(governance.clone()));
+    h.pool.set_withdrawal_cooldown(&governance, &60);
+    assert_eq!(h.pool.get_withdrawal_cooldown(), 60);
+    h.pool.set_transfer_hook(&governance, &None);
+
+    // Clearing it takes the powers back
+    h.pool.set_governance_contract(&h.admin, &None);
+    assert_eq!(h.pool.get_governance_contract(), None);
+    assert_eq!(h.pool.try_set_withdrawal_cooldown(&governance, &120), Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(h.pool.try_set_transfer_hook(&governance, &None), Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_share_transfer_checkpoints_rewards_and_calls_hook() {
+    let h = Harness::new();
+    let sender = h.provider(1_000);
+    let receiver = Address::generate(&h.env);
+    let manager = h.manager(REWARD_STREAM_SECONDS as i128);
+    let hook = ShareRegistryClient::new(&h.env, &h.env.register(ShareRegistry, ()));
+    h.pool.set_transfer_hook(&h.admin, &Some(hook.address.clone()));
+
+    assert_eq!(
+        h.pool.try_transfer_shares(&sender, &receiver, &400),
+        Err(Ok(ContractError::DepositLocked))
+    );
+    h.next_ledger();
+    assert_eq!(
+        h.pool.try_transfer_shares(&sender, &receiver, &(MIN_STAKE - 1)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        h.pool.try_transfer_shares(&sender, &sender, &400),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    assert_eq!(
+        h.pool.try_transfer_shares(&sender, &receiver, &1_001),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+
+    // What the shares earned before the transfer stays with the sender
+    h.pool.distribute_rewards(&manager, &(REWARD_STREAM_SECONDS as i128));
+    h.advance(REWARD_STREAM_SECONDS / 2);
+    h.pool.transfer_shares(&sender, &receiver, &400);
+    assert_eq!((h.shares(&sender), h.shares(&receiver)), (600, 400));
+    assert_eq!(hook.last(), Some((sender.clone(), receiver.clone(), 400)));
+
+    h.advance(REWARD_STREAM_SECONDS);
+    assert_eq!(h.pool.get_pending_rewards(&sender), 302_400 + 181_440);
+    assert_eq!(h.pool.get_pending_rewards(&receiver), 120_960);
+
+    // The hook can veto a transfer
+    assert!(h.pool.try_transfer_shares(&sender, &receiver, &501).is_err());
+    assert_eq!(h.shares(&sender), 600);
+    assert!(!h.pool.reconcile().has_drift);
+}
+
+#[test]
+fn test_deposit_lock_holds_fresh_shares() {
+    let h = Harness::new();
+    let outsider = Address::generate(&h.env);
+    assert_eq!(h.pool.try_set_deposit_lock(&outsider, &3), Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(h.pool.try_set_deposit_lock(&h.admin, &0), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(
+        h.pool.try_set_deposit_lock(&h.admin, &(MAX_DEPOSIT_LOCK_LEDGERS + 1)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.pool.set_deposit_lock(&h.admin, &3);
+    assert_eq!(h.pool.get_deposit_lock(), 3);
+    assert_eq!(h.pool.get_deposit_unlock_ledger(&outsider), 0);
+
+    let provider = h.provider(1_000);
+    let unlock = h.env.ledger().sequence() + 3;
+    assert_eq!(h.pool.get_deposit_unlock_ledger(&provider), unlock);
+    for _ in 0..2 {
+        h.next_ledger();
+        assert_eq!(
+            h.pool.try_withdraw_liquidity(&provider, &100),
+            Err(Ok(ContractError::DepositLocked))
+        );
+        assert_eq!(
+            h.pool.try_request_withdrawal(&provider, &100),
+            Err(Ok(ContractError::DepositLocked))
+        );
+        assert_eq!(
+            h.pool.try_transfer_shares(&provider, &outsider, &100),
+            Err(Ok(ContractError::DepositLocked))
+        );
+    }
+    h.next_ledger();
+    h.pool.withdraw_liquidity(&provider, &100);
+
+    // Topping up starts the lock over
+    h.mint(&provider, 100);
+    h.pool.deposit_liquidity(&provider, &100);
+    assert_eq!(h.pool.get_deposit_unlock_ledger(&provider), unlock + 3);
+    assert_eq!(
+        h.pool.try_withdraw_liquidity(&provider, &100),
+        Err(Ok(ContractError::DepositLocked))
+    );
+}
+
+#[test]
+fn test_reconcile_reports_and_corrects_drift() {
+    let h = Harness::new();
+    h.provider(1_000);
+    h.provider(500);
+    h.pool.reserve_liquidity(&h.claims, &7, &300);
+
+    let report = h.pool.reconcile();
+    assert_eq!((report.providers, report.reservations), (2, 1));
+    assert_eq!((report.computed_stake, report.computed_deposited, report.computed_reserved), (1_500, 1_500, 300));
+    assert!(!report.has_drift);
+
+    h.env.as_contract(&h.pool.address, || {
+        let mut stats: (i128, i128, i128, u64) = h.env.storage().instance().get(&POOL_STATS).unwrap();
+        stats.2 += 10;
+        h.env.storage().instance().set(&POOL_STATS, &stats);
+        h.env.storage().instance().set(&TOTAL_STAKE, &1_600i128);
+        h.env.storage().instance().set(&RESERVED_TOTAL, &250i128);
+    });
+    let report = h.pool.reconcile();
+    assert!(report.has_drift);
+    assert_eq!((report.recorded_stake, report.recorded_deposited, report.recorded_reserved), (1_600, 1_510, 250));
+
+    // Correcting returns what was found and writes the recomputed values
+    let outsider = Address::generate(&h.env);
+    assert_eq!(h.pool.try_correct_aggregates(&outsider), Err(Ok(ContractError::Unauthorized)));
+    assert!(h.pool.correct_aggregates(&h.admin).has_drift);
+    assert!(!h.pool.reconcile().has_drift);
+    assert_eq!(h.pool.get_pool_stats().2, 1_500);
+    assert!(!h.pool.correct_aggregates(&h.admin).has_drift);
+}
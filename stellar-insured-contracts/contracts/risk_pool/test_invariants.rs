@@ -0,0 +1,228 @@
+#![cfg(test)]
+//! Share accounting invariants
+//!
+//! Sequences of deposits, withdrawals, exits, claims and premiums are run
+//! step by step, and after every step the pool must still account for each
+//! share and each unit of liquidity:
+//!
+//! - the shares held by providers add up to the recorded total stake, and
+//!   the stored aggregates show no drift
+//! - the providers' values add up to the pool value (liquidity less what is
+//!   owed to queued exits and IOU holders), short of it by less than one
+//!   unit per provider from rounding down
+//! - the contract holds the liquidity and every filled exit not yet redeemed
+//! - deposits and withdrawals never lower the value of a share
+
+use super::*;
+use crate::test::{Harness, MIN_STAKE};
+use soroban_sdk::testutils::Address as _;
+
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Deposit(u32, i128),
+    Withdraw(u32, i128),
+    Exit(u32, i128),
+    Redeem,
+    Claim(i128),
+    Premium(i128),
+}
+
+struct Run<'a> {
+    h: Harness<'a>,
+    lps: Vec<Address>,
+    manager: Address,
+    receipts: Vec<(u64, u32)>,
+    next_policy: u64,
+}
+
+impl Run<'_> {
+    fn new(lps: u32) -> Self {
+        let h = Harness::new();
+        let manager = h.manager(0);
+        let mut providers = Vec::new(&h.env);
+        for _ in 0..lps {
+            providers.push_back(Address::generate(&h.env));
+        }
+        let receipts = Vec::new(&h.env);
+        Run { h, lps: providers, manager, receipts, next_policy: 1 }
+    }
+
+    /// Shares and value held by each provider; one that never deposited
+    /// holds neither
+    fn holdings(&self, lp: &Address) -> (i128, i128) {
+        match self.h.pool.try_get_provider_info(lp) {
+            Ok(Ok(info)) => (info.1, self.h.pool.get_provider_value(lp)),
+            _ => (0, 0),
+        }
+    }
+
+    fn total_shares(&self) -> i128 {
+        self.lps.iter().map(|lp| self.holdings(&lp).0).sum()
+    }
+
+    /// Liquidity less what queued exits and IOU holders are still owed
+    fn pool_value(&self) -> i128 {
+        let unfilled: i128 = self
+            .receipts
+            .iter()
+            .map(|(id, _)| self.h.pool.get_exit_queue_status(&id).unfilled)
+            .sum();
+        let owed = unfilled + self.h.pool.get_iou_total();
+        (self.h.pool.get_pool_stats().0 - owed).max(0)
+    }
+
+    /// Apply `step`, returning whether the pool accepted it
+    fn apply(&mut self, step: Step) -> bool {
+        let h = &self.h;
+        h.next_ledger();
+        match step {
+            Step::Deposit(lp, amount) => {
+                let provider = self.lps.get_unchecked(lp);
+                h.mint(&provider, amount);
+                h.pool.try_deposit_liquidity(&provider, &amount).is_ok()
+            }
+            Step::Withdraw(lp, shares) => h.pool.try_withdraw_liquidity(&self.lps.get_unchecked(lp), &shares).is_ok(),
+            Step::Exit(lp, shares) => match h.pool.try_request_withdrawal(&self.lps.get_unchecked(lp), &shares) {
+                Ok(Ok(receipt_id)) => {
+                    self.receipts.push_back((receipt_id, lp));
+                    true
+                }
+                _ => false,
+            },
+            Step::Redeem => {
+                h.mature();
+                let mut redeemed = false;
+                let mut open = Vec::new(&h.env);
+                for (receipt_id, lp) in self.receipts.iter() {
+                    redeemed |= h.pool.try_redeem_withdrawal(&self.lps.get_unchecked(lp), &receipt_id).is_ok();
+                    if h.pool.try_get_withdrawal_receipt(&receipt_id).is_ok() {
+                        open.push_back((receipt_id, lp));
+                    }
+                }
+                self.receipts = open;
+                redeemed
+            }
+            Step::Claim(amount) => {
+                let claimant = Address::generate(&h.env);
+                h.pool.payout_claim(&self.manager, &claimant, &amount);
+                true
+            }
+            Step::Premium(amount) => {
+                h.premium(self.next_policy, amount);
+                self.next_policy += 1;
+                true
+            }
+        }
+    }
+
+    fn assert_invariants(&self, step: Step) {
+        let h = &self.h;
+        let shares = self.total_shares();
+        let drift = h.pool.reconcile();
+        assert!(!drift.has_drift, "{step:?}: {drift:?}");
+        assert_eq!(drift.recorded_stake, shares, "{step:?}");
+        assert!(h.pool.check_invariants().all_hold(), "{step:?}");
+
+        let value = self.pool_value();
+        let held: i128 = self.lps.iter().map(|lp| self.holdings(&lp).1).sum();
+        assert!(held <= value, "{step:?}: providers hold {held} of {value}");
+        if shares > 0 {
+            assert!(value - held < self.lps.len() as i128, "{step:?}: providers hold {held} of {value}");
+        }
+
+        let filled: i128 = self
+            .receipts
+            .iter()
+            .map(|(id, _)| h.pool.get_exit_queue_status(&id).filled)
+            .sum();
+        assert_eq!(h.token.balance(&h.pool.address), h.pool.get_pool_stats().0 + filled, "{step:?}");
+    }
+
+    /// Run `steps`, checking the invariants after each; returns how many
+    /// steps the pool accepted
+    fn run(&mut self, steps: &[Step]) -> usize {
+        let mut applied = 0;
+        for step in steps.iter().copied() {
+            let (value, shares) = (self.pool_value(), self.total_shares());
+            if !self.apply(step) {
+                continue;
+            }
+            applied += 1;
+            self.assert_invariants(step);
+
+            // Only claims and premiums move the value of a share
+            let (after, after_shares) = (self.pool_value(), self.total_shares());
+            if matches!(step, Step::Deposit(..) | Step::Withdraw(..) | Step::Exit(..)) && shares > 0 && after_shares > 0 {
+                assert!(after * shares >= value * after_shares, "{step:?} diluted the share price");
+            }
+        }
+        applied
+    }
+}
+
+#[test]
+fn test_share_invariants_hold_across_sequences() {
+    use Step::*;
+
+    let sequences: [(&str, &[Step]); 6] = [
+        ("deposits then full exits", &[
+            Deposit(0, 1_000), Deposit(1, 333), Deposit(2, 7_777),
+            Withdraw(1, 333), Withdraw(0, 1_000), Withdraw(2, 7_777),
+        ]),
+        ("uneven premium rounds the share price", &[
+            Deposit(0, 1_000), Premium(7), Deposit(1, 1_001), Premium(3), Deposit(2, 999),
+            Withdraw(0, 500), Withdraw(1, 333), Withdraw(2, 101),
+        ]),
+        ("claims cut the share price", &[
+            Deposit(0, 5_000), Deposit(1, 3_000), Claim(2_001), Deposit(2, 1_234),
+            Claim(999), Withdraw(1, 1_000), Withdraw(0, 2_500),
+        ]),
+        ("a shortfall owed as IOUs, repaid by a deposit", &[
+            Deposit(0, 1_000), Deposit(1, 500), Claim(2_000), Deposit(2, 700),
+            Deposit(0, 600), Premium(250), Exit(2, 700), Redeem,
+        ]),
+        ("queued exits filled around claims", &[
+            Deposit(0, 2_000), Deposit(1, 2_000), Exit(0, 1_500), Claim(1_800),
+            Exit(1, 1_000), Premium(900), Redeem, Deposit(2, 3_000), Claim(700), Withdraw(2, 1_000),
+        ]),
+        ("everyone exits after losses", &[
+            Deposit(0, 3_000), Deposit(1, 1_000), Claim(1_333), Premium(77),
+            Exit(0, 3_000), Exit(1, 1_000), Redeem,
+        ]),
+    ];
+
+    for (name, steps) in sequences {
+        let mut run = Run::new(3);
+        let applied = run.run(steps);
+        assert_eq!(applied, steps.len(), "{name}");
+    }
+}
+
+#[test]
+fn test_share_invariants_hold_across_generated_sequences() {
+    for seed in [1u64, 42, 1_337] {
+        let mut state = seed;
+        let mut next = |bound: u64| {
+            // Knuth's MMIX linear congruential generator
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) % bound
+        };
+
+        let steps: [Step; 40] = core::array::from_fn(|_| {
+            let lp = next(4) as u32;
+            let amount = MIN_STAKE + next(5_000) as i128;
+            match next(10) {
+                0..=2 => Step::Deposit(lp, amount),
+                3 | 4 => Step::Withdraw(lp, amount / 2),
+                5 => Step::Exit(lp, amount / 2),
+                6 => Step::Redeem,
+                7 | 8 => Step::Claim(amount / 3),
+                _ => Step::Premium(amount / 7),
+            }
+        });
+
+        let mut run = Run::new(4);
+        let applied = run.run(&steps);
+        assert!(applied >= steps.len() / 2, "seed {seed}: only {applied} steps applied");
+    }
+}
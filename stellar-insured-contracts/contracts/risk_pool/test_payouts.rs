@@ -0,0 +1,209 @@
+#![cfg(test)]
+//! Payouts, stress mode and IOUs
+//!
+//! Claims are paid straight from the pool or out of their reservations.
+//! What the pool cannot pay now, or holds back in stress mode, is owed to
+//! the claimant as an IOU and repaid oldest first.
+
+use super::*;
+use crate::test::Harness;
+use soroban_sdk::testutils::Address as _;
+
+#[test]
+fn test_stress_mode_haircuts_payouts_above_threshold() {
+    let h = Harness::new();
+    let provider = h.provider(10_000);
+    let manager = h.manager(0);
+    let (first, second, third) = (Address::generate(&h.env), Address::generate(&h.env), Address::generate(&h.env));
+
+    assert_eq!(
+        h.pool.try_activate_stress_mode(&manager, &1_000, &2_500),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    for (threshold, haircut_bps) in [(-1, 2_500), (1_000, 0), (1_000, MAX_HAIRCUT_BPS + 1)] {
+        assert_eq!(
+            h.pool.try_activate_stress_mode(&h.admin, &threshold, &haircut_bps),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.pool.activate_stress_mode(&h.admin, &1_000, &2_500);
+
+    // A quarter of what is above the threshold is owed, rounded up
+    h.pool.payout_claim(&manager, &first, &3_001);
+    assert_eq!((h.token.balance(&first), h.pool.get_iou(&first)), (2_500, 501));
+    let entry = h.pool.get_iou_entry(&0);
+    assert_eq!((entry.creditor, entry.amount, entry.created_at), (first, 501, 1_000));
+
+    h.pool.payout_claim(&manager, &second, &1_000);
+    assert_eq!((h.token.balance(&second), h.pool.get_iou(&second)), (1_000, 0));
+
+    h.pool.reserve_liquidity(&h.claims, &1, &2_000);
+    h.pool.payout_reserved_claim(&h.claims, &1, &third);
+    assert_eq!((h.token.balance(&third), h.pool.get_iou(&third)), (1_750, 250));
+    assert_eq!(h.pool.get_iou_queue_bounds(), (0, 2));
+
+    // Only what was paid counts as paid out; what is owed comes off the
+    // providers' value
+    let stats = h.pool.get_pool_stats();
+    assert_eq!((stats.0, stats.1), (4_750, 5_250));
+    assert_eq!(h.pool.get_iou_total(), 751);
+    assert_eq!(h.pool.get_provider_value(&provider), 3_999);
+
+    assert_eq!(h.pool.try_repay_ious(&manager, &100), Err(Ok(ContractError::InvalidState)));
+    h.pool.deactivate_stress_mode(&h.admin);
+    assert!(!h.pool.get_stress_config().unwrap().active);
+    assert_eq!(h.pool.repay_ious(&manager, &751), 751);
+}
+
+#[test]
+fn test_ious_repaid_oldest_first_and_kept_out_of_surplus() {
+    let h = Harness::new();
+    let provider = h.provider(1_000);
+    let manager = h.manager(0);
+    let (first, second) = (Address::generate(&h.env), Address::generate(&h.env));
+    let recipient = Address::generate(&h.env);
+
+    // A shortfall is owed rather than failing the payout
+    h.pool.payout_claim(&manager, &first, &1_500);
+    h.pool.payout_claim(&manager, &second, &300);
+    assert_eq!(h.token.balance(&first), 1_000);
+    assert_eq!(h.pool.get_iou_total(), 800);
+    assert_eq!(h.pool.get_provider_value(&provider), 0);
+
+    // Deposits go to the oldest IOUs before they become liquidity
+    let depositor = h.provider(600);
+    assert_eq!(h.shares(&depositor), 600);
+    assert_eq!((h.token.balance(&first), h.token.balance(&second)), (1_500, 100));
+    assert_eq!((h.pool.get_iou(&first), h.pool.get_iou(&second)), (0, 200));
+    assert_eq!(h.pool.get_iou_queue_bounds(), (1, 2));
+    assert_eq!(h.pool.try_get_iou_entry(&0), Err(Ok(ContractError::NotFound)));
+    assert_eq!(h.pool.get_iou_entry(&1).amount, 200);
+    let stats = h.pool.get_pool_stats();
+    assert_eq!((stats.0, stats.1), (0, 1_600));
+
+    // Managers repay the rest out of free liquidity
+    h.premium(1, 400);
+    assert_eq!(h.pool.try_repay_ious(&manager, &401), Err(Ok(ContractError::InsufficientFunds)));
+    assert_eq!(h.pool.repay_ious(&manager, &300), 200);
+    assert_eq!(h.token.balance(&second), 300);
+    assert_eq!((h.pool.get_iou_total(), h.pool.get_iou_queue_bounds()), (0, (2, 2)));
+    assert_eq!(h.pool.repay_ious(&manager, &100), 0);
+    assert_eq!(h.pool.get_provider_value(&provider) + h.pool.get_provider_value(&depositor), 200);
+
+    // Nothing paid to creditors is left behind as surplus
+    assert_eq!(h.token.balance(&h.pool.address), 200);
+    assert_eq!(
+        h.pool.try_rescue_tokens(&h.admin, &h.token.address, &1, &recipient),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    h.mint(&h.pool.address, 10);
+    h.pool.rescue_tokens(&h.admin, &h.token.address, &10, &recipient);
+    assert_eq!(h.token.balance(&recipient), 10);
+}
+
+#[test]
+fn test_manager_limits_roll_daily_and_weekly() {
+    let h = Harness::new();
+    h.provider(100_000);
+    let manager = h.manager(0);
+    let claimant = Address::generate(&h.env);
+
+    assert_eq!(
+        h.pool.try_set_manager_limits(&manager, &manager, &1_000, &2_500),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.pool.try_set_manager_limits(&h.admin, &manager, &0, &2_500),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        h.pool.try_set_manager_limits(&h.admin, &manager, &1_000, &999),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.pool.set_manager_limits(&h.admin, &manager, &1_000, &2_500);
+    assert_eq!(h.pool.get_manager_limits(&manager), Some(PayoutLimits { daily: 1_000, weekly: 2_500 }));
+
+    h.pool.payout_claim(&manager, &claimant, &600);
+    assert_eq!(
+        h.pool.try_payout_claim(&manager, &claimant, &401),
+        Err(Ok(ContractError::PayoutLimitExceeded))
+    );
+    h.pool.payout_claim(&manager, &claimant, &400);
+    assert_eq!(h.pool.get_manager_usage(&manager), (1_000, 1_000));
+
+    // A new day frees the daily limit but not the weekly one
+    h.advance(PAYOUT_WINDOW_SECONDS);
+    assert_eq!(h.pool.get_manager_usage(&manager), (0, 1_000));
+    h.pool.payout_claim(&manager, &claimant, &1_000);
+    h.advance(PAYOUT_WINDOW_SECONDS);
+    assert_eq!(
+        h.pool.try_payout_claim(&manager, &claimant, &600),
+        Err(Ok(ContractError::PayoutLimitExceeded))
+    );
+    h.pool.payout_claim(&manager, &claimant, &500);
+
+    // The first day drops out of the rolling week
+    h.advance(5 * PAYOUT_WINDOW_SECONDS);
+    assert_eq!(h.pool.get_manager_usage(&manager), (0, 1_500));
+    h.pool.payout_claim(&manager, &claimant, &1_000);
+    assert_eq!(h.pool.get_manager_usage(&manager), (1_000, 2_500));
+
+    // Admins override their own limits, and cleared limits stop applying
+    h.pool.set_manager_limits(&h.admin, &h.admin, &1, &1);
+    h.pool.payout_claim(&h.admin, &claimant, &5_000);
+    h.pool.clear_manager_limits(&h.admin, &manager);
+    assert_eq!(h.pool.get_manager_limits(&manager), None);
+    h.pool.payout_claim(&manager, &claimant, &10_000);
+    assert_eq!(h.token.balance(&claimant), 18_500);
+}
+
+#[test]
+fn test_liquidity_buffer_holds_back_outflows() {
+    let h = Harness::new();
+    let provider = h.provider(1_000);
+    let manager = h.manager(0);
+    let claimant = Address::generate(&h.env);
+    h.next_ledger();
+
+    assert_eq!(
+        h.pool.try_set_liquidity_buffer(&manager, &Some(LiquidityBuffer::Absolute(300))),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    for buffer in [
+        LiquidityBuffer::Absolute(0),
+        LiquidityBuffer::CoverageBps(0),
+        LiquidityBuffer::CoverageBps(MAX_BUFFER_BPS + 1),
+    ] {
+        assert_eq!(
+            h.pool.try_set_liquidity_buffer(&h.admin, &Some(buffer)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.pool.set_liquidity_buffer(&h.admin, &Some(LiquidityBuffer::Absolute(300)));
+    assert_eq!(h.pool.get_required_buffer(), 300);
+
+    assert_eq!(
+        h.pool.try_payout_claim(&manager, &claimant, &701),
+        Err(Ok(ContractError::BufferBreached))
+    );
+    h.pool.payout_claim(&manager, &claimant, &700);
+
+    // Exits only take liquidity above the buffer
+    assert_eq!(
+        h.pool.try_withdraw_liquidity(&provider, &100),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    let receipt_id = h.pool.request_withdrawal(&provider, &100);
+    h.mature();
+    assert_eq!(h.pool.process_exit_queue(&1), 0);
+
+    // A coverage-based buffer follows the outstanding exposure
+    h.pool.set_liquidity_buffer(&h.admin, &Some(LiquidityBuffer::CoverageBps(1_000)));
+    h.pool.register_coverage(&h.claims, &1, &0, &2_000, &100_000, &None);
+    assert_eq!(h.pool.get_required_buffer(), 200);
+    assert_eq!(h.pool.process_exit_queue(&1), 30);
+    assert_eq!(h.pool.redeem_withdrawal(&provider, &receipt_id), 30);
+
+    h.pool.set_liquidity_buffer(&h.admin, &None);
+    assert_eq!(h.pool.get_required_buffer(), 0);
+}
@@ -0,0 +1,297 @@
+#![cfg(test)]
+//! Coverage, premium reserves and risk transfer
+//!
+//! The claims stand-in is trusted, so it registers coverage and pays in
+//! premium the way the policy contract does. Limits on regions, correlated
+//! products and expected losses are checked as each policy is registered.
+
+use super::*;
+use crate::test::Harness;
+use soroban_sdk::testutils::Address as _;
+
+const YEAR: u64 = 365 * 86_400;
+
+impl Harness<'_> {
+    /// Register `amount` of a product's coverage running for `term` seconds
+    fn cover(&self, policy_id: u64, product_id: u32, amount: i128, term: u64, region: Option<u32>) -> Result<(), ContractError> {
+        let expiry = self.env.ledger().timestamp() + term;
+        match self.pool.try_register_coverage(&self.claims, &policy_id, &product_id, &amount, &expiry, &region) {
+            Ok(Ok(())) => Ok(()),
+            Err(Ok(error)) => Err(error),
+            _ => panic!("register_coverage did not return a contract result"),
+        }
+    }
+}
+
+#[test]
+fn test_premium_reserved_until_earned() {
+    let h = Harness::new();
+    h.provider(1_000);
+    let outsider = Address::generate(&h.env);
+    assert_eq!(
+        h.pool.try_receive_premium(&outsider, &1, &100),
+        Err(Ok(ContractError::NotTrustedContract))
+    );
+
+    // Premium for a policy the pool does not cover is earned at once
+    h.premium(9, 100);
+    assert_eq!(h.pool.get_premium_reserve(&9), None);
+    assert_eq!((h.pool.get_premium_income(), h.pool.get_unearned_premium()), (100, 0));
+
+    h.cover(1, 0, 5_000, 10_000, None).unwrap();
+    h.premium(1, 1_000);
+    assert_eq!(
+        h.pool.get_premium_reserve(&1),
+        Some(PremiumReserve { unearned: 1_000, earned: 0, accrued_at: 1_000, expiry: 11_000 })
+    );
+    assert_eq!((h.pool.get_policy_premium(&1), h.pool.get_pool_stats().0), (1_000, 2_100));
+
+    h.advance(2_500);
+    assert_eq!(h.pool.accrue_premiums(&Vec::from_array(&h.env, [1u64, 9, 42])), 250);
+    assert_eq!(h.pool.get_unearned_premium(), 750);
+
+    // A later installment is spread over the term left
+    h.premium(1, 750);
+    h.advance(3_750);
+    assert_eq!(h.pool.accrue_premiums(&Vec::from_array(&h.env, [1u64])), 750);
+    let reserve = h.pool.get_premium_reserve(&1).unwrap();
+    assert_eq!((reserve.unearned, reserve.earned, reserve.accrued_at), (750, 1_000, 7_250));
+
+    let mut batch = Vec::new(&h.env);
+    for policy_id in 0..=MAX_ACCRUAL_BATCH as u64 {
+        batch.push_back(policy_id);
+    }
+    assert_eq!(h.pool.try_accrue_premiums(&batch), Err(Ok(ContractError::InvalidInput)));
+
+    // Coverage that stops being tracked earns the rest
+    h.pool.deregister_coverage(&h.claims, &1);
+    assert_eq!(h.pool.get_premium_reserve(&1).unwrap().earned, 1_750);
+    assert_eq!(h.pool.get_unearned_premium(), 0);
+    assert_eq!(
+        h.pool.try_deregister_coverage(&h.claims, &1),
+        Err(Ok(ContractError::NotFound))
+    );
+}
+
+#[test]
+fn test_region_capacity_limits_new_coverage() {
+    let h = Harness::new();
+    let outsider = Address::generate(&h.env);
+    assert_eq!(
+        h.pool.try_register_coverage(&outsider, &1, &0, &1_000, &YEAR, &None),
+        Err(Ok(ContractError::NotTrustedContract))
+    );
+    assert_eq!(
+        h.pool.try_set_region_capacity(&outsider, &7, &Some(3_000)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.pool.try_set_region_capacity(&h.admin, &7, &Some(0)),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.pool.set_region_capacity(&h.admin, &7, &Some(3_000));
+
+    h.cover(1, 0, 2_000, YEAR, Some(7)).unwrap();
+    assert_eq!(h.cover(2, 0, 1_001, YEAR, Some(7)), Err(ContractError::RegionCapacityExceeded));
+    h.cover(2, 0, 1_000, YEAR, Some(7)).unwrap();
+    assert_eq!(h.cover(2, 0, 1_000, YEAR, None), Err(ContractError::AlreadyExists));
+    assert_eq!(h.cover(3, 0, 0, YEAR, None), Err(ContractError::InvalidAmount));
+
+    // Other regions and untagged policies are not counted against it
+    h.cover(3, 0, 5_000, YEAR, Some(8)).unwrap();
+    h.cover(4, 0, 5_000, YEAR, None).unwrap();
+    assert_eq!(h.pool.get_region_exposure(&7), 3_000);
+    assert_eq!(h.pool.get_outstanding_exposure(), 13_000);
+    assert_eq!(h.pool.get_coverage(&4), Some((5_000, 1_000 + YEAR)));
+
+    // A lower cap only blocks new policies, and expiring ones free room
+    h.pool.set_region_capacity(&h.admin, &7, &Some(2_500));
+    assert_eq!(h.cover(5, 0, 1, YEAR, Some(7)), Err(ContractError::RegionCapacityExceeded));
+    h.pool.deregister_coverage(&h.claims, &1);
+    h.cover(5, 0, 1_000, YEAR, Some(7)).unwrap();
+    assert_eq!(h.pool.get_region_exposure(&7), 2_000);
+
+    h.pool.set_region_capacity(&h.admin, &7, &None);
+    assert_eq!(h.pool.get_region_capacity(&7), None);
+    h.cover(6, 0, 10_000, YEAR, Some(7)).unwrap();
+}
+
+#[test]
+fn test_group_limit_caps_correlated_share_of_exposure() {
+    let h = Harness::new();
+    h.pool.set_product_group(&h.admin, &1, &Some(9));
+    h.pool.set_product_group(&h.admin, &2, &Some(9));
+    for limit in [
+        GroupLimit { max_share_bps: 0, min_total_exposure: 0 },
+        GroupLimit { max_share_bps: MAX_GROUP_SHARE_BPS + 1, min_total_exposure: 0 },
+        GroupLimit { max_share_bps: 5_000, min_total_exposure: -1 },
+    ] {
+        assert_eq!(
+            h.pool.try_set_group_limit(&h.admin, &9, &Some(limit)),
+            Err(Ok(ContractError::InvalidInput))
+        );
+    }
+    h.pool.set_group_limit(&h.admin, &9, &Some(GroupLimit { max_share_bps: 5_000, min_total_exposure: 10_000 }));
+
+    // A young pool may be all one group
+    h.cover(1, 1, 4_000, YEAR, None).unwrap();
+    h.cover(2, 0, 6_000, YEAR, None).unwrap();
+
+    // Past the minimum the group may make up half of all exposure
+    h.cover(3, 2, 1_000, YEAR, None).unwrap();
+    h.cover(4, 1, 1_000, YEAR, None).unwrap();
+    assert_eq!(h.pool.get_group_exposure(&9), 6_000);
+    assert_eq!(h.cover(5, 2, 1, YEAR, None), Err(ContractError::GroupConcentrationExceeded));
+
+    // Ungrouped cover makes room, and so does grouped cover leaving
+    h.cover(5, 0, 2_000, YEAR, None).unwrap();
+    h.cover(6, 2, 1_000, YEAR, None).unwrap();
+    h.pool.deregister_coverage(&h.claims, &1);
+    assert_eq!(h.pool.get_group_exposure(&9), 3_000);
+
+    h.pool.set_product_group(&h.admin, &1, &None);
+    assert_eq!(h.pool.get_product_group(&1), None);
+}
+
+#[test]
+fn test_actuarial_params_book_expected_losses_against_solvency() {
+    let h = Harness::new();
+    let outsider = Address::generate(&h.env);
+    let params = ActuarialParams { expected_loss_bps: 1_000, risk_margin_bps: 2_000 };
+    assert_eq!(
+        h.pool.try_set_actuarial_params(&outsider, &5, &params),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        h.pool.try_set_actuarial_params(&h.admin, &5, &ActuarialParams { expected_loss_bps: 0, risk_margin_bps: 0 }),
+        Err(Ok(ContractError::InvalidInput))
+    );
+    h.pool.set_actuarial_params(&h.admin, &5, &params);
+
+    // A year of 10_000 cover is expected to cost 1_000 plus a 20% margin
+    h.provider(1_000);
+    assert_eq!(h.cover(1, 5, 10_000, YEAR, None), Err(ContractError::SolvencyShortfall));
+    h.provider(500);
+    h.cover(1, 5, 10_000, YEAR, None).unwrap();
+    assert_eq!(h.pool.get_expected_losses(), 1_200);
+
+    // New parameters apply to new policies only
+    h.pool.set_actuarial_params(&h.admin, &5, &ActuarialParams { expected_loss_bps: 500, risk_margin_bps: 0 });
+    let history = h.pool.get_actuarial_history(&5);
+    assert_eq!((history.len(), history.get(0).unwrap().changed_by), (2, h.admin.clone()));
+    assert_eq!(h.pool.get_expected_losses(), 1_200);
+    h.cover(2, 5, 1_000, YEAR / 2, None).unwrap();
+    h.cover(3, 6, 100_000, YEAR, None).unwrap();
+    assert_eq!(h.pool.get_expected_losses(), 1_225);
+    h.pool.deregister_coverage(&h.claims, &1);
+    assert_eq!(h.pool.get_expected_losses(), 25);
+
+    // Reserved liquidity cannot also back expected losses
+    h.pool.reserve_liquidity(&h.claims, &1, &1_400);
+    assert_eq!(h.cover(4, 5, 10_000, YEAR, None), Err(ContractError::SolvencyShortfall));
+    h.cover(4, 5, 1_000, YEAR, None).unwrap();
+}
+
+#[test]
+fn test_risk_orders_move_exposure_and_its_results() {
+    let h = Harness::new();
+    let cedent = h.provider(1_000);
+    let assumer = h.provider(1_000);
+    let outsider = Address::generate(&h.env);
+    h.next_ledger();
+    h.cover(1, 3, 4_000, 8_000, None).unwrap();
+
+    let order = RiskOrder {
+        maker: cedent.clone(),
+        side: RiskSide::Cede,
+        product_id: 3,
+        exposure: 1_000,
+        price: 100,
+        term_secs: 30 * 86_400,
+        expires_at: 4_600,
+    };
+    assert_eq!(
+        h.pool.try_post_risk_order(&RiskOrder { exposure: 0, ..order.clone() }),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    for invalid in [
+        RiskOrder { price: -1, ..order.clone() },
+        RiskOrder { term_secs: MIN_RISK_TERM_SECONDS - 1, ..order.clone() },
+        RiskOrder { expires_at: 1_000, ..order.clone() },
+    ] {
+        assert_eq!(h.pool.try_post_risk_order(&invalid), Err(Ok(ContractError::InvalidInput)));
+    }
+    assert_eq!(
+        h.pool.try_post_risk_order(&RiskOrder { maker: outsider.clone(), ..order.clone() }),
+        Err(Ok(ContractError::NotFound))
+    );
+
+    // A provider cedes at most its share of the product's coverage
+    let oversized = h.pool.post_risk_order(&RiskOrder { exposure: 2_001, ..order.clone() });
+    assert_eq!(
+        h.pool.try_fill_risk_order(&assumer, &oversized),
+        Err(Ok(ContractError::InsufficientFunds))
+    );
+    assert_eq!(
+        h.pool.try_cancel_risk_order(&assumer, &oversized),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    h.pool.cancel_risk_order(&cedent, &oversized);
+    assert_eq!(h.pool.get_risk_order(&oversized), None);
+
+    let order_id = h.pool.post_risk_order(&order);
+    assert_eq!(h.pool.try_fill_risk_order(&cedent, &order_id), Err(Ok(ContractError::InvalidInput)));
+    let slice_id = h.pool.fill_risk_order(&assumer, &order_id);
+    assert_eq!((h.shares(&cedent), h.shares(&assumer)), (900, 1_100));
+    assert_eq!(h.pool.get_risk_position(&assumer, &3), (1_000, 0));
+    assert_eq!(h.pool.get_risk_position(&cedent, &3), (0, 1_000));
+
+    // The assumed exposure stays staked
+    assert_eq!(
+        h.pool.try_withdraw_liquidity(&assumer, &101),
+        Err(Ok(ContractError::ExposureLocked))
+    );
+    assert_eq!(
+        h.pool.try_transfer_shares(&assumer, &outsider, &101),
+        Err(Ok(ContractError::ExposureLocked))
+    );
+
+    // The slice earns a quarter of the product's premium and bears a
+    // quarter of its losses
+    h.premium(1, 800);
+    h.advance(4_000);
+    assert_eq!(h.pool.accrue_premiums(&Vec::from_array(&h.env, [1u64])), 400);
+    assert_eq!(
+        h.pool.try_record_product_loss(&outsider, &3, &200),
+        Err(Ok(ContractError::NotTrustedContract))
+    );
+    h.pool.record_product_loss(&h.claims, &3, &200);
+    assert_eq!(h.pool.settle_risk_slice(&slice_id), 50);
+    assert_eq!((h.shares(&cedent), h.shares(&assumer)), (850, 1_150));
+    assert!(h.pool.get_risk_slice(&slice_id).is_some());
+
+    // An assume order is filled by the cedent
+    let assume_id = h.pool.post_risk_order(&RiskOrder {
+        maker: assumer.clone(),
+        side: RiskSide::Assume,
+        exposure: 100,
+        price: 10,
+        term_secs: MIN_RISK_TERM_SECONDS,
+        expires_at: 6_000,
+        ..order.clone()
+    });
+    let assumed_id = h.pool.fill_risk_order(&cedent, &assume_id);
+    assert_eq!(h.pool.get_risk_position(&assumer, &3), (1_100, 0));
+
+    // Settling after the end closes each slice and unlocks its exposure
+    h.advance(30 * 86_400);
+    assert_eq!(h.pool.settle_risk_slice(&slice_id), 0);
+    assert_eq!(h.pool.settle_risk_slice(&assumed_id), 0);
+    assert_eq!(h.pool.get_risk_position(&assumer, &3), (0, 0));
+    assert_eq!(h.pool.get_risk_position(&cedent, &3), (0, 0));
+    assert_eq!(h.pool.try_settle_risk_slice(&slice_id), Err(Ok(ContractError::NotFound)));
+
+    let stale = h.pool.post_risk_order(&RiskOrder { expires_at: h.env.ledger().timestamp() + 10, ..order });
+    h.advance(11);
+    assert_eq!(h.pool.try_fill_risk_order(&assumer, &stale), Err(Ok(ContractError::OrderExpired)));
+}
@@ -0,0 +1,89 @@
+//! Actuarial pricing parameters
+//!
+//! Governance sets an expected loss rate and a risk margin per product. The
+//! loaded loss, expected claims plus the margin on top of them, is the floor
+//! premium for cover and what the pool must hold against outstanding cover
+//! to stay solvent.
+
+use crate::constants::MAX_BASIS_POINTS;
+use crate::math::{bps_of, mul_div_ceil, MathError};
+use soroban_sdk::{contracttype, Address};
+
+const SECONDS_PER_YEAR: u64 = 365 * 86_400;
+
+/// Expected loss and risk margin of a product
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActuarialParams {
+    /// Expected annual claims as basis points of coverage
+    pub expected_loss_bps: u32,
+    /// Loading on the expected loss, in basis points of it
+    pub risk_margin_bps: u32,
+}
+
+/// Parameters a product was given, by whom and when
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActuarialChange {
+    pub params: ActuarialParams,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
+impl ActuarialParams {
+    /// An expected loss rate must be positive and below the coverage itself
+    pub fn is_valid(&self) -> bool {
+        self.expected_loss_bps > 0 && self.expected_loss_bps < MAX_BASIS_POINTS
+    }
+
+    /// Expected claims plus margin on `coverage_amount` over one year,
+    /// rounded up
+    pub fn annual_loaded_loss(&self, coverage_amount: i128) -> Result<i128, MathError> {
+        let expected = mul_div_ceil(coverage_amount, self.expected_loss_bps as i128, MAX_BASIS_POINTS as i128)?;
+        expected
+            .checked_add(bps_of(expected, self.risk_margin_bps)?)
+            .ok_or(MathError::Overflow)
+    }
+
+    /// Loaded loss on `coverage_amount` over `duration_days`, rounded up
+    pub fn premium(&self, coverage_amount: i128, duration_days: u32) -> Result<i128, MathError> {
+        mul_div_ceil(self.annual_loaded_loss(coverage_amount)?, duration_days as i128, 365)
+    }
+
+    /// Loaded loss on `coverage_amount` over `term_seconds`, rounded up
+    pub fn loss_over(&self, coverage_amount: i128, term_seconds: u64) -> Result<i128, MathError> {
+        mul_div_ceil(
+            self.annual_loaded_loss(coverage_amount)?,
+            term_seconds as i128,
+            SECONDS_PER_YEAR as i128,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> ActuarialParams {
+        ActuarialParams {
+            expected_loss_bps: 200,
+            risk_margin_bps: 2_500,
+        }
+    }
+
+    #[test]
+    fn test_loaded_loss_and_premium() {
+        let params = params();
+        assert_eq!(params.annual_loaded_loss(100_000), Ok(2_500));
+        assert_eq!(params.premium(100_000, 365), Ok(2_500));
+        assert_eq!(params.premium(100_000, 30), Ok(206));
+        assert_eq!(params.loss_over(100_000, SECONDS_PER_YEAR / 2), Ok(1_250));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(params().is_valid());
+        assert!(!ActuarialParams { expected_loss_bps: 0, risk_margin_bps: 0 }.is_valid());
+        assert!(!ActuarialParams { expected_loss_bps: 10_000, risk_margin_bps: 0 }.is_valid());
+    }
+}
@@ -184,7 +184,7 @@ pub fn is_premium_amount_valid(amount: i128) -> bool {
 
 /// Validate policy duration
 pub fn is_duration_valid(duration_days: u32) -> bool {
-    duration_days >= MIN_POLICY_DURATION_DAYS && duration_days <= MAX_POLICY_DURATION_DAYS
+    (MIN_POLICY_DURATION_DAYS..=MAX_POLICY_DURATION_DAYS).contains(&duration_days)
 }
 
 /// Validate percentage (0-100)
@@ -0,0 +1,142 @@
+//! Fixed-point decimal for rates and curves
+//!
+//! `Decimal` stores a value scaled by 10^7, matching the 7 decimals of
+//! Stellar assets, so a rate of 5% is `Decimal(500_000)`. All operations are
+//! checked; products and quotients round down unless the name says otherwise.
+
+use crate::constants::MAX_BASIS_POINTS;
+use crate::math::{mul_div_ceil, mul_div_floor, MathError};
+use soroban_sdk::contracttype;
+
+/// Scaling factor of [`Decimal`]
+pub const DECIMAL_SCALE: i128 = 10_000_000;
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Decimal(pub i128);
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal(0);
+    pub const ONE: Decimal = Decimal(DECIMAL_SCALE);
+
+    pub fn from_int(value: i128) -> Result<Decimal, MathError> {
+        value
+            .checked_mul(DECIMAL_SCALE)
+            .map(Decimal)
+            .ok_or(MathError::Overflow)
+    }
+
+    /// `numerator / denominator`, rounded down
+    pub fn from_ratio(numerator: i128, denominator: i128) -> Result<Decimal, MathError> {
+        mul_div_floor(numerator, DECIMAL_SCALE, denominator).map(Decimal)
+    }
+
+    pub fn from_bps(bps: u32) -> Decimal {
+        Decimal(bps as i128 * DECIMAL_SCALE / MAX_BASIS_POINTS as i128)
+    }
+
+    /// Value in basis points, rounded down
+    pub fn to_bps(self) -> Result<i128, MathError> {
+        mul_div_floor(self.0, MAX_BASIS_POINTS as i128, DECIMAL_SCALE)
+    }
+
+    pub fn checked_add(self, other: Decimal) -> Result<Decimal, MathError> {
+        self.0.checked_add(other.0).map(Decimal).ok_or(MathError::Overflow)
+    }
+
+    pub fn checked_sub(self, other: Decimal) -> Result<Decimal, MathError> {
+        self.0.checked_sub(other.0).map(Decimal).ok_or(MathError::Overflow)
+    }
+
+    pub fn checked_mul(self, other: Decimal) -> Result<Decimal, MathError> {
+        mul_div_floor(self.0, other.0, DECIMAL_SCALE).map(Decimal)
+    }
+
+    pub fn checked_div(self, other: Decimal) -> Result<Decimal, MathError> {
+        mul_div_floor(self.0, DECIMAL_SCALE, other.0).map(Decimal)
+    }
+
+    /// Apply the decimal to an integer amount, rounded down
+    pub fn mul_int(self, amount: i128) -> Result<i128, MathError> {
+        mul_div_floor(amount, self.0, DECIMAL_SCALE)
+    }
+
+    /// Apply the decimal to an integer amount, rounded up
+    pub fn mul_int_ceil(self, amount: i128) -> Result<i128, MathError> {
+        mul_div_ceil(amount, self.0, DECIMAL_SCALE)
+    }
+
+    /// `self^exp` by repeated squaring
+    pub fn pow(self, exp: u32) -> Result<Decimal, MathError> {
+        let mut result = Decimal::ONE;
+        let mut base = self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// `(1 + self)^periods` approximated by the first four terms of its
+    /// binomial expansion. Cheap and accurate for the small per-period rates
+    /// used in interest accrual; slightly underestimates large ones.
+    pub fn compound_approx(self, periods: u64) -> Result<Decimal, MathError> {
+        if periods == 0 {
+            return Ok(Decimal::ONE);
+        }
+        let n = periods as i128;
+        let rate_sq = self.checked_mul(self)?;
+        let rate_cu = rate_sq.checked_mul(self)?;
+
+        let first = self.0.checked_mul(n).ok_or(MathError::Overflow)?;
+        let pairs = n.checked_mul(n - 1).ok_or(MathError::Overflow)?;
+        let triples = pairs.checked_mul((n - 2).max(0)).ok_or(MathError::Overflow)?;
+        let second = mul_div_floor(rate_sq.0, pairs, 2)?;
+        let third = mul_div_floor(rate_cu.0, triples, 6)?;
+
+        Decimal::ONE
+            .checked_add(Decimal(first))?
+            .checked_add(Decimal(second))?
+            .checked_add(Decimal(third))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Decimal::from_bps(500), Decimal(500_000));
+        assert_eq!(Decimal(500_000).to_bps(), Ok(500));
+        assert_eq!(Decimal::from_ratio(1, 4), Ok(Decimal(2_500_000)));
+        assert_eq!(Decimal::from_int(i128::MAX), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn test_mul_div_and_rounding() {
+        let half = Decimal::from_ratio(1, 2).unwrap();
+        assert_eq!(half.checked_mul(half), Ok(Decimal(2_500_000)));
+        assert_eq!(Decimal::ONE.checked_div(half), Decimal::from_int(2));
+        assert_eq!(Decimal::ONE.checked_div(Decimal::ZERO), Err(MathError::DivisionByZero));
+        let third = Decimal::from_ratio(1, 3).unwrap();
+        assert_eq!(third.mul_int(10), Ok(3));
+        assert_eq!(third.mul_int_ceil(10), Ok(4));
+    }
+
+    #[test]
+    fn test_pow_and_compounding() {
+        let rate = Decimal::from_bps(100);
+        let one_plus = Decimal::ONE.checked_add(rate).unwrap();
+        assert_eq!(one_plus.pow(0), Ok(Decimal::ONE));
+        assert_eq!(one_plus.pow(2), Ok(Decimal(10_201_000)));
+        assert_eq!(rate.compound_approx(2), one_plus.pow(2));
+        assert_eq!(rate.compound_approx(0), Ok(Decimal::ONE));
+    }
+}
@@ -0,0 +1,73 @@
+//! Short-lived records in temporary storage
+//!
+//! Records that only mean something for a bounded time, such as locked
+//! quotes, scheduled payouts and oracle submissions awaiting consensus, are
+//! kept in temporary storage with a TTL covering that time. They cost less
+//! rent than persistent entries and leave the ledger on their own once they
+//! lapse, so an abandoned record needs no cleanup path. A lapsed record
+//! reads as absent: callers must treat absence as expired, never as a
+//! default that grants anything.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Seconds per ledger assumed when converting lifetimes to ledgers
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// Ledgers a record is kept past its lifetime, about an hour, so it is
+/// still readable on the last second it is valid
+pub const GRACE_LEDGERS: u32 = 720;
+
+/// Ledgers covering `seconds` plus the grace, capped at the network's
+/// maximum TTL
+pub fn ledgers_for(env: &Env, seconds: u64) -> u32 {
+    let ledgers = seconds.div_ceil(LEDGER_CLOSE_SECONDS).saturating_add(GRACE_LEDGERS as u64);
+    ledgers.min(env.storage().max_ttl() as u64) as u32
+}
+
+/// Store `value` under `key` in temporary storage for at least
+/// `lifetime_secs` seconds
+pub fn set<K, V>(env: &Env, key: &K, value: &V, lifetime_secs: u64)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    let ledgers = ledgers_for(env, lifetime_secs);
+    let storage = env.storage().temporary();
+    storage.set(key, value);
+    storage.extend_ttl(key, ledgers, ledgers);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{storage::Temporary, Ledger};
+    use soroban_sdk::{contract, symbol_short};
+
+    #[contract]
+    struct Host;
+
+    #[test]
+    fn test_lifetime_covers_seconds_plus_grace() {
+        let env = Env::default();
+        assert_eq!(ledgers_for(&env, 0), GRACE_LEDGERS);
+        assert_eq!(ledgers_for(&env, 11), 3 + GRACE_LEDGERS);
+        assert_eq!(ledgers_for(&env, u64::MAX), env.storage().max_ttl());
+    }
+
+    #[test]
+    fn test_record_lapses_after_lifetime() {
+        let env = Env::default();
+        let host = env.register(Host, ());
+        let key = symbol_short!("QUOTE");
+
+        env.as_contract(&host, || {
+            set(&env, &key, &7u32, 900);
+            assert_eq!(env.storage().temporary().get_ttl(&key), 180 + GRACE_LEDGERS);
+        });
+
+        env.ledger().with_mut(|li| li.sequence_number += 180 + GRACE_LEDGERS + 1);
+        env.as_contract(&host, || {
+            assert!(!env.storage().temporary().has(&key));
+        });
+    }
+}
@@ -10,6 +10,14 @@
 //! - `types` - Shared data types and enums (PolicyStatus, ClaimStatus, etc.)
 //! - `constants` - Configuration constants for validation and limits
 //! - `validation` - Reusable validation helper functions
+//! - `math` - Checked basis-point, percentage and mul-div helpers
+//! - `decimal` - Fixed-point decimal for rates and curves
+//! - `rate_model` - Utilization-based rate models for pricing and yield
+//! - `pagination` - Index-list storage and paged reads for listing endpoints
+//! - `actuarial` - Per-product expected loss and risk margin parameters
+//! - `rewards` - Streamed reward-per-share index for pool rewards
+//! - `ephemeral` - Short-lived records in temporary storage
+//! - `units` - Canonical amounts across assets of different decimals
 //!
 //! ## Usage
 //!
@@ -33,9 +41,19 @@ pub mod errors;
 pub mod types;
 pub mod constants;
 pub mod validation;
+pub mod math;
+pub mod decimal;
+pub mod rate_model;
+pub mod pagination;
+pub mod actuarial;
+pub mod rewards;
+pub mod ephemeral;
+pub mod units;
+pub mod twap;
 
 // Re-export commonly used types
 pub use errors::ContractError;
+pub use decimal::Decimal;
 pub use types::{
     PolicyStatus, ClaimStatus, ProposalStatus, ProposalType, VoteType,
     RiskPoolStatus, ClaimEvidence, VoteRecord, OracleConfig, RiskMetrics,
@@ -0,0 +1,160 @@
+//! Checked fixed-ratio arithmetic for fees, shares and percentages
+//!
+//! Every helper multiplies before dividing, reports overflow and zero
+//! denominators as errors instead of panicking, and rounds explicitly.
+//! Round amounts owed to users down and amounts charged to users up so
+//! rounding never works against the protocol.
+
+use crate::constants::MAX_BASIS_POINTS;
+
+/// Arithmetic errors
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MathError {
+    Overflow,
+    DivisionByZero,
+}
+
+/// `a * b / denominator`, rounded towards negative infinity
+pub fn mul_div_floor(a: i128, b: i128, denominator: i128) -> Result<i128, MathError> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let product = a.checked_mul(b).ok_or(MathError::Overflow)?;
+    let quotient = product.checked_div(denominator).ok_or(MathError::Overflow)?;
+    if product % denominator != 0 && (product < 0) != (denominator < 0) {
+        return Ok(quotient - 1);
+    }
+    Ok(quotient)
+}
+
+/// `a * b / denominator`, rounded towards positive infinity
+pub fn mul_div_ceil(a: i128, b: i128, denominator: i128) -> Result<i128, MathError> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let product = a.checked_mul(b).ok_or(MathError::Overflow)?;
+    let quotient = product.checked_div(denominator).ok_or(MathError::Overflow)?;
+    if product % denominator != 0 && (product < 0) == (denominator < 0) {
+        return Ok(quotient + 1);
+    }
+    Ok(quotient)
+}
+
+/// `bps` basis points of `amount`, rounded down
+pub fn bps_of(amount: i128, bps: u32) -> Result<i128, MathError> {
+    mul_div_floor(amount, bps as i128, MAX_BASIS_POINTS as i128)
+}
+
+/// `bps` basis points of `amount`, rounded up
+pub fn bps_of_ceil(amount: i128, bps: u32) -> Result<i128, MathError> {
+    mul_div_ceil(amount, bps as i128, MAX_BASIS_POINTS as i128)
+}
+
+/// Increment of a per-unit index at `scale` that credits `amount` across
+/// `total` units, rounded down, and the dust that rounding leaves
+/// uncredited. Holders settling against the index floor their own share
+/// again, so they can together claim at most `amount - dust`.
+pub fn index_increment(amount: i128, total: i128, scale: i128) -> Result<(i128, i128), MathError> {
+    let increment = mul_div_floor(amount, scale, total)?;
+    let credited = mul_div_ceil(increment, total, scale)?;
+    Ok((increment, amount - credited))
+}
+
+/// `percent` percent of `amount`, rounded down
+pub fn percent_of(amount: i128, percent: u32) -> Result<i128, MathError> {
+    mul_div_floor(amount, percent as i128, 100)
+}
+
+/// `part` as basis points of `whole`, rounded down
+pub fn ratio_bps(part: i128, whole: i128) -> Result<i128, MathError> {
+    mul_div_floor(part, MAX_BASIS_POINTS as i128, whole)
+}
+
+/// `part` as a percentage of `whole`, rounded down
+pub fn ratio_percent(part: i128, whole: i128) -> Result<i128, MathError> {
+    mul_div_floor(part, 100, whole)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_direction() {
+        assert_eq!(mul_div_floor(7, 1, 2), Ok(3));
+        assert_eq!(mul_div_ceil(7, 1, 2), Ok(4));
+        assert_eq!(mul_div_floor(-7, 1, 2), Ok(-4));
+        assert_eq!(mul_div_ceil(-7, 1, 2), Ok(-3));
+        assert_eq!(mul_div_floor(6, 1, 2), mul_div_ceil(6, 1, 2));
+    }
+
+    #[test]
+    fn test_errors_instead_of_panics() {
+        assert_eq!(mul_div_floor(1, 1, 0), Err(MathError::DivisionByZero));
+        assert_eq!(mul_div_ceil(i128::MAX, 2, 3), Err(MathError::Overflow));
+        assert_eq!(ratio_percent(1, 0), Err(MathError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_ratio_helpers() {
+        assert_eq!(bps_of(10_000, 250), Ok(250));
+        assert_eq!(percent_of(999, 10), Ok(99));
+        assert_eq!(ratio_bps(1, 3), Ok(3_333));
+        assert_eq!(ratio_percent(2, 3), Ok(66));
+        assert_eq!(bps_of_ceil(999, 10), Ok(1));
+        assert_eq!(bps_of_ceil(10_000, 250), bps_of(10_000, 250));
+    }
+
+    /// Deterministic xorshift so the property tests need no extra crate
+    fn next(seed: &mut u64) -> u64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *seed
+    }
+
+    #[test]
+    fn test_index_shares_reconcile() {
+        const SCALE: i128 = 1_000_000_000_000;
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+
+        for _ in 0..500 {
+            let holders = 1 + next(&mut seed) % 16;
+            let mut stakes = [0i128; 16];
+            let mut total = 0i128;
+            for stake in stakes.iter_mut().take(holders as usize) {
+                *stake = 1 + (next(&mut seed) % 1_000_000_000) as i128;
+                total += *stake;
+            }
+            let amount = 1 + (next(&mut seed) % 10_000_000) as i128;
+
+            let (increment, dust) = index_increment(amount, total, SCALE).unwrap();
+            let claimed: i128 = stakes
+                .iter()
+                .map(|stake| mul_div_floor(*stake, increment, SCALE).unwrap())
+                .sum();
+
+            // Never more than distributed, and each holder's own flooring
+            // loses less than one unit
+            assert!(dust >= 0);
+            assert!(claimed + dust <= amount);
+            assert!(amount - claimed - dust <= holders as i128);
+        }
+    }
+
+    #[test]
+    fn test_ceil_and_floor_split_exactly() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+
+        for _ in 0..500 {
+            let amount = (next(&mut seed) % 1_000_000_000) as i128;
+            let bps = (next(&mut seed) % 10_001) as u32;
+
+            // A cut rounded up for the pool leaves the rest rounded down
+            let kept = bps_of_ceil(amount, bps).unwrap();
+            let paid = amount - kept;
+            assert_eq!(paid, mul_div_floor(amount, (MAX_BASIS_POINTS - bps) as i128, MAX_BASIS_POINTS as i128).unwrap());
+            assert!(kept >= bps_of(amount, bps).unwrap());
+        }
+    }
+}
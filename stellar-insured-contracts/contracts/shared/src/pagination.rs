@@ -0,0 +1,266 @@
+//! Pagination and index-list storage
+//!
+//! An index list stores each item under its own `(tag, list, index)` key
+//! with the length under `(tag, list)`, so appends and removals touch a
+//! constant number of entries however long the list grows. Removal swaps the
+//! last item into the freed slot, so order is not preserved.
+//!
+//! Listing endpoints read a window with `read_page` (or `page_of` for lists
+//! kept in a single `Vec`) and return it as a [`Page`]. Contract functions
+//! cannot return generic types, so pages cross the contract boundary as
+//! [`IdPage`] or [`AddressPage`].
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+/// Largest page any listing endpoint returns
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+const INDEX_LEN: Symbol = symbol_short!("IDX_LEN");
+const INDEX_ITEM: Symbol = symbol_short!("IDX_ITEM");
+
+/// A window of a list. `next_cursor` is the cursor of the following page,
+/// or `None` once the end of the list is reached.
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<u32>,
+    pub total: u32,
+}
+
+/// Page of ids returned by listing endpoints
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdPage {
+    pub items: Vec<u64>,
+    pub next_cursor: Option<u32>,
+    pub total: u32,
+}
+
+/// Page of addresses returned by listing endpoints
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressPage {
+    pub items: Vec<Address>,
+    pub next_cursor: Option<u32>,
+    pub total: u32,
+}
+
+impl From<Page<u64>> for IdPage {
+    fn from(page: Page<u64>) -> Self {
+        IdPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            total: page.total,
+        }
+    }
+}
+
+impl From<Page<Address>> for AddressPage {
+    fn from(page: Page<Address>) -> Self {
+        AddressPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            total: page.total,
+        }
+    }
+}
+
+/// Clamp a requested page size to `1..=MAX_PAGE_SIZE`
+pub fn clamp_limit(limit: u32) -> u32 {
+    limit.clamp(1, MAX_PAGE_SIZE)
+}
+
+fn next_cursor(cursor: u32, taken: u32, total: u32) -> Option<u32> {
+    let next = cursor.saturating_add(taken);
+    if next < total {
+        Some(next)
+    } else {
+        None
+    }
+}
+
+/// Number of items in an index list
+pub fn len<K>(env: &Env, list: &K) -> u32
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+{
+    env.storage()
+        .persistent()
+        .get(&(INDEX_LEN, list.clone()))
+        .unwrap_or(0)
+}
+
+/// Item at `index`, if any
+pub fn get<K, T>(env: &Env, list: &K, index: u32) -> Option<T>
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    T: TryFromVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .get(&(INDEX_ITEM, list.clone(), index))
+}
+
+/// Append an item and return its index
+pub fn append<K, T>(env: &Env, list: &K, item: &T) -> u32
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    T: IntoVal<Env, Val>,
+{
+    let index = len(env, list);
+    env.storage()
+        .persistent()
+        .set(&(INDEX_ITEM, list.clone(), index), item);
+    env.storage()
+        .persistent()
+        .set(&(INDEX_LEN, list.clone()), &(index + 1));
+    index
+}
+
+/// Remove the item at `index` by moving the last item into its slot.
+/// Returns the removed item, or `None` if `index` is out of range.
+pub fn remove_swap<K, T>(env: &Env, list: &K, index: u32) -> Option<T>
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let length = len(env, list);
+    if index >= length {
+        return None;
+    }
+    let removed: T = get(env, list, index)?;
+
+    let last = length - 1;
+    if index != last {
+        let moved: T = get(env, list, last)?;
+        env.storage()
+            .persistent()
+            .set(&(INDEX_ITEM, list.clone(), index), &moved);
+    }
+    env.storage()
+        .persistent()
+        .remove(&(INDEX_ITEM, list.clone(), last));
+    env.storage()
+        .persistent()
+        .set(&(INDEX_LEN, list.clone()), &last);
+
+    Some(removed)
+}
+
+/// Read up to `limit` items of an index list starting at `cursor`
+pub fn read_page<K, T>(env: &Env, list: &K, cursor: u32, limit: u32) -> Page<T>
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let total = len(env, list);
+    let end = cursor.saturating_add(clamp_limit(limit)).min(total);
+
+    let mut items = Vec::new(env);
+    for index in cursor..end {
+        if let Some(item) = get(env, list, index) {
+            items.push_back(item);
+        }
+    }
+
+    Page {
+        items,
+        next_cursor: next_cursor(cursor, end.saturating_sub(cursor), total),
+        total,
+    }
+}
+
+/// Page over a list kept in a single `Vec`
+pub fn page_of<T>(env: &Env, list: &Vec<T>, cursor: u32, limit: u32) -> Page<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let total = list.len();
+    let end = cursor.saturating_add(clamp_limit(limit)).min(total);
+
+    let items = if cursor < end {
+        list.slice(cursor..end)
+    } else {
+        Vec::new(env)
+    };
+
+    Page {
+        items,
+        next_cursor: next_cursor(cursor, end.saturating_sub(cursor), total),
+        total,
+    }
+}
+
+/// Page over the id range `1..=count`, for sequentially numbered records
+pub fn page_of_ids(env: &Env, count: u64, cursor: u32, limit: u32) -> Page<u64> {
+    let total = count.min(u32::MAX as u64) as u32;
+    let end = cursor.saturating_add(clamp_limit(limit)).min(total);
+
+    let mut items = Vec::new(env);
+    for index in cursor..end {
+        items.push_back(index as u64 + 1);
+    }
+
+    Page {
+        items,
+        next_cursor: next_cursor(cursor, end.saturating_sub(cursor), total),
+        total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, vec};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn test_index_list_append_remove_and_page() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+        let list = symbol_short!("LIST");
+
+        env.as_contract(&contract_id, || {
+            for id in 10u64..15 {
+                append(&env, &list, &id);
+            }
+            assert_eq!(len(&env, &list), 5);
+
+            // The last item fills the removed slot
+            assert_eq!(remove_swap::<_, u64>(&env, &list, 1), Some(11));
+            assert_eq!(remove_swap::<_, u64>(&env, &list, 9), None);
+
+            let page: Page<u64> = read_page(&env, &list, 0, 3);
+            assert_eq!(page.items, vec![&env, 10u64, 14, 12]);
+            assert_eq!(page.next_cursor, Some(3));
+            assert_eq!(page.total, 4);
+
+            let page: Page<u64> = read_page(&env, &list, 3, 3);
+            assert_eq!(page.items, vec![&env, 13u64]);
+            assert_eq!(page.next_cursor, None);
+        });
+    }
+
+    #[test]
+    fn test_vec_and_id_pages() {
+        let env = Env::default();
+        let ids = vec![&env, 1u64, 2, 3];
+
+        let page = IdPage::from(page_of(&env, &ids, 1, 0));
+        assert_eq!(page.items, vec![&env, 2u64]);
+        assert_eq!(page.next_cursor, Some(2));
+
+        assert_eq!(page_of(&env, &ids, 5, 10).items.len(), 0);
+
+        let page = page_of_ids(&env, 120, 0, 100);
+        assert_eq!(page.items.len(), MAX_PAGE_SIZE);
+        assert_eq!(page.items.last(), Some(50));
+        assert_eq!(page.next_cursor, Some(50));
+    }
+}
@@ -0,0 +1,142 @@
+//! Utilization-based rate models
+//!
+//! A rate model maps pool utilization (0 to 1) to an annualized rate. The
+//! same curve prices premiums for new cover and gives liquidity providers
+//! the yield they can expect at the current utilization.
+
+use crate::decimal::Decimal;
+use crate::math::MathError;
+use soroban_sdk::contracttype;
+
+/// Annualized rate as a function of utilization
+pub trait InterestRateModel {
+    fn rate(&self, utilization: Decimal) -> Result<Decimal, MathError>;
+}
+
+/// `base + slope * utilization`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinearModel {
+    pub base: Decimal,
+    pub slope: Decimal,
+}
+
+/// Linear up to `kink`, then rising at `slope_high` so rates climb steeply
+/// as the pool approaches full utilization
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KinkedModel {
+    pub base: Decimal,
+    pub slope_low: Decimal,
+    pub slope_high: Decimal,
+    pub kink: Decimal,
+}
+
+/// Rate model selected by governance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateModel {
+    Linear(LinearModel),
+    Kinked(KinkedModel),
+}
+
+impl InterestRateModel for LinearModel {
+    fn rate(&self, utilization: Decimal) -> Result<Decimal, MathError> {
+        self.base.checked_add(self.slope.checked_mul(utilization)?)
+    }
+}
+
+impl InterestRateModel for KinkedModel {
+    fn rate(&self, utilization: Decimal) -> Result<Decimal, MathError> {
+        if utilization <= self.kink {
+            return self.base.checked_add(self.slope_low.checked_mul(utilization)?);
+        }
+        let excess = utilization.checked_sub(self.kink)?;
+        self.base
+            .checked_add(self.slope_low.checked_mul(self.kink)?)?
+            .checked_add(self.slope_high.checked_mul(excess)?)
+    }
+}
+
+impl InterestRateModel for RateModel {
+    fn rate(&self, utilization: Decimal) -> Result<Decimal, MathError> {
+        match self {
+            RateModel::Linear(model) => model.rate(utilization),
+            RateModel::Kinked(model) => model.rate(utilization),
+        }
+    }
+}
+
+impl RateModel {
+    /// Parameters are non-negative and the kink lies within 0 to 1
+    pub fn is_valid(&self) -> bool {
+        match self {
+            RateModel::Linear(model) => model.base >= Decimal::ZERO && model.slope >= Decimal::ZERO,
+            RateModel::Kinked(model) => {
+                model.base >= Decimal::ZERO
+                    && model.slope_low >= Decimal::ZERO
+                    && model.slope_high >= model.slope_low
+                    && model.kink > Decimal::ZERO
+                    && model.kink <= Decimal::ONE
+            }
+        }
+    }
+}
+
+/// `used / total`, capped at 1. An empty pool counts as fully utilized
+/// once anything is used.
+pub fn utilization(used: i128, total: i128) -> Result<Decimal, MathError> {
+    if used <= 0 {
+        return Ok(Decimal::ZERO);
+    }
+    if total <= 0 {
+        return Ok(Decimal::ONE);
+    }
+    Ok(Decimal::from_ratio(used, total)?.min(Decimal::ONE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinked() -> RateModel {
+        RateModel::Kinked(KinkedModel {
+            base: Decimal::from_bps(100),
+            slope_low: Decimal::from_bps(1_000),
+            slope_high: Decimal::from_bps(10_000),
+            kink: Decimal::from_bps(8_000),
+        })
+    }
+
+    #[test]
+    fn test_kinked_rate_steepens_past_kink() {
+        let model = kinked();
+        assert!(model.is_valid());
+        assert_eq!(model.rate(Decimal::ZERO), Ok(Decimal::from_bps(100)));
+        assert_eq!(model.rate(Decimal::from_bps(8_000)), Ok(Decimal::from_bps(900)));
+        assert_eq!(model.rate(Decimal::ONE), Ok(Decimal::from_bps(2_900)));
+    }
+
+    #[test]
+    fn test_linear_rate_and_validation() {
+        let model = RateModel::Linear(LinearModel {
+            base: Decimal::from_bps(200),
+            slope: Decimal::from_bps(400),
+        });
+        assert_eq!(model.rate(Decimal::from_bps(5_000)), Ok(Decimal::from_bps(400)));
+
+        let invalid = RateModel::Linear(LinearModel {
+            base: Decimal(-1),
+            slope: Decimal::ZERO,
+        });
+        assert!(!invalid.is_valid());
+    }
+
+    #[test]
+    fn test_utilization_is_capped() {
+        assert_eq!(utilization(0, 0), Ok(Decimal::ZERO));
+        assert_eq!(utilization(5, 0), Ok(Decimal::ONE));
+        assert_eq!(utilization(1, 4), Ok(Decimal::from_bps(2_500)));
+        assert_eq!(utilization(8, 4), Ok(Decimal::ONE));
+    }
+}
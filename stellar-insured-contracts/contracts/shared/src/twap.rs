@@ -0,0 +1,103 @@
+//! Time-weighted averages of oracle readings
+//!
+//! A window accumulates each reading weighted by how long it stood, from
+//! the moment it was observed until the next observation. The average over
+//! the window is then resistant to a single outlying reading, which a
+//! point-in-time trigger is not.
+
+use crate::math::MathError;
+use soroban_sdk::contracttype;
+
+/// Readings accumulated since `started_at`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TwapWindow {
+    pub started_at: u64,
+    /// Sum of each reading times the seconds it stood
+    pub cumulative: i128,
+    pub last_value: i128,
+    pub last_at: u64,
+}
+
+impl TwapWindow {
+    /// Open a window on a first reading
+    pub fn start(value: i128, at: u64) -> Self {
+        TwapWindow {
+            started_at: at,
+            cumulative: 0,
+            last_value: value,
+            last_at: at,
+        }
+    }
+
+    /// Seconds covered by the readings so far
+    pub fn elapsed(&self) -> u64 {
+        self.last_at - self.started_at
+    }
+
+    /// Book the last reading up to `at` and make `value` the reading from
+    /// then on. Readings at or before the last one are ignored.
+    pub fn observe(&mut self, value: i128, at: u64) -> Result<(), MathError> {
+        if at <= self.last_at {
+            return Ok(());
+        }
+        let weighted = self
+            .last_value
+            .checked_mul((at - self.last_at) as i128)
+            .ok_or(MathError::Overflow)?;
+        self.cumulative = self.cumulative.checked_add(weighted).ok_or(MathError::Overflow)?;
+        self.last_value = value;
+        self.last_at = at;
+        Ok(())
+    }
+
+    /// Time-weighted average over the window, rounded down; `None` until
+    /// any time has passed
+    pub fn average(&self) -> Option<i128> {
+        match self.elapsed() {
+            0 => None,
+            elapsed => Some(self.cumulative.div_euclid(elapsed as i128)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_average_weights_readings_by_time() {
+        let mut window = TwapWindow::start(1_000, 100);
+        assert_eq!(window.average(), None);
+
+        // 1_000 for 30s, then 400 for 10s
+        window.observe(400, 130).unwrap();
+        window.observe(900, 140).unwrap();
+        assert_eq!(window.elapsed(), 40);
+        assert_eq!(window.average(), Some(850));
+    }
+
+    #[test]
+    fn test_brief_spike_barely_moves_average() {
+        let mut window = TwapWindow::start(9_700, 0);
+        window.observe(10_000, 3_595).unwrap();
+        window.observe(9_700, 3_600).unwrap();
+        assert!(window.average().unwrap() < 9_800);
+    }
+
+    #[test]
+    fn test_ignores_stale_readings() {
+        let mut window = TwapWindow::start(5, 10);
+        window.observe(7, 20).unwrap();
+        window.observe(1_000, 20).unwrap();
+        window.observe(1_000, 15).unwrap();
+        assert_eq!(window.last_value, 7);
+        assert_eq!(window.average(), Some(5));
+    }
+
+    #[test]
+    fn test_reports_overflow() {
+        let mut window = TwapWindow::start(i128::MAX, 0);
+        assert_eq!(window.observe(1, 2), Err(MathError::Overflow));
+    }
+}
@@ -29,14 +29,16 @@ pub enum PolicyStatus {
     Claimed = 3,
 }
 
-/// Represents the lifecycle status of a claim
+/// Represents the lifecycle status of a claim. This is the one claim status
+/// used by every contract.
 ///
 /// # Transitions
-/// - `Submitted` → `UnderReview`, `Rejected`
-/// - `UnderReview` → `Approved`, `Rejected`
-/// - `Approved` → `Settled`
-/// - `Rejected` → Terminal (no further transitions)
-/// - `Settled` → Terminal (no further transitions)
+/// - `Submitted` → `UnderReview`, `Withdrawn`
+/// - `UnderReview` → `Approved`, `Rejected`, `Withdrawn`
+/// - `Approved` → `PendingSettlement`, `Settled`
+/// - `PendingSettlement` → `Disputed`, `Settled`
+/// - `Disputed` → `PendingSettlement`, `Rejected`
+/// - `Rejected`, `Settled`, `Withdrawn` → Terminal (no further transitions)
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ClaimStatus {
@@ -54,6 +56,44 @@ pub enum ClaimStatus {
 
     /// Claim has been settled (payment made)
     Settled = 4,
+
+    /// Claim is approved and waiting out its dispute window
+    PendingSettlement = 5,
+
+    /// Claim settlement has been disputed
+    Disputed = 6,
+
+    /// Claim was withdrawn by the claimant before a decision
+    Withdrawn = 7,
+}
+
+impl ClaimStatus {
+    /// Whether a claim in this status may move to `next`
+    pub fn can_transition_to(self, next: ClaimStatus) -> bool {
+        use ClaimStatus::*;
+        matches!(
+            (self, next),
+            (Submitted, UnderReview)
+                | (Submitted, Withdrawn)
+                | (UnderReview, Approved)
+                | (UnderReview, Rejected)
+                | (UnderReview, Withdrawn)
+                | (Approved, PendingSettlement)
+                | (Approved, Settled)
+                | (PendingSettlement, Disputed)
+                | (PendingSettlement, Settled)
+                | (Disputed, PendingSettlement)
+                | (Disputed, Rejected)
+        )
+    }
+
+    /// Whether no further transitions are possible
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            ClaimStatus::Rejected | ClaimStatus::Settled | ClaimStatus::Withdrawn
+        )
+    }
 }
 
 /// Represents the status of a governance proposal
@@ -359,3 +399,23 @@ pub enum DataKey {
     /// Authorization role
     AuthRole,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_status_transitions() {
+        use ClaimStatus::*;
+        assert!(Submitted.can_transition_to(UnderReview));
+        assert!(Approved.can_transition_to(PendingSettlement));
+        assert!(PendingSettlement.can_transition_to(Disputed));
+        assert!(Disputed.can_transition_to(PendingSettlement));
+        assert!(!Submitted.can_transition_to(Approved));
+        assert!(!Disputed.can_transition_to(Settled));
+        for terminal in [Rejected, Settled, Withdrawn] {
+            assert!(terminal.is_terminal());
+            assert!(!terminal.can_transition_to(UnderReview));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Amounts across assets of different decimals
+//!
+//! Protocol amounts such as coverage, premiums, limits and pool balances
+//! are kept in canonical units of `CANONICAL_DECIMALS`, the seven decimals
+//! of a classic Stellar asset, so amounts from 6, 7 or 18 decimal tokens
+//! can be compared and summed. Token amounts are converted only where
+//! tokens move: charges round up and payouts round down, as in `math`.
+
+use crate::math::{mul_div_ceil, mul_div_floor, MathError};
+
+/// Decimals of canonical protocol amounts
+pub const CANONICAL_DECIMALS: u32 = 7;
+
+/// Largest exponent `pow10` accepts; 10^38 is the largest power of ten an
+/// i128 holds
+pub const MAX_DECIMALS_EXPONENT: u32 = 38;
+
+/// `10^exp`
+pub fn pow10(exp: u32) -> Result<i128, MathError> {
+    if exp > MAX_DECIMALS_EXPONENT {
+        return Err(MathError::Overflow);
+    }
+    Ok(10i128.pow(exp))
+}
+
+/// Convert `amount` from `from` decimals to `to` decimals, rounded down
+pub fn rescale_floor(amount: i128, from: u32, to: u32) -> Result<i128, MathError> {
+    if to >= from {
+        amount.checked_mul(pow10(to - from)?).ok_or(MathError::Overflow)
+    } else {
+        mul_div_floor(amount, 1, pow10(from - to)?)
+    }
+}
+
+/// Convert `amount` from `from` decimals to `to` decimals, rounded up
+pub fn rescale_ceil(amount: i128, from: u32, to: u32) -> Result<i128, MathError> {
+    if to >= from {
+        amount.checked_mul(pow10(to - from)?).ok_or(MathError::Overflow)
+    } else {
+        mul_div_ceil(amount, 1, pow10(from - to)?)
+    }
+}
+
+/// Canonical value of a token amount, rounded down
+pub fn to_canonical(amount: i128, decimals: u32) -> Result<i128, MathError> {
+    rescale_floor(amount, decimals, CANONICAL_DECIMALS)
+}
+
+/// Token amount to charge for a canonical `amount`, rounded up
+pub fn to_native_ceil(amount: i128, decimals: u32) -> Result<i128, MathError> {
+    rescale_ceil(amount, CANONICAL_DECIMALS, decimals)
+}
+
+/// Token amount to pay out for a canonical `amount`, rounded down
+pub fn to_native_floor(amount: i128, decimals: u32) -> Result<i128, MathError> {
+    rescale_floor(amount, CANONICAL_DECIMALS, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_between_common_decimals() {
+        // 12.3456789 canonical
+        let amount = 123_456_789;
+        assert_eq!(to_native_floor(amount, 7), Ok(amount));
+        assert_eq!(to_native_floor(amount, 6), Ok(12_345_678));
+        assert_eq!(to_native_ceil(amount, 6), Ok(12_345_679));
+        assert_eq!(to_native_ceil(amount, 18), Ok(12_345_678_900_000_000_000));
+
+        assert_eq!(to_canonical(12_345_678, 6), Ok(123_456_780));
+        assert_eq!(to_canonical(12_345_678_912_345_678_912, 18), Ok(123_456_789));
+    }
+
+    #[test]
+    fn test_amounts_in_different_assets_compare_canonically() {
+        let usdc = to_canonical(1_000_000, 6).unwrap();
+        let xlm = to_canonical(10_000_000, 7).unwrap();
+        let eth = to_canonical(1_000_000_000_000_000_000, 18).unwrap();
+        assert_eq!(usdc, xlm);
+        assert_eq!(xlm, eth);
+        assert_eq!(usdc + xlm + eth, 30_000_000);
+    }
+
+    #[test]
+    fn test_charges_never_fall_short() {
+        for amount in [1i128, 9, 10, 11, 99_999, 1_000_001] {
+            let charged = to_native_ceil(amount, 6).unwrap();
+            assert!(to_canonical(charged, 6).unwrap() >= amount);
+            let paid = to_native_floor(amount, 6).unwrap();
+            assert!(to_canonical(paid, 6).unwrap() <= amount);
+        }
+        assert_eq!(to_native_ceil(-15, 6), Ok(-1));
+    }
+
+    #[test]
+    fn test_reports_overflow() {
+        assert_eq!(pow10(39), Err(MathError::Overflow));
+        assert_eq!(to_native_ceil(i128::MAX / 10, 18), Err(MathError::Overflow));
+    }
+}
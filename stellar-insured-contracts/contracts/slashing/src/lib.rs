@@ -1,18 +1,23 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol, Vec};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, BytesN, Env, String, Symbol, symbol_short, Vec};
 
 #[contract]
 pub struct SlashingContract;
 
-const ADMIN: Symbol = Symbol::short("ADMIN");
-const PAUSED: Symbol = Symbol::short("PAUSED");
-const CONFIG: Symbol = Symbol::short("CONFIG");
-const SLASHING_RECORD: Symbol = Symbol::short("SLASH_REC");
-const SLASHABLE_ROLES: Symbol = Symbol::short("SLASH_RL");
-const PENALTY_PARAMS: Symbol = Symbol::short("PENALTY");
-const SLASH_COUNTER: Symbol = Symbol::short("SLASH_CNT");
-const GOVERNANCE_CONTRACT: Symbol = Symbol::short("GOV_CON");
-const RISK_POOL_CONTRACT: Symbol = Symbol::short("RISK_PO");
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const SLASHING_RECORD: Symbol = symbol_short!("SLASH_REC");
+const SLASHABLE_ROLES: Symbol = symbol_short!("SLASH_RL");
+const PENALTY_PARAMS: Symbol = symbol_short!("PENALTY");
+const SLASH_COUNTER: Symbol = symbol_short!("SLASH_CNT");
+const GOVERNANCE_CONTRACT: Symbol = symbol_short!("GOV_CON");
+const RISK_POOL_CONTRACT: Symbol = symbol_short!("RISK_PO");
+const WASM_HASH: Symbol = symbol_short!("WASM_HASH");
+
+/// Stored slash: (slash_id, target, role, reason, timestamp, penalty_amount,
+/// destination, violation_count)
+type SlashingRecord = (u64, Address, u32, u32, u64, i128, u32, u32);
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum SlashingRole {
@@ -43,6 +48,28 @@ pub enum PenaltyDestination {
     CompensationFund = 3,
 }
 
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub governance_contract: Address,
+    pub risk_pool_contract: Address,
+}
+
+/// Crate version and the wasm installed by the last upgrade
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    /// Semantic version of the contract crate
+    pub semver: String,
+    /// Whether the contract has been upgraded since deployment
+    pub has_wasm_hash: bool,
+    /// Wasm installed by the last upgrade; all zeroes for the original
+    /// deployment
+    pub wasm_hash: BytesN<32>,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ContractError {
@@ -67,6 +94,13 @@ fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.governance_contract)?;
+    validate_address(env, &config.risk_pool_contract)?;
+    Ok(())
+}
+
 fn is_paused(env: &Env) -> bool {
     env.storage()
         .persistent()
@@ -121,9 +155,9 @@ fn is_role_slashable(env: &Env, role: u32) -> bool {
         .storage()
         .persistent()
         .get(&SLASHABLE_ROLES)
-        .unwrap_or_else(|| Vec::new(&env));
+        .unwrap_or_else(|| Vec::new(env));
     
-    slashable_roles.contains(&role)
+    slashable_roles.contains(role)
 }
 
 fn calculate_penalty_amount(
@@ -143,11 +177,11 @@ fn calculate_penalty_amount(
 }
 
 fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64) -> bool {
-    let slashing_records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+    let slashing_records: Vec<SlashingRecord> = env
         .storage()
         .persistent()
         .get(&(SLASHING_RECORD, target, role))
-        .unwrap_or_else(|| Vec::new(&env));
+        .unwrap_or_else(|| Vec::new(env));
     
     let cooldown_period = 86400u64; // 24 hours in seconds
     
@@ -162,19 +196,13 @@ fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64
 
 #[contractimpl]
 impl SlashingContract {
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        governance_contract: Address,
-        risk_pool_contract: Address,
-    ) -> Result<(), ContractError> {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
         if env.storage().persistent().has(&ADMIN) {
             return Err(ContractError::AlreadyInitialized);
         }
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &governance_contract)?;
-        validate_address(&env, &risk_pool_contract)?;
+        validate_init_config(&env, &config)?;
+        let InitConfig { admin, governance_contract, risk_pool_contract } = config;
 
         env.storage().persistent().set(&ADMIN, &admin);
         env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance_contract);
@@ -233,7 +261,8 @@ impl SlashingContract {
             .persistent()
             .set(&(PENALTY_PARAMS, role, reason), &penalty_params);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "penalty_configured"), role),
             (reason, penalty_percentage, destination),
         );
@@ -250,12 +279,13 @@ impl SlashingContract {
             .get(&SLASHABLE_ROLES)
             .unwrap_or_else(|| Vec::new(&env));
 
-        if !slashable_roles.contains(&role) {
+        if !slashable_roles.contains(role) {
             slashable_roles.push_back(role);
             env.storage().persistent().set(&SLASHABLE_ROLES, &slashable_roles);
         }
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_added"), role),
             (),
         );
@@ -278,7 +308,8 @@ impl SlashingContract {
         }
         env.storage().persistent().set(&SLASHABLE_ROLES, &slashable_roles);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "role_removed"), role),
             (),
         );
@@ -341,7 +372,7 @@ impl SlashingContract {
             violation_count + 1,
         );
 
-        let mut user_records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+        let mut user_records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target.clone(), role))
@@ -355,7 +386,8 @@ impl SlashingContract {
 
         Self::redirect_funds(env.clone(), penalty_amount, penalty_params.3)?;
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "funds_slashed"), slash_id),
             (target, role, reason, penalty_amount, penalty_params.3),
         );
@@ -375,28 +407,32 @@ impl SlashingContract {
                 
                 // In a real implementation, this would call the risk pool contract
                 // to deposit the slashed funds
-                env.events().publish(
+                publish_event(
+                    &env,
                     (Symbol::new(&env, "funds_redirected"), 0u32),
                     (risk_pool_contract, amount),
                 );
             }
             1 => {
                 // Treasury - funds are burned or sent to treasury address
-                env.events().publish(
+                publish_event(
+                    &env,
                     (Symbol::new(&env, "funds_burned"), 1u32),
                     (amount,),
                 );
             }
             2 => {
                 // Burn directly
-                env.events().publish(
+                publish_event(
+                    &env,
                     (Symbol::new(&env, "funds_burned"), 2u32),
                     (amount,),
                 );
             }
             3 => {
                 // Compensation Fund
-                env.events().publish(
+                publish_event(
+                    &env,
                     (Symbol::new(&env, "funds_redirected"), 3u32),
                     (amount,),
                 );
@@ -408,21 +444,21 @@ impl SlashingContract {
     }
 
     pub fn get_violation_count(env: Env, target: Address, role: u32) -> Result<u32, ContractError> {
-        let records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+        let records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target, role))
             .unwrap_or_else(|| Vec::new(&env));
 
-        Ok(records.len() as u32)
+        Ok(records.len())
     }
 
     pub fn get_slashing_history(
         env: Env,
         target: Address,
         role: u32,
-    ) -> Result<Vec<(u64, Address, u32, u32, u64, i128, u32, u32)>, ContractError> {
-        let records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+    ) -> Result<Vec<SlashingRecord>, ContractError> {
+        let records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target, role))
@@ -468,6 +504,29 @@ impl SlashingContract {
         Ok(!has_recent_slashing(&env, &target, role, current_time))
     }
 
+    /// Repoint the governance and risk pool contracts (admin only)
+    pub fn update_config(
+        env: Env,
+        governance_contract: Address,
+        risk_pool_contract: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env)?;
+
+        validate_address(&env, &governance_contract)?;
+        validate_address(&env, &risk_pool_contract)?;
+
+        env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance_contract);
+        env.storage().persistent().set(&RISK_POOL_CONTRACT, &risk_pool_contract);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "config_updated"), ()),
+            (governance_contract, risk_pool_contract),
+        );
+
+        Ok(())
+    }
+
     pub fn pause(env: Env) -> Result<(), ContractError> {
         require_admin(&env)?;
         set_paused(&env, true);
@@ -508,23 +567,77 @@ impl SlashingContract {
 
         Ok((slash_count, unique_addresses, total_slashed))
     }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        let wasm_hash: Option<BytesN<32>> = env.storage().persistent().get(&WASM_HASH);
+        ContractVersion {
+            semver: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            has_wasm_hash: wasm_hash.is_some(),
+            wasm_hash: wasm_hash.unwrap_or_else(|| BytesN::from_array(&env, &[0; 32])),
+        }
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        let mut interfaces = Vec::new(&env);
+        interfaces.push_back(Symbol::new(&env, "slashing_v1"));
+        interfaces.push_back(Symbol::new(&env, "version_v1"));
+        interfaces
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        env.storage().persistent().set(&WASM_HASH, &new_wasm_hash);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{Address, Env};
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::Env;
 
     #[test]
     fn test_contract_compiles() {
         // Basic test to ensure the contract compiles correctly
         let env = Env::default();
         
-        let contract_id = env.register_contract(None, SlashingContract);
-        
-        // Test that we can create the contract
-        // Just verify the contract ID exists
-        assert!(true);
+        // Registering the contract is the check
+        env.register(SlashingContract, ());
+    }
+
+    #[test]
+    fn test_events_are_sequence_numbered() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = SlashingContractClient::new(&env, &env.register(SlashingContract, ()));
+        // Admin calls are checked against the contract's own address
+        client.initialize(&InitConfig {
+            admin: client.address.clone(),
+            governance_contract: Address::generate(&env),
+            risk_pool_contract: Address::generate(&env),
+        });
+        assert_eq!(client.get_last_event_seq(), 0);
+
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::FraudulentClaim as u32;
+        client.configure_penalty_parameters(&role, &reason, &50, &(PenaltyDestination::RiskPool as u32), &2, &86_400);
+        assert_eq!(client.get_last_event_seq(), 1);
+
+        // A slash redirects the funds, then records the slash
+        client.slash_funds(&Address::generate(&env), &role, &reason, &1_000);
+        assert_eq!(env.events().all().events().len(), 2);
+        assert_eq!(client.get_last_event_seq(), 3);
     }
 
     #[test]
@@ -1,28 +1,33 @@
 #![no_std]
 // Import authorization from the common library
-use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin,
-    require_governance_permission, Role,
+use insurance_contracts::allowlist::{self, AllowlistError};
+use insurance_contracts::authorization::{initialize_admin, require_admin, require_governance_permission};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::payout_delay::{self, PayoutDelayConfig, PayoutDelayError};
+use insurance_contracts::rescue::{self, RescueError};
+use insurance_contracts::version::{
+    self, ContractVersion, IFACE_ALLOWLIST, IFACE_PAYOUT_DELAY, IFACE_RESCUE, IFACE_VERSION,
 };
 // Import invariant checks
-use insurance_invariants::{InvariantError, ProtocolInvariants};
+use insurance_invariants::InvariantError;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, token, Address, BytesN, Env, Symbol, symbol_short, Vec,
 };
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-const PAUSED: Symbol = Symbol::short("PAUSED");
-const CONFIG: Symbol = Symbol::short("CONFIG");
-const TREASURY_BALANCE: Symbol = Symbol::short("BALANCE");
-const WITHDRAWAL_PROPOSALS: Symbol = Symbol::short("WITH_PROP");
-const PROPOSAL_COUNTER: Symbol = Symbol::short("PROP_CNT");
-const ALLOCATIONS: Symbol = Symbol::short("ALLOC");
-const TOTAL_FEES_COLLECTED: Symbol = Symbol::short("TOTAL_FEE");
-const TOTAL_WITHDRAWN: Symbol = Symbol::short("TOTAL_WIT");
-const TRUSTED_CONTRACTS: Symbol = Symbol::short("TRUST_CON");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const TREASURY_BALANCE: Symbol = symbol_short!("BALANCE");
+const WITHDRAWAL_PROPOSALS: Symbol = symbol_short!("WITH_PROP");
+const PROPOSAL_COUNTER: Symbol = symbol_short!("PROP_CNT");
+const ALLOCATIONS: Symbol = symbol_short!("ALLOC");
+const TOTAL_FEES_COLLECTED: Symbol = symbol_short!("TOTAL_FEE");
+const TOTAL_WITHDRAWN: Symbol = symbol_short!("TOTAL_WIT");
+const TRUSTED_CONTRACTS: Symbol = symbol_short!("TRUST_CON");
+const DISPUTE_REWARDS: Symbol = symbol_short!("DSP_RWD");
 
 // ============================================================================
 // Error Handling
@@ -49,6 +54,10 @@ pub enum ContractError {
     ProposalNotActive = 16,
     QuorumNotMet = 17,
     ThresholdNotMet = 18,
+    RecipientNotAllowed = 19,
+    RecipientPending = 20,
+    PayoutLocked = 21,
+    PayoutHeld = 22,
     // Invariant violation errors (100-199)
     InvalidAmount = 103,
     BalanceViolation = 100,
@@ -74,6 +83,35 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
     }
 }
 
+impl From<AllowlistError> for ContractError {
+    fn from(err: AllowlistError) -> Self {
+        match err {
+            AllowlistError::RecipientNotAllowed => ContractError::RecipientNotAllowed,
+            AllowlistError::RecipientPending => ContractError::RecipientPending,
+        }
+    }
+}
+
+impl From<PayoutDelayError> for ContractError {
+    fn from(err: PayoutDelayError) -> Self {
+        match err {
+            PayoutDelayError::NotPending => ContractError::NotFound,
+            PayoutDelayError::StillLocked => ContractError::PayoutLocked,
+            PayoutDelayError::NotGuardian => ContractError::Unauthorized,
+            PayoutDelayError::Held => ContractError::PayoutHeld,
+        }
+    }
+}
+
+impl From<RescueError> for ContractError {
+    fn from(err: RescueError) -> Self {
+        match err {
+            RescueError::InvalidAmount => ContractError::InvalidAmount,
+            RescueError::ExceedsSurplus => ContractError::InsufficientFunds,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -118,6 +156,15 @@ pub struct TreasuryConfig {
     pub fee_percentage: u32, // Fee percentage in basis points (e.g., 500 = 5%)
 }
 
+/// Deployment parameters for `initialize`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    pub admin: Address,
+    pub governance_contract: Address,
+    pub fee_percentage: u32, // Fee percentage in basis points (e.g., 500 = 5%)
+}
+
 /// Fee deposit record
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -185,6 +232,16 @@ fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+fn validate_init_config(env: &Env, config: &InitConfig) -> Result<(), ContractError> {
+    validate_address(env, &config.admin)?;
+    validate_address(env, &config.governance_contract)?;
+
+    if config.fee_percentage == 0 || config.fee_percentage > 10000 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
 fn is_paused(env: &Env) -> bool {
     env.storage().persistent().get(&PAUSED).unwrap_or(false)
 }
@@ -236,23 +293,14 @@ fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
 impl TreasuryContract {
     /// Initialize the Treasury contract
     /// Only called once at deployment
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        governance_contract: Address,
-        fee_percentage: u32,
-    ) -> Result<(), ContractError> {
+    pub fn initialize(env: Env, config: InitConfig) -> Result<(), ContractError> {
         // Check if already initialized
         if insurance_contracts::authorization::get_admin(&env).is_some() {
             return Err(ContractError::AlreadyInitialized);
         }
 
-        validate_address(&env, &admin)?;
-        validate_address(&env, &governance_contract)?;
-
-        if fee_percentage == 0 || fee_percentage > 10000 {
-            return Err(ContractError::InvalidInput);
-        }
+        validate_init_config(&env, &config)?;
+        let InitConfig { admin, governance_contract, fee_percentage } = config;
 
         admin.require_auth();
         initialize_admin(&env, admin.clone());
@@ -265,7 +313,7 @@ impl TreasuryContract {
         env.storage().persistent().set(&TOTAL_WITHDRAWN, &0i128);
         env.storage().persistent().set(&PROPOSAL_COUNTER, &0u64);
 
-        env.events().publish((Symbol::new(&env, "treasury_initialized"), ()), admin);
+        publish_event(&env, (Symbol::new(&env, "treasury_initialized"), ()), admin);
 
         Ok(())
     }
@@ -273,14 +321,17 @@ impl TreasuryContract {
     /// Register a trusted contract that can deposit fees
     pub fn register_trusted_contract(
         env: Env,
+        admin: Address,
         contract_address: Address,
     ) -> Result<(), ContractError> {
-        require_admin(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
         validate_address(&env, &contract_address)?;
 
         env.storage().persistent().set(&(TRUSTED_CONTRACTS, &contract_address), &true);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "trusted_contract_registered"), contract_address.clone()),
             contract_address,
         );
@@ -288,6 +339,52 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Let the governance contract call admin and governance setters, or
+    /// stop it with `None` (admin only)
+    pub fn set_governance_contract(env: Env, admin: Address, governance: Option<Address>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::set_governance_contract(&env, &admin, governance.as_ref())?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "governance_contract_set"), admin),
+            governance,
+        );
+
+        Ok(())
+    }
+
+    /// Governance contract holding admin powers, if any
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        insurance_contracts::authorization::get_governance_contract(&env)
+    }
+
+    /// Schedule the one-way handover to governance (admin only). From
+    /// `sunset_at` the admin's powers end and only the governance contract
+    /// can make admin calls; the time can be brought forward, never pushed
+    /// back.
+    pub fn schedule_admin_sunset(env: Env, admin: Address, sunset_at: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        insurance_contracts::authorization::schedule_admin_sunset(&env, &admin, sunset_at)?;
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "admin_sunset_scheduled"), admin),
+            insurance_contracts::authorization::get_admin_sunset(&env),
+        );
+
+        Ok(())
+    }
+
+    /// When the admin's powers end, if a handover is scheduled
+    pub fn get_admin_sunset(env: Env) -> Option<u64> {
+        insurance_contracts::authorization::get_admin_sunset(&env)
+    }
+
     /// Deposit premium fees from policy contract
     pub fn deposit_premium_fee(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
         if is_paused(&env) {
@@ -297,8 +394,8 @@ impl TreasuryContract {
         validate_amount(amount)?;
 
         // Verify caller is a trusted contract
-        let caller = env.invoker();
-        if !is_trusted_contract(&env, &caller) {
+        from.require_auth();
+        if !is_trusted_contract(&env, &from) {
             return Err(ContractError::NotTrustedContract);
         }
 
@@ -312,7 +409,8 @@ impl TreasuryContract {
         let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
         env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "premium_fee_deposited"), from.clone()),
             (amount, new_balance, new_total),
         );
@@ -333,8 +431,8 @@ impl TreasuryContract {
         validate_amount(amount)?;
 
         // Verify caller is a trusted contract
-        let caller = env.invoker();
-        if !is_trusted_contract(&env, &caller) {
+        from.require_auth();
+        if !is_trusted_contract(&env, &from) {
             return Err(ContractError::NotTrustedContract);
         }
 
@@ -348,7 +446,8 @@ impl TreasuryContract {
         let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
         env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "claim_penalty_deposited"), from.clone()),
             (amount, new_balance, new_total),
         );
@@ -369,8 +468,8 @@ impl TreasuryContract {
         validate_amount(amount)?;
 
         // Verify caller is a trusted contract
-        let caller = env.invoker();
-        if !is_trusted_contract(&env, &caller) {
+        from.require_auth();
+        if !is_trusted_contract(&env, &from) {
             return Err(ContractError::NotTrustedContract);
         }
 
@@ -384,7 +483,8 @@ impl TreasuryContract {
         let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
         env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "slashing_fee_deposited"), from.clone()),
             (amount, new_balance, new_total),
         );
@@ -406,8 +506,8 @@ impl TreasuryContract {
         validate_amount(amount)?;
 
         // Verify caller is a trusted contract
-        let caller = env.invoker();
-        if !is_trusted_contract(&env, &caller) {
+        from.require_auth();
+        if !is_trusted_contract(&env, &from) {
             return Err(ContractError::NotTrustedContract);
         }
 
@@ -421,7 +521,8 @@ impl TreasuryContract {
         let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
         env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "fee_deposited"), from.clone()),
             (amount, fee_type, new_balance, new_total),
         );
@@ -452,9 +553,9 @@ impl TreasuryContract {
             return Err(ContractError::InsufficientFunds);
         }
 
-        // Get config
-        let config: TreasuryConfig =
-            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        if !env.storage().persistent().has(&CONFIG) {
+            return Err(ContractError::NotInitialized);
+        }
 
         // Get current timestamp for voting period (7 days = 604800 seconds)
         let now = env.ledger().timestamp();
@@ -480,7 +581,8 @@ impl TreasuryContract {
 
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "withdrawal_proposed"), proposal.recipient.clone()),
             (
                 proposal_id,
@@ -495,12 +597,13 @@ impl TreasuryContract {
     }
 
     /// Execute approved withdrawal (DAO governance required)
-    pub fn execute_withdrawal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+    pub fn execute_withdrawal(env: Env, admin: Address, proposal_id: u64) -> Result<(), ContractError> {
         if is_paused(&env) {
             return Err(ContractError::Paused);
         }
 
-        require_admin(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         // Get proposal
         let mut proposal: WithdrawalProposal = env
@@ -519,6 +622,23 @@ impl TreasuryContract {
             return Err(ContractError::ProposalNotApproved);
         }
 
+        allowlist::require_allowed(&env, &proposal.recipient)?;
+
+        // Large withdrawals are scheduled first and executed by a second
+        // call once the guardian's cancellation window has passed
+        if payout_delay::requires_delay(&env, proposal.amount) {
+            if payout_delay::pending_release_at(&env, proposal_id).is_none() {
+                let release_at = payout_delay::schedule(&env, proposal_id)?;
+                publish_event(
+                    &env,
+                    (Symbol::new(&env, "withdrawal_pending"), proposal.recipient.clone()),
+                    (proposal_id, proposal.amount, release_at),
+                );
+                return Ok(());
+            }
+            payout_delay::release(&env, proposal_id)?;
+        }
+
         // Check treasury has sufficient balance
         let balance = get_balance(&env);
         if proposal.amount > balance {
@@ -558,7 +678,8 @@ impl TreasuryContract {
         proposal.executed = true;
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "withdrawal_executed"), proposal.recipient.clone()),
             (proposal_id, proposal.amount, new_balance, proposal.purpose),
         );
@@ -567,8 +688,9 @@ impl TreasuryContract {
     }
 
     /// Reject a withdrawal proposal
-    pub fn reject_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn reject_proposal(env: Env, admin: Address, proposal_id: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         let mut proposal: WithdrawalProposal = env
             .storage()
@@ -583,7 +705,8 @@ impl TreasuryContract {
         proposal.status = 2; // Rejected
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "proposal_rejected"), proposal.recipient.clone()),
             (proposal_id, proposal.amount, proposal.purpose),
         );
@@ -592,8 +715,9 @@ impl TreasuryContract {
     }
 
     /// Approve a withdrawal proposal (DAO governance required)
-    pub fn approve_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn approve_proposal(env: Env, admin: Address, proposal_id: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         let mut proposal: WithdrawalProposal = env
             .storage()
@@ -614,7 +738,8 @@ impl TreasuryContract {
         proposal.status = 1; // Approved
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
-        env.events().publish(
+        publish_event(
+            &env,
             (Symbol::new(&env, "proposal_approved"), proposal.recipient.clone()),
             (proposal_id, proposal.amount, proposal.purpose),
         );
@@ -622,6 +747,55 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Pay the reward for an upheld claim dispute (trusted contracts only)
+    pub fn pay_dispute_reward(
+        env: Env,
+        caller_contract: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        caller_contract.require_auth();
+        if !is_trusted_contract(&env, &caller_contract) {
+            return Err(ContractError::NotTrustedContract);
+        }
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(amount)?;
+
+        let balance = get_balance(&env);
+        if amount > balance {
+            return Err(ContractError::InsufficientFunds);
+        }
+        let new_balance = balance.checked_sub(amount).ok_or(ContractError::BalanceViolation)?;
+        set_balance(&env, new_balance)?;
+
+        let total_rewards: i128 = env.storage().persistent().get(&DISPUTE_REWARDS).unwrap_or(0i128);
+        let new_total = total_rewards.checked_add(amount).ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&DISPUTE_REWARDS, &new_total);
+
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "dispute_reward_paid"), recipient),
+            (caller_contract, amount, new_balance),
+        );
+
+        Ok(())
+    }
+
+    /// Total paid out as dispute rewards
+    pub fn get_dispute_rewards_paid(env: Env) -> i128 {
+        env.storage().persistent().get(&DISPUTE_REWARDS).unwrap_or(0i128)
+    }
+
     /// Get current treasury balance
     pub fn get_balance(env: Env) -> i128 {
         get_balance(&env)
@@ -647,6 +821,47 @@ impl TreasuryContract {
         })
     }
 
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(
+            &env,
+            &[
+                "treasury_v1",
+                IFACE_VERSION,
+                IFACE_ALLOWLIST,
+                IFACE_PAYOUT_DELAY,
+                IFACE_RESCUE,
+            ],
+        )
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(&env, (Symbol::new(&env, "upgraded"), ()), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
     /// Get withdrawal proposal details
     pub fn get_proposal(env: Env, proposal_id: u64) -> Result<WithdrawalProposal, ContractError> {
         env.storage()
@@ -663,19 +878,164 @@ impl TreasuryContract {
             .ok_or(ContractError::NotFound)
     }
 
+    /// Recover tokens sent to the treasury by mistake (governance only).
+    /// Treasury fees are booked without holding a token balance, so any
+    /// token balance held by the contract is recoverable.
+    pub fn rescue_tokens(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        rescue::transfer_surplus(&env, &token, amount, &recipient, 0)?;
+
+        publish_event(&env, (Symbol::new(&env, "tokens_rescued"), token), (recipient, amount));
+
+        Ok(())
+    }
+
+    /// Delay withdrawals above `threshold` by `delay` seconds, cancellable by
+    /// `guardian` in the meantime (governance only)
+    pub fn set_payout_delay(
+        env: Env,
+        caller: Address,
+        threshold: i128,
+        delay: u64,
+        guardian: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        validate_amount(threshold)?;
+
+        payout_delay::set_config(
+            &env,
+            &PayoutDelayConfig { threshold, delay, guardian: guardian.clone() },
+        );
+
+        publish_event(&env, (Symbol::new(&env, "payout_delay_set"), guardian), (threshold, delay));
+
+        Ok(())
+    }
+
+    pub fn get_payout_delay(env: Env) -> Option<PayoutDelayConfig> {
+        payout_delay::get_config(&env)
+    }
+
+    /// Time a delayed withdrawal can be executed, if pending
+    pub fn get_pending_withdrawal(env: Env, proposal_id: u64) -> Option<u64> {
+        payout_delay::pending_release_at(&env, proposal_id)
+    }
+
+    /// Cancel a delayed withdrawal during its delay; it is held until
+    /// governance clears it
+    pub fn cancel_withdrawal(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        guardian.require_auth();
+
+        payout_delay::cancel(&env, &guardian, proposal_id)?;
+
+        publish_event(&env, (Symbol::new(&env, "withdrawal_cancelled"), guardian), proposal_id);
+
+        Ok(())
+    }
+
+    /// Lift the hold on a cancelled withdrawal so it can be scheduled again
+    /// (governance only)
+    pub fn clear_withdrawal_hold(
+        env: Env,
+        caller: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        payout_delay::clear_hold(&env, proposal_id)?;
+
+        publish_event(&env, (Symbol::new(&env, "withdrawal_hold_cleared"), caller), proposal_id);
+
+        Ok(())
+    }
+
+    /// Turn withdrawal recipient allowlisting on or off (governance only)
+    pub fn set_allowlist_mode(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        allowlist::set_enabled(&env, enabled);
+
+        publish_event(&env, (Symbol::new(&env, "allowlist_mode_set"), caller), enabled);
+
+        Ok(())
+    }
+
+    /// Allowlist a withdrawal recipient; usable after the activation delay (governance only)
+    pub fn add_allowed_recipient(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+    ) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let active_at = allowlist::add_recipient(&env, &recipient);
+
+        publish_event(&env, (Symbol::new(&env, "recipient_allowlisted"), recipient), active_at);
+
+        Ok(active_at)
+    }
+
+    /// Remove a withdrawal recipient from the allowlist (governance only)
+    pub fn remove_allowed_recipient(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        allowlist::remove_recipient(&env, &recipient);
+
+        publish_event(&env, (Symbol::new(&env, "recipient_removed"), recipient), caller);
+
+        Ok(())
+    }
+
+    /// Time an allowlisted recipient becomes usable, if registered
+    pub fn get_allowed_recipient(env: Env, recipient: Address) -> Option<u64> {
+        allowlist::recipient_active_at(&env, &recipient)
+    }
+
+    pub fn is_allowlist_enabled(env: Env) -> bool {
+        allowlist::is_enabled(&env)
+    }
+
     /// Pause/unpause contract (admin only)
-    pub fn set_pause(env: Env, paused: bool) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn set_pause(env: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
         set_paused(&env, paused);
 
-        env.events().publish((Symbol::new(&env, "pause_state_changed"), ()), paused);
+        publish_event(&env, (Symbol::new(&env, "pause_state_changed"), ()), paused);
 
         Ok(())
     }
 
     /// Update fee percentage (admin only)
-    pub fn update_fee_percentage(env: Env, new_percentage: u32) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn update_fee_percentage(env: Env, admin: Address, new_percentage: u32) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         if new_percentage == 0 || new_percentage > 10000 {
             return Err(ContractError::InvalidInput);
@@ -687,8 +1047,7 @@ impl TreasuryContract {
         config.fee_percentage = new_percentage;
         env.storage().persistent().set(&CONFIG, &config);
 
-        env.events()
-            .publish((Symbol::new(&env, "fee_percentage_updated"), ()), new_percentage);
+        publish_event(&env, (Symbol::new(&env, "fee_percentage_updated"), ()), new_percentage);
 
         Ok(())
     }
@@ -705,27 +1064,59 @@ mod tests {
 
     fn create_test_env() -> (Env, Address, Address, Address) {
         let env = Env::default();
-        let admin = Address::random(&env);
-        let governance = Address::random(&env);
-        let trusted_contract = Address::random(&env);
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let governance = Address::generate(&env);
+        let trusted_contract = Address::generate(&env);
         (env, admin, governance, trusted_contract)
     }
 
+    fn init_config(admin: &Address, governance: &Address, fee_percentage: u32) -> InitConfig {
+        InitConfig {
+            admin: admin.clone(),
+            governance_contract: governance.clone(),
+            fee_percentage,
+        }
+    }
+
+    fn register(env: &Env) -> TreasuryContractClient<'_> {
+        TreasuryContractClient::new(env, &env.register(TreasuryContract, ()))
+    }
+
+    /// Registered treasury initialized by `admin` with `trusted` allowed to
+    /// deposit fees
+    fn setup<'a>(
+        env: &'a Env,
+        admin: &Address,
+        governance: &Address,
+        trusted: &Address,
+    ) -> TreasuryContractClient<'a> {
+        let client = register(env);
+        client.initialize(&init_config(admin, governance, 500));
+        client.register_trusted_contract(admin, trusted);
+        client
+    }
+
+    /// Propose a withdrawal of `amount` to a fresh recipient
+    fn propose(env: &Env, client: &TreasuryContractClient, amount: i128, purpose: u32) -> u64 {
+        client.propose_withdrawal(
+            &Address::generate(env),
+            &Address::generate(env),
+            &amount,
+            &purpose,
+            &Symbol::new(env, "Audit_funding"),
+        )
+    }
+
     #[test]
     fn test_initialize_treasury() {
         let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
 
-        let result = TreasuryContract::initialize(
-            env.clone(),
-            admin.clone(),
-            governance.clone(),
-            500, // 5% fee
-        );
-
-        assert!(result.is_ok());
+        client.initialize(&init_config(&admin, &governance, 500));
 
         // Verify initial state
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats();
         assert_eq!(stats.total_balance, 0);
         assert_eq!(stats.total_fees_collected, 0);
         assert_eq!(stats.total_withdrawn, 0);
@@ -734,215 +1125,166 @@ mod tests {
     #[test]
     fn test_initialize_already_initialized() {
         let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        client.initialize(&init_config(&admin, &governance, 500));
 
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500);
+        let result = client.try_initialize(&init_config(&admin, &governance, 500));
 
-        assert_eq!(result, Err(ContractError::AlreadyInitialized));
+        assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
     }
 
     #[test]
     fn test_initialize_invalid_fee_percentage() {
         let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
 
         // Test with 0%
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_initialize(&init_config(&admin, &governance, 0));
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
 
         // Test with > 100%
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 10001);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_initialize(&init_config(&admin, &governance, 10001));
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
     }
 
     #[test]
     fn test_register_trusted_contract() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = register(&env);
+
+        client.initialize(&init_config(&admin, &governance, 500));
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        assert!(client.try_register_trusted_contract(&admin, &trusted).is_ok());
 
-        let result = TreasuryContract::register_trusted_contract(env.clone(), trusted.clone());
-        assert!(result.is_ok());
+        // Only the admin can register
+        let outsider = Address::generate(&env);
+        let result = client.try_register_trusted_contract(&outsider, &trusted);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_deposit_premium_fee_without_trust() {
-        let (env, admin, governance, untrusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let depositor = Address::generate(&env);
+        let result = client.try_deposit_premium_fee(&depositor, &1000);
 
-        assert_eq!(result, Err(ContractError::NotTrustedContract));
+        assert_eq!(result, Err(Ok(ContractError::NotTrustedContract)));
     }
 
     #[test]
     fn test_deposit_premium_fee_success() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         env.ledger().set_sequence_number(1);
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
 
-        assert!(result.is_ok());
+        client.deposit_premium_fee(&trusted, &1000);
 
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 1000);
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats();
         assert_eq!(stats.total_fees_collected, 1000);
     }
 
     #[test]
     fn test_deposit_premium_fee_invalid_amount() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let result = client.try_deposit_premium_fee(&trusted, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidAmount));
-
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), -100);
-        assert_eq!(result, Err(ContractError::InvalidAmount));
+        let result = client.try_deposit_premium_fee(&trusted, &-100);
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
     }
 
     #[test]
     fn test_deposit_claim_penalty() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        client.deposit_claim_penalty(&trusted, &5000);
 
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_claim_penalty(env.clone(), depositor.clone(), 5000);
-
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 5000);
     }
 
     #[test]
     fn test_deposit_slashing_fee() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_slashing_fee(env.clone(), depositor.clone(), 2000);
+        client.deposit_slashing_fee(&trusted, &2000);
 
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 2000);
     }
 
     #[test]
     fn test_multiple_fee_deposits() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        client.deposit_premium_fee(&trusted, &1000);
+        client.deposit_claim_penalty(&trusted, &500);
+        client.deposit_slashing_fee(&trusted, &250);
 
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
-        TreasuryContract::deposit_claim_penalty(env.clone(), depositor.clone(), 500).unwrap();
-        TreasuryContract::deposit_slashing_fee(env.clone(), depositor.clone(), 250).unwrap();
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 1750);
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats();
         assert_eq!(stats.total_fees_collected, 1750);
     }
 
     #[test]
     fn test_deposit_fee_generic() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        client.deposit_fee(&trusted, &3000, &4); // Other fee type
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_fee(env.clone(), depositor.clone(), 3000, 4); // Other fee type
-
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 3000);
     }
 
     #[test]
     fn test_deposit_when_paused() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        TreasuryContract::set_pause(env.clone(), true).unwrap();
+        client.set_pause(&admin, &true);
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let result = client.try_deposit_premium_fee(&trusted, &1000);
 
-        assert_eq!(result, Err(ContractError::Paused));
+        assert_eq!(result, Err(Ok(ContractError::Paused)));
     }
 
     #[test]
     fn test_propose_withdrawal_success() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit funds first
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        client.deposit_premium_fee(&trusted, &10000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let result = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1, // AuditFunding
-            Symbol::new(&env, "Audit funding"),
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &5000,
+            &1, // AuditFunding
+            &Symbol::new(&env, "Audit_funding"),
         );
-
-        assert!(result.is_ok());
-        let proposal_id = result.unwrap();
         assert_eq!(proposal_id, 1);
 
         // Verify proposal exists
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert_eq!(proposal.amount, 5000);
         assert_eq!(proposal.recipient, recipient);
         assert_eq!(proposal.status, 0); // Active
@@ -951,296 +1293,293 @@ mod tests {
     #[test]
     fn test_propose_withdrawal_insufficient_funds() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit only 1000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
+        client.deposit_premium_fee(&trusted, &1000);
 
         // Try to propose withdrawal of 5000
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let result = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
+        let result = client.try_propose_withdrawal(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &5000,
+            &1,
+            &Symbol::new(&env, "Audit_funding"),
         );
 
-        assert_eq!(result, Err(ContractError::InsufficientFunds));
+        assert_eq!(result, Err(Ok(ContractError::InsufficientFunds)));
     }
 
     #[test]
     fn test_approve_and_execute_withdrawal() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        client.deposit_premium_fee(&trusted, &10000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
+        let proposal_id = propose(&env, &client, 5000, 1);
+
+        // Approval waits for the voting period
+        let early = client.try_approve_proposal(&admin, &proposal_id);
+        assert_eq!(early, Err(Ok(ContractError::VotingPeriodEnded)));
 
         // Simulate time passing beyond voting period (7 days)
         env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
 
         // Approve proposal
-        let approve_result = TreasuryContract::approve_proposal(env.clone(), proposal_id);
-        assert!(approve_result.is_ok());
+        client.approve_proposal(&admin, &proposal_id);
 
         // Verify proposal status changed to approved
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert_eq!(proposal.status, 1); // Approved
 
         // Execute withdrawal
-        let execute_result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert!(execute_result.is_ok());
+        client.execute_withdrawal(&admin, &proposal_id);
 
         // Verify balance decreased
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 5000);
 
         // Verify proposal marked as executed
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert!(proposal.executed);
 
         // Verify total withdrawn increased
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats();
         assert_eq!(stats.total_withdrawn, 5000);
     }
 
     #[test]
     fn test_execute_withdrawal_insufficient_funds() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit only 2000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 2000).unwrap();
-
-        // Create withdrawal proposal for 5000
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            1500,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
+        client.deposit_premium_fee(&trusted, &2000);
+
+        // Two proposals that together exceed the balance
+        let first = propose(&env, &client, 1500, 1);
+        let second = propose(&env, &client, 1500, 1);
 
         env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
 
-        TreasuryContract::approve_proposal(env.clone(), proposal_id).unwrap();
+        client.approve_proposal(&admin, &first);
+        client.approve_proposal(&admin, &second);
 
         // Withdraw some funds to reduce balance
-        let execute_result =
-            TreasuryContract::execute_withdrawal(env.clone(), proposal_id).unwrap();
+        client.execute_withdrawal(&admin, &first);
+
+        // The second no longer fits in what is left
+        let result = client.try_execute_withdrawal(&admin, &second);
+        assert_eq!(result, Err(Ok(ContractError::InsufficientFunds)));
 
         // Try to execute same proposal again - should fail
-        let result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert_eq!(result, Err(ContractError::InvalidState)); // Already executed
+        let result = client.try_execute_withdrawal(&admin, &first);
+        assert_eq!(result, Err(Ok(ContractError::InvalidState))); // Already executed
     }
 
     #[test]
     fn test_reject_proposal() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        client.deposit_premium_fee(&trusted, &10000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
+        let proposal_id = propose(&env, &client, 5000, 1);
 
         // Reject proposal
-        let result = TreasuryContract::reject_proposal(env.clone(), proposal_id);
-        assert!(result.is_ok());
+        client.reject_proposal(&admin, &proposal_id);
 
         // Verify proposal status changed to rejected
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert_eq!(proposal.status, 2); // Rejected
 
         // Try to execute rejected proposal - should fail
-        let execute_result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert_eq!(execute_result, Err(ContractError::ProposalNotApproved));
+        let execute_result = client.try_execute_withdrawal(&admin, &proposal_id);
+        assert_eq!(execute_result, Err(Ok(ContractError::ProposalNotApproved)));
     }
 
     #[test]
-    fn test_pause_unpause() {
+    fn test_admin_calls_reject_other_callers() {
         let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
+        client.deposit_premium_fee(&trusted, &10000);
+        let proposal_id = propose(&env, &client, 5000, 1);
+        env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let outsider = Address::generate(&env);
+        let unauthorized = Err(Ok(ContractError::Unauthorized));
+        assert_eq!(client.try_approve_proposal(&outsider, &proposal_id), unauthorized);
+        assert_eq!(client.try_reject_proposal(&outsider, &proposal_id), unauthorized);
+        assert_eq!(client.try_set_pause(&outsider, &true), unauthorized);
+        assert_eq!(client.try_update_fee_percentage(&outsider, &1000), unauthorized);
 
-        env.mock_all_auths();
+        client.approve_proposal(&admin, &proposal_id);
+        assert_eq!(client.try_execute_withdrawal(&outsider, &proposal_id), unauthorized);
+        assert_eq!(client.get_balance(), 10000);
+    }
+
+    #[test]
+    fn test_pause_unpause() {
+        let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Pause contract
-        let result = TreasuryContract::set_pause(env.clone(), true);
-        assert!(result.is_ok());
+        client.set_pause(&admin, &true);
 
         // Verify deposits fail
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
-        assert_eq!(result, Err(ContractError::Paused));
+        let result = client.try_deposit_premium_fee(&trusted, &1000);
+        assert_eq!(result, Err(Ok(ContractError::Paused)));
 
         // Unpause contract
-        let result = TreasuryContract::set_pause(env.clone(), false);
-        assert!(result.is_ok());
+        client.set_pause(&admin, &false);
 
         // Verify deposits work again
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
-        assert!(result.is_ok());
+        assert!(client.try_deposit_premium_fee(&trusted, &1000).is_ok());
     }
 
     #[test]
     fn test_update_fee_percentage() {
         let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-
-        env.mock_all_auths();
+        client.initialize(&init_config(&admin, &governance, 500));
 
         // Update fee percentage
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 1000); // 10%
-        assert!(result.is_ok());
+        assert!(client.try_update_fee_percentage(&admin, &1000).is_ok()); // 10%
 
         // Try invalid percentage (> 100%)
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 10001);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_update_fee_percentage(&admin, &10001);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
 
         // Try 0%
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_update_fee_percentage(&admin, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
     }
 
     #[test]
     fn test_allocation_tracking() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 20000).unwrap();
+        client.deposit_premium_fee(&trusted, &20000);
 
         // Create multiple withdrawal proposals
-        let recipient1 = Address::random(&env);
-        let recipient2 = Address::random(&env);
-        let proposer = Address::random(&env);
-
-        let proposal_id1 = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient1.clone(),
-            5000,
-            1, // AuditFunding
-            Symbol::new(&env, "Audit 1"),
-        )
-        .unwrap();
-
-        let proposal_id2 = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient2.clone(),
-            3000,
-            2, // DevelopmentGrants
-            Symbol::new(&env, "Development grant"),
-        )
-        .unwrap();
+        let proposal_id1 = propose(&env, &client, 5000, 1); // AuditFunding
+        let proposal_id2 = propose(&env, &client, 3000, 2); // DevelopmentGrants
 
         env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
 
         // Approve and execute both proposals
-        TreasuryContract::approve_proposal(env.clone(), proposal_id1).unwrap();
-        TreasuryContract::execute_withdrawal(env.clone(), proposal_id1).unwrap();
+        client.approve_proposal(&admin, &proposal_id1);
+        client.execute_withdrawal(&admin, &proposal_id1);
 
-        TreasuryContract::approve_proposal(env.clone(), proposal_id2).unwrap();
-        TreasuryContract::execute_withdrawal(env.clone(), proposal_id2).unwrap();
+        client.approve_proposal(&admin, &proposal_id2);
+        client.execute_withdrawal(&admin, &proposal_id2);
 
         // Verify allocations
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance();
         assert_eq!(balance, 12000); // 20000 - 5000 - 3000
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats();
         assert_eq!(stats.total_withdrawn, 8000);
         assert_eq!(stats.total_fees_collected, 20000);
         assert_eq!(stats.total_balance, 12000);
+        assert_eq!(client.get_allocation(&1).total_withdrawn, 5000);
+        assert_eq!(client.get_allocation(&2).total_withdrawn, 3000);
     }
 
     #[test]
     fn test_treasury_invariants() {
         let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
+        let client = setup(&env, &admin, &governance, &trusted);
 
         // Test overflow prevention
-        let result =
-            TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), i128::MAX);
-        assert!(result.is_ok());
+        assert!(client.try_deposit_premium_fee(&trusted, &i128::MAX).is_ok());
 
         // Trying to add more should fail
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1);
-        assert_eq!(result, Err(ContractError::Overflow));
+        let result = client.try_deposit_premium_fee(&trusted, &1);
+        assert_eq!(result, Err(Ok(ContractError::Overflow)));
     }
 
     #[test]
     fn test_get_proposal_not_found() {
         let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
+
+        client.initialize(&init_config(&admin, &governance, 500));
+
+        let result = client.try_get_proposal(&999);
+        assert_eq!(result, Err(Ok(ContractError::NotFound)));
+    }
+
+    #[test]
+    fn test_allowlist_recipient_activation_delay() {
+        let (env, admin, governance, _) = create_test_env();
+        let client = register(&env);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        client.initialize(&init_config(&admin, &governance, 500));
 
-        let result = TreasuryContract::get_proposal(env.clone(), 999);
-        assert_eq!(result, Err(ContractError::NotFound));
+        let recipient = Address::generate(&env);
+        client.set_allowlist_mode(&admin, &true);
+        assert!(client.is_allowlist_enabled());
+
+        let active_at = client.add_allowed_recipient(&admin, &recipient);
+        assert_eq!(active_at, env.ledger().timestamp() + allowlist::RECIPIENT_ACTIVATION_DELAY);
+        assert_eq!(client.get_allowed_recipient(&recipient), Some(active_at));
+        env.as_contract(&client.address, || {
+            assert_eq!(
+                allowlist::require_allowed(&env, &recipient),
+                Err(AllowlistError::RecipientPending)
+            );
+        });
+
+        env.ledger().set_timestamp(active_at);
+        env.as_contract(&client.address, || {
+            assert_eq!(allowlist::require_allowed(&env, &recipient), Ok(()));
+        });
+
+        client.remove_allowed_recipient(&admin, &recipient);
+        env.as_contract(&client.address, || {
+            assert_eq!(
+                allowlist::require_allowed(&env, &recipient),
+                Err(AllowlistError::RecipientNotAllowed)
+            );
+        });
+    }
+
+    #[test]
+    fn test_governance_contract_holds_admin_powers_until_cleared() {
+        let (env, admin, governance, trusted) = create_test_env();
+        let client = setup(&env, &admin, &governance, &trusted);
+        let governance_contract = Address::generate(&env);
+
+        assert_eq!(
+            client.try_set_governance_contract(&trusted, &Some(governance_contract.clone())),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_update_fee_percentage(&governance_contract, &300),
+            Err(Ok(ContractError::Unauthorized))
+        );
+
+        client.set_governance_contract(&admin, &Some(governance_contract.clone()));
+        assert_eq!(client.get_governance_contract(), Some(governance_contract.clone()));
+        client.update_fee_percentage(&governance_contract, &300);
+        client.set_allowlist_mode(&governance_contract, &true);
+        assert!(client.is_allowlist_enabled());
+
+        // Clearing it takes the powers back
+        client.set_governance_contract(&admin, &None);
+        assert_eq!(client.get_governance_contract(), None);
+        assert_eq!(
+            client.try_set_allowlist_mode(&governance_contract, &false),
+            Err(Ok(ContractError::Unauthorized))
+        );
     }
 }
@@ -0,0 +1,597 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+// Import authorization from the common library
+use insurance_contracts::authorization::{initialize_admin, require_admin, require_governance_permission};
+use insurance_contracts::events::{last_event_seq, publish_event};
+use insurance_contracts::math::{mul_div_floor, MathError};
+use insurance_contracts::version::{self, ContractVersion, IFACE_VERSION};
+
+#[contract]
+pub struct VestingContract;
+
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const SCHEDULE: Symbol = symbol_short!("SCHEDULE");
+const SCHEDULE_COUNTER: Symbol = symbol_short!("SCHED_CNT");
+const BENEFICIARY: Symbol = symbol_short!("BENEF");
+
+// Upper bound on schedules per beneficiary
+const MAX_SCHEDULES_PER_BENEFICIARY: u32 = 20;
+
+/// Token allocation released linearly between `cliff` and `end`. Nothing is
+/// claimable before the cliff; at the cliff everything vested since `start`
+/// unlocks at once.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub beneficiary: Address,
+    pub total: i128,
+    pub claimed: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+    pub revocable: bool,
+    pub revoked_at: Option<u64>,
+}
+
+/// Terms of a new schedule for `create_schedule`. Vesting runs linearly
+/// from `start` over `duration` seconds with nothing claimable for the
+/// first `cliff_duration` seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleTerms {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start: u64,
+    pub cliff_duration: u64,
+    pub duration: u64,
+    pub revocable: bool,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ContractError {
+    Unauthorized = 1,
+    Paused = 2,
+    InvalidInput = 3,
+    InsufficientFunds = 4,
+    NotFound = 5,
+    AlreadyExists = 6,
+    InvalidState = 7,
+    NotInitialized = 8,
+    AlreadyInitialized = 9,
+    InvalidRole = 10,
+    RoleNotFound = 11,
+    NotTrustedContract = 12,
+    NotRevocable = 13,
+    AlreadyRevoked = 14,
+    NothingToClaim = 15,
+    TooManySchedules = 16,
+    Overflow = 107,
+}
+
+impl From<insurance_contracts::authorization::AuthError> for ContractError {
+    fn from(err: insurance_contracts::authorization::AuthError) -> Self {
+        match err {
+            insurance_contracts::authorization::AuthError::Unauthorized => ContractError::Unauthorized,
+            insurance_contracts::authorization::AuthError::InvalidRole => ContractError::InvalidRole,
+            insurance_contracts::authorization::AuthError::RoleNotFound => ContractError::RoleNotFound,
+            insurance_contracts::authorization::AuthError::NotTrustedContract => ContractError::NotTrustedContract,
+        }
+    }
+}
+
+impl From<MathError> for ContractError {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::Overflow => ContractError::Overflow,
+            MathError::DivisionByZero => ContractError::InvalidState,
+        }
+    }
+}
+
+fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&PAUSED)
+        .unwrap_or(false)
+}
+
+fn set_paused(env: &Env, paused: bool) {
+    env.storage()
+        .persistent()
+        .set(&PAUSED, &paused);
+}
+
+fn vesting_token(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&CONFIG)
+        .ok_or(ContractError::NotInitialized)
+}
+
+fn get_schedule(env: &Env, schedule_id: u64) -> Result<VestingSchedule, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(SCHEDULE, schedule_id))
+        .ok_or(ContractError::NotFound)
+}
+
+/// Amount of the schedule vested at `now`. Revocation cuts `total` down to
+/// what had vested by then, so a revoked schedule is fully vested.
+fn vested_amount(schedule: &VestingSchedule, now: u64) -> Result<i128, ContractError> {
+    if schedule.revoked_at.is_some() {
+        return Ok(schedule.total);
+    }
+    if now < schedule.cliff {
+        return Ok(0);
+    }
+    if now >= schedule.end {
+        return Ok(schedule.total);
+    }
+    Ok(mul_div_floor(
+        schedule.total,
+        (now - schedule.start) as i128,
+        (schedule.end - schedule.start) as i128,
+    )?)
+}
+
+/// I4: Validate amount is positive and within safe range
+fn validate_amount(amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl VestingContract {
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), ContractError> {
+        if insurance_contracts::authorization::get_admin(&env).is_some() {
+            return Err(ContractError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        initialize_admin(&env, admin.clone());
+
+        env.storage().persistent().set(&CONFIG, &token);
+        env.storage().persistent().set(&SCHEDULE_COUNTER, &0u64);
+        set_paused(&env, false);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "initialized"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    /// Create a schedule on `terms` funded by `funder` (governance only)
+    pub fn create_schedule(env: Env, caller: Address, funder: Address, terms: ScheduleTerms) -> Result<u64, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+        funder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let ScheduleTerms { beneficiary, amount, start, cliff_duration, duration, revocable } = terms;
+        validate_amount(amount)?;
+        if duration == 0 || cliff_duration > duration {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut schedule_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(BENEFICIARY, beneficiary.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        if schedule_ids.len() >= MAX_SCHEDULES_PER_BENEFICIARY {
+            return Err(ContractError::TooManySchedules);
+        }
+
+        let token = vesting_token(&env)?;
+        token::Client::new(&env, &token).transfer(&funder, env.current_contract_address(), &amount);
+
+        let schedule_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&SCHEDULE_COUNTER)
+            .unwrap_or(0u64)
+            + 1;
+        let schedule = VestingSchedule {
+            beneficiary: beneficiary.clone(),
+            total: amount,
+            claimed: 0,
+            start,
+            cliff: start.checked_add(cliff_duration).ok_or(ContractError::Overflow)?,
+            end: start.checked_add(duration).ok_or(ContractError::Overflow)?,
+            revocable,
+            revoked_at: None,
+        };
+        env.storage().persistent().set(&(SCHEDULE, schedule_id), &schedule);
+        env.storage().persistent().set(&SCHEDULE_COUNTER, &schedule_id);
+
+        schedule_ids.push_back(schedule_id);
+        env.storage()
+            .persistent()
+            .set(&(BENEFICIARY, beneficiary.clone()), &schedule_ids);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "schedule_created"), beneficiary),
+            (schedule_id, amount, schedule.cliff, schedule.end),
+        );
+
+        Ok(schedule_id)
+    }
+
+    /// Add tokens to a live schedule, typically from the treasury's
+    /// allocation for DAO operations. The top-up vests on the schedule's
+    /// existing curve.
+    pub fn top_up(env: Env, funder: Address, schedule_id: u64, amount: i128) -> Result<(), ContractError> {
+        funder.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        validate_amount(amount)?;
+
+        let mut schedule = get_schedule(&env, schedule_id)?;
+        if schedule.revoked_at.is_some() {
+            return Err(ContractError::AlreadyRevoked);
+        }
+
+        let token = vesting_token(&env)?;
+        token::Client::new(&env, &token).transfer(&funder, env.current_contract_address(), &amount);
+
+        schedule.total = schedule.total.checked_add(amount).ok_or(ContractError::Overflow)?;
+        env.storage().persistent().set(&(SCHEDULE, schedule_id), &schedule);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "schedule_topped_up"), funder),
+            (schedule_id, amount, schedule.total),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer everything vested and not yet claimed to the beneficiary
+    pub fn claim(env: Env, beneficiary: Address, schedule_id: u64) -> Result<i128, ContractError> {
+        beneficiary.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut schedule = get_schedule(&env, schedule_id)?;
+        if schedule.beneficiary != beneficiary {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let claimable = vested_amount(&schedule, env.ledger().timestamp())? - schedule.claimed;
+        if claimable <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        schedule.claimed += claimable;
+        env.storage().persistent().set(&(SCHEDULE, schedule_id), &schedule);
+
+        let token = vesting_token(&env)?;
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &beneficiary, &claimable);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "vesting_claimed"), beneficiary),
+            (schedule_id, claimable, schedule.claimed),
+        );
+
+        Ok(claimable)
+    }
+
+    /// Stop a revocable schedule (governance only). Tokens vested so far stay
+    /// claimable by the beneficiary; the unvested rest goes to `refund_to`.
+    pub fn revoke(env: Env, caller: Address, schedule_id: u64, refund_to: Address) -> Result<i128, ContractError> {
+        caller.require_auth();
+        require_governance_permission(&env, &caller)?;
+
+        let mut schedule = get_schedule(&env, schedule_id)?;
+        if !schedule.revocable {
+            return Err(ContractError::NotRevocable);
+        }
+        if schedule.revoked_at.is_some() {
+            return Err(ContractError::AlreadyRevoked);
+        }
+
+        let now = env.ledger().timestamp();
+        let vested = vested_amount(&schedule, now)?;
+        let unvested = schedule.total - vested;
+        schedule.total = vested;
+        schedule.revoked_at = Some(now);
+        env.storage().persistent().set(&(SCHEDULE, schedule_id), &schedule);
+
+        if unvested > 0 {
+            let token = vesting_token(&env)?;
+            token::Client::new(&env, &token).transfer(&env.current_contract_address(), &refund_to, &unvested);
+        }
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "schedule_revoked"), schedule.beneficiary),
+            (schedule_id, vested, unvested),
+        );
+
+        Ok(unvested)
+    }
+
+    pub fn get_schedule(env: Env, schedule_id: u64) -> Result<VestingSchedule, ContractError> {
+        get_schedule(&env, schedule_id)
+    }
+
+    pub fn get_beneficiary_schedules(env: Env, beneficiary: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&(BENEFICIARY, beneficiary))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_vested(env: Env, schedule_id: u64) -> Result<i128, ContractError> {
+        let schedule = get_schedule(&env, schedule_id)?;
+        vested_amount(&schedule, env.ledger().timestamp())
+    }
+
+    pub fn get_claimable(env: Env, schedule_id: u64) -> Result<i128, ContractError> {
+        let schedule = get_schedule(&env, schedule_id)?;
+        Ok(vested_amount(&schedule, env.ledger().timestamp())? - schedule.claimed)
+    }
+
+    /// Crate version and the wasm hash installed by the last upgrade
+    pub fn version(env: Env) -> ContractVersion {
+        version::get(&env, env!("CARGO_PKG_VERSION"))
+    }
+
+    /// Interface ids implemented by this contract
+    pub fn supported_interfaces(env: Env) -> Vec<Symbol> {
+        version::interfaces(&env, &["vesting_v1", IFACE_VERSION])
+    }
+
+    /// Replace the contract code (admin only). The new hash is reported by
+    /// `version`.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        version::upgrade(&env, &new_wasm_hash);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "upgraded"), ()),
+            new_wasm_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Sequence number of the last event published by this contract
+    pub fn get_last_event_seq(env: Env) -> u64 {
+        last_event_seq(&env)
+    }
+
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, true);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "paused"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        set_paused(&env, false);
+
+        publish_event(
+            &env,
+            (Symbol::new(&env, "unpaused"), ()),
+            admin,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+    struct Setup<'a> {
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        beneficiary: Address,
+        token: TokenClient<'a>,
+        client: VestingContractClient<'a>,
+    }
+
+    fn setup<'a>() -> Setup<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        StellarAssetClient::new(&env, &token).mint(&treasury, &10_000);
+
+        let client = VestingContractClient::new(&env, &env.register(VestingContract, ()));
+        client.initialize(&admin, &token);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        Setup { token: TokenClient::new(&env, &token), env, admin, treasury, beneficiary, client }
+    }
+
+    /// 1_000 tokens over 1000s from 1_000 with a 250s cliff
+    fn terms(s: &Setup, revocable: bool) -> ScheduleTerms {
+        ScheduleTerms {
+            beneficiary: s.beneficiary.clone(),
+            amount: 1_000,
+            start: 1_000,
+            cliff_duration: 250,
+            duration: 1_000,
+            revocable,
+        }
+    }
+
+    fn at(env: &Env, timestamp: u64) {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+    }
+
+    #[test]
+    fn test_cliff_linear_claim_and_revoke() {
+        let s = setup();
+        let schedule_id = s.client.create_schedule(&s.admin, &s.treasury, &terms(&s, true));
+
+        at(&s.env, 1_100);
+        assert_eq!(s.client.get_claimable(&schedule_id), 0);
+        assert_eq!(s.client.try_claim(&s.beneficiary, &schedule_id), Err(Ok(ContractError::NothingToClaim)));
+
+        at(&s.env, 1_400);
+        assert_eq!(s.client.claim(&s.beneficiary, &schedule_id), 400);
+
+        // Treasury top-up vests on the same curve
+        s.client.top_up(&s.treasury, &schedule_id, &1_000);
+        assert_eq!(s.client.get_claimable(&schedule_id), 400);
+
+        at(&s.env, 1_500);
+        let refunded = s.client.revoke(&s.admin, &schedule_id, &s.treasury);
+        assert_eq!(refunded, 1_000);
+        assert_eq!(s.client.claim(&s.beneficiary, &schedule_id), 600);
+
+        at(&s.env, 3_000);
+        assert_eq!(s.client.get_claimable(&schedule_id), 0);
+
+        assert_eq!(s.token.balance(&s.beneficiary), 1_000);
+        assert_eq!(s.token.balance(&s.treasury), 9_000);
+    }
+
+    #[test]
+    fn test_invalid_terms_are_rejected() {
+        let s = setup();
+        for bad in [
+            ScheduleTerms { amount: 0, ..terms(&s, true) },
+            ScheduleTerms { duration: 0, cliff_duration: 0, ..terms(&s, true) },
+            ScheduleTerms { cliff_duration: 1_001, ..terms(&s, true) },
+        ] {
+            assert_eq!(
+                s.client.try_create_schedule(&s.admin, &s.treasury, &bad),
+                Err(Ok(ContractError::InvalidInput))
+            );
+        }
+        let stranger = Address::generate(&s.env);
+        assert_eq!(
+            s.client.try_create_schedule(&stranger, &s.treasury, &terms(&s, true)),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(s.token.balance(&s.treasury), 10_000);
+    }
+
+    #[test]
+    fn test_cliff_boundaries() {
+        let s = setup();
+        let schedule_id = s.client.create_schedule(&s.admin, &s.treasury, &terms(&s, false));
+
+        // Nothing a second before the cliff, everything since start at it
+        at(&s.env, 1_249);
+        assert_eq!(s.client.get_vested(&schedule_id), 0);
+        at(&s.env, 1_250);
+        assert_eq!(s.client.get_vested(&schedule_id), 250);
+
+        // Fully vested exactly at the end and no further after it
+        at(&s.env, 1_999);
+        assert_eq!(s.client.get_vested(&schedule_id), 999);
+        at(&s.env, 2_000);
+        assert_eq!(s.client.get_vested(&schedule_id), 1_000);
+        at(&s.env, 5_000);
+        assert_eq!(s.client.get_vested(&schedule_id), 1_000);
+
+        // A cliff as long as the schedule releases everything at the end
+        let all_at_end = ScheduleTerms { start: 5_000, cliff_duration: 1_000, ..terms(&s, false) };
+        let cliff_id = s.client.create_schedule(&s.admin, &s.treasury, &all_at_end);
+        at(&s.env, 5_999);
+        assert_eq!(s.client.get_vested(&cliff_id), 0);
+        at(&s.env, 6_000);
+        assert_eq!(s.client.get_vested(&cliff_id), 1_000);
+    }
+
+    #[test]
+    fn test_claims_never_exceed_vested() {
+        let s = setup();
+        let schedule_id = s.client.create_schedule(&s.admin, &s.treasury, &terms(&s, false));
+
+        at(&s.env, 1_300);
+        let stranger = Address::generate(&s.env);
+        assert_eq!(s.client.try_claim(&stranger, &schedule_id), Err(Ok(ContractError::Unauthorized)));
+        assert_eq!(s.client.claim(&s.beneficiary, &schedule_id), 300);
+        assert_eq!(s.client.try_claim(&s.beneficiary, &schedule_id), Err(Ok(ContractError::NothingToClaim)));
+
+        at(&s.env, 1_301);
+        assert_eq!(s.client.claim(&s.beneficiary, &schedule_id), 1);
+
+        // Past the end only the remainder is paid, once
+        at(&s.env, 9_000);
+        assert_eq!(s.client.claim(&s.beneficiary, &schedule_id), 699);
+        assert_eq!(s.client.try_claim(&s.beneficiary, &schedule_id), Err(Ok(ContractError::NothingToClaim)));
+        assert_eq!(s.token.balance(&s.beneficiary), 1_000);
+        assert_eq!(s.client.get_schedule(&schedule_id).claimed, 1_000);
+        assert_eq!(s.client.try_claim(&s.beneficiary, &99), Err(Ok(ContractError::NotFound)));
+    }
+
+    #[test]
+    fn test_revocation() {
+        let s = setup();
+        let fixed_id = s.client.create_schedule(&s.admin, &s.treasury, &terms(&s, false));
+        let schedule_id = s.client.create_schedule(&s.admin, &s.treasury, &terms(&s, true));
+        assert_eq!(
+            s.client.try_revoke(&s.admin, &fixed_id, &s.treasury),
+            Err(Ok(ContractError::NotRevocable))
+        );
+        assert_eq!(
+            s.client.try_revoke(&s.beneficiary, &schedule_id, &s.beneficiary),
+            Err(Ok(ContractError::Unauthorized))
+        );
+
+        // Revoked before the cliff everything is refunded
+        at(&s.env, 1_200);
+        assert_eq!(s.client.revoke(&s.admin, &schedule_id, &s.treasury), 1_000);
+        assert_eq!(s.token.balance(&s.treasury), 9_000);
+        assert_eq!(
+            s.client.try_revoke(&s.admin, &schedule_id, &s.treasury),
+            Err(Ok(ContractError::AlreadyRevoked))
+        );
+        assert_eq!(
+            s.client.try_top_up(&s.treasury, &schedule_id, &100),
+            Err(Ok(ContractError::AlreadyRevoked))
+        );
+
+        // Nothing vests after revocation
+        at(&s.env, 5_000);
+        assert_eq!(s.client.get_vested(&schedule_id), 0);
+        assert_eq!(s.client.try_claim(&s.beneficiary, &schedule_id), Err(Ok(ContractError::NothingToClaim)));
+        assert_eq!(s.client.claim(&s.beneficiary, &fixed_id), 1_000);
+    }
+}